@@ -0,0 +1,124 @@
+use crate::unit_formatter::{DisplayConfig, UnitFormatter};
+use serde_json::Value;
+
+/// Process completion responses to pretty-print whippyunits types in completion items
+#[derive(Clone)]
+pub struct CompletionProcessor {
+    formatter: UnitFormatter,
+    display_config: DisplayConfig,
+}
+
+impl Default for CompletionProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompletionProcessor {
+    pub fn new() -> Self {
+        Self {
+            formatter: UnitFormatter::new(),
+            display_config: DisplayConfig::default(),
+        }
+    }
+
+    pub fn with_config(display_config: DisplayConfig) -> Self {
+        Self {
+            formatter: UnitFormatter::new(),
+            display_config,
+        }
+    }
+
+    /// Check if a result is a completion response
+    ///
+    /// Accepts a `CompletionList` (`{ "isIncomplete": .., "items": [..] }`), a bare array of
+    /// completion items, or a single item (`completionItem/resolve`).  Completion items carry a
+    /// `label` but, unlike inlay hints, no `position`.
+    pub fn is_completion_response(&self, result: &Value) -> bool {
+        if let Some(obj) = result.as_object() {
+            if obj.contains_key("isIncomplete") {
+                return obj.get("items").is_some_and(Value::is_array);
+            }
+            return Self::is_completion_item(result);
+        }
+
+        if let Some(array) = result.as_array() {
+            return !array.is_empty() && array.iter().all(Self::is_completion_item);
+        }
+
+        false
+    }
+
+    fn is_completion_item(item: &Value) -> bool {
+        item.as_object().is_some_and(|obj| {
+            obj.get("label").is_some_and(Value::is_string) && !obj.contains_key("position")
+        })
+    }
+
+    /// Process a completion result in place, converting whippyunits types to pretty format
+    ///
+    /// Returns whether any item was changed.
+    pub fn process_completion_result(&self, result: &mut Value) -> bool {
+        let items = if let Some(items) = result.get_mut("items").and_then(Value::as_array_mut) {
+            items
+        } else if let Some(items) = result.as_array_mut() {
+            items
+        } else {
+            return self.process_completion_item(result);
+        };
+
+        let mut changed = false;
+        for item in items.iter_mut() {
+            changed |= self.process_completion_item(item);
+        }
+        changed
+    }
+
+    /// Process a single completion item, rewriting its `detail`, `labelDetails`, and
+    /// `documentation` fields if they contain Quantity types
+    fn process_completion_item(&self, item: &mut Value) -> bool {
+        let mut changed = false;
+
+        if let Some(detail) = item.get_mut("detail") {
+            changed |= self.format_string_value(detail);
+        }
+
+        if let Some(label_details) = item.get_mut("labelDetails") {
+            for field in ["detail", "description"] {
+                if let Some(text) = label_details.get_mut(field) {
+                    changed |= self.format_string_value(text);
+                }
+            }
+        }
+
+        if let Some(documentation) = item.get_mut("documentation") {
+            // Documentation is either a plain string or a MarkupContent object
+            if documentation.is_string() {
+                changed |= self.format_string_value(documentation);
+            } else if let Some(value) = documentation.get_mut("value") {
+                changed |= self.format_string_value(value);
+            }
+        }
+
+        changed
+    }
+
+    /// Format a JSON string value in place, leaving it untouched if it has no Quantity types
+    fn format_string_value(&self, value: &mut Value) -> bool {
+        let Some(text) = value.as_str() else {
+            return false;
+        };
+
+        if !text.contains("Quantity<") {
+            return false;
+        }
+
+        let formatted = self.formatter.format_types(text, &self.display_config);
+        if formatted == text {
+            return false;
+        }
+
+        *value = Value::String(formatted);
+        true
+    }
+}
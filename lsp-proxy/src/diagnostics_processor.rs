@@ -0,0 +1,87 @@
+use crate::unit_formatter::{DisplayConfig, UnitFormatter};
+use serde_json::Value;
+
+/// Process diagnostics notifications to pretty-print whippyunits types in diagnostic messages
+#[derive(Clone)]
+pub struct DiagnosticsProcessor {
+    formatter: UnitFormatter,
+    display_config: DisplayConfig,
+}
+
+impl Default for DiagnosticsProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DiagnosticsProcessor {
+    pub fn new() -> Self {
+        Self {
+            formatter: UnitFormatter::new(),
+            display_config: DisplayConfig::default(),
+        }
+    }
+
+    pub fn with_config(display_config: DisplayConfig) -> Self {
+        Self {
+            formatter: UnitFormatter::new(),
+            display_config,
+        }
+    }
+
+    /// Check if a method is a diagnostics notification
+    pub fn is_diagnostics_notification(&self, method: Option<&str>) -> bool {
+        method == Some("textDocument/publishDiagnostics")
+    }
+
+    /// Process the params of a `textDocument/publishDiagnostics` notification in place,
+    /// converting whippyunits types in each diagnostic's `message` to pretty format
+    ///
+    /// Messages of related information are rewritten too.  Only the `Quantity<...>` types in a
+    /// message are replaced; the rest of its text, and every range, is left as it was.
+    ///
+    /// Returns whether any diagnostic was changed.
+    pub fn process_diagnostics_params(&self, params: &mut Value) -> bool {
+        let Some(diagnostics) = params.get_mut("diagnostics").and_then(Value::as_array_mut) else {
+            return false;
+        };
+
+        let mut changed = false;
+        for diagnostic in diagnostics.iter_mut() {
+            if let Some(message) = diagnostic.get_mut("message") {
+                changed |= self.format_string_value(message);
+            }
+
+            if let Some(related) = diagnostic
+                .get_mut("relatedInformation")
+                .and_then(Value::as_array_mut)
+            {
+                for information in related.iter_mut() {
+                    if let Some(message) = information.get_mut("message") {
+                        changed |= self.format_string_value(message);
+                    }
+                }
+            }
+        }
+        changed
+    }
+
+    /// Format a JSON string value in place, leaving it untouched if it has no Quantity types
+    fn format_string_value(&self, value: &mut Value) -> bool {
+        let Some(text) = value.as_str() else {
+            return false;
+        };
+
+        if !text.contains("Quantity<") {
+            return false;
+        }
+
+        let formatted = self.formatter.format_types(text, &self.display_config);
+        if formatted == text {
+            return false;
+        }
+
+        *value = Value::String(formatted);
+        true
+    }
+}
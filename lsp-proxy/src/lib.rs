@@ -2,6 +2,8 @@ use anyhow::Result;
 use log::warn;
 use serde_json::Value;
 
+pub mod completion_processor;
+pub mod diagnostics_processor;
 pub mod hover_processor;
 pub mod inlay_hint_processor;
 pub mod lsp_structures;
@@ -11,6 +13,8 @@ pub mod unit_formatter;
 #[cfg(test)]
 mod tests;
 
+use completion_processor::CompletionProcessor;
+use diagnostics_processor::DiagnosticsProcessor;
 use hover_processor::HoverProcessor;
 use inlay_hint_processor::InlayHintProcessor;
 use lsp_structures::LspMessage;
@@ -18,17 +22,20 @@ use lsp_structures::LspMessage;
 // Re-export for public API
 pub use unit_formatter::DisplayConfig;
 
-/// LSP Proxy that intercepts and modifies hover responses
+/// LSP Proxy that intercepts and modifies hover, inlay hint, and completion responses, and
+/// diagnostics notifications
 #[derive(Clone)]
 pub struct LspProxy {
     hover_processor: HoverProcessor,
     inlay_hint_processor: InlayHintProcessor,
+    completion_processor: CompletionProcessor,
+    diagnostics_processor: DiagnosticsProcessor,
 }
 
 impl LspProxy {
     pub fn new() -> Self {
         let display_config = DisplayConfig::default();
-        // Create a non-verbose config for inlay hints
+        // Create a non-verbose config for inlay hints, completion items, and diagnostics
         let inlay_hint_config = DisplayConfig {
             verbose: false,
             unicode: true,
@@ -36,12 +43,14 @@ impl LspProxy {
         };
         Self {
             hover_processor: HoverProcessor::new(display_config),
-            inlay_hint_processor: InlayHintProcessor::with_config(inlay_hint_config),
+            inlay_hint_processor: InlayHintProcessor::with_config(inlay_hint_config.clone()),
+            completion_processor: CompletionProcessor::with_config(inlay_hint_config.clone()),
+            diagnostics_processor: DiagnosticsProcessor::with_config(inlay_hint_config),
         }
     }
 
     pub fn with_config(display_config: DisplayConfig) -> Self {
-        // Create a non-verbose config for inlay hints
+        // Create a non-verbose config for inlay hints, completion items, and diagnostics
         let inlay_hint_config = DisplayConfig {
             verbose: false,
             unicode: display_config.unicode,
@@ -49,7 +58,9 @@ impl LspProxy {
         };
         Self {
             hover_processor: HoverProcessor::new(display_config),
-            inlay_hint_processor: InlayHintProcessor::with_config(inlay_hint_config),
+            inlay_hint_processor: InlayHintProcessor::with_config(inlay_hint_config.clone()),
+            completion_processor: CompletionProcessor::with_config(inlay_hint_config.clone()),
+            diagnostics_processor: DiagnosticsProcessor::with_config(inlay_hint_config),
         }
     }
 
@@ -119,6 +130,32 @@ impl LspProxy {
             }
         }
 
+        // Check if this is a completion response (list, item array, or resolved item)
+        if !needs_processing {
+            if let Some(result) = &mut lsp_msg.result {
+                if self.completion_processor.is_completion_response(result)
+                    && self.completion_processor.process_completion_result(result)
+                {
+                    needs_processing = true;
+                }
+            }
+        }
+
+        // Check if this is a diagnostics notification
+        if self
+            .diagnostics_processor
+            .is_diagnostics_notification(lsp_msg.method.as_deref())
+        {
+            if let Some(params) = &mut lsp_msg.params {
+                if self
+                    .diagnostics_processor
+                    .process_diagnostics_params(params)
+                {
+                    needs_processing = true;
+                }
+            }
+        }
+
         // Only reconstruct if we actually modified something
         if needs_processing {
             match serde_json::to_string(&lsp_msg) {
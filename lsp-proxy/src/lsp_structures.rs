@@ -2,13 +2,21 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// LSP Message structure
+///
+/// Absent fields are omitted when serializing, so that a notification (which has no `id`) is
+/// not turned into a response.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LspMessage {
     pub jsonrpc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub params: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<Value>,
 }
 
@@ -49,6 +49,11 @@ pub fn validate_quantity_format(json_payload: &str) -> bool {
                     if has_scale && has_dimension {
                         return true; // Found a valid Quantity type
                     }
+
+                    // Legacy flat format: Quantity<M, L, T, I, Θ, N, J, A[, P2, P3, P5, PI[, T]]>
+                    if is_flat_quantity_format(inner_content) {
+                        return true;
+                    }
                 }
             }
         }
@@ -57,6 +62,44 @@ pub fn validate_quantity_format(json_payload: &str) -> bool {
     false
 }
 
+/// Sentinel used by legacy flat Quantity types for unused parameters (`i64::MAX`)
+pub const UNUSED_SENTINEL: &str = "9223372036854775807";
+
+/// Check whether the contents of `Quantity<...>` use the legacy flat parameter format:
+/// 8 dimension exponents, optionally followed by 4 scale exponents and then the storage type
+pub fn is_flat_quantity_format(inner_content: &str) -> bool {
+    let args = split_top_level_args(inner_content);
+    let numeric_count = match args.len() {
+        8 | 12 => args.len(),
+        13 => 12,
+        _ => return false,
+    };
+
+    args[..numeric_count]
+        .iter()
+        .all(|arg| *arg == "_" || *arg == UNUSED_SENTINEL || arg.parse::<i16>().is_ok())
+}
+
+/// Split generic arguments on top-level commas, trimming whitespace
+pub fn split_top_level_args(content: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut arg_start = 0;
+    for (i, ch) in content.char_indices() {
+        match ch {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(content[arg_start..i].trim());
+                arg_start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(content[arg_start..].trim());
+    args
+}
+
 /// Find the matching closing angle bracket for a Quantity type
 pub fn find_matching_angle_bracket(text: &str) -> Option<usize> {
     let mut depth = 1; // Start at depth 1 since we're already inside the first <
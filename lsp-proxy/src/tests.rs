@@ -1,4 +1,7 @@
-use crate::{inlay_hint_processor, quantity_detection, unit_formatter::UnitFormatter, LspProxy};
+use crate::{
+    completion_processor::CompletionProcessor, inlay_hint_processor, quantity_detection,
+    unit_formatter::UnitFormatter, LspProxy,
+};
 use serde_json::json;
 
 #[test]
@@ -415,7 +418,7 @@ fn test_wholly_unresolved_type_formatting() {
 
     // Test the user's specific case: wholly unresolved type with all parameters as _
     // This matches the exact format from the IDE hover
-    let input = "Quantity<Scale<_2<_>, _3<_>, _5<_>, _Pi<_>>, Dimension<_M<_>, _L<_>, _T<_>, _I<_>, _Θ<_>, _N<_>, _J<_>, _A<_>>";
+    let input = "Quantity<Scale<_2<_>, _3<_>, _5<_>, _Pi<_>>, Dimension<_M<_>, _L<_>, _T<_>, _I<_>, _Θ<_>, _N<_>, _J<_>, _A<_>, _Info<_>>";
     let result = converter.format_types(input, &crate::DisplayConfig::default());
 
     // Should format as wholly unresolved type
@@ -458,7 +461,7 @@ fn test_pid_controller_nested_quantity_types() {
 
     // Test complex nested type with multiple Quantity types in PIDController
     // This tests that the algorithm finds and transforms ALL Quantity types, not just the first one
-    let input = "let mut controller: PIDController<Quantity<Scale, Dimension<_M, _L<1>>>, Quantity<Scale<_2<_>, _3<_>, _5<_>, _Pi<_>>, Dimension<_M<_>, _L<_>, _T<_>, _I<_>, _Θ<_>, _N<_>, _J<_>, _A<_>>>, Quantity<Scale<_2<-3>, _3, _5<-3>>, Dimension<_M, _L, _T<1>>>, Quantity<Scale, Dimension<_M<1>, _L<1>, _T<-3>, _I<-1>>>, Quantity<Scale<_2<3>, _3, _5<3>>, Dimension<_M<1>, _L<1>, _T<-4>, _I<-1>>>, Quantity<Scale<_2<-3>, _3, _5<-3>>, Dimension<_M<1>, _L<1>, _T<-2>, _I<-1>>>, Quantity<Scale<_2<_>, _3<_>, _5<_>, _Pi<_>>, Dimension<_M<_>, _L<_>, _T<_>, _I<_>, _Θ<_>, _N<_>, _J<_>, _A<_>>>, Quantity<Scale<_2<_>, _3<_>, _5<_>, _Pi<_>>, Dimension<_M<_>, _L<_>, _T<_>, _I<_>, _Θ<_>, _N<_>, _J<_>, _A<_>>>>";
+    let input = "let mut controller: PIDController<Quantity<Scale, Dimension<_M, _L<1>>>, Quantity<Scale<_2<_>, _3<_>, _5<_>, _Pi<_>>, Dimension<_M<_>, _L<_>, _T<_>, _I<_>, _Θ<_>, _N<_>, _J<_>, _A<_>, _Info<_>>>, Quantity<Scale<_2<-3>, _3, _5<-3>>, Dimension<_M, _L, _T<1>>>, Quantity<Scale, Dimension<_M<1>, _L<1>, _T<-3>, _I<-1>>>, Quantity<Scale<_2<3>, _3, _5<3>>, Dimension<_M<1>, _L<1>, _T<-4>, _I<-1>>>, Quantity<Scale<_2<-3>, _3, _5<-3>>, Dimension<_M<1>, _L<1>, _T<-2>, _I<-1>>>, Quantity<Scale<_2<_>, _3<_>, _5<_>, _Pi<_>>, Dimension<_M<_>, _L<_>, _T<_>, _I<_>, _Θ<_>, _N<_>, _J<_>, _A<_>, _Info<_>>>, Quantity<Scale<_2<_>, _3<_>, _5<_>, _Pi<_>>, Dimension<_M<_>, _L<_>, _T<_>, _I<_>, _Θ<_>, _N<_>, _J<_>, _A<_>, _Info<_>>>>";
 
     let result = converter.format_types(input, &crate::DisplayConfig::default());
 
@@ -677,3 +680,472 @@ let ratio: Quantity<Scale<_2<3>, _3<0>, _5<3>, _Pi<0>>, Dimension>, f64> = quant
     println!("Detection result for hover JSON: {}", detected);
     assert!(detected, "Hover JSON should be detected");
 }
+
+#[test]
+fn test_fully_qualified_structured_quantity_formatting() {
+    let converter = UnitFormatter::new();
+
+    let input = "let x: whippyunits::quantity::Quantity<whippyunits::quantity::Scale<whippyunits::quantity::_2<-3>, whippyunits::quantity::_3<0>, whippyunits::quantity::_5<-3>, whippyunits::quantity::_Pi<0>>, whippyunits::quantity::Dimension<whippyunits::quantity::_M<0>, whippyunits::quantity::_L<1>, whippyunits::quantity::_T<0>, whippyunits::quantity::_I<0>, whippyunits::quantity::_Θ<0>, whippyunits::quantity::_N<0>, whippyunits::quantity::_J<0>, whippyunits::quantity::_A<0>>, f64> = 5.0.millimeters();";
+    let result = converter.format_types(input, &crate::DisplayConfig::default());
+    assert_eq!(result, "let x: Quantity<mm, f64> = 5.0.millimeters();");
+
+    // Partially qualified paths are handled the same way
+    let input = "quantity::Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, quantity::Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>";
+    let result = converter.format_types(input, &crate::DisplayConfig::default());
+    assert_eq!(result, "Quantity<m, f64>");
+
+    // Non-whippyunits paths (e.g. brands) are left intact
+    let input = "whippyunits::Quantity<Scale, Dimension<_M<0>, _L<1>>, f64, my_crate::Brand>";
+    let result = converter.format_types(input, &crate::DisplayConfig::default());
+    assert!(result.contains("my_crate"));
+    assert!(!result.contains("Dimension"));
+}
+
+#[test]
+fn test_flat_quantity_formatting() {
+    let converter = UnitFormatter::new();
+
+    // 8 parameters: dimension exponents only
+    let input = "expected `Quantity<0, 1, 0, 0, 0, 0, 0, 0>`";
+    let result = converter.format_types(input, &crate::DisplayConfig::default());
+    assert_eq!(result, "expected `Quantity<m, f64>`");
+
+    // 12 parameters: dimension exponents and scale exponents
+    let input = "Quantity<0, 1, 0, 0, 0, 0, 0, 0, -3, 0, -3, 0>";
+    let result = converter.format_types(input, &crate::DisplayConfig::default());
+    assert_eq!(result, "Quantity<mm, f64>");
+
+    // 13 parameters: dimension exponents, scale exponents and storage type, fully qualified
+    let input = "whippyunits::quantity::Quantity<0, 1, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, i32>";
+    let result = converter.format_types(input, &crate::DisplayConfig::default());
+    let structured = "Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<-1>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, i32>";
+    assert_eq!(
+        result,
+        converter.format_types(structured, &crate::DisplayConfig::default())
+    );
+    assert!(result.ends_with(", i32>"));
+
+    // Structured and flat forms can coexist in the same text
+    let input = "Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64> vs Quantity<0, 0, 1, 0, 0, 0, 0, 0>";
+    let result = converter.format_types(input, &crate::DisplayConfig::default());
+    assert_eq!(result, "Quantity<m, f64> vs Quantity<s, f64>");
+
+    // Other parameter counts are not whippyunits types
+    let input = "Quantity<1, 2, 3>";
+    let result = converter.format_types(input, &crate::DisplayConfig::default());
+    assert_eq!(result, input);
+
+    // Flat types are detected by the fast path as well
+    assert!(quantity_detection::validate_quantity_format(
+        "Quantity<0, 1, 0, 0, 0, 0, 0, 0, -3, 0, -3, 0, f64>"
+    ));
+}
+
+#[test]
+fn test_flat_structured_migration_equivalence() {
+    let converter = UnitFormatter::new();
+    let config = crate::DisplayConfig::default();
+
+    // 13-parameter flat form maps to the canonical structured form
+    let flat = "Quantity<0, 1, 0, 0, 0, 0, 0, 0, -3, 0, -3, 0, f64>";
+    let structured = converter.flat_to_structured(flat).unwrap();
+    assert_eq!(
+        structured,
+        "Quantity<Scale<_2<-3>, _3<0>, _5<-3>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>, f64>"
+    );
+
+    // ...and both forms pretty-print identically
+    assert_eq!(
+        converter.format_types(flat, &config),
+        converter.format_types(&structured, &config)
+    );
+
+    // Round trip back to the flat form
+    assert_eq!(converter.structured_to_flat(&structured).unwrap(), flat);
+
+    // 8- and 12-parameter forms default the missing scale and storage type
+    assert_eq!(
+        converter
+            .flat_to_structured("Quantity<1, 0, 0, 0, 0, 0, 0, 0>")
+            .unwrap(),
+        "Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<1>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>, f64>"
+    );
+    assert_eq!(
+        converter
+            .structured_to_flat("Quantity<Scale, Dimension<_M<0>, _L<1>>, i32>")
+            .unwrap(),
+        "Quantity<0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, i32>"
+    );
+
+    // The flat form predates the information dimension, so data quantities have no flat form
+    assert!(converter
+        .structured_to_flat(
+            "Quantity<Scale, Dimension<_M, _L, _T<-1>, _I, _Θ, _N, _J, _A, _Info<1>>>"
+        )
+        .is_none());
+
+    // Unresolved parameters survive the conversion
+    let unresolved = "Quantity<_, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, f64>";
+    let structured = converter.flat_to_structured(unresolved).unwrap();
+    assert!(structured.contains("_M<_>"));
+    assert_eq!(
+        converter.structured_to_flat(&structured).unwrap(),
+        unresolved
+    );
+
+    // The interleaved 9-parameter form predates the 2/3/5/π scale model and is not converted
+    assert!(converter
+        .flat_to_structured("Quantity<1, -1, 0, 9223372036854775807, 0, 9223372036854775807, 9223372036854775807, 9223372036854775807, 9223372036854775807>")
+        .is_none());
+}
+
+#[test]
+fn test_completion_detail_processing() {
+    let proxy = LspProxy::new();
+
+    // Captured rust-analyzer `textDocument/completion` response for `5.0.`
+    let completion_response = json!({
+        "jsonrpc": "2.0",
+        "id": 7,
+        "result": {
+            "isIncomplete": true,
+            "items": [
+                {
+                    "label": "kilometers()",
+                    "kind": 2,
+                    "detail": "fn(self) -> Quantity<Scale<_2<3>, _3<0>, _5<3>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>",
+                    "labelDetails": {
+                        "detail": "(as Length)",
+                        "description": "fn(self) -> Quantity<Scale<_2<3>, _3<0>, _5<3>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>"
+                    },
+                    "documentation": {
+                        "kind": "markdown",
+                        "value": "Create a `Quantity<Scale<_2<3>, _3<0>, _5<3>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>` from this value"
+                    },
+                    "sortText": "7fffffff",
+                    "textEdit": {
+                        "range": {
+                            "start": { "line": 4, "character": 16 },
+                            "end": { "line": 4, "character": 16 }
+                        },
+                        "newText": "kilometers()"
+                    }
+                },
+                {
+                    "label": "abs()",
+                    "kind": 2,
+                    "detail": "fn(self) -> f64",
+                    "sortText": "7fffffff"
+                }
+            ]
+        }
+    });
+
+    let response_str = serde_json::to_string(&completion_response).unwrap();
+    let processed = proxy.process_incoming(&response_str).unwrap();
+    let json_start = processed.find('{').unwrap();
+    let processed: serde_json::Value = serde_json::from_str(&processed[json_start..]).unwrap();
+
+    let items = processed["result"]["items"].as_array().unwrap();
+    let detail = items[0]["detail"].as_str().unwrap();
+    assert!(detail.starts_with("fn(self) -> "));
+    assert!(detail.contains("km"));
+    assert!(!detail.contains("Dimension<"));
+
+    let documentation = items[0]["documentation"]["value"].as_str().unwrap();
+    assert!(documentation.contains("km"));
+    assert!(!documentation.contains("Dimension<"));
+
+    let description = items[0]["labelDetails"]["description"].as_str().unwrap();
+    assert!(description.contains("km"));
+
+    // Label details without Quantity types and items without Quantity types are left alone
+    assert_eq!(items[0]["labelDetails"]["detail"], "(as Length)");
+    assert_eq!(items[1], completion_response["result"]["items"][1]);
+    assert_eq!(
+        items[0]["textEdit"],
+        completion_response["result"]["items"][0]["textEdit"]
+    );
+}
+
+#[test]
+fn test_completion_response_detection() {
+    let processor = CompletionProcessor::new();
+
+    // Completion lists, bare item arrays, and resolved items are all recognised
+    assert!(processor.is_completion_response(&json!({ "isIncomplete": false, "items": [] })));
+    assert!(processor.is_completion_response(&json!([{ "label": "meters()" }])));
+    assert!(processor.is_completion_response(&json!({ "label": "meters()", "detail": "fn(self)" })));
+
+    // Inlay hints carry a position, and hovers have no label
+    assert!(!processor.is_completion_response(&json!([{
+        "position": { "line": 0, "character": 0 },
+        "label": ": Quantity<Scale, Dimension<_M, _L<1>>, f64>"
+    }])));
+    assert!(!processor.is_completion_response(&json!({
+        "contents": { "kind": "markdown", "value": "Quantity<Scale, Dimension<_M, _L<1>>, f64>" }
+    })));
+}
+
+#[test]
+fn test_diagnostics_message_processing() {
+    let proxy = LspProxy::new();
+
+    let range = json!({
+        "start": { "line": 7, "character": 24 },
+        "end": { "line": 7, "character": 49 }
+    });
+    let diagnostics_notification = json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": {
+            "uri": "file:///project/src/main.rs",
+            "version": 3,
+            "diagnostics": [
+                {
+                    "range": range,
+                    "severity": 1,
+                    "code": "E0308",
+                    "source": "rustc",
+                    "message": "mismatched types\nexpected `Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>`, found `Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<0>, _L<0>, _T<1>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>`",
+                    "relatedInformation": [
+                        {
+                            "location": { "uri": "file:///project/src/main.rs", "range": range },
+                            "message": "expected due to this type `Quantity<Scale<_2<3>, _3<0>, _5<3>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>`"
+                        }
+                    ]
+                },
+                {
+                    "range": range,
+                    "severity": 2,
+                    "source": "rustc",
+                    "message": "unused variable: `distance`"
+                }
+            ]
+        }
+    });
+
+    let notification_str = serde_json::to_string(&diagnostics_notification).unwrap();
+    let processed = proxy.process_incoming(&notification_str).unwrap();
+    let json_start = processed.find('{').unwrap();
+    let processed: serde_json::Value = serde_json::from_str(&processed[json_start..]).unwrap();
+
+    // Still a notification, not a response
+    assert_eq!(processed["method"], "textDocument/publishDiagnostics");
+    assert!(processed.get("id").is_none());
+    assert!(processed.get("result").is_none());
+
+    let diagnostics = processed["params"]["diagnostics"].as_array().unwrap();
+    let message = diagnostics[0]["message"].as_str().unwrap();
+    assert_eq!(
+        message,
+        "mismatched types\nexpected `Quantity<m, f64>`, found `Quantity<s, f64>`"
+    );
+
+    let related = diagnostics[0]["relatedInformation"][0]["message"]
+        .as_str()
+        .unwrap();
+    assert_eq!(related, "expected due to this type `Quantity<km, f64>`");
+
+    // Ranges, other fields, and diagnostics without Quantity types are left alone
+    assert_eq!(diagnostics[0]["range"], range);
+    assert_eq!(diagnostics[0]["code"], "E0308");
+    assert_eq!(
+        diagnostics[0]["relatedInformation"][0]["location"],
+        diagnostics_notification["params"]["diagnostics"][0]["relatedInformation"][0]["location"]
+    );
+    assert_eq!(
+        diagnostics[1],
+        diagnostics_notification["params"]["diagnostics"][1]
+    );
+    assert_eq!(processed["params"]["uri"], "file:///project/src/main.rs");
+}
+
+#[test]
+fn test_ascii_display_config() {
+    let converter = UnitFormatter::new();
+    let ascii = crate::DisplayConfig {
+        unicode: false,
+        ..crate::DisplayConfig::default()
+    };
+    let verbose_ascii = crate::DisplayConfig {
+        verbose: true,
+        ..ascii.clone()
+    };
+
+    // Area, acceleration in micrometres, an unnamed dimension and a partially resolved type
+    let inputs = [
+        "Quantity<Scale, Dimension<_M, _L<2>>, f64>",
+        "Quantity<Scale<_2<-6>, _3, _5<-6>>, Dimension<_M, _L<1>, _T<-2>>, f64>",
+        "Quantity<Scale, Dimension<_M<0>, _L<0>, _T<-1>, _I<0>, _Θ<3>, _N<0>, _J<0>, _A<0>>, f64>",
+        "Quantity<Scale<_2<_>, _3<0>, _5<_>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<_>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>",
+    ];
+    for input in inputs {
+        for config in [&ascii, &verbose_ascii] {
+            let result = converter.format_types(input, config);
+            assert!(result.is_ascii(), "non-ASCII output {result:?} for {input}");
+            let hint = converter.format_types_inlay_hint(input, config);
+            assert!(hint.is_ascii(), "non-ASCII inlay hint {hint:?} for {input}");
+        }
+    }
+
+    assert_eq!(
+        converter.format_types(inputs[0], &ascii),
+        "Quantity<m^2, f64>"
+    );
+    assert_eq!(
+        converter.format_types(inputs[0], &crate::DisplayConfig::default()),
+        "Quantity<m², f64>"
+    );
+    assert!(converter.format_types(inputs[1], &ascii).contains("s^-2"));
+}
+
+/// Render exponents as a structured type string, either in full or abbreviated the way rustc
+/// prints it: zero exponents as bare `_M`, and trailing defaulted parameters omitted
+fn structured_quantity_type(dimensions: [i16; 9], scale: [i16; 4], abbreviated: bool) -> String {
+    fn params(names: &[&str], exponents: &[i16], abbreviated: bool) -> Option<String> {
+        let len = if abbreviated {
+            exponents.iter().rposition(|&exponent| exponent != 0)? + 1
+        } else {
+            exponents.len()
+        };
+        let params: Vec<String> = names[..len]
+            .iter()
+            .zip(exponents)
+            .map(|(name, &exponent)| match exponent {
+                0 if abbreviated => name.to_string(),
+                _ => format!("{name}<{exponent}>"),
+            })
+            .collect();
+        Some(params.join(", "))
+    }
+
+    let scale = match params(&["_2", "_3", "_5", "_Pi"], &scale, abbreviated) {
+        Some(params) => format!("Scale<{params}>"),
+        None => "Scale".to_string(),
+    };
+    let dimension = match params(
+        &["_M", "_L", "_T", "_I", "_Θ", "_N", "_J", "_A", "_Info"],
+        &dimensions,
+        abbreviated,
+    ) {
+        Some(params) => format!("Dimension<{params}>"),
+        None => "Dimension".to_string(),
+    };
+    format!("Quantity<{scale}, {dimension}, f64>")
+}
+
+#[test]
+fn test_converter_matches_library_pretty_printer() {
+    use whippyunits::print::prettyprint::pretty_print_quantity_type;
+    use whippyunits_core::{
+        dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
+    };
+
+    let converter = UnitFormatter::new();
+    let config = crate::DisplayConfig::default();
+
+    // Deterministic xorshift generator, so failures are reproducible
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut next = move |low: i16, high: i16| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        low + (state % (high - low + 1) as u64) as i16
+    };
+
+    for _ in 0..500 {
+        // Mostly-zero dimension exponents, as in real units
+        let mut dimensions = [0i16; 9];
+        for exponent in dimensions.iter_mut() {
+            if next(0, 2) == 0 {
+                *exponent = next(-3, 3);
+            }
+        }
+        let scale = [next(-6, 6), next(-3, 3), next(-6, 6), next(-1, 1)];
+
+        // The dimensionless identity type is special-cased by the converter as `Quantity<1, T>`
+        if dimensions == [0; 9] && scale == [0; 4] {
+            continue;
+        }
+
+        let expected = pretty_print_quantity_type(
+            DynDimensionExponents(dimensions),
+            ScaleExponents(scale),
+            "f64",
+            false,
+            false,
+            None,
+        );
+        for abbreviated in [false, true] {
+            let input = structured_quantity_type(dimensions, scale, abbreviated);
+            assert_eq!(
+                converter.format_types(&input, &config),
+                expected,
+                "converter and pretty printer disagree on {input}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_converter_matches_quantity_display() {
+    use whippyunits::quantity;
+
+    let converter = UnitFormatter::new();
+    let config = crate::DisplayConfig::default();
+
+    // The type name rustc reports for a quantity, run through the converter, should read the
+    // same as the quantity's own Display output
+    macro_rules! assert_display_matches {
+        ($($unit:tt)*) => {{
+            let value = quantity!(1.0, $($unit)*);
+            let type_name = std::any::type_name_of_val(&value);
+            let display = value.to_string();
+            let from_display = &display[display.find("Quantity<").unwrap()..];
+            assert_eq!(
+                converter.format_types(type_name, &config),
+                from_display,
+                "converter and Display disagree on {type_name}"
+            );
+        }};
+    }
+
+    assert_display_matches!(m);
+    assert_display_matches!(km);
+    assert_display_matches!(mg);
+    assert_display_matches!(h);
+    assert_display_matches!(km / h);
+    assert_display_matches!(m / s ^ 2);
+    assert_display_matches!(N);
+    assert_display_matches!(kJ);
+    assert_display_matches!(mW);
+    assert_display_matches!(Pa);
+    assert_display_matches!(V);
+    assert_display_matches!(mA * h);
+    assert_display_matches!(K);
+    assert_display_matches!(mol / L);
+    assert_display_matches!(rad / s);
+    assert_display_matches!(deg);
+    assert_display_matches!(kg * m ^ 2);
+}
+
+#[test]
+fn test_converter_handles_partially_resolved_types() {
+    // Only the converter sees unresolved `_` parameters; it must still produce a pretty type
+    let converter = UnitFormatter::new();
+    let config = crate::DisplayConfig::default();
+
+    let inputs = [
+        "Quantity<Scale<_2<_>, _3<0>, _5<_>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>",
+        "Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<1>, _L<_>, _T<-2>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>",
+        "Quantity<Scale<_2<-3>, _3<0>, _5<-3>, _Pi<0>>, Dimension<_M<_>, _L<_>, _T<1>, _I<_>, _Θ<_>, _N<_>, _J<_>, _A<_>, _Info<_>>, f64>",
+    ];
+    for input in inputs {
+        let result = converter.format_types(input, &config);
+        assert!(result.starts_with("Quantity<"), "{input} became {result}");
+        assert!(result.ends_with(", f64>"), "{input} became {result}");
+        assert!(!result.contains("Scale"), "{input} became {result}");
+        assert!(!result.contains("Dimension"), "{input} became {result}");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::quantity_detection;
 use syn::{parse_str, TypePath};
 use whippyunits_core::{
     dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
@@ -125,6 +126,10 @@ impl UnitFormatter {
     }
 
     /// Core method to format Quantity types with configurable parameters
+    ///
+    /// Handles both the structured `Quantity<Scale<..>, Dimension<..>, T>` form and the legacy
+    /// flat 8/12/13-parameter form, with or without fully-qualified paths
+    /// (e.g. `whippyunits::quantity::Quantity<..>`).
     fn format_quantity_types(
         &self,
         text: &str,
@@ -132,96 +137,86 @@ impl UnitFormatter {
         unicode: bool,
         is_inlay_hint: bool,
     ) -> String {
-        if text.contains("Quantity<") && text.contains("Scale") {
-            // First pass: find all Quantity<Scale types and their positions
-            struct QuantityMatch {
-                start: usize,
-                end: usize,
-                formatted: String,
-            }
+        if !text.contains("Quantity<") {
+            return text.to_string();
+        }
 
-            let mut matches = Vec::new();
-            let mut i = 0;
-
-            while i < text.len() {
-                // Search for "Quantity<Scale" starting from position i
-                if let Some(relative_start) = text[i..].find("Quantity<Scale") {
-                    let start_pos = i + relative_start;
-
-                    // Count brackets to find the matching end of this Quantity type
-                    // start_pos points to "Q" in "Quantity<Scale"
-                    // start_pos + 8 points to the '<' after "Quantity"
-                    let quantity_start = start_pos + 8; // Position of the '<'
-                                                        // Start bracket_count at 1 because we're already inside Quantity<
-                                                        // Start j after the '<' so we don't count it again
-                    let mut bracket_count = 1;
-                    let mut found_end = false;
-                    // Use byte indices, not char indices, to match the string slicing
-                    let mut byte_pos = quantity_start + 1; // Start after the opening '<'
-                    let text_bytes = text.as_bytes();
-
-                    while byte_pos < text_bytes.len() {
-                        match text_bytes[byte_pos] {
-                            b'<' => bracket_count += 1,
-                            b'>' => {
-                                bracket_count -= 1;
-                                if bracket_count == 0 {
-                                    found_end = true;
-                                    break;
-                                }
-                            }
-                            _ => {}
-                        }
-                        byte_pos += 1;
-                    }
+        // First pass: find all Quantity types and their positions
+        struct QuantityMatch {
+            start: usize,
+            end: usize,
+            formatted: String,
+        }
 
-                    if found_end {
-                        let actual_end = byte_pos + 1; // +1 to include the '>'
-                                                       // Ensure we don't go past the end of the string
-                        let actual_end = actual_end.min(text.len());
-                        let quantity_type = &text[start_pos..actual_end];
-
-                        let formatted = self.format_new_quantity_type(
-                            quantity_type,
-                            verbose,
-                            unicode,
-                            is_inlay_hint,
-                        );
-
-                        matches.push(QuantityMatch {
-                            start: start_pos,
-                            end: actual_end,
-                            formatted,
-                        });
-
-                        // Continue searching from after this Quantity type
-                        i = actual_end;
-                    } else {
-                        // Bracket counting failed, stop searching
-                        break;
-                    }
-                } else {
-                    // No more Quantity<Scale found
-                    break;
-                }
+        let mut matches = Vec::new();
+        let mut i = 0;
+
+        while i < text.len() {
+            // Search for "Quantity<" starting from position i
+            let Some(relative_start) = text[i..].find("Quantity<") else {
+                // No more Quantity< found
+                break;
+            };
+            let start_pos = i + relative_start;
+            let search_resume = start_pos + "Quantity<".len();
+
+            // Skip identifiers that merely end in "Quantity" (e.g. `MyQuantity<`)
+            if text[..start_pos]
+                .chars()
+                .next_back()
+                .is_some_and(is_ident_char)
+            {
+                i = search_resume;
+                continue;
             }
 
-            // Second pass: replace all matches from end to start to preserve positions
-            if matches.is_empty() {
-                return text.to_string();
+            // Count brackets to find the matching end of this Quantity type
+            let Some(close_offset) =
+                quantity_detection::find_matching_angle_bracket(&text[search_resume..])
+            else {
+                // Bracket counting failed, stop searching
+                break;
+            };
+            let actual_end = search_resume + close_offset + 1; // +1 to include the '>'
+
+            // Replace the whole path, including any `whippyunits::quantity::` qualifier
+            let replace_start = qualified_path_start(text, start_pos);
+            let quantity_type = strip_whippyunits_paths(&text[start_pos..actual_end]);
+            let inner = &quantity_type["Quantity<".len()..quantity_type.len() - 1];
+
+            let formatted = if quantity_detection::is_flat_quantity_format(inner) {
+                self.format_flat_quantity_type(&quantity_type, verbose, unicode, is_inlay_hint)
+            } else if inner.starts_with("Scale") {
+                self.format_new_quantity_type(&quantity_type, verbose, unicode, is_inlay_hint)
+            } else {
+                // Not a whippyunits Quantity; keep searching inside it
+                i = search_resume;
+                continue;
+            };
+
+            if formatted != quantity_type {
+                matches.push(QuantityMatch {
+                    start: replace_start,
+                    end: actual_end,
+                    formatted,
+                });
             }
 
-            let mut result = text.to_string();
-            // Replace from end to start to preserve positions
-            for m in matches.iter().rev() {
-                result.replace_range(m.start..m.end, &m.formatted);
-            }
+            // Continue searching from after this Quantity type
+            i = actual_end;
+        }
 
-            return result;
+        // Second pass: replace all matches from end to start to preserve positions
+        if matches.is_empty() {
+            return text.to_string();
         }
 
-        // If we reach here, no new format was found, return original text
-        text.to_string()
+        let mut result = text.to_string();
+        for m in matches.iter().rev() {
+            result.replace_range(m.start..m.end, &m.formatted);
+        }
+
+        result
     }
 
     /// Format the new Quantity type with Scale<...> and Dimension<...> structs
@@ -229,11 +224,9 @@ impl UnitFormatter {
         &self,
         full_match: &str,
         verbose: bool,
-        _unicode: bool,
+        unicode: bool,
         is_inlay_hint: bool,
     ) -> String {
-        use whippyunits::print::prettyprint::pretty_print_quantity_type;
-
         // Check if this is a generic type definition (contains parameter names like Scale, Dimension, T)
         // rather than a concrete instantiation with actual values
         if self.is_generic_type_definition(full_match) {
@@ -241,17 +234,93 @@ impl UnitFormatter {
             return full_match.to_string();
         }
 
-        // Parse the new format: Quantity<Scale<_2<P2>, _3<P3>, _5<P5>, _Pi<PI>>, Dimension<_M<MASS>, _L<LENGTH>, _T<TIME>, _I<CURRENT>, _Θ<TEMP>, _N<AMOUNT>, _J<LUMINOSITY>, _A<ANGLE>>, T>
+        // Parse the new format: Quantity<Scale<_2<P2>, _3<P3>, _5<P5>, _Pi<PI>>, Dimension<_M<MASS>, _L<LENGTH>, _T<TIME>, _I<CURRENT>, _Θ<TEMP>, _N<AMOUNT>, _J<LUMINOSITY>, _A<ANGLE>, _Info<INFORMATION>>, T>
         if let Some(params) = self.parse_new_quantity_params(full_match) {
-            // Check if this is a wholly unresolved type (all parameters are sentinel values)
-            let all_dimensions_unresolved = params.dimensions.0.iter().all(|&exp| exp == i16::MIN);
-            let all_scales_unresolved = params.scale.0.iter().all(|&exp| exp == i16::MIN);
+            self.format_quantity_params(&params, verbose, unicode, is_inlay_hint)
+        } else {
+            // If parsing fails, return the original
+            full_match.to_string()
+        }
+    }
 
-            // Get Brand name for passing to prettyprint function
-            let brand_name = params.brand.as_deref();
+    /// Format the legacy flat Quantity type, e.g. `Quantity<0, 1, 0, 0, 0, 0, 0, 0, -3, 0, -3, 0, f64>`
+    fn format_flat_quantity_type(
+        &self,
+        full_match: &str,
+        verbose: bool,
+        unicode: bool,
+        is_inlay_hint: bool,
+    ) -> String {
+        if let Some(params) = self.parse_flat_quantity_params(full_match) {
+            self.format_quantity_params(&params, verbose, unicode, is_inlay_hint)
+        } else {
+            full_match.to_string()
+        }
+    }
+
+    /// Format parsed Quantity parameters
+    ///
+    /// With `unicode` off, units are rendered in plain ASCII (`m^2` rather than `m²`).
+    fn format_quantity_params(
+        &self,
+        params: &QuantityParams,
+        verbose: bool,
+        unicode: bool,
+        is_inlay_hint: bool,
+    ) -> String {
+        use whippyunits::print::prettyprint::{pretty_print_quantity_type_with_format, UnitFormat};
 
-            if all_dimensions_unresolved && all_scales_unresolved {
-                // Format as wholly unresolved type
+        let format = if unicode {
+            UnitFormat::Unicode
+        } else {
+            UnitFormat::Ascii
+        };
+
+        // Check if this is a wholly unresolved type (all parameters are sentinel values)
+        let all_dimensions_unresolved = params.dimensions.0.iter().all(|&exp| exp == i16::MIN);
+        let all_scales_unresolved = params.scale.0.iter().all(|&exp| exp == i16::MIN);
+
+        // Get Brand name for passing to prettyprint function
+        let brand_name = params.brand.as_deref();
+
+        if all_dimensions_unresolved && all_scales_unresolved {
+            // Format as wholly unresolved type
+            let mut result = format!("Quantity<?, {}>", params.generic_type);
+            if let Some(brand) = brand_name {
+                if brand != "()" {
+                    result = format!("{}, {}>", &result[..result.len() - 1], brand);
+                }
+            }
+            return result;
+        }
+
+        // Check if this is a dimensionless quantity (all dimensions are zero)
+        if params.dimensions == DynDimensionExponents::ZERO
+            && params.scale == ScaleExponents::IDENTITY
+        {
+            // Format as dimensionless quantity
+            let mut result = format!("Quantity<1, {}>", params.generic_type);
+            if let Some(brand) = brand_name {
+                if brand != "()" {
+                    result = format!("{}, {}>", &result[..result.len() - 1], brand);
+                }
+            }
+            return result;
+        }
+        if is_inlay_hint {
+            // Use the main pretty print function with verbose=false to get the unit literal
+            let full_output = pretty_print_quantity_type_with_format(
+                params.dimensions,
+                params.scale,
+                &params.generic_type,
+                false, // Non-verbose mode for inlay hints
+                false, // Don't show type in brackets
+                brand_name,
+                format,
+            );
+
+            // Check if the pretty print function returned just "?" for wholly unresolved types
+            if full_output == "?" {
                 let mut result = format!("Quantity<?, {}>", params.generic_type);
                 if let Some(brand) = brand_name {
                     if brand != "()" {
@@ -261,71 +330,71 @@ impl UnitFormatter {
                 return result;
             }
 
-            // Check if this is a dimensionless quantity (all dimensions are zero)
-            if params.dimensions == DynDimensionExponents::ZERO
-                && params.scale == ScaleExponents::IDENTITY
-            {
-                // Format as dimensionless quantity
-                let mut result = format!("Quantity<1, {}>", params.generic_type);
+            full_output
+        } else {
+            // Use the prettyprint API with configurable parameters
+            let result = pretty_print_quantity_type_with_format(
+                params.dimensions,
+                params.scale,
+                &params.generic_type,
+                verbose,
+                false, // show_type_in_brackets = false for pretty printer
+                brand_name,
+                format,
+            );
+
+            // Check if the pretty print function returned just "?" for wholly unresolved types
+            if result == "?" {
+                let mut formatted = format!("Quantity<?, {}>", params.generic_type);
                 if let Some(brand) = brand_name {
                     if brand != "()" {
-                        result = format!("{}, {}>", &result[..result.len() - 1], brand);
+                        formatted = format!("{}, {}>", &formatted[..formatted.len() - 1], brand);
                     }
                 }
-                return result;
+                return formatted;
             }
-            if is_inlay_hint {
-                // Use the main pretty print function with verbose=false to get the unit literal
-                let full_output = pretty_print_quantity_type(
-                    params.dimensions,
-                    params.scale,
-                    &params.generic_type,
-                    false, // Non-verbose mode for inlay hints
-                    false, // Don't show type in brackets
-                    brand_name,
-                );
-
-                // Check if the pretty print function returned just "?" for wholly unresolved types
-                if full_output == "?" {
-                    let mut result = format!("Quantity<?, {}>", params.generic_type);
-                    if let Some(brand) = brand_name {
-                        if brand != "()" {
-                            result = format!("{}, {}>", &result[..result.len() - 1], brand);
-                        }
-                    }
-                    return result;
-                }
 
-                full_output
-            } else {
-                // Use the prettyprint API with configurable parameters
-                let result = pretty_print_quantity_type(
-                    params.dimensions,
-                    params.scale,
-                    &params.generic_type,
-                    verbose,
-                    false, // show_type_in_brackets = false for pretty printer
-                    brand_name,
-                );
-
-                // Check if the pretty print function returned just "?" for wholly unresolved types
-                if result == "?" {
-                    let mut formatted = format!("Quantity<?, {}>", params.generic_type);
-                    if let Some(brand) = brand_name {
-                        if brand != "()" {
-                            formatted =
-                                format!("{}, {}>", &formatted[..formatted.len() - 1], brand);
-                        }
-                    }
-                    return formatted;
-                }
+            result
+        }
+    }
 
-                result
-            }
-        } else {
-            // If parsing fails, return the original
-            full_match.to_string()
+    /// Parse the legacy flat Quantity type format
+    ///
+    /// - 8 parameters: dimension exponents only (identity scale, `f64` storage)
+    /// - 12 parameters: dimension exponents followed by the 2, 3, 5 and π scale exponents
+    /// - 13 parameters: as above, followed by the storage type
+    fn parse_flat_quantity_params(&self, quantity_type: &str) -> Option<QuantityParams> {
+        let inner = quantity_type.strip_prefix("Quantity<")?.strip_suffix('>')?;
+        if !quantity_detection::is_flat_quantity_format(inner) {
+            return None;
+        }
+        let args = quantity_detection::split_top_level_args(inner);
+
+        let numeric: Vec<i16> = args
+            .iter()
+            .take(12)
+            .map(|arg| parse_flat_parameter(arg))
+            .collect();
+
+        // The flat form predates the information dimension, which is always zero there
+        let mut dimensions = [0; 9];
+        dimensions[..8].copy_from_slice(&numeric[..8]);
+        let mut scale = [0; 4];
+        if numeric.len() == 12 {
+            scale.copy_from_slice(&numeric[8..12]);
         }
+
+        let generic_type = args
+            .get(12)
+            .map(|arg| arg.to_string())
+            .unwrap_or_else(|| "f64".to_string());
+
+        Some(QuantityParams {
+            dimensions: DynDimensionExponents(dimensions),
+            scale: ScaleExponents(scale),
+            generic_type,
+            brand: None,
+        })
     }
 
     /// Parse the new Quantity type format with Scale<...> and Dimension<...> structs
@@ -344,21 +413,37 @@ impl UnitFormatter {
         };
 
         // Parse Dimension parameters - handle both full format and truncated format
-        let dimensions = if quantity_type.contains("Dimension<_M<") && quantity_type.contains("_A<")
+        // Full format: Dimension<_M<MASS>, _L<LENGTH>, _T<TIME>, _I<CURRENT>, _Θ<TEMP>, _N<AMOUNT>, _J<LUMINOSITY>, _A<ANGLE>, _Info<INFORMATION>>
+        // rustc abbreviates zero exponents to a bare `_Θ` even when `_A<..>` is present, so a
+        // type that is not in full format falls through to the truncated parser
+        let full_format =
+            if quantity_type.contains("Dimension<_M<") && quantity_type.contains("_A<") {
+                self.parse_dimension_full_format(quantity_type)
+            } else {
+                None
+            };
+        let dimensions = if let Some((
+            mass,
+            length,
+            time,
+            current,
+            temp,
+            amount,
+            lum,
+            angle,
+            info,
+        )) = full_format
         {
-            // Full format: Dimension<_M<MASS>, _L<LENGTH>, _T<TIME>, _I<CURRENT>, _Θ<TEMP>, _N<AMOUNT>, _J<LUMINOSITY>, _A<ANGLE>>
-            let (mass, length, time, current, temp, amount, lum, angle) =
-                self.parse_dimension_full_format(quantity_type)?;
-            DynDimensionExponents([mass, length, time, current, temp, amount, lum, angle])
+            DynDimensionExponents([mass, length, time, current, temp, amount, lum, angle, info])
         } else if quantity_type.contains("Dimension,") || quantity_type.contains("Dimension>") {
             // Fully defaulted Dimension (dimensionless): Dimension, T or Dimension> T
             DynDimensionExponents::ZERO
         } else {
             // Truncated format: parse only the non-zero parameters
             // Look for patterns like Dimension<_M<0>, _L<1>> (only non-zero parameters are shown)
-            let (mass, length, time, current, temp, amount, lum, angle) =
+            let (mass, length, time, current, temp, amount, lum, angle, info) =
                 self.parse_dimension_truncated_format(quantity_type);
-            DynDimensionExponents([mass, length, time, current, temp, amount, lum, angle])
+            DynDimensionExponents([mass, length, time, current, temp, amount, lum, angle, info])
         };
 
         // Don't apply base scale offset here - let the prettyprint functions handle it
@@ -411,11 +496,11 @@ impl UnitFormatter {
         }
     }
 
-    /// Parse full Dimension format: Dimension<_M<MASS>, _L<LENGTH>, _T<TIME>, _I<CURRENT>, _Θ<TEMP>, _N<AMOUNT>, _J<LUMINOSITY>, _A<ANGLE>>
+    /// Parse full Dimension format: Dimension<_M<MASS>, _L<LENGTH>, _T<TIME>, _I<CURRENT>, _Θ<TEMP>, _N<AMOUNT>, _J<LUMINOSITY>, _A<ANGLE>, _Info<INFORMATION>>
     fn parse_dimension_full_format(
         &self,
         quantity_type: &str,
-    ) -> Option<(i16, i16, i16, i16, i16, i16, i16, i16)> {
+    ) -> Option<(i16, i16, i16, i16, i16, i16, i16, i16, i16)> {
         let dimension_start = quantity_type.find("Dimension<_M<")?;
         let dimension_content = &quantity_type[dimension_start + 9..]; // Skip "Dimension<"
 
@@ -429,15 +514,19 @@ impl UnitFormatter {
         let amount = self.parse_dimension_param(dimension_content, "_N<")?;
         let lum = self.parse_dimension_param(dimension_content, "_J<")?;
         let angle = self.parse_dimension_param(dimension_content, "_A<")?;
+        // rustc may abbreviate a zero information exponent to a bare `_Info`
+        let info = self
+            .parse_dimension_param(dimension_content, "_Info<")
+            .unwrap_or(0);
 
-        Some((mass, length, time, current, temp, amount, lum, angle))
+        Some((mass, length, time, current, temp, amount, lum, angle, info))
     }
 
     /// Parse truncated Dimension format: Dimension<_M<0>, _L<1>> (only non-zero parameters are shown)
     fn parse_dimension_truncated_format(
         &self,
         quantity_type: &str,
-    ) -> (i16, i16, i16, i16, i16, i16, i16, i16) {
+    ) -> (i16, i16, i16, i16, i16, i16, i16, i16, i16) {
         let mut mass_exp = 0;
         let mut length_exp = 0;
         let mut time_exp = 0;
@@ -446,6 +535,7 @@ impl UnitFormatter {
         let mut amount_of_substance_exp = 0;
         let mut luminous_intensity_exp = 0;
         let mut angle_exp = 0;
+        let mut information_exp = 0;
 
         // Parse individual dimension parameters that are present
         if let Some(value) = self.parse_dimension_param(quantity_type, "_M<") {
@@ -472,6 +562,9 @@ impl UnitFormatter {
         if let Some(value) = self.parse_dimension_param(quantity_type, "_A<") {
             angle_exp = value;
         }
+        if let Some(value) = self.parse_dimension_param(quantity_type, "_Info<") {
+            information_exp = value;
+        }
 
         (
             mass_exp,
@@ -482,6 +575,7 @@ impl UnitFormatter {
             amount_of_substance_exp,
             luminous_intensity_exp,
             angle_exp,
+            information_exp,
         )
     }
 
@@ -596,6 +690,53 @@ impl UnitFormatter {
         }
     }
 
+    /// Convert a legacy flat Quantity type to the canonical structured form
+    ///
+    /// `Quantity<0, 1, 0, 0, 0, 0, 0, 0, -3, 0, -3, 0, f64>` becomes
+    /// `Quantity<Scale<_2<-3>, _3<0>, _5<-3>, _Pi<0>>, Dimension<_M<0>, _L<1>, ...>, f64>`.
+    /// Returns `None` for anything that is not an 8/12/13-parameter flat type; in particular
+    /// the interleaved 9-parameter form (exponent and scale index per base unit) predates the
+    /// 2/3/5/π scale model and has no faithful structured equivalent.
+    pub fn flat_to_structured(&self, flat: &str) -> Option<String> {
+        let flat = strip_whippyunits_paths(flat.trim());
+        let params = self.parse_flat_quantity_params(&flat)?;
+        let [p2, p3, p5, pi] = params.scale.0.map(format_structured_parameter);
+        let [m, l, t, i, temp, n, j, a, info] =
+            params.dimensions.0.map(format_structured_parameter);
+        Some(format!(
+            "Quantity<Scale<_2<{p2}>, _3<{p3}>, _5<{p5}>, _Pi<{pi}>>, Dimension<_M<{m}>, _L<{l}>, _T<{t}>, _I<{i}>, _Θ<{temp}>, _N<{n}>, _J<{j}>, _A<{a}>, _Info<{info}>>, {}>",
+            params.generic_type
+        ))
+    }
+
+    /// Convert a structured Quantity type to the legacy 13-parameter flat form
+    ///
+    /// This is the inverse of [`flat_to_structured`](Self::flat_to_structured), for tooling that
+    /// still consumes the flat form.  Branded quantities and quantities of information have no
+    /// flat equivalent and return `None`.
+    pub fn structured_to_flat(&self, structured: &str) -> Option<String> {
+        let structured = strip_whippyunits_paths(structured.trim());
+        if !structured.starts_with("Quantity<Scale") || self.is_generic_type_definition(&structured)
+        {
+            return None;
+        }
+        let params = self.parse_new_quantity_params(&structured)?;
+        if params.brand.as_deref().is_some_and(|brand| brand != "()") || params.dimensions.0[8] != 0
+        {
+            return None;
+        }
+        let flat_params: Vec<String> = params.dimensions.0[..8]
+            .iter()
+            .chain(params.scale.0.iter())
+            .map(|&param| format_structured_parameter(param))
+            .collect();
+        Some(format!(
+            "Quantity<{}, {}>",
+            flat_params.join(", "),
+            params.generic_type
+        ))
+    }
+
     /// Extract just the raw type information from hover content
     /// Looks for any type declaration pattern: let [mut] [var]: TypeName<...>
     pub fn extract_raw_type_from_hover(&self, hover_text: &str) -> String {
@@ -710,6 +851,112 @@ impl UnitFormatter {
     }
 }
 
+/// Whippyunits type names that rust-analyzer may print with a fully-qualified path
+const WHIPPYUNITS_TYPE_NAMES: &[&str] = &[
+    "Quantity",
+    "Scale",
+    "Dimension",
+    "_2",
+    "_3",
+    "_5",
+    "_Pi",
+    "_M",
+    "_L",
+    "_T",
+    "_I",
+    "_Θ",
+    "_N",
+    "_J",
+    "_A",
+    "_Info",
+];
+
+fn is_ident_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+/// Find the start of the path qualifying the identifier at `ident_start`
+/// (e.g. the `w` in `whippyunits::quantity::Quantity`)
+fn qualified_path_start(text: &str, ident_start: usize) -> usize {
+    let mut start = ident_start;
+    while let Some(before_separator) = text[..start].strip_suffix("::") {
+        let segment_len: usize = before_separator
+            .chars()
+            .rev()
+            .take_while(|&ch| is_ident_char(ch))
+            .map(char::len_utf8)
+            .sum();
+        if segment_len == 0 {
+            break;
+        }
+        start = before_separator.len() - segment_len;
+    }
+    start
+}
+
+/// Strip path qualifiers from whippyunits type names, so that
+/// `whippyunits::quantity::Quantity<whippyunits::quantity::Scale<..>, ..>` becomes `Quantity<Scale<..>, ..>`.
+/// Paths to other types (e.g. brands) are left intact.
+fn strip_whippyunits_paths(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(ch) = rest.chars().next() {
+        if !is_ident_char(ch) {
+            result.push(ch);
+            rest = &rest[ch.len_utf8()..];
+            continue;
+        }
+
+        // Consume a whole path: ident(::ident)*
+        let mut path_end = 0;
+        let mut last_segment_start;
+        loop {
+            let segment_len: usize = rest[path_end..]
+                .chars()
+                .take_while(|&ch| is_ident_char(ch))
+                .map(char::len_utf8)
+                .sum();
+            last_segment_start = path_end;
+            path_end += segment_len;
+            let after = &rest[path_end..];
+            if after.starts_with("::") && after[2..].chars().next().is_some_and(is_ident_char) {
+                path_end += 2;
+            } else {
+                break;
+            }
+        }
+
+        let last_segment = &rest[last_segment_start..path_end];
+        if WHIPPYUNITS_TYPE_NAMES.contains(&last_segment) {
+            result.push_str(last_segment);
+        } else {
+            result.push_str(&rest[..path_end]);
+        }
+        rest = &rest[path_end..];
+    }
+
+    result
+}
+
+/// Parse a legacy flat parameter: an integer, `_` (unresolved) or the `i64::MAX` "unused" sentinel
+fn parse_flat_parameter(param: &str) -> i16 {
+    match param {
+        "_" => i16::MIN,
+        quantity_detection::UNUSED_SENTINEL => 0,
+        _ => param.parse().unwrap_or(0),
+    }
+}
+
+/// Format a parsed parameter for a type string, restoring `_` for unresolved values
+fn format_structured_parameter(param: i16) -> String {
+    if param == i16::MIN {
+        "_".to_string()
+    } else {
+        param.to_string()
+    }
+}
+
 #[derive(Debug)]
 struct QuantityParams {
     dimensions: DynDimensionExponents,
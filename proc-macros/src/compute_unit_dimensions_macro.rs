@@ -5,7 +5,7 @@ use syn::parse;
 /// Computes unit dimensions for a unit expression.
 ///
 /// Usage: `compute_unit_dimensions!(unit_expr)`
-/// Returns a tuple of 12 i16 values representing the dimensions
+/// Returns a tuple of 13 i16 values representing the dimensions
 pub fn compute_unit_dimensions(input: TokenStream) -> TokenStream {
     let unit_expr: whippyunits_core::UnitExpr = parse(input).expect("Expected unit expression");
 
@@ -13,7 +13,7 @@ pub fn compute_unit_dimensions(input: TokenStream) -> TokenStream {
     let result = unit_expr.evaluate_with_mode(whippyunits_core::EvaluationMode::Tolerant);
 
     // Extract individual values for the quote
-    let (d0, d1, d2, d3, d4, d5, d6, d7) = (
+    let (d0, d1, d2, d3, d4, d5, d6, d7, d8) = (
         result.dimension_exponents.0[0],
         result.dimension_exponents.0[1],
         result.dimension_exponents.0[2],
@@ -22,8 +22,9 @@ pub fn compute_unit_dimensions(input: TokenStream) -> TokenStream {
         result.dimension_exponents.0[5],
         result.dimension_exponents.0[6],
         result.dimension_exponents.0[7],
+        result.dimension_exponents.0[8],
     );
-    let (d8, d9, d10, d11) = (
+    let (d9, d10, d11, d12) = (
         result.scale_exponents.0[0],
         result.scale_exponents.0[1],
         result.scale_exponents.0[2],
@@ -32,8 +33,8 @@ pub fn compute_unit_dimensions(input: TokenStream) -> TokenStream {
 
     quote! {
         (
-            whippyunits_core::dimension_exponents::DynDimensionExponents([#d0, #d1, #d2, #d3, #d4, #d5, #d6, #d7]),
-            whippyunits_core::scale_exponents::ScaleExponents([#d8, #d9, #d10, #d11])
+            whippyunits_core::dimension_exponents::DynDimensionExponents([#d0, #d1, #d2, #d3, #d4, #d5, #d6, #d7, #d8]),
+            whippyunits_core::scale_exponents::ScaleExponents([#d9, #d10, #d11, #d12])
         )
     }
     .into()
@@ -105,11 +105,11 @@ impl DimensionExpr {
     }
 
     // Evaluate the expression to get dimension exponents (safe version that doesn't panic)
-    fn evaluate_safe(&self) -> (i16, i16, i16, i16, i16, i16, i16, i16) {
+    fn evaluate_safe(&self) -> (i16, i16, i16, i16, i16, i16, i16, i16, i16) {
         match self {
             DimensionExpr::Dimensionless => {
                 // Dimensionless quantity - all exponents are zero
-                (0, 0, 0, 0, 0, 0, 0, 0)
+                (0, 0, 0, 0, 0, 0, 0, 0, 0)
             }
             DimensionExpr::Dimension(ident) => {
                 let name_or_symbol = ident.to_string();
@@ -125,15 +125,16 @@ impl DimensionExpr {
                         dim_info.exponents.0[5], // amount
                         dim_info.exponents.0[6], // luminous_intensity
                         dim_info.exponents.0[7], // angle
+                        dim_info.exponents.0[8], // information
                     );
                 }
 
                 // If not found, return zero exponents (error will be caught in documentation generation)
-                (0, 0, 0, 0, 0, 0, 0, 0)
+                (0, 0, 0, 0, 0, 0, 0, 0, 0)
             }
             DimensionExpr::Mul(a, b) => {
-                let (ma, la, ta, ca, tempa, aa, luma, anga) = a.evaluate_safe();
-                let (mb, lb, tb, cb, tempb, ab, lumb, angb) = b.evaluate_safe();
+                let (ma, la, ta, ca, tempa, aa, luma, anga, infoa) = a.evaluate_safe();
+                let (mb, lb, tb, cb, tempb, ab, lumb, angb, infob) = b.evaluate_safe();
                 (
                     ma + mb,
                     la + lb,
@@ -143,11 +144,12 @@ impl DimensionExpr {
                     aa + ab,
                     luma + lumb,
                     anga + angb,
+                    infoa + infob,
                 )
             }
             DimensionExpr::Div(a, b) => {
-                let (ma, la, ta, ca, tempa, aa, luma, anga) = a.evaluate_safe();
-                let (mb, lb, tb, cb, tempb, ab, lumb, angb) = b.evaluate_safe();
+                let (ma, la, ta, ca, tempa, aa, luma, anga, infoa) = a.evaluate_safe();
+                let (mb, lb, tb, cb, tempb, ab, lumb, angb, infob) = b.evaluate_safe();
                 (
                     ma - mb,
                     la - lb,
@@ -157,10 +159,11 @@ impl DimensionExpr {
                     aa - ab,
                     luma - lumb,
                     anga - angb,
+                    infoa - infob,
                 )
             }
             DimensionExpr::Pow(base, exp) => {
-                let (m, l, t, c, temp, a, lum, ang) = base.evaluate_safe();
+                let (m, l, t, c, temp, a, lum, ang, info) = base.evaluate_safe();
                 let exp_val: i16 = exp.base10_parse().unwrap();
                 (
                     m * exp_val,
@@ -171,16 +174,41 @@ impl DimensionExpr {
                     a * exp_val,
                     lum * exp_val,
                     ang * exp_val,
+                    info * exp_val,
                 )
             }
         }
     }
 }
 
+// A dimension the trait matches, or with a leading `!`, an atomic dimension it excludes
+pub enum DimensionConstraint {
+    Include(DimensionExpr),
+    Exclude(Token![!], DimensionExpr),
+}
+
+impl Parse for DimensionConstraint {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![!]) {
+            Ok(DimensionConstraint::Exclude(input.parse()?, input.parse()?))
+        } else {
+            Ok(DimensionConstraint::Include(input.parse()?))
+        }
+    }
+}
+
+impl DimensionConstraint {
+    fn expr(&self) -> &DimensionExpr {
+        match self {
+            DimensionConstraint::Include(expr) | DimensionConstraint::Exclude(_, expr) => expr,
+        }
+    }
+}
+
 pub struct DefineGenericDimensionInput {
     pub trait_name: Ident,
     pub _comma: Token![,],
-    pub dimension_exprs: Punctuated<DimensionExpr, Comma>,
+    pub dimension_exprs: Punctuated<DimensionConstraint, Comma>,
 }
 
 impl Parse for DefineGenericDimensionInput {
@@ -188,7 +216,7 @@ impl Parse for DefineGenericDimensionInput {
         Ok(DefineGenericDimensionInput {
             trait_name: input.parse()?,
             _comma: input.parse()?,
-            dimension_exprs: input.parse_terminated(DimensionExpr::parse, Token![,])?,
+            dimension_exprs: input.parse_terminated(DimensionConstraint::parse, Token![,])?,
         })
     }
 }
@@ -207,10 +235,30 @@ impl DefineGenericDimensionInput {
             }
         };
 
+        let excluded: Vec<&DimensionConstraint> = self
+            .dimension_exprs
+            .iter()
+            .filter(|constraint| matches!(constraint, DimensionConstraint::Exclude(..)))
+            .collect();
+        if !excluded.is_empty() {
+            let impl_block = match self.generate_exclusion_impl(&excluded) {
+                Ok(impl_block) => impl_block,
+                Err(error) => error.to_compile_error(),
+            };
+            return quote! {
+                #doc_structs
+
+                #trait_def
+
+                #impl_block
+            };
+        }
+
         // Generate implementations for each dimension expression
         let impl_blocks: Vec<TokenStream> = self
             .dimension_exprs
             .iter()
+            .map(DimensionConstraint::expr)
             .map(|expr| {
                 // Try to evaluate the expression, but handle errors gracefully
                 let (
@@ -222,6 +270,7 @@ impl DefineGenericDimensionInput {
                     amount_exp,
                     lum_exp,
                     angle_exp,
+                    info_exp,
                 ) = expr.evaluate_safe();
                 self.generate_impl(
                     mass_exp,
@@ -232,6 +281,7 @@ impl DefineGenericDimensionInput {
                     amount_exp,
                     lum_exp,
                     angle_exp,
+                    info_exp,
                 )
             })
             .collect();
@@ -255,6 +305,7 @@ impl DefineGenericDimensionInput {
         amount_exp: i16,
         lum_exp: i16,
         angle_exp: i16,
+        info_exp: i16,
     ) -> TokenStream {
         let trait_name = &self.trait_name;
 
@@ -269,7 +320,7 @@ impl DefineGenericDimensionInput {
                 T
             > #trait_name for whippyunits::quantity::Quantity<
                 whippyunits::quantity::Scale<whippyunits::quantity::_2<SCALE_P2>, whippyunits::quantity::_3<SCALE_P3>, whippyunits::quantity::_5<SCALE_P5>, whippyunits::quantity::_Pi<SCALE_PI>>,
-                whippyunits::quantity::Dimension<whippyunits::quantity::_M<#mass_exp>, whippyunits::quantity::_L<#length_exp>, whippyunits::quantity::_T<#time_exp>, whippyunits::quantity::_I<#current_exp>, whippyunits::quantity::_Θ<#temp_exp>, whippyunits::quantity::_N<#amount_exp>, whippyunits::quantity::_J<#lum_exp>, whippyunits::quantity::_A<#angle_exp>>,
+                whippyunits::quantity::Dimension<whippyunits::quantity::_M<#mass_exp>, whippyunits::quantity::_L<#length_exp>, whippyunits::quantity::_T<#time_exp>, whippyunits::quantity::_I<#current_exp>, whippyunits::quantity::_Θ<#temp_exp>, whippyunits::quantity::_N<#amount_exp>, whippyunits::quantity::_J<#lum_exp>, whippyunits::quantity::_A<#angle_exp>, whippyunits::quantity::_Info<#info_exp>>,
                 T
             > {
                 type Unit = Self;
@@ -277,15 +328,115 @@ impl DefineGenericDimensionInput {
         }
     }
 
+    // A single impl for every quantity whose excluded exponents are all zero; every other
+    // dimension exponent stays generic, alongside the scale and storage type.
+    fn generate_exclusion_impl(&self, excluded: &[&DimensionConstraint]) -> Result<TokenStream> {
+        let trait_name = &self.trait_name;
+
+        if excluded.len() != self.dimension_exprs.len() {
+            return Err(syn::Error::new(
+                trait_name.span(),
+                "excluded dimensions (`!Dimension`) cannot be combined with included dimensions",
+            ));
+        }
+
+        let mut is_excluded = [false; 9];
+        for constraint in excluded {
+            let DimensionConstraint::Exclude(bang, expr) = constraint else {
+                unreachable!()
+            };
+            let DimensionExpr::Dimension(ident) = expr else {
+                return Err(syn::Error::new(
+                    bang.span,
+                    "only atomic dimensions (such as `!Mass` or `!M`) can be excluded",
+                ));
+            };
+            let exponents = expr.evaluate_safe();
+            let exponents = [
+                exponents.0,
+                exponents.1,
+                exponents.2,
+                exponents.3,
+                exponents.4,
+                exponents.5,
+                exponents.6,
+                exponents.7,
+                exponents.8,
+            ];
+            // Unknown dimensions evaluate to all zeros and are reported by the documentation
+            // structs; composite named dimensions (such as `!Velocity`) are rejected here
+            if exponents.iter().filter(|&&exponent| exponent != 0).count() > 1
+                || exponents
+                    .iter()
+                    .any(|&exponent| exponent != 0 && exponent != 1)
+            {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "only atomic dimensions (such as `!Mass` or `!M`) can be excluded",
+                ));
+            }
+            if let Some(index) = exponents.iter().position(|&exponent| exponent == 1) {
+                is_excluded[index] = true;
+            }
+        }
+
+        let names = [
+            "MASS_EXPONENT",
+            "LENGTH_EXPONENT",
+            "TIME_EXPONENT",
+            "CURRENT_EXPONENT",
+            "TEMPERATURE_EXPONENT",
+            "AMOUNT_EXPONENT",
+            "LUMINOSITY_EXPONENT",
+            "ANGLE_EXPONENT",
+            "INFORMATION_EXPONENT",
+        ];
+        let generic_params: Vec<TokenStream> = names
+            .iter()
+            .zip(is_excluded)
+            .filter(|(_, excluded)| !excluded)
+            .map(|(name, _)| {
+                let ident = Ident::new(name, proc_macro2::Span::call_site());
+                quote! { const #ident: i16, }
+            })
+            .collect();
+        let exponents: [TokenStream; 9] = core::array::from_fn(|index| {
+            if is_excluded[index] {
+                quote! { 0 }
+            } else {
+                let ident = Ident::new(names[index], proc_macro2::Span::call_site());
+                quote! { #ident }
+            }
+        });
+        let [m, l, t, i, th, n, j, a, info] = &exponents;
+
+        Ok(quote! {
+            impl <
+                #(#generic_params)*
+                const SCALE_P2: i16,
+                const SCALE_P3: i16,
+                const SCALE_P5: i16,
+                const SCALE_PI: i16,
+                T
+            > #trait_name for whippyunits::quantity::Quantity<
+                whippyunits::quantity::Scale<whippyunits::quantity::_2<SCALE_P2>, whippyunits::quantity::_3<SCALE_P3>, whippyunits::quantity::_5<SCALE_P5>, whippyunits::quantity::_Pi<SCALE_PI>>,
+                whippyunits::quantity::Dimension<whippyunits::quantity::_M<#m>, whippyunits::quantity::_L<#l>, whippyunits::quantity::_T<#t>, whippyunits::quantity::_I<#i>, whippyunits::quantity::_Θ<#th>, whippyunits::quantity::_N<#n>, whippyunits::quantity::_J<#j>, whippyunits::quantity::_A<#a>, whippyunits::quantity::_Info<#info>>,
+                T
+            > {
+                type Unit = Self;
+            }
+        })
+    }
+
     /// Generate documentation structs for dimension identifiers used in expressions
     fn generate_dimension_documentation(
-        dimension_exprs: &Punctuated<DimensionExpr, Comma>,
+        dimension_exprs: &Punctuated<DimensionConstraint, Comma>,
     ) -> TokenStream {
         let mut doc_structs = Vec::new();
 
         // Generate documentation for each identifier occurrence (no filtering)
-        for expr in dimension_exprs {
-            Self::collect_and_generate_dimension_docs(expr, &mut doc_structs);
+        for constraint in dimension_exprs {
+            Self::collect_and_generate_dimension_docs(constraint.expr(), &mut doc_structs);
         }
 
         quote! {
@@ -378,6 +529,7 @@ impl DefineGenericDimensionInput {
             "Amount" => "Atomic dimension: Amount (N) - The fundamental dimension of amount of substance in the SI system".to_string(),
             "Luminosity" => "Atomic dimension: Luminosity (J) - The fundamental dimension of luminous intensity in the SI system".to_string(),
             "Angle" => "Atomic dimension: Angle (A) - The fundamental dimension of plane angle in the SI system".to_string(),
+            "Information" => "Atomic dimension: Information (Info) - The fundamental dimension of information, measured in bits".to_string(),
             // Atomic dimensions - symbols
             "M" => "Atomic dimension: Mass (M) - The fundamental dimension of mass in the SI system".to_string(),
             "L" => "Atomic dimension: Length (L) - The fundamental dimension of length in the SI system".to_string(),
@@ -387,6 +539,7 @@ impl DefineGenericDimensionInput {
             "N" => "Atomic dimension: Amount (N) - The fundamental dimension of amount of substance in the SI system".to_string(),
             "J" => "Atomic dimension: Luminosity (J) - The fundamental dimension of luminous intensity in the SI system".to_string(),
             "A" => "Atomic dimension: Angle (A) - The fundamental dimension of plane angle in the SI system".to_string(),
+            "Info" => "Atomic dimension: Information (Info) - The fundamental dimension of information, measured in bits".to_string(),
             _ => format!("Dimension: {} - Custom dimension expression", dimension_name),
         }
     }
@@ -404,6 +557,7 @@ impl DefineGenericDimensionInput {
             "Amount" => Some(quote! { whippyunits::dimension_traits::Amount }),
             "Luminosity" => Some(quote! { whippyunits::dimension_traits::Luminosity }),
             "Angle" => Some(quote! { whippyunits::dimension_traits::Angle }),
+            "Information" => Some(quote! { whippyunits::dimension_traits::Information }),
 
             // Atomic dimensions - symbols
             "M" => Some(quote! { whippyunits::dimension_traits::Mass }),
@@ -414,6 +568,7 @@ impl DefineGenericDimensionInput {
             "N" => Some(quote! { whippyunits::dimension_traits::Amount }),
             "J" => Some(quote! { whippyunits::dimension_traits::Luminosity }),
             "A" => Some(quote! { whippyunits::dimension_traits::Angle }),
+            "Info" => Some(quote! { whippyunits::dimension_traits::Information }),
 
             _ => None, // Unknown dimension
         }
@@ -434,7 +589,7 @@ impl DefineGenericDimensionInput {
                 Dimension::ALL.iter().map(|dim| dim.symbol).collect();
 
             format!(
-                "Unknown dimension '{}'. Supported dimension names: {}. Supported dimension symbols: {}", 
+                "Unknown dimension '{}'. Supported dimension names: {}. Supported dimension symbols: {}",
                 dimension_name,
                 supported_names.join(", "),
                 supported_symbols.join(", ")
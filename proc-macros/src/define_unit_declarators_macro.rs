@@ -434,7 +434,7 @@ impl DefineBaseUnitsInput {
     ) {
         use whippyunits_core::Dimension;
 
-        // Get the atomic dimensions (first 8 dimensions are the base dimensions)
+        // Get the atomic dimensions (first 9 dimensions are the base dimensions)
         let base_dimensions = Dimension::BASIS;
 
         for dimension in base_dimensions {
@@ -447,6 +447,7 @@ impl DefineBaseUnitsInput {
                 _amount_exp,
                 _luminosity_exp,
                 _angle_exp,
+                _information_exp,
             ) = (
                 dimension.exponents.0[0], // mass
                 dimension.exponents.0[1], // length
@@ -456,6 +457,7 @@ impl DefineBaseUnitsInput {
                 dimension.exponents.0[5], // amount
                 dimension.exponents.0[6], // luminous_intensity
                 dimension.exponents.0[7], // angle
+                dimension.exponents.0[8], // information
             );
 
             // Get the first unit (base unit) from this dimension
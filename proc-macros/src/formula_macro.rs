@@ -0,0 +1,248 @@
+use proc_macro2::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream, Result};
+use syn::{Ident, Token};
+use whippyunits_core::UnitExpr;
+
+/// Input for the formula! macro
+/// Syntax: formula!(expr), formula!(name = expr), or formula!(name: unit = expr), where `expr`
+/// is written with `+`, `-`, `*`, `/`, parentheses, and `^` raised to an integer literal
+pub struct FormulaMacroInput {
+    binding: Option<Ident>,
+    unit: Option<TokenStream>,
+    expr: TokenStream,
+}
+
+impl Parse for FormulaMacroInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut binding = None;
+        let mut unit = None;
+        if input.peek(Ident) && (input.peek2(Token![=]) && !input.peek2(Token![==])) {
+            binding = Some(input.parse()?);
+            let _eq: Token![=] = input.parse()?;
+        } else if input.peek(Ident) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+            binding = Some(input.parse()?);
+            let colon: Token![:] = input.parse()?;
+            let unit_tokens = input.step(|cursor| {
+                let mut tokens = TokenStream::new();
+                let mut rest = *cursor;
+                while let Some((tt, next)) = rest.token_tree() {
+                    match &tt {
+                        TokenTree::Punct(punct) if punct.as_char() == '=' => {
+                            return Ok((tokens, rest));
+                        }
+                        _ => {
+                            tokens.extend(core::iter::once(tt));
+                            rest = next;
+                        }
+                    }
+                }
+                Err(cursor.error("expected `=` followed by the formula"))
+            })?;
+            if unit_tokens.is_empty() {
+                return Err(syn::Error::new(
+                    colon.span,
+                    "expected the unit of the result after `:`",
+                ));
+            }
+            syn::parse2::<UnitExpr>(unit_tokens.clone())?;
+            unit = Some(unit_tokens);
+            let _eq: Token![=] = input.parse()?;
+        }
+
+        let tokens: Vec<TokenTree> = input.parse::<TokenStream>()?.into_iter().collect();
+        if tokens.is_empty() {
+            return Err(input.error("expected a formula"));
+        }
+        let expr = rewrite(&tokens)?;
+
+        Ok(FormulaMacroInput {
+            binding,
+            unit,
+            expr,
+        })
+    }
+}
+
+/// Whether `tt` is one of the arithmetic operators that separate the terms of a formula.
+fn is_operator(tt: &TokenTree) -> bool {
+    matches!(tt, TokenTree::Punct(punct) if "+-*/^".contains(punct.as_char()))
+}
+
+/// A bare numeric literal as a dimensionless `f64`, or `None` if it needs no rewriting.
+///
+/// Integer literals gain a fractional part, so that `2 * m` scales an `f64` quantity rather than
+/// asking for an integer-by-quantity product.  With `typed`, the literal is also given an `f64`
+/// suffix, which a literal base of `^` needs to pick its `pow`.  Suffixed literals are left to
+/// the caller's choice.
+fn dimensionless_literal(literal: &Literal, typed: bool) -> Option<Literal> {
+    let text = literal.to_string().replace('_', "");
+    if !text.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let value: f64 = text.parse().ok()?;
+    let mut rewritten = if typed {
+        Literal::f64_suffixed(value)
+    } else if text.chars().all(|c| c.is_ascii_digit()) {
+        Literal::f64_unsuffixed(value)
+    } else {
+        return None;
+    };
+    rewritten.set_span(literal.span());
+    Some(rewritten)
+}
+
+/// The integer exponent following a `^`, either a literal (`2`, `-1`) or a parenthesized one
+/// (`(-1)`), and the number of tokens it took.
+fn exponent(tokens: &[TokenTree], caret: Span) -> Result<(TokenStream, usize)> {
+    let error = || {
+        syn::Error::new(
+            caret,
+            "`^` must be followed by an integer exponent, such as `^2` or `^-1`",
+        )
+    };
+    let (inner, consumed): (Vec<TokenTree>, usize) = match tokens.first() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            (group.stream().into_iter().collect(), 1)
+        }
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '-' => {
+            (tokens.iter().take(2).cloned().collect(), 2)
+        }
+        Some(_) => (tokens.iter().take(1).cloned().collect(), 1),
+        None => return Err(error()),
+    };
+    let (negative, literal) = match inner.as_slice() {
+        [TokenTree::Literal(literal)] => (false, literal),
+        [TokenTree::Punct(minus), TokenTree::Literal(literal)] if minus.as_char() == '-' => {
+            (true, literal)
+        }
+        _ => return Err(error()),
+    };
+    let power: i16 = literal.to_string().parse().map_err(|_| error())?;
+    let power = Literal::i16_unsuffixed(power);
+    let tokens = if negative {
+        quote! { -#power }
+    } else {
+        quote! { #power }
+    };
+    Ok((tokens, consumed))
+}
+
+/// Rewrite a formula into an ordinary Rust expression.
+///
+/// The formula is split into operators and the terms between them.  A term is a literal, a
+/// parenthesized sub-formula, or any other run of tokens (a variable, a path such as
+/// `constants::G`, a method call), which is kept as written.  Bare numeric literals become
+/// dimensionless `f64`s, and `term ^ n` becomes `term.pow::<n>()`, which scales the dimension of
+/// a quantity and is plain exponentiation for a scalar.  Since `^` binds tighter than every
+/// other operator, `-x ^ 2` is `-(x²)`, as in ordinary mathematical notation.
+fn rewrite(tokens: &[TokenTree]) -> Result<TokenStream> {
+    // Terms are kept apart from operators until every `^` has claimed the term before it.
+    enum Item {
+        Term(TokenStream, bool),
+        Operator(TokenTree),
+    }
+
+    let mut items: Vec<Item> = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let tt = &tokens[i];
+        if let TokenTree::Punct(punct) = tt {
+            if punct.as_char() == '^' {
+                let base = match items.pop() {
+                    Some(Item::Term(term, is_literal)) => (term, is_literal),
+                    _ => {
+                        return Err(syn::Error::new(
+                            punct.span(),
+                            "`^` must follow the term it raises to a power",
+                        ))
+                    }
+                };
+                let (power, consumed) = exponent(&tokens[i + 1..], punct.span())?;
+                let (mut term, is_literal) = base;
+                if is_literal {
+                    // A literal base needs a concrete type to pick the scalar `pow`
+                    let literal: Vec<TokenTree> = term.clone().into_iter().collect();
+                    if let [TokenTree::Literal(literal)] = literal.as_slice() {
+                        if let Some(typed) = dimensionless_literal(literal, true) {
+                            term = typed.into_token_stream();
+                        }
+                    }
+                }
+                items.push(Item::Term(quote! { #term.pow::<#power>() }, false));
+                i += 1 + consumed;
+                if let Some(TokenTree::Punct(next)) = tokens.get(i) {
+                    if next.as_char() == '^' {
+                        return Err(syn::Error::new(
+                            next.span(),
+                            "chained `^` is ambiguous; parenthesize the base, as in `(x ^ 2) ^ 3`",
+                        ));
+                    }
+                }
+                continue;
+            }
+        }
+        if is_operator(tt) {
+            items.push(Item::Operator(tt.clone()));
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < tokens.len() && !is_operator(&tokens[i]) {
+            i += 1;
+        }
+        let run = &tokens[start..i];
+        let term = match run {
+            [TokenTree::Literal(literal)] => match dimensionless_literal(literal, false) {
+                Some(literal) => Item::Term(literal.into_token_stream(), true),
+                None => Item::Term(literal.to_token_stream(), true),
+            },
+            [TokenTree::Group(group), rest @ ..] if group.delimiter() == Delimiter::Parenthesis => {
+                let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+                if inner.is_empty() {
+                    return Err(syn::Error::new(group.span(), "expected a formula"));
+                }
+                let mut rewritten = Group::new(Delimiter::Parenthesis, rewrite(&inner)?);
+                rewritten.set_span(group.span());
+                let mut term = rewritten.into_token_stream();
+                term.extend(rest.iter().cloned());
+                Item::Term(term, false)
+            }
+            _ => Item::Term(run.iter().cloned().collect(), false),
+        };
+        items.push(term);
+    }
+
+    let mut output = TokenStream::new();
+    for item in items {
+        match item {
+            Item::Term(term, _) => output.extend(term),
+            Item::Operator(operator) => output.extend(core::iter::once(operator)),
+        }
+    }
+    Ok(output)
+}
+
+impl FormulaMacroInput {
+    pub fn expand(self) -> TokenStream {
+        let expr = &self.expr;
+        let value = quote! {
+            {
+                #[allow(unused_imports)]
+                use whippyunits::__ScalarPow as _;
+                #expr
+            }
+        };
+
+        match (self.binding, self.unit) {
+            (Some(binding), Some(unit)) => quote! {
+                let #binding: whippyunits::unit!(#unit) = #value;
+            },
+            (Some(binding), None) => quote! {
+                let #binding = #value;
+            },
+            (None, _) => value,
+        }
+    }
+}
@@ -48,7 +48,7 @@ impl DefaultDeclaratorsInput {
     /// Extract dimension exponents from a dimension
     fn extract_dimension_exponents(
         dimension: &whippyunits_core::Dimension,
-    ) -> (i16, i16, i16, i16, i16, i16, i16, i16) {
+    ) -> (i16, i16, i16, i16, i16, i16, i16, i16, i16) {
         (
             dimension.exponents.0[0], // mass
             dimension.exponents.0[1], // length
@@ -58,6 +58,7 @@ impl DefaultDeclaratorsInput {
             dimension.exponents.0[5], // amount
             dimension.exponents.0[6], // luminous_intensity
             dimension.exponents.0[7], // angle
+            dimension.exponents.0[8], // information
         )
     }
 
@@ -143,6 +144,7 @@ impl DefaultDeclaratorsInput {
             amount_exp,
             luminosity_exp,
             angle_exp,
+            info_exp,
         ) = Self::extract_dimension_exponents(dimension);
 
         // Filter units by type
@@ -180,6 +182,7 @@ impl DefaultDeclaratorsInput {
                     amount_exp,
                     luminosity_exp,
                     angle_exp,
+                    info_exp,
                     &trait_ident,
                     &scale_definitions,
                 );
@@ -209,6 +212,7 @@ impl DefaultDeclaratorsInput {
                     amount_exp,
                     luminosity_exp,
                     angle_exp,
+                    info_exp,
                     &trait_ident,
                     &scale_definitions,
                 );
@@ -248,6 +252,7 @@ impl DefaultDeclaratorsInput {
                     amount_exp,
                     luminosity_exp,
                     angle_exp,
+                    info_exp,
                     &trait_ident,
                     &storage_scale_ident,
                     &scale_definitions,
@@ -279,6 +284,7 @@ impl DefaultDeclaratorsInput {
                     amount_exp,
                     luminosity_exp,
                     angle_exp,
+                    info_exp,
                     &trait_ident,
                     &scale_definitions,
                 );
@@ -326,6 +332,7 @@ impl DefaultDeclaratorsInput {
             amount_exp,
             luminosity_exp,
             angle_exp,
+            info_exp,
         ) = Self::extract_dimension_exponents(dimension);
 
         // Generate trait name from dimension name
@@ -455,6 +462,7 @@ impl DefaultDeclaratorsInput {
                 amount_exp,
                 luminosity_exp,
                 angle_exp,
+                info_exp,
                 &trait_ident,
                 &scale_definitions,
             );
@@ -544,6 +552,7 @@ impl DefaultDeclaratorsInput {
                 amount_exp,
                 luminosity_exp,
                 angle_exp,
+                info_exp,
             ) = Self::extract_dimension_exponents(dimension);
 
             // Convert units to the format expected by process_units_by_type
@@ -584,6 +593,7 @@ impl DefaultDeclaratorsInput {
                         amount_exp,
                         luminosity_exp,
                         angle_exp,
+                        info_exp,
                     );
 
                     unit_definitions.push((
@@ -607,6 +617,7 @@ impl DefaultDeclaratorsInput {
                     amount_exp,
                     luminosity_exp,
                     angle_exp,
+                    info_exp,
                     &trait_ident,
                     &unit_definitions,
                 );
@@ -664,6 +675,7 @@ impl DefaultDeclaratorsInput {
         amount_exp: i16,
         luminosity_exp: i16,
         angle_exp: i16,
+        info_exp: i16,
     ) -> String {
         use whippyunits_core::{
             dimension_exponents::DynDimensionExponents,
@@ -684,6 +696,7 @@ impl DefaultDeclaratorsInput {
             amount_exp,
             luminosity_exp,
             angle_exp,
+            info_exp,
         ]);
 
         // Use the exact same logic as prettyprint by calling the same functions from core
@@ -754,6 +767,7 @@ impl DefaultDeclaratorsInput {
         amount_exp: i16,
         luminosity_exp: i16,
         angle_exp: i16,
+        info_exp: i16,
         trait_ident: &Ident,
         scale_definitions: &[TokenStream],
     ) -> TokenStream {
@@ -767,6 +781,7 @@ impl DefaultDeclaratorsInput {
                 #amount_exp,
                 #luminosity_exp,
                 #angle_exp,
+                #info_exp,
                 #trait_ident,
                 #(#scale_definitions),*
             );
@@ -784,6 +799,7 @@ impl DefaultDeclaratorsInput {
         amount_exp: i16,
         luminosity_exp: i16,
         angle_exp: i16,
+        info_exp: i16,
         trait_ident: &Ident,
         scale_definitions: &[TokenStream],
     ) -> TokenStream {
@@ -797,6 +813,7 @@ impl DefaultDeclaratorsInput {
                 #amount_exp,
                 #luminosity_exp,
                 #angle_exp,
+                #info_exp,
                 #trait_ident,
                 #(#scale_definitions),*
             );
@@ -814,6 +831,7 @@ impl DefaultDeclaratorsInput {
         amount_exp: i16,
         luminosity_exp: i16,
         angle_exp: i16,
+        info_exp: i16,
         trait_ident: &Ident,
         storage_scale_ident: &Ident,
         scale_definitions: &[TokenStream],
@@ -828,6 +846,7 @@ impl DefaultDeclaratorsInput {
                 #amount_exp,
                 #luminosity_exp,
                 #angle_exp,
+                #info_exp,
                 #trait_ident,
                 #storage_scale_ident,
                 #(#scale_definitions),*
@@ -846,6 +865,7 @@ impl DefaultDeclaratorsInput {
         amount_exp: i16,
         luminosity_exp: i16,
         angle_exp: i16,
+        info_exp: i16,
         trait_ident: &Ident,
         scale_definitions: &[TokenStream],
     ) -> TokenStream {
@@ -859,6 +879,7 @@ impl DefaultDeclaratorsInput {
                 #amount_exp,
                 #luminosity_exp,
                 #angle_exp,
+                #info_exp,
                 #trait_ident,
                 #(#scale_definitions),*
             );
@@ -876,6 +897,7 @@ impl DefaultDeclaratorsInput {
         amount_exp: i16,
         luminosity_exp: i16,
         angle_exp: i16,
+        info_exp: i16,
         trait_ident: &Ident,
         unit_definitions: &[(Ident, f64, i16, i16, i16, i16, String)],
     ) -> TokenStream {
@@ -902,7 +924,7 @@ impl DefaultDeclaratorsInput {
                 #[doc = #doc_string]
                 fn #fn_name_ident(self) -> crate::quantity::Quantity<
                     crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>,
-                    crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>>,
+                    crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>, crate::quantity::_Info<#info_exp>>,
                     T,
                 >;
             });
@@ -911,10 +933,10 @@ impl DefaultDeclaratorsInput {
             impl_f64_methods.push(quote! {
                 fn #fn_name_ident(self) -> crate::quantity::Quantity<
                     crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>,
-                    crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>>,
+                    crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>, crate::quantity::_Info<#info_exp>>,
                     f64,
                 > {
-                    crate::quantity::Quantity::<crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>, crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>>, f64>::new(self * #conversion_factor)
+                    crate::quantity::Quantity::<crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>, crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>, crate::quantity::_Info<#info_exp>>, f64>::new(self * #conversion_factor)
                 }
             });
 
@@ -922,10 +944,10 @@ impl DefaultDeclaratorsInput {
             impl_i32_methods.push(quote! {
                 fn #fn_name_ident(self) -> crate::quantity::Quantity<
                     crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>,
-                    crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>>,
+                    crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>, crate::quantity::_Info<#info_exp>>,
                     i32,
                 > {
-                    crate::quantity::Quantity::<crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>, crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>>, i32>::new((self as f64 * #conversion_factor) as i32)
+                    crate::quantity::Quantity::<crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>, crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>, crate::quantity::_Info<#info_exp>>, i32>::new((self as f64 * #conversion_factor) as i32)
                 }
             });
 
@@ -933,10 +955,10 @@ impl DefaultDeclaratorsInput {
             impl_i64_methods.push(quote! {
                 fn #fn_name_ident(self) -> crate::quantity::Quantity<
                     crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>,
-                    crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>>,
+                    crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>, crate::quantity::_Info<#info_exp>>,
                     i64,
                 > {
-                    crate::quantity::Quantity::<crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>, crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>>, i64>::new((self as f64 * #conversion_factor) as i64)
+                    crate::quantity::Quantity::<crate::quantity::Scale<crate::quantity::_2<#p2>, crate::quantity::_3<#p3>, crate::quantity::_5<#p5>, crate::quantity::_Pi<#pi>>, crate::quantity::Dimension<crate::quantity::_M<#mass_exp>, crate::quantity::_L<#length_exp>, crate::quantity::_T<#time_exp>, crate::quantity::_I<#current_exp>, crate::quantity::_Θ<#temperature_exp>, crate::quantity::_N<#amount_exp>, crate::quantity::_J<#luminosity_exp>, crate::quantity::_A<#angle_exp>, crate::quantity::_Info<#info_exp>>, i64>::new((self as f64 * #conversion_factor) as i64)
                 }
             });
 
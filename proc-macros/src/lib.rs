@@ -8,6 +8,7 @@ mod define_generic_dimension_macro;
 mod define_literals_macro;
 mod define_local_quantity_macro;
 mod define_unit_declarators_macro;
+mod formula_macro;
 mod generate_all_dimensionless_cross_type_macro;
 mod generate_all_radian_erasures_macro;
 mod generate_default_declarators_macro;
@@ -17,6 +18,7 @@ mod local_unit_type_macro;
 mod pow_lookup_macro;
 mod quantity_macro;
 mod unit_macro;
+mod value_fmt_macro;
 mod value_macro;
 
 mod utils {
@@ -24,6 +26,7 @@ mod utils {
     pub mod dimension_suggestions;
     pub mod lift_trace;
     pub mod literal_macros;
+    pub mod prefix_confusion;
     pub mod scale_suggestions;
     pub mod shared_utils;
     pub mod unit_suggestions;
@@ -63,7 +66,7 @@ pub fn define_generic_dimension(input: TokenStream) -> TokenStream {
 ///         - An exponentiation of an atomic unit:
 ///             - `m2`, `m^2`
 ///         - A multiplication of two or more (possibly exponentiated) atomic units:
-///             - `kg.m2`, `kg * m2`
+///             - `kg.m2`, `kg * m2`, `kg m2` (whitespace between units multiplies)
 ///         - A division of two such product expressions:
 ///             - `kg.m2/s2`, `kg * m2 / s^2`
 ///             - There may be at most one division expression in a unit literal expression
@@ -116,7 +119,7 @@ pub fn proc_unit(input: TokenStream) -> TokenStream {
 ///         - An exponentiation of an atomic unit:
 ///             - `m2`, `m^2`
 ///         - A multiplication of two or more (possibly exponentiated) atomic units:
-///             - `kg.m2`, `kg * m2`
+///             - `kg.m2`, `kg * m2`, `kg m2` (whitespace between units multiplies)
 ///         - A division of two such product expressions:
 ///             - `kg.m2/s2`, `kg * m2 / s^2`
 ///             - There may be at most one division expression in a unit literal expression
@@ -215,6 +218,18 @@ pub fn proc_value(input: TokenStream) -> TokenStream {
     input.expand().into()
 }
 
+#[proc_macro]
+pub fn proc_formula(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as formula_macro::FormulaMacroInput);
+    input.expand().into()
+}
+
+#[proc_macro]
+pub fn proc_value_fmt(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as value_fmt_macro::ValueFmtMacroInput);
+    input.expand().into()
+}
+
 #[proc_macro]
 #[doc(hidden)]
 pub fn local_unit_type(input: TokenStream) -> TokenStream {
@@ -318,7 +333,7 @@ pub fn define_unit_declarators(input: TokenStream) -> TokenStream {
 /// - `output!(CO / PV)` → `<CO as Div<PV>>::Output`
 /// - `output!(CO / PV * PV)` → `<<CO as Div<PV>>::Output as Mul<PV>>::Output`
 /// - `output!((CO * T) / PV)` → `<<CO as Mul<T>>::Output as Div<PV>>::Output`
-/// - `output!(1 / T)` → `<<whippyunits::quantity::Quantity<whippyunits::quantity::Scale<whippyunits::quantity::_2<0>, whippyunits::quantity::_3<0>, whippyunits::quantity::_5<0>, whippyunits::quantity::_Pi<0>>, whippyunits::quantity::Dimension<whippyunits::quantity::_M<0>, whippyunits::quantity::_L<0>, whippyunits::quantity::_T<0>, whippyunits::quantity::_I<0>, whippyunits::quantity::_Θ<0>, whippyunits::quantity::_N<0>, whippyunits::quantity::_J<0>, whippyunits::quantity::_A<0>>, f64> as Div<T>>::Output`
+/// - `output!(1 / T)` → `<<whippyunits::quantity::Quantity<whippyunits::quantity::Scale<whippyunits::quantity::_2<0>, whippyunits::quantity::_3<0>, whippyunits::quantity::_5<0>, whippyunits::quantity::_Pi<0>>, whippyunits::quantity::Dimension<whippyunits::quantity::_M<0>, whippyunits::quantity::_L<0>, whippyunits::quantity::_T<0>, whippyunits::quantity::_I<0>, whippyunits::quantity::_Θ<0>, whippyunits::quantity::_N<0>, whippyunits::quantity::_J<0>, whippyunits::quantity::_A<0>, whippyunits::quantity::_Info<0>>, f64> as Div<T>>::Output`
 #[proc_macro]
 pub fn output(input: TokenStream) -> TokenStream {
     use quote::quote;
@@ -382,7 +397,8 @@ pub fn output(input: TokenStream) -> TokenStream {
                                     whippyunits::quantity::_Θ<0>,
                                     whippyunits::quantity::_N<0>,
                                     whippyunits::quantity::_J<0>,
-                                    whippyunits::quantity::_A<0>
+                                    whippyunits::quantity::_A<0>,
+                                    whippyunits::quantity::_Info<0>
                                 >,
                                 f64
                             >
@@ -76,9 +76,19 @@ fn get_units_by_exponents(
 /// This converts dimension exponents back to a unit expression string using the provided base units
 fn dimension_exponents_to_unit_expression_with_base_units(
     exponents: DynDimensionExponents,
-    base_units: &[(&str, &str); 8],
+    base_units: &[(&str, &str); 9],
 ) -> String {
-    let (mass_exp, length_exp, time_exp, current_exp, temp_exp, amount_exp, lum_exp, angle_exp) = (
+    let (
+        mass_exp,
+        length_exp,
+        time_exp,
+        current_exp,
+        temp_exp,
+        amount_exp,
+        lum_exp,
+        angle_exp,
+        info_exp,
+    ) = (
         exponents.0[0],
         exponents.0[1],
         exponents.0[2],
@@ -87,6 +97,7 @@ fn dimension_exponents_to_unit_expression_with_base_units(
         exponents.0[5],
         exponents.0[6],
         exponents.0[7],
+        exponents.0[8],
     );
 
     let mut terms = Vec::new();
@@ -116,6 +127,9 @@ fn dimension_exponents_to_unit_expression_with_base_units(
     if angle_exp != 0 {
         terms.push(format!("{}^{}", base_units[7].0, angle_exp));
     }
+    if info_exp != 0 {
+        terms.push(format!("{}^{}", base_units[8].0, info_exp));
+    }
 
     if terms.is_empty() {
         "1".to_string() // dimensionless
@@ -274,6 +288,7 @@ impl LocalQuantityMacroInput {
                 let unit_name = unit.name.to_string();
                 self.get_transformed_unit_symbol(&unit_name)
             }
+            UnitExpr::Coefficient(coefficient) => coefficient.to_string(),
             UnitExpr::Div(numerator, denominator) => {
                 let num_str = match numerator.as_ref() {
                     UnitExpr::Unit(unit) => {
@@ -315,6 +330,7 @@ impl LocalQuantityMacroInput {
     fn generate_transformed_unit_expression_string_for_expr(&self, expr: &UnitExpr) -> String {
         match expr {
             UnitExpr::Unit(unit) => self.get_transformed_unit_symbol(&unit.name.to_string()),
+            UnitExpr::Coefficient(coefficient) => coefficient.to_string(),
             UnitExpr::Div(numerator, denominator) => {
                 let num_str = self.generate_transformed_unit_expression_string_for_expr(numerator);
                 let den_str =
@@ -506,13 +522,14 @@ impl LocalQuantityMacroInput {
             ("mol", "mol"),
             ("cd", "cd"),
             ("rad", "rad"),
+            ("bit", "bit"),
         ];
 
         self.generate_unit_expression_with_base_units(&si_base_units)
     }
 
     /// Get the local base units array, converting scale types to actual unit symbols
-    fn get_local_base_units(&self) -> [(String, String); 8] {
+    fn get_local_base_units(&self) -> [(String, String); 9] {
         let mass_base = scale_type_to_actual_unit_symbol(&self.mass_scale.to_string())
             .unwrap_or_else(|| "g".to_string());
         let length_base = scale_type_to_actual_unit_symbol(&self.length_scale.to_string())
@@ -540,11 +557,13 @@ impl LocalQuantityMacroInput {
             (amount_base.clone(), amount_base),
             (luminosity_base.clone(), luminosity_base),
             (angle_base.clone(), angle_base),
+            // Information has no local scale; it is always stored in bits
+            ("bit".to_string(), "bit".to_string()),
         ]
     }
 
     /// Shared helper to generate unit expression string with given base units
-    fn generate_unit_expression_with_base_units(&self, base_units: &[(&str, &str); 8]) -> String {
+    fn generate_unit_expression_with_base_units(&self, base_units: &[(&str, &str); 9]) -> String {
         // Evaluate the unit expression to get dimension exponents
         let result = self
             .unit_expr
@@ -630,7 +649,7 @@ impl LocalQuantityMacroInput {
     }
 
     /// Convert local base units array to string references for use in dimension_exponents_to_unit_expression
-    fn get_local_base_units_refs(&self) -> [(String, String); 8] {
+    fn get_local_base_units_refs(&self) -> [(String, String); 9] {
         self.get_local_base_units()
     }
 
@@ -673,7 +692,7 @@ impl LocalQuantityMacroInput {
     ) -> String {
         // Decompose into component units and map each to its local scale counterpart
         let local_base_units = self.get_local_base_units_refs();
-        let base_units_refs: [(&str, &str); 8] = [
+        let base_units_refs: [(&str, &str); 9] = [
             (
                 local_base_units[0].0.as_str(),
                 local_base_units[0].1.as_str(),
@@ -706,6 +725,10 @@ impl LocalQuantityMacroInput {
                 local_base_units[7].0.as_str(),
                 local_base_units[7].1.as_str(),
             ),
+            (
+                local_base_units[8].0.as_str(),
+                local_base_units[8].1.as_str(),
+            ),
         ];
 
         let result =
@@ -142,7 +142,17 @@ impl QuantityMacroInput {
             .evaluate_with_mode(whippyunits_core::EvaluationMode::Tolerant);
         let (conversion_factor, affine_offset) = calculate_unit_conversion_factors(&self.unit_expr);
 
-        let (mass_exp, length_exp, time_exp, current_exp, temp_exp, amount_exp, lum_exp, angle_exp) = (
+        let (
+            mass_exp,
+            length_exp,
+            time_exp,
+            current_exp,
+            temp_exp,
+            amount_exp,
+            lum_exp,
+            angle_exp,
+            info_exp,
+        ) = (
             result.dimension_exponents.0[0],
             result.dimension_exponents.0[1],
             result.dimension_exponents.0[2],
@@ -151,6 +161,7 @@ impl QuantityMacroInput {
             result.dimension_exponents.0[5],
             result.dimension_exponents.0[6],
             result.dimension_exponents.0[7],
+            result.dimension_exponents.0[8],
         );
         let (p2, p3, p5, pi) = (
             result.scale_exponents.0[0],
@@ -181,18 +192,18 @@ impl QuantityMacroInput {
 
             quote! {
                 {
-                    use whippyunits::quantity::{Quantity, Scale, Dimension, _2, _3, _5, _Pi, _M, _L, _T, _I, _Θ, _N, _J, _A};
+                    use whippyunits::quantity::{Quantity, Scale, Dimension, _2, _3, _5, _Pi, _M, _L, _T, _I, _Θ, _N, _J, _A, _Info};
                     let raw_value: #storage_type_ty = #value_expr;
                     let converted_value = (raw_value as f64) * #cf + #af;
-                    Quantity::<Scale<_2<#p2>, _3<#p3>, _5<#p5>, _Pi<#pi>>, Dimension<_M<#mass_exp>, _L<#length_exp>, _T<#time_exp>, _I<#current_exp>, _Θ<#temp_exp>, _N<#amount_exp>, _J<#lum_exp>, _A<#angle_exp>>, #storage_type_ty, #brand_type_ty>::new(converted_value as #storage_type_ty)
+                    Quantity::<Scale<_2<#p2>, _3<#p3>, _5<#p5>, _Pi<#pi>>, Dimension<_M<#mass_exp>, _L<#length_exp>, _T<#time_exp>, _I<#current_exp>, _Θ<#temp_exp>, _N<#amount_exp>, _J<#lum_exp>, _A<#angle_exp>, _Info<#info_exp>>, #storage_type_ty, #brand_type_ty>::new(converted_value as #storage_type_ty)
                 }
             }
         } else {
             // Pure storage unit - no conversion needed
             quote! {
                 {
-                    use whippyunits::quantity::{Quantity, Scale, Dimension, _2, _3, _5, _Pi, _M, _L, _T, _I, _Θ, _N, _J, _A};
-                    Quantity::<Scale<_2<#p2>, _3<#p3>, _5<#p5>, _Pi<#pi>>, Dimension<_M<#mass_exp>, _L<#length_exp>, _T<#time_exp>, _I<#current_exp>, _Θ<#temp_exp>, _N<#amount_exp>, _J<#lum_exp>, _A<#angle_exp>>, #storage_type_ty, #brand_type_ty>::new(#value_expr)
+                    use whippyunits::quantity::{Quantity, Scale, Dimension, _2, _3, _5, _Pi, _M, _L, _T, _I, _Θ, _N, _J, _A, _Info};
+                    Quantity::<Scale<_2<#p2>, _3<#p3>, _5<#p5>, _Pi<#pi>>, Dimension<_M<#mass_exp>, _L<#length_exp>, _T<#time_exp>, _I<#current_exp>, _Θ<#temp_exp>, _N<#amount_exp>, _J<#lum_exp>, _A<#angle_exp>, _Info<#info_exp>>, #storage_type_ty, #brand_type_ty>::new(#value_expr)
                 }
             }
         }
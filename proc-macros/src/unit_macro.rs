@@ -52,7 +52,17 @@ impl UnitMacroInput {
         }
 
         let result = self.unit_expr.evaluate();
-        let (mass_exp, length_exp, time_exp, current_exp, temp_exp, amount_exp, lum_exp, angle_exp) = (
+        let (
+            mass_exp,
+            length_exp,
+            time_exp,
+            current_exp,
+            temp_exp,
+            amount_exp,
+            lum_exp,
+            angle_exp,
+            info_exp,
+        ) = (
             result.dimension_exponents.0[0],
             result.dimension_exponents.0[1],
             result.dimension_exponents.0[2],
@@ -61,6 +71,7 @@ impl UnitMacroInput {
             result.dimension_exponents.0[5],
             result.dimension_exponents.0[6],
             result.dimension_exponents.0[7],
+            result.dimension_exponents.0[8],
         );
         let (p2, p3, p5, pi) = (
             result.scale_exponents.0[0],
@@ -87,7 +98,7 @@ impl UnitMacroInput {
         let quantity_type = quote! {
             whippyunits::quantity::Quantity<
                 whippyunits::quantity::Scale<whippyunits::quantity::_2<#p2>, whippyunits::quantity::_3<#p3>, whippyunits::quantity::_5<#p5>, whippyunits::quantity::_Pi<#pi>>,
-                whippyunits::quantity::Dimension<whippyunits::quantity::_M<#mass_exp>, whippyunits::quantity::_L<#length_exp>, whippyunits::quantity::_T<#time_exp>, whippyunits::quantity::_I<#current_exp>, whippyunits::quantity::_Θ<#temp_exp>, whippyunits::quantity::_N<#amount_exp>, whippyunits::quantity::_J<#lum_exp>, whippyunits::quantity::_A<#angle_exp>>,
+                whippyunits::quantity::Dimension<whippyunits::quantity::_M<#mass_exp>, whippyunits::quantity::_L<#length_exp>, whippyunits::quantity::_T<#time_exp>, whippyunits::quantity::_I<#current_exp>, whippyunits::quantity::_Θ<#temp_exp>, whippyunits::quantity::_N<#amount_exp>, whippyunits::quantity::_J<#lum_exp>, whippyunits::quantity::_A<#angle_exp>, whippyunits::quantity::_Info<#info_exp>>,
                 #storage_type,
                 #brand_type
             >
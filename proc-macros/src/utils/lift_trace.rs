@@ -86,6 +86,7 @@ where
     pub fn format(&self, expr: &UnitExpr) -> String {
         match expr {
             UnitExpr::Unit(unit) => (self.unit_formatter)(unit),
+            UnitExpr::Coefficient(coefficient) => coefficient.to_string(),
             UnitExpr::Div(numerator, denominator) => {
                 let num_str = self.format(numerator);
                 let den_str = self.format(denominator);
@@ -119,7 +120,7 @@ impl DimensionProcessor {
     where
         F: FnMut(&str, i16),
     {
-        let [mass_exp, length_exp, time_exp, current_exp, temp_exp, amount_exp, lum_exp, angle_exp] =
+        let [mass_exp, length_exp, time_exp, current_exp, temp_exp, amount_exp, lum_exp, angle_exp, info_exp] =
             self.dimensions.0;
 
         if mass_exp != 0 {
@@ -146,6 +147,9 @@ impl DimensionProcessor {
         if angle_exp != 0 {
             f("rad", angle_exp);
         }
+        if info_exp != 0 {
+            f("bit", info_exp);
+        }
     }
 
     /// Get the scale identifier for simple base units
@@ -175,6 +179,10 @@ impl DimensionProcessor {
                     DimensionBasis::Amount => Some(amount_scale.clone()),
                     DimensionBasis::Luminosity => Some(luminosity_scale.clone()),
                     DimensionBasis::Angle => Some(angle_scale.clone()),
+                    // Information has no local scale; it is always stored in bits
+                    DimensionBasis::Information => {
+                        Some(Ident::new("Bit", proc_macro2::Span::call_site()))
+                    }
                 }
             } else {
                 None // Compound unit
@@ -257,7 +265,7 @@ pub fn is_prefixed_compound_unit(unit_symbol: &str) -> Option<(String, String)>
     if let Some((base_symbol, prefix)) = is_prefixed_base_unit(unit_symbol) {
         // Check if the base unit is a compound unit (has multiple non-zero dimension exponents)
         if let Some((_unit, dimension)) = Dimension::find_unit_by_symbol(&base_symbol) {
-            let (m, l, t, c, temp, a, lum, ang) = (
+            let (m, l, t, c, temp, a, lum, ang, info) = (
                 dimension.exponents.0[0], // mass
                 dimension.exponents.0[1], // length
                 dimension.exponents.0[2], // time
@@ -266,8 +274,9 @@ pub fn is_prefixed_compound_unit(unit_symbol: &str) -> Option<(String, String)>
                 dimension.exponents.0[5], // amount
                 dimension.exponents.0[6], // luminous_intensity
                 dimension.exponents.0[7], // angle
+                dimension.exponents.0[8], // information
             );
-            let non_zero_count = [m, l, t, c, temp, a, lum, ang]
+            let non_zero_count = [m, l, t, c, temp, a, lum, ang, info]
                 .iter()
                 .filter(|&&x| x != 0)
                 .count();
@@ -660,7 +669,7 @@ impl LocalContext {
         dimensions: DynDimensionExponents,
         scale_factor_diff: i16,
     ) -> String {
-        let [mass_exp, length_exp, time_exp, current_exp, temp_exp, amount_exp, lum_exp, angle_exp] =
+        let [mass_exp, length_exp, time_exp, current_exp, temp_exp, amount_exp, lum_exp, angle_exp, info_exp] =
             dimensions.0;
 
         // Check if this is a prefixed unit that needs to show prefix dropping
@@ -697,6 +706,9 @@ impl LocalContext {
         if angle_exp != 0 {
             dim_parts.push(format!("rad^{}", angle_exp));
         }
+        if info_exp != 0 {
+            dim_parts.push(format!("bit^{}", info_exp));
+        }
 
         let original_dims = dim_parts.join(" * ");
 
@@ -766,6 +778,9 @@ impl LocalContext {
             };
             transformed_parts.push(format!("{}^{}", angle_unit, angle_exp));
         }
+        if info_exp != 0 {
+            transformed_parts.push(format!("bit^{}", info_exp));
+        }
 
         let transformed_dims = transformed_parts.join(" * ");
 
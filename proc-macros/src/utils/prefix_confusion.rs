@@ -0,0 +1,75 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote_spanned;
+use whippyunits_core::{Dimension, SiPrefix, System};
+
+use crate::utils::shared_utils::parse_prefixed_unit;
+
+/// Detect a unit whose large prefix is likely a mistyped small prefix of the other case, such
+/// as `Mm` (megameter) written for `mm` (millimeter).
+///
+/// Large prefixes are routine on units with uppercase symbols (`MPa`, `MW`, `GHz`), so only
+/// units with lowercase symbols (`m`, `g`, `s`, `mol`, ...) are considered.  Units of
+/// information are exempt: a millibit is meaningless, so `Mbit` can only be the megabit.
+/// Returns the likely intended unit and a message explaining the warning.
+pub fn find_prefix_confusion(unit_name: &str) -> Option<(String, String)> {
+    // Exact unit symbols (e.g. `Pa`, `min`) are never prefixed units
+    if Dimension::find_unit_by_symbol(unit_name).is_some() {
+        return None;
+    }
+
+    let (prefix_symbol, base_symbol) = parse_prefixed_unit(unit_name)?;
+    if !unit_name.starts_with(&prefix_symbol) || !base_symbol.starts_with(char::is_lowercase) {
+        return None;
+    }
+
+    let prefix = SiPrefix::from_symbol(&prefix_symbol)?;
+    if !prefix_symbol.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    let confused = SiPrefix::from_symbol(&prefix_symbol.to_ascii_lowercase())?;
+
+    let intended = format!("{}{}", confused.symbol(), base_symbol);
+    let (base_unit, _) = Dimension::find_unit_by_symbol(&base_symbol)?;
+    if base_unit.system == System::Information {
+        return None;
+    }
+    let message = format!(
+        "`{}` is {}{} (10^{} {}); did you mean `{}` ({}{}, 10^{} {})? \
+         Use #[allow(deprecated)] if {}{}s are intended",
+        unit_name,
+        prefix.name(),
+        base_unit.name,
+        prefix.factor_log10(),
+        base_symbol,
+        intended,
+        confused.name(),
+        base_unit.name,
+        confused.factor_log10(),
+        base_symbol,
+        prefix.name(),
+        base_unit.name,
+    );
+    Some((intended, message))
+}
+
+/// Generate a deprecation warning at `ident` if it looks like a prefix case confusion.
+///
+/// Proc macros cannot emit warnings on stable, so the warning is raised by using a deprecated
+/// constant; it can be silenced with `#[allow(deprecated)]` like any other deprecation.  Since
+/// that also silences real deprecations, the check only runs with the `prefix-lint` feature.
+pub fn generate_prefix_confusion_warning(ident: &Ident) -> TokenStream {
+    if !cfg!(feature = "prefix-lint") {
+        return TokenStream::new();
+    }
+
+    let Some((_, message)) = find_prefix_confusion(&ident.to_string()) else {
+        return TokenStream::new();
+    };
+
+    quote_spanned! {ident.span()=>
+        #[deprecated(note = #message)]
+        const POSSIBLE_PREFIX_CONFUSION: () = ();
+        #[allow(clippy::let_unit_value)]
+        let _ = POSSIBLE_PREFIX_CONFUSION;
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::prefix_confusion::generate_prefix_confusion_warning;
 use proc_macro2::TokenStream;
 use quote::quote;
 /// Shared utilities for proc macros
@@ -33,6 +34,13 @@ pub fn get_declarator_type_for_unit(unit_name: &str) -> Option<TokenStream> {
         return None;
     }
 
+    // Units of information are dimensionless too, and have no default declarator types either
+    if whippyunits_core::get_unit_info(unit_name)
+        .is_some_and(|unit| unit.system == whippyunits_core::System::Information)
+    {
+        return None;
+    }
+
     // Check if it's a base unit (these have corresponding types)
     let atomic_dimensions = whippyunits_core::Dimension::BASIS;
     for dimension in atomic_dimensions {
@@ -229,7 +237,13 @@ pub fn get_storage_unit_type_for_unit(unit_name: &str) -> Option<TokenStream> {
                 .units
                 .iter()
                 .find(|u| u.conversion_factor == 1.0 && u.affine_offset == 0.0)
-        })?;
+        });
+
+    // Dimensions whose only named units are nonstorage (such as the knot for velocity) have no
+    // storage declarator type to point at
+    let Some(storage_unit) = storage_unit else {
+        return Some(quote! { () });
+    };
 
     // Get the declarator type for the storage unit
     get_declarator_type_for_unit(storage_unit.symbols[0])
@@ -241,6 +255,7 @@ pub fn collect_identifiers_from_expr(expr: &UnitExpr, identifiers: &mut Vec<Iden
         UnitExpr::Unit(unit) => {
             identifiers.push(unit.name.clone());
         }
+        UnitExpr::Coefficient(_) => {}
         UnitExpr::Mul(left, right) => {
             collect_identifiers_from_expr(left, identifiers);
             collect_identifiers_from_expr(right, identifiers);
@@ -288,6 +303,21 @@ pub fn get_unit_doc_info(unit_name: &str) -> Option<String> {
         return Some(format!("{} ({})", unit.name, symbol));
     }
 
+    // Units of information can also carry a binary prefix
+    if let Some((base_unit, prefix)) = Dimension::find_binary_prefixed_unit(unit_name) {
+        use whippyunits_core::to_unicode_superscript;
+        return Some(format!(
+            "{}{} ({}{}) - Prefix: {} (2{}), Base: {}",
+            prefix.name(),
+            base_unit.name,
+            prefix.symbol(),
+            base_unit.symbols[0],
+            prefix.name(),
+            to_unicode_superscript(prefix.factor_log2(), false),
+            base_unit.name
+        ));
+    }
+
     // Only if no exact match found, check if it's a prefixed unit
     if let Some((prefix_symbol, _base_symbol)) = parse_prefixed_unit(unit_name) {
         use whippyunits_core::to_unicode_superscript;
@@ -338,12 +368,16 @@ pub fn get_unit_doc_info(unit_name: &str) -> Option<String> {
 /// Parse a unit name to extract prefix and base unit
 ///
 /// This function now uses the centralized parsing logic from whippyunits-core.
-/// Only allows prefixing of base units (first unit in each dimension by declaration order).
+/// Only allows prefixing of base units (first unit in each dimension by declaration order),
+/// and of units of information.
 pub fn parse_prefixed_unit(unit_name: &str) -> Option<(String, String)> {
     // Try to strip any prefix from the unit name
     if let Some((prefix, base)) = SiPrefix::strip_any_prefix_symbol(unit_name) {
         // Check if the base unit exists and is a base unit (first unit in its dimension)
         if let Some((unit, dimension)) = Dimension::find_unit_by_symbol(base) {
+            if unit.system == whippyunits_core::System::Information {
+                return Some((prefix.symbol().to_string(), base.to_string()));
+            }
             // Check if this is the first unit in its dimension (base unit)
             if dimension
                 .units
@@ -363,6 +397,9 @@ pub fn parse_prefixed_unit(unit_name: &str) -> Option<(String, String)> {
     if let Some((prefix, base)) = SiPrefix::strip_any_prefix_name(unit_name) {
         // Check if the base unit exists by name and is a base unit
         if let Some((unit, dimension)) = Dimension::find_unit_by_name(base) {
+            if unit.system == whippyunits_core::System::Information {
+                return Some((prefix.symbol().to_string(), base.to_string()));
+            }
             // Check if this is the first unit in its dimension (base unit)
             if dimension
                 .units
@@ -418,12 +455,14 @@ pub fn generate_unit_documentation_for_expr(
             };
 
             let doc_comment = generate_unit_doc_comment(&unit_name);
+            let prefix_confusion_warning = generate_prefix_confusion_warning(&ident);
 
             quote! {
                 const _: () = {
                     #doc_comment
                     #[allow(non_camel_case_types)]
                     type #ident = #declarator_type;
+                    #prefix_confusion_warning
                 };
             }
         })
@@ -0,0 +1,153 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Expr, LitStr};
+use whippyunits_core::UnitExpr;
+
+/// Input for the value_fmt! macro
+/// Syntax: value_fmt!("format string", quantity, ...), where each `{}` placeholder in the format
+/// string is followed by the unit its quantity is displayed in
+pub struct ValueFmtMacroInput {
+    format: LitStr,
+    units: Vec<String>,
+    quantities: Vec<Expr>,
+}
+
+impl Parse for ValueFmtMacroInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let format: LitStr = input.parse()?;
+        let quantities = if input.is_empty() {
+            Vec::new()
+        } else {
+            let _comma: Comma = input.parse()?;
+            Punctuated::<Expr, Comma>::parse_terminated(input)?
+                .into_iter()
+                .collect()
+        };
+
+        let units = placeholder_units(&format.value())
+            .map_err(|message| syn::Error::new(format.span(), message))?;
+        for unit in &units {
+            syn::parse_str::<UnitExpr>(unit).map_err(|_| {
+                syn::Error::new(
+                    format.span(),
+                    format!("`{}` is not a unit expression", unit),
+                )
+            })?;
+        }
+        if units.len() != quantities.len() {
+            return Err(syn::Error::new(
+                format.span(),
+                format!(
+                    "format string has {} placeholder(s) but {} quantit{} given",
+                    units.len(),
+                    quantities.len(),
+                    if quantities.len() == 1 {
+                        "y was"
+                    } else {
+                        "ies were"
+                    }
+                ),
+            ));
+        }
+
+        Ok(ValueFmtMacroInput {
+            format,
+            units,
+            quantities,
+        })
+    }
+}
+
+/// Whether `c` can appear in a unit written after a placeholder.
+fn is_unit_char(c: char) -> bool {
+    c.is_alphanumeric() || "_/*^-()".contains(c)
+}
+
+/// The unit following each placeholder in `format`, in order.
+///
+/// `{{` and `}}` are escaped braces.  A placeholder may hold a format spec (`{:.2}`) but not an
+/// argument position or name, since quantities are taken in order.  Its unit is the run of unit
+/// characters after it (skipping spaces), less any closing parentheses that the unit itself
+/// does not open, so that `({} m)` gives the unit `m`.
+fn placeholder_units(format: &str) -> core::result::Result<Vec<String>, String> {
+    let mut units = Vec::new();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+            }
+            '}' => return Err("unmatched `}` in format string; escape it as `}}`".to_string()),
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => {
+                            return Err(
+                                "unmatched `{` in format string; escape it as `{{`".to_string()
+                            )
+                        }
+                    }
+                }
+                if !(placeholder.is_empty() || placeholder.starts_with(':')) {
+                    return Err(format!(
+                        "placeholder `{{{}}}` names an argument, but value_fmt! takes its \
+                         quantities in order; use `{{}}` or `{{:spec}}`",
+                        placeholder
+                    ));
+                }
+
+                while chars.peek() == Some(&' ') {
+                    chars.next();
+                }
+                let mut unit = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !is_unit_char(c) {
+                        break;
+                    }
+                    unit.push(c);
+                    chars.next();
+                }
+                while unit.ends_with(')') && unit.matches(')').count() > unit.matches('(').count() {
+                    unit.pop();
+                }
+                if unit.is_empty() {
+                    return Err(format!(
+                        "placeholder `{{{}}}` must be followed by the unit to display its \
+                         quantity in, e.g. `{{}} km/h`",
+                        placeholder
+                    ));
+                }
+                units.push(unit);
+            }
+            _ => {}
+        }
+    }
+    Ok(units)
+}
+
+impl ValueFmtMacroInput {
+    pub fn expand(self) -> TokenStream {
+        let format = &self.format;
+        let values = self
+            .units
+            .iter()
+            .zip(&self.quantities)
+            .map(|(unit, quantity)| {
+                let unit: TokenStream = unit.parse().expect("unit was validated as parseable");
+                quote! { whippyunits::value!(#quantity, #unit) }
+            });
+
+        quote! {
+            whippyunits::__format(core::format_args!(#format, #(#values),*))
+        }
+    }
+}
@@ -8,6 +8,7 @@
 
 #[cfg(not(feature = "std"))]
 pub use alloc_crate::{
+    boxed::Box,
     format,
     string::{String, ToString},
     vec,
@@ -16,6 +17,7 @@ pub use alloc_crate::{
 
 #[cfg(feature = "std")]
 pub use std::{
+    boxed::Box,
     format,
     string::{String, ToString},
     vec,
@@ -21,6 +21,7 @@
 //! - [`rescale_u32`]
 //! - [`rescale_u64`]
 //! - [`rescale_u128`]
+//! - [`rescale_checked`]: any integer storage type, failing rather than truncating
 //!
 //! All rescale functions work with type inference - specify the target type using the [`unit!`](crate::unit!) macro:
 //!
@@ -56,7 +57,11 @@
 //! # }
 //! ```
 //!
-//! The result has the same dimensions and scale as the operands.
+//! The result has the same dimensions and scale as the operands.  For float quantities,
+//! [`add_rescaled`](crate::quantity::Quantity::add_rescaled) and
+//! [`sub_rescaled`](crate::quantity::Quantity::sub_rescaled) accept a right-hand side of any
+//! scale and rescale it into the scale of the left-hand side.  Integer quantities must be rescaled
+//! explicitly, since rescaling integer-backed quantities can truncate.
 //!
 //! ### Multiplication and Division
 //!
@@ -103,6 +108,21 @@
 //!  - `(m.s) * (mm/s)`
 //!  - etc.
 //!
+//! The exception is dividing two float quantities of the same dimension, which folds the scale
+//! difference of the operands into the value, so that the quotient is an unscaled dimensionless
+//! quantity.  With integer storage the quotient carries the scale difference instead (`km / m` is
+//! dimensionless with a scale of 10³), so that integer division stays lossless; use
+//! [`Quantity::ratio`](crate::Quantity::ratio) to fold it into the value:
+//!
+//! ```rust
+//! # #[culit::culit(whippyunits::default_declarators::literals)]
+//! # fn main() {
+//! # use whippyunits::{quantity, unit};
+//! let ratio: unit!(1) = 1.0km / 1.0m; // ✅ 1000.0 Quantity<1, f64>
+//! let ratio: unit!(1, i32) = quantity!(3, km, i32).ratio(quantity!(500, m, i32)); // ✅ 6 Quantity<1, i32>
+//! # }
+//! ```
+//!
 //! ### Comparison Operators
 //!
 //! Comparison operators (`<`, `<=`, `>`, `>=`) are scale-strict, just like addition and subtraction.
@@ -202,6 +222,7 @@ macro_rules! define_float_rescale {
                 const AMOUNT_EXPONENT: i16,
                 const LUMINOSITY_EXPONENT: i16,
                 const ANGLE_EXPONENT: i16,
+                const INFORMATION_EXPONENT: i16,
                 const SCALE_P2_FROM: i16, const SCALE_P2_TO: i16,
                 const SCALE_P3_FROM: i16, const SCALE_P3_TO: i16,
                 const SCALE_P5_FROM: i16, const SCALE_P5_TO: i16,
@@ -211,7 +232,7 @@ macro_rules! define_float_rescale {
             (
                 Quantity<
                     Scale<_2<SCALE_P2_FROM>, _3<SCALE_P3_FROM>, _5<SCALE_P5_FROM>, _Pi<SCALE_PI_FROM>>,
-                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
                     $T,
                     Brand,
                 >
@@ -219,7 +240,7 @@ macro_rules! define_float_rescale {
             (
                 Quantity<
                     Scale<_2<SCALE_P2_TO>, _3<SCALE_P3_TO>, _5<SCALE_P5_TO>, _Pi<SCALE_PI_TO>>,
-                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
                     $T,
                     Brand,
                 >
@@ -246,6 +267,7 @@ macro_rules! define_int_rescale {
                 const AMOUNT_EXPONENT: i16,
                 const LUMINOSITY_EXPONENT: i16,
                 const ANGLE_EXPONENT: i16,
+                const INFORMATION_EXPONENT: i16,
                 const SCALE_P2_FROM: i16, const SCALE_P2_TO: i16,
                 const SCALE_P3_FROM: i16, const SCALE_P3_TO: i16,
                 const SCALE_P5_FROM: i16, const SCALE_P5_TO: i16,
@@ -255,7 +277,7 @@ macro_rules! define_int_rescale {
             (
                 Quantity<
                     Scale<_2<SCALE_P2_FROM>, _3<SCALE_P3_FROM>, _5<SCALE_P5_FROM>, _Pi<SCALE_PI_FROM>>,
-                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
                     $T,
                     Brand,
                 >
@@ -263,7 +285,7 @@ macro_rules! define_int_rescale {
             (
                 Quantity<
                     Scale<_2<SCALE_P2_TO>, _3<SCALE_P3_TO>, _5<SCALE_P5_TO>, _Pi<SCALE_PI_TO>>,
-                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
                     $T,
                     Brand,
                 >
@@ -314,6 +336,7 @@ macro_rules! define_arithmetic_signed {
             const AMOUNT_EXPONENT: i16,
             const LUMINOSITY_EXPONENT: i16,
             const ANGLE_EXPONENT: i16,
+            const INFORMATION_EXPONENT: i16,
             const SCALE_P2: i16,
             const SCALE_P3: i16,
             const SCALE_P5: i16,
@@ -330,6 +353,7 @@ macro_rules! define_arithmetic_signed {
             const AMOUNT_EXPONENT_1: i16, const AMOUNT_EXPONENT_2: i16,
             const LUMINOSITY_EXPONENT_1: i16, const LUMINOSITY_EXPONENT_2: i16,
             const ANGLE_EXPONENT_1: i16, const ANGLE_EXPONENT_2: i16,
+            const INFORMATION_EXPONENT_1: i16, const INFORMATION_EXPONENT_2: i16,
             const SCALE_P2_1: i16, const SCALE_P3_1: i16, const SCALE_P5_1: i16, const SCALE_PI_1: i16,
             const SCALE_P2_2: i16, const SCALE_P3_2: i16, const SCALE_P5_2: i16, const SCALE_PI_2: i16,
             Brand,
@@ -346,6 +370,7 @@ macro_rules! define_arithmetic_signed {
             (): IsI16<{ -AMOUNT_EXPONENT }>,
             (): IsI16<{ -LUMINOSITY_EXPONENT }>,
             (): IsI16<{ -ANGLE_EXPONENT }>,
+            (): IsI16<{ -INFORMATION_EXPONENT }>,
             (): IsI16<{ -SCALE_P2 }>,
             (): IsI16<{ -SCALE_P3 }>,
             (): IsI16<{ -SCALE_P5 }>,
@@ -361,6 +386,7 @@ macro_rules! define_arithmetic_signed {
             (): IsI16<{ AMOUNT_EXPONENT_1 + AMOUNT_EXPONENT_2 }>,
             (): IsI16<{ LUMINOSITY_EXPONENT_1 + LUMINOSITY_EXPONENT_2 }>,
             (): IsI16<{ ANGLE_EXPONENT_1 + ANGLE_EXPONENT_2 }>,
+            (): IsI16<{ INFORMATION_EXPONENT_1 + INFORMATION_EXPONENT_2 }>,
             (): IsI16<{ SCALE_P2_1 + SCALE_P2_2 }>,
             (): IsI16<{ SCALE_P3_1 + SCALE_P3_2 }>,
             (): IsI16<{ SCALE_P5_1 + SCALE_P5_2 }>,
@@ -376,6 +402,7 @@ macro_rules! define_arithmetic_signed {
             (): IsI16<{ AMOUNT_EXPONENT_1 - AMOUNT_EXPONENT_2 }>,
             (): IsI16<{ LUMINOSITY_EXPONENT_1 - LUMINOSITY_EXPONENT_2 }>,
             (): IsI16<{ ANGLE_EXPONENT_1 - ANGLE_EXPONENT_2 }>,
+            (): IsI16<{ INFORMATION_EXPONENT_1 - INFORMATION_EXPONENT_2 }>,
             (): IsI16<{ SCALE_P2_1 - SCALE_P2_2 }>,
             (): IsI16<{ SCALE_P3_1 - SCALE_P3_2 }>,
             (): IsI16<{ SCALE_P5_1 - SCALE_P5_2 }>,
@@ -403,6 +430,7 @@ macro_rules! define_arithmetic_signed {
             const AMOUNT_EXPONENT: i16,
             const LUMINOSITY_EXPONENT: i16,
             const ANGLE_EXPONENT: i16,
+            const INFORMATION_EXPONENT: i16,
             const SCALE_P2: i16,
             const SCALE_P3: i16,
             const SCALE_P5: i16,
@@ -419,6 +447,7 @@ macro_rules! define_arithmetic_signed {
             const AMOUNT_EXPONENT: i16,
             const LUMINOSITY_EXPONENT: i16,
             const ANGLE_EXPONENT: i16,
+            const INFORMATION_EXPONENT: i16,
             const SCALE_P2: i16,
             const SCALE_P3: i16,
             const SCALE_P5: i16,
@@ -431,6 +460,7 @@ macro_rules! define_arithmetic_signed {
             const AMOUNT_EXPONENT_1: i16, const AMOUNT_EXPONENT_2: i16,
             const LUMINOSITY_EXPONENT_1: i16, const LUMINOSITY_EXPONENT_2: i16,
             const ANGLE_EXPONENT_1: i16, const ANGLE_EXPONENT_2: i16,
+            const INFORMATION_EXPONENT_1: i16, const INFORMATION_EXPONENT_2: i16,
             const SCALE_P2_1: i16, const SCALE_P3_1: i16, const SCALE_P5_1: i16, const SCALE_PI_1: i16,
             const SCALE_P2_2: i16, const SCALE_P3_2: i16, const SCALE_P5_2: i16, const SCALE_PI_2: i16,
             Brand,
@@ -445,6 +475,7 @@ macro_rules! define_arithmetic_signed {
             const INVERSE_AMOUNT_EXPONENT: i16,
             const INVERSE_LUMINOSITY_EXPONENT: i16,
             const INVERSE_ANGLE_EXPONENT: i16,
+            const INVERSE_INFORMATION_EXPONENT: i16,
             const INVERSE_SCALE_P2: i16,
             const INVERSE_SCALE_P3: i16,
             const INVERSE_SCALE_P5: i16,
@@ -460,6 +491,7 @@ macro_rules! define_arithmetic_signed {
             N<AMOUNT_EXPONENT>: core::ops::Neg<Output = N<INVERSE_AMOUNT_EXPONENT>>,
             N<LUMINOSITY_EXPONENT>: core::ops::Neg<Output = N<INVERSE_LUMINOSITY_EXPONENT>>,
             N<ANGLE_EXPONENT>: core::ops::Neg<Output = N<INVERSE_ANGLE_EXPONENT>>,
+            N<INFORMATION_EXPONENT>: core::ops::Neg<Output = N<INVERSE_INFORMATION_EXPONENT>>,
             N<SCALE_P2>: core::ops::Neg<Output = N<INVERSE_SCALE_P2>>,
             N<SCALE_P3>: core::ops::Neg<Output = N<INVERSE_SCALE_P3>>,
             N<SCALE_P5>: core::ops::Neg<Output = N<INVERSE_SCALE_P5>>,
@@ -475,6 +507,7 @@ macro_rules! define_arithmetic_signed {
             N<AMOUNT_EXPONENT_1>: core::ops::Add<N<AMOUNT_EXPONENT_2>, Output = N<AMOUNT_EXPONENT>>,
             N<LUMINOSITY_EXPONENT_1>: core::ops::Add<N<LUMINOSITY_EXPONENT_2>, Output = N<LUMINOSITY_EXPONENT>>,
             N<ANGLE_EXPONENT_1>: core::ops::Add<N<ANGLE_EXPONENT_2>, Output = N<ANGLE_EXPONENT>>,
+            N<INFORMATION_EXPONENT_1>: core::ops::Add<N<INFORMATION_EXPONENT_2>, Output = N<INFORMATION_EXPONENT>>,
             N<SCALE_P2_1>: core::ops::Add<N<SCALE_P2_2>, Output = N<SCALE_P2>>,
             N<SCALE_P3_1>: core::ops::Add<N<SCALE_P3_2>, Output = N<SCALE_P3>>,
             N<SCALE_P5_1>: core::ops::Add<N<SCALE_P5_2>, Output = N<SCALE_P5>>,
@@ -490,6 +523,7 @@ macro_rules! define_arithmetic_signed {
             N<AMOUNT_EXPONENT_1>: core::ops::Sub<N<AMOUNT_EXPONENT_2>, Output = N<AMOUNT_EXPONENT>>,
             N<LUMINOSITY_EXPONENT_1>: core::ops::Sub<N<LUMINOSITY_EXPONENT_2>, Output = N<LUMINOSITY_EXPONENT>>,
             N<ANGLE_EXPONENT_1>: core::ops::Sub<N<ANGLE_EXPONENT_2>, Output = N<ANGLE_EXPONENT>>,
+            N<INFORMATION_EXPONENT_1>: core::ops::Sub<N<INFORMATION_EXPONENT_2>, Output = N<INFORMATION_EXPONENT>>,
             N<SCALE_P2_1>: core::ops::Sub<N<SCALE_P2_2>, Output = N<SCALE_P2>>,
             N<SCALE_P3_1>: core::ops::Sub<N<SCALE_P3_2>, Output = N<SCALE_P3>>,
             N<SCALE_P5_1>: core::ops::Sub<N<SCALE_P5_2>, Output = N<SCALE_P5>>,
@@ -517,6 +551,7 @@ macro_rules! define_arithmetic {
             const AMOUNT_EXPONENT: i16,
             const LUMINOSITY_EXPONENT: i16,
             const ANGLE_EXPONENT: i16,
+            const INFORMATION_EXPONENT: i16,
             const SCALE_P2: i16,
             const SCALE_P3: i16,
             const SCALE_P5: i16,
@@ -533,6 +568,7 @@ macro_rules! define_arithmetic {
             const AMOUNT_EXPONENT_1: i16, const AMOUNT_EXPONENT_2: i16,
             const LUMINOSITY_EXPONENT_1: i16, const LUMINOSITY_EXPONENT_2: i16,
             const ANGLE_EXPONENT_1: i16, const ANGLE_EXPONENT_2: i16,
+            const INFORMATION_EXPONENT_1: i16, const INFORMATION_EXPONENT_2: i16,
             const SCALE_P2_1: i16, const SCALE_P3_1: i16, const SCALE_P5_1: i16, const SCALE_PI_1: i16,
             const SCALE_P2_2: i16, const SCALE_P3_2: i16, const SCALE_P5_2: i16, const SCALE_PI_2: i16,
             Brand,
@@ -549,6 +585,7 @@ macro_rules! define_arithmetic {
             (): IsI16<{ -AMOUNT_EXPONENT }>,
             (): IsI16<{ -LUMINOSITY_EXPONENT }>,
             (): IsI16<{ -ANGLE_EXPONENT }>,
+            (): IsI16<{ -INFORMATION_EXPONENT }>,
             (): IsI16<{ -SCALE_P2 }>,
             (): IsI16<{ -SCALE_P3 }>,
             (): IsI16<{ -SCALE_P5 }>,
@@ -564,6 +601,7 @@ macro_rules! define_arithmetic {
             (): IsI16<{ AMOUNT_EXPONENT_1 + AMOUNT_EXPONENT_2 }>,
             (): IsI16<{ LUMINOSITY_EXPONENT_1 + LUMINOSITY_EXPONENT_2 }>,
             (): IsI16<{ ANGLE_EXPONENT_1 + ANGLE_EXPONENT_2 }>,
+            (): IsI16<{ INFORMATION_EXPONENT_1 + INFORMATION_EXPONENT_2 }>,
             (): IsI16<{ SCALE_P2_1 + SCALE_P2_2 }>,
             (): IsI16<{ SCALE_P3_1 + SCALE_P3_2 }>,
             (): IsI16<{ SCALE_P5_1 + SCALE_P5_2 }>,
@@ -579,6 +617,7 @@ macro_rules! define_arithmetic {
             (): IsI16<{ AMOUNT_EXPONENT_1 - AMOUNT_EXPONENT_2 }>,
             (): IsI16<{ LUMINOSITY_EXPONENT_1 - LUMINOSITY_EXPONENT_2 }>,
             (): IsI16<{ ANGLE_EXPONENT_1 - ANGLE_EXPONENT_2 }>,
+            (): IsI16<{ INFORMATION_EXPONENT_1 - INFORMATION_EXPONENT_2 }>,
             (): IsI16<{ SCALE_P2_1 - SCALE_P2_2 }>,
             (): IsI16<{ SCALE_P3_1 - SCALE_P3_2 }>,
             (): IsI16<{ SCALE_P5_1 - SCALE_P5_2 }>,
@@ -606,6 +645,7 @@ macro_rules! define_arithmetic {
             const AMOUNT_EXPONENT: i16,
             const LUMINOSITY_EXPONENT: i16,
             const ANGLE_EXPONENT: i16,
+            const INFORMATION_EXPONENT: i16,
             const SCALE_P2: i16,
             const SCALE_P3: i16,
             const SCALE_P5: i16,
@@ -622,6 +662,7 @@ macro_rules! define_arithmetic {
             const AMOUNT_EXPONENT: i16,
             const LUMINOSITY_EXPONENT: i16,
             const ANGLE_EXPONENT: i16,
+            const INFORMATION_EXPONENT: i16,
             const SCALE_P2: i16,
             const SCALE_P3: i16,
             const SCALE_P5: i16,
@@ -634,6 +675,7 @@ macro_rules! define_arithmetic {
             const AMOUNT_EXPONENT_1: i16, const AMOUNT_EXPONENT_2: i16,
             const LUMINOSITY_EXPONENT_1: i16, const LUMINOSITY_EXPONENT_2: i16,
             const ANGLE_EXPONENT_1: i16, const ANGLE_EXPONENT_2: i16,
+            const INFORMATION_EXPONENT_1: i16, const INFORMATION_EXPONENT_2: i16,
             const SCALE_P2_1: i16, const SCALE_P3_1: i16, const SCALE_P5_1: i16, const SCALE_PI_1: i16,
             const SCALE_P2_2: i16, const SCALE_P3_2: i16, const SCALE_P5_2: i16, const SCALE_PI_2: i16,
             Brand,
@@ -648,6 +690,7 @@ macro_rules! define_arithmetic {
             const INVERSE_AMOUNT_EXPONENT: i16,
             const INVERSE_LUMINOSITY_EXPONENT: i16,
             const INVERSE_ANGLE_EXPONENT: i16,
+            const INVERSE_INFORMATION_EXPONENT: i16,
             const INVERSE_SCALE_P2: i16,
             const INVERSE_SCALE_P3: i16,
             const INVERSE_SCALE_P5: i16,
@@ -663,6 +706,7 @@ macro_rules! define_arithmetic {
             N<AMOUNT_EXPONENT>: core::ops::Neg<Output = N<INVERSE_AMOUNT_EXPONENT>>,
             N<LUMINOSITY_EXPONENT>: core::ops::Neg<Output = N<INVERSE_LUMINOSITY_EXPONENT>>,
             N<ANGLE_EXPONENT>: core::ops::Neg<Output = N<INVERSE_ANGLE_EXPONENT>>,
+            N<INFORMATION_EXPONENT>: core::ops::Neg<Output = N<INVERSE_INFORMATION_EXPONENT>>,
             N<SCALE_P2>: core::ops::Neg<Output = N<INVERSE_SCALE_P2>>,
             N<SCALE_P3>: core::ops::Neg<Output = N<INVERSE_SCALE_P3>>,
             N<SCALE_P5>: core::ops::Neg<Output = N<INVERSE_SCALE_P5>>,
@@ -678,6 +722,7 @@ macro_rules! define_arithmetic {
             N<AMOUNT_EXPONENT_1>: core::ops::Add<N<AMOUNT_EXPONENT_2>, Output = N<AMOUNT_EXPONENT>>,
             N<LUMINOSITY_EXPONENT_1>: core::ops::Add<N<LUMINOSITY_EXPONENT_2>, Output = N<LUMINOSITY_EXPONENT>>,
             N<ANGLE_EXPONENT_1>: core::ops::Add<N<ANGLE_EXPONENT_2>, Output = N<ANGLE_EXPONENT>>,
+            N<INFORMATION_EXPONENT_1>: core::ops::Add<N<INFORMATION_EXPONENT_2>, Output = N<INFORMATION_EXPONENT>>,
             N<SCALE_P2_1>: core::ops::Add<N<SCALE_P2_2>, Output = N<SCALE_P2>>,
             N<SCALE_P3_1>: core::ops::Add<N<SCALE_P3_2>, Output = N<SCALE_P3>>,
             N<SCALE_P5_1>: core::ops::Add<N<SCALE_P5_2>, Output = N<SCALE_P5>>,
@@ -693,6 +738,7 @@ macro_rules! define_arithmetic {
             N<AMOUNT_EXPONENT_1>: core::ops::Sub<N<AMOUNT_EXPONENT_2>, Output = N<AMOUNT_EXPONENT>>,
             N<LUMINOSITY_EXPONENT_1>: core::ops::Sub<N<LUMINOSITY_EXPONENT_2>, Output = N<LUMINOSITY_EXPONENT>>,
             N<ANGLE_EXPONENT_1>: core::ops::Sub<N<ANGLE_EXPONENT_2>, Output = N<ANGLE_EXPONENT>>,
+            N<INFORMATION_EXPONENT_1>: core::ops::Sub<N<INFORMATION_EXPONENT_2>, Output = N<INFORMATION_EXPONENT>>,
             N<SCALE_P2_1>: core::ops::Sub<N<SCALE_P2_2>, Output = N<SCALE_P2>>,
             N<SCALE_P3_1>: core::ops::Sub<N<SCALE_P3_2>, Output = N<SCALE_P3>>,
             N<SCALE_P5_1>: core::ops::Sub<N<SCALE_P5_2>, Output = N<SCALE_P5>>,
@@ -736,6 +782,7 @@ define_display_traits!(
         const AMOUNT_EXPONENT: i16,
         const LUMINOSITY_EXPONENT: i16,
         const ANGLE_EXPONENT: i16,
+        const INFORMATION_EXPONENT: i16,
         const SCALE_P2: i16,
         const SCALE_P3: i16,
         const SCALE_P5: i16,
@@ -750,6 +797,7 @@ define_display_traits!(
         AMOUNT_EXPONENT,
         LUMINOSITY_EXPONENT,
         ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
     ),
     (
         SCALE_P2,
@@ -758,3 +806,168 @@ define_display_traits!(
         SCALE_PI,
     )
 );
+
+/// The four-quadrant angle of the point `(x, y)`, in radians.
+///
+/// Both components must have the same dimension and scale, so their ratio needs no rescaling;
+/// [rescale] one of them first if they differ.  As with [f64::atan2], the result lies in
+/// `[-π, π]`.
+///
+/// ```rust
+/// # use whippyunits::api::atan2;
+/// # use whippyunits::{quantity, unit};
+/// let heading: unit!(rad) = atan2(quantity!(1.0, m), quantity!(1.0, m));
+/// assert_eq!(heading, quantity!(core::f64::consts::FRAC_PI_4, rad));
+///
+/// let heading = atan2(quantity!(-2.0, km), quantity!(0.0, km));
+/// assert_eq!(heading, quantity!(-core::f64::consts::FRAC_PI_2, rad));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use whippyunits::api::atan2;
+/// # use whippyunits::quantity;
+/// let _ = atan2(quantity!(1.0, m), quantity!(1.0, s));
+/// ```
+///
+/// # Panics
+///
+/// Panics if either value cannot be converted to `f64`.
+pub fn atan2<Scale, Dimension, T, Brand>(
+    y: Quantity<Scale, Dimension, T, Brand>,
+    x: Quantity<Scale, Dimension, T, Brand>,
+) -> Radians<Brand>
+where
+    T: Copy + num_traits::NumCast,
+{
+    let to_f64 = |value: T| {
+        <f64 as num_traits::NumCast>::from(value).expect("unable to convert numeric value to f64")
+    };
+    Quantity::new(libm::atan2(to_f64(y.unsafe_value), to_f64(x.unsafe_value)))
+}
+
+/// Error returned by [rescale_checked].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RescaleError {
+    /// The rescaled value has a fractional part, which the integer storage type would truncate
+    LossyIntegerConversion,
+    /// The rescaled value does not fit the storage type
+    Overflow,
+}
+
+impl core::fmt::Display for RescaleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RescaleError::LossyIntegerConversion => {
+                write!(f, "rescaled value is not a whole number of the target unit")
+            }
+            RescaleError::Overflow => write!(f, "rescaled value does not fit the storage type"),
+        }
+    }
+}
+
+impl core::error::Error for RescaleError {}
+
+/// Rescale an integer-backed quantity to a different unit of the same dimension, failing
+/// rather than truncating.
+///
+/// The integer rescale functions ([rescale_i32] and friends) truncate toward zero when the
+/// target unit is coarser than the source: 1500 mm rescales to 1 m.  `rescale_checked` instead
+/// returns [RescaleError::LossyIntegerConversion] if any remainder would be lost, and
+/// [RescaleError::Overflow] if the result does not fit the storage type.  Specify the target
+/// type with the [unit!](crate::unit!) macro, as for [rescale]:
+///
+/// ```rust
+/// # use whippyunits::api::{rescale_checked, RescaleError};
+/// # use whippyunits::{quantity, unit};
+/// let distance: Result<unit!(m, i32), _> = rescale_checked(quantity!(2000, mm, i32));
+/// assert_eq!(distance, Ok(quantity!(2, m, i32)));
+///
+/// let distance: Result<unit!(m, i32), _> = rescale_checked(quantity!(1500, mm, i32));
+/// assert_eq!(distance, Err(RescaleError::LossyIntegerConversion));
+/// ```
+///
+/// Conversions involving π are computed with the same rational approximation (355/113) as the
+/// integer rescale functions, so are exact only in that approximation.
+pub fn rescale_checked<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2_FROM: i16,
+    const SCALE_P2_TO: i16,
+    const SCALE_P3_FROM: i16,
+    const SCALE_P3_TO: i16,
+    const SCALE_P5_FROM: i16,
+    const SCALE_P5_TO: i16,
+    const SCALE_PI_FROM: i16,
+    const SCALE_PI_TO: i16,
+    T,
+    Brand,
+>(
+    quantity: QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2_FROM,
+        SCALE_P3_FROM,
+        SCALE_P5_FROM,
+        SCALE_PI_FROM,
+        T,
+        Brand,
+    >,
+) -> Result<
+    QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2_TO,
+        SCALE_P3_TO,
+        SCALE_P5_TO,
+        SCALE_PI_TO,
+        T,
+        Brand,
+    >,
+    RescaleError,
+>
+where
+    T: num_traits::PrimInt,
+{
+    let (num, den) = aggregate_scale_factor(
+        SCALE_P2_FROM,
+        SCALE_P3_FROM,
+        SCALE_P5_FROM,
+        SCALE_PI_FROM,
+        SCALE_P2_TO,
+        SCALE_P3_TO,
+        SCALE_P5_TO,
+        SCALE_PI_TO,
+    );
+    let scaled = quantity
+        .unsafe_value
+        .to_i128()
+        .and_then(|value| value.checked_mul(num))
+        .ok_or(RescaleError::Overflow)?;
+    if scaled % den != 0 {
+        return Err(RescaleError::LossyIntegerConversion);
+    }
+    T::from(scaled / den)
+        .map(Quantity::new)
+        .ok_or(RescaleError::Overflow)
+}
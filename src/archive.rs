@@ -0,0 +1,394 @@
+//! Zero-copy [rkyv](https://docs.rs/rkyv) support for [Quantity].
+//!
+//! A quantity archives to an [ArchivedQuantity]: its thirteen type-level exponents followed by
+//! the archived storage value.  The exponents are redundant with the archived type, but storing
+//! them lets validation reject an archive that was written for a different unit (or whose
+//! exponent bytes were corrupted) instead of silently reinterpreting its value.
+//!
+//! ## Format
+//!
+//! ```text
+//! [M, L, T, I, Θ, N, J, A, Info, 2, 3, 5, π]: [i16; 13]   (little-endian)
+//! value: Archived<T>
+//! ```
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use whippyunits::{quantity, unit};
+//! use rkyv::rancor::Error;
+//!
+//! let length = quantity!(5.0, mm);
+//! let bytes = rkyv::to_bytes::<Error>(&length).unwrap();
+//!
+//! // Validated zero-copy access
+//! let archived = rkyv::access::<rkyv::Archived<unit!(mm)>, Error>(&bytes).unwrap();
+//! assert_eq!(archived.value().to_native(), 5.0);
+//!
+//! // Archives written for another unit are rejected
+//! assert!(rkyv::access::<rkyv::Archived<unit!(m)>, Error>(&bytes).is_err());
+//!
+//! let restored: unit!(mm) = rkyv::from_bytes::<unit!(mm), Error>(&bytes).unwrap();
+//! assert_eq!(restored, length);
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr::addr_of;
+
+use rkyv::bytecheck::CheckBytes;
+use rkyv::munge::munge;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::{Archive, Archived, Deserialize, Place, Portable, Serialize};
+
+use crate::quantity::Quantity;
+use crate::quantity::{_2, _3, _5, _A, _I, _Info, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
+
+/// The archived form of a [Quantity].
+///
+/// Obtained through `rkyv::access`, which checks that the stored exponents match the
+/// requested quantity type before handing out a reference.
+#[repr(C)]
+pub struct ArchivedQuantity<Scale, Dimension, T: Archive, Brand = ()> {
+    exponents: [Archived<i16>; 13],
+    value: Archived<T>,
+    _phantom: Marker<Scale, Dimension, Brand>,
+}
+
+type Marker<Scale, Dimension, Brand> = PhantomData<fn() -> (Scale, Dimension, Brand)>;
+
+impl<Scale, Dimension, T: Archive, Brand> ArchivedQuantity<Scale, Dimension, T, Brand> {
+    /// The stored exponents, in the order `[M, L, T, I, Θ, N, J, A, Info, 2, 3, 5, π]`.
+    pub fn exponents(&self) -> [i16; 13] {
+        self.exponents.map(|exponent| exponent.to_native())
+    }
+
+    /// The archived storage value.
+    ///
+    /// **⚠️ WARNING: This is NOT unit-safe**, for the same reasons as
+    /// [`Quantity::unsafe_value`](crate::quantity::Quantity::unsafe_value).
+    pub fn value(&self) -> &Archived<T> {
+        &self.value
+    }
+}
+
+// SAFETY: `ArchivedQuantity` is `repr(C)` and consists only of portable fields; the phantom
+// field is zero-sized.
+unsafe impl<Scale, Dimension, T: Archive, Brand> Portable
+    for ArchivedQuantity<Scale, Dimension, T, Brand>
+{
+}
+
+/// Error raised when an archived quantity's exponents do not match the type it is accessed as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExponentMismatch {
+    /// Exponents of the quantity type being accessed
+    pub expected: [i16; 13],
+    /// Exponents found in the archive
+    pub found: [i16; 13],
+}
+
+impl fmt::Display for ExponentMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "archived quantity exponents {:?} do not match expected {:?} \
+             (order: M, L, T, I, Θ, N, J, A, Info, 2, 3, 5, π)",
+            self.found, self.expected
+        )
+    }
+}
+
+impl core::error::Error for ExponentMismatch {}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T: Archive,
+    Brand,
+>
+    ArchivedQuantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+{
+    const EXPECTED_EXPONENTS: [i16; 13] = [
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    ];
+}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T: Archive,
+    Brand,
+> Archive
+    for Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+{
+    type Archived = ArchivedQuantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >;
+    type Resolver = T::Resolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedQuantity { exponents, value, .. } = out);
+        Self::Archived::EXPECTED_EXPONENTS.resolve([(); 13], exponents);
+        self.unsafe_value.resolve(resolver, value);
+    }
+}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T: Serialize<S>,
+    Brand,
+    S: Fallible + ?Sized,
+> Serialize<S>
+    for Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.unsafe_value.serialize(serializer)
+    }
+}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T: Archive,
+    Brand,
+    D: Fallible + ?Sized,
+>
+    Deserialize<
+        Quantity<
+            Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+            Dimension<
+                _M<MASS_EXPONENT>,
+                _L<LENGTH_EXPONENT>,
+                _T<TIME_EXPONENT>,
+                _I<CURRENT_EXPONENT>,
+                _Θ<TEMPERATURE_EXPONENT>,
+                _N<AMOUNT_EXPONENT>,
+                _J<LUMINOSITY_EXPONENT>,
+                _A<ANGLE_EXPONENT>,
+                _Info<INFORMATION_EXPONENT>,
+            >,
+            T,
+            Brand,
+        >,
+        D,
+    >
+    for ArchivedQuantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+where
+    Archived<T>: Deserialize<T, D>,
+{
+    fn deserialize(
+        &self,
+        deserializer: &mut D,
+    ) -> Result<
+        Quantity<
+            Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+            Dimension<
+                _M<MASS_EXPONENT>,
+                _L<LENGTH_EXPONENT>,
+                _T<TIME_EXPONENT>,
+                _I<CURRENT_EXPONENT>,
+                _Θ<TEMPERATURE_EXPONENT>,
+                _N<AMOUNT_EXPONENT>,
+                _J<LUMINOSITY_EXPONENT>,
+                _A<ANGLE_EXPONENT>,
+                _Info<INFORMATION_EXPONENT>,
+            >,
+            T,
+            Brand,
+        >,
+        D::Error,
+    > {
+        Ok(Quantity::new(self.value.deserialize(deserializer)?))
+    }
+}
+
+// SAFETY: both fields are checked with their own `CheckBytes` impls before the exponents are
+// read, and the phantom field is zero-sized.
+unsafe impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T: Archive,
+    Brand,
+    C: Fallible + ?Sized,
+> CheckBytes<C>
+    for ArchivedQuantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+where
+    C::Error: Source,
+    Archived<T>: CheckBytes<C>,
+{
+    unsafe fn check_bytes(archived: *const Self, context: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees `archived` is aligned and points to enough
+        // initialized bytes for `Self`, so the same holds for each of its fields.
+        unsafe {
+            let exponents = addr_of!((*archived).exponents);
+            <[Archived<i16>; 13]>::check_bytes(exponents, context)?;
+            <Archived<T>>::check_bytes(addr_of!((*archived).value), context)?;
+
+            let found = (*exponents).map(|exponent| exponent.to_native());
+            if found != Self::EXPECTED_EXPONENTS {
+                return Err(C::Error::new(ExponentMismatch {
+                    expected: Self::EXPECTED_EXPONENTS,
+                    found,
+                }));
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,453 @@
+use crate::quantity::{
+    _2, _3, _5, _A, _I, _Info, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Quantity, QuantityOf, Scale,
+};
+#[cfg(not(has_generic_const_exprs))]
+use whippyunits_core::num::{AllZero, N};
+
+/// Marker for a quantity type that can be added to or subtracted from `Lhs` after rescaling.
+///
+/// This is the bound on [`add_rescaled`](Quantity::add_rescaled) and
+/// [`sub_rescaled`](Quantity::sub_rescaled).  Both operands must have the same dimension, storage
+/// type, and brand.  With float storage the right-hand side may have any scale, and is rescaled
+/// into the scale of the left-hand side, so that `1.0 m` plus `50.0 cm` is `1.5 m`.  Integer
+/// storage is scale-strict, since the rescale could truncate; the diagnostic points the user at
+/// an explicit [`rescale`](crate::api::rescale()).
+///
+/// The `+` and `-` operators themselves are scale-strict for every storage type, so that the
+/// right-hand side of `a + b` can always be inferred from `a`.
+#[diagnostic::on_unimplemented(
+    message = "cannot add or subtract `{Self}` and `{Lhs}`",
+    label = "dimension, scale, storage type, or brand differs from the left-hand side",
+    note = "addition and subtraction require both operands to have the same dimension, storage type, and brand",
+    note = "float storage rescales the right-hand side into the left-hand scale, but integer storage must also match in scale",
+    note = "for integer quantities of the same dimension, convert one operand first, e.g. `a + rescale_i32(b)`"
+)]
+pub trait AddSubCompatible<Lhs> {
+    /// Conversion to the left-hand side type.
+    fn into_lhs(self) -> Lhs;
+}
+
+// Integer storage is scale-strict, since rescaling can truncate
+macro_rules! scale_strict_add_sub_compatible {
+    ($($T:ty),* $(,)?) => {
+        $(
+            #[diagnostic::do_not_recommend]
+            impl<Scale, Dimension, Brand> AddSubCompatible<Quantity<Scale, Dimension, $T, Brand>>
+                for Quantity<Scale, Dimension, $T, Brand>
+            {
+                fn into_lhs(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+scale_strict_add_sub_compatible!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+// Float storage rescales the right-hand side into the left-hand scale
+macro_rules! rescaling_add_sub_compatible {
+    ($($T:ty => $rescale_fn:ident),* $(,)?) => {
+        $(
+            #[diagnostic::do_not_recommend]
+            impl<
+                const MASS_EXPONENT: i16,
+                const LENGTH_EXPONENT: i16,
+                const TIME_EXPONENT: i16,
+                const CURRENT_EXPONENT: i16,
+                const TEMPERATURE_EXPONENT: i16,
+                const AMOUNT_EXPONENT: i16,
+                const LUMINOSITY_EXPONENT: i16,
+                const ANGLE_EXPONENT: i16,
+                const INFORMATION_EXPONENT: i16,
+                const SCALE_P2_FROM: i16, const SCALE_P2_TO: i16,
+                const SCALE_P3_FROM: i16, const SCALE_P3_TO: i16,
+                const SCALE_P5_FROM: i16, const SCALE_P5_TO: i16,
+                const SCALE_PI_FROM: i16, const SCALE_PI_TO: i16,
+                Brand,
+            >
+                AddSubCompatible<
+                    QuantityOf<
+                        MASS_EXPONENT,
+                        LENGTH_EXPONENT,
+                        TIME_EXPONENT,
+                        CURRENT_EXPONENT,
+                        TEMPERATURE_EXPONENT,
+                        AMOUNT_EXPONENT,
+                        LUMINOSITY_EXPONENT,
+                        ANGLE_EXPONENT,
+                        INFORMATION_EXPONENT,
+                        SCALE_P2_TO,
+                        SCALE_P3_TO,
+                        SCALE_P5_TO,
+                        SCALE_PI_TO,
+                        $T,
+                        Brand,
+                    >,
+                >
+                for QuantityOf<
+                    MASS_EXPONENT,
+                    LENGTH_EXPONENT,
+                    TIME_EXPONENT,
+                    CURRENT_EXPONENT,
+                    TEMPERATURE_EXPONENT,
+                    AMOUNT_EXPONENT,
+                    LUMINOSITY_EXPONENT,
+                    ANGLE_EXPONENT,
+                    INFORMATION_EXPONENT,
+                    SCALE_P2_FROM,
+                    SCALE_P3_FROM,
+                    SCALE_P5_FROM,
+                    SCALE_PI_FROM,
+                    $T,
+                    Brand,
+                >
+            {
+                fn into_lhs(
+                    self,
+                ) -> QuantityOf<
+                    MASS_EXPONENT,
+                    LENGTH_EXPONENT,
+                    TIME_EXPONENT,
+                    CURRENT_EXPONENT,
+                    TEMPERATURE_EXPONENT,
+                    AMOUNT_EXPONENT,
+                    LUMINOSITY_EXPONENT,
+                    ANGLE_EXPONENT,
+                    INFORMATION_EXPONENT,
+                    SCALE_P2_TO,
+                    SCALE_P3_TO,
+                    SCALE_P5_TO,
+                    SCALE_PI_TO,
+                    $T,
+                    Brand,
+                > {
+                    crate::api::$rescale_fn(self)
+                }
+            }
+        )*
+    };
+}
+
+rescaling_add_sub_compatible!(f32 => rescale_f32, f64 => rescale_f64);
+
+/// Scale of a quotient of quantities with storage `T`, whose dimension is `Self` and whose
+/// operand scales differ by `S`.
+///
+/// A dimensionless float quotient folds the scale difference into its value, so that
+/// `1.0 km / 1.0 m` is an unscaled `1000.0`; every other quotient keeps the scale difference in
+/// its type, as integer division must to stay lossless.
+pub trait QuotientScale<S, T> {
+    /// Scale of the quotient.
+    type Output;
+
+    /// Folds the scale difference `S` into the quotient value, where the output scale drops it.
+    fn fold(value: T) -> T;
+}
+
+// Integer storage keeps the scale difference, since folding it could truncate
+macro_rules! scale_keeping_quotient {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl<D, S> QuotientScale<S, $T> for D {
+                type Output = S;
+
+                fn fold(value: $T) -> $T {
+                    value
+                }
+            }
+        )*
+    };
+}
+
+scale_keeping_quotient!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// The scale exponent of a float quotient, for [`QuotientScale`]: zero if the quotient is
+/// dimensionless (all its dimension exponents, or-ed into `dimension`, are zero), and
+/// `scale_exponent` otherwise.
+#[cfg(has_generic_const_exprs)]
+#[doc(hidden)]
+pub const fn quotient_scale_exponent(scale_exponent: i16, dimension: i16) -> i16 {
+    if dimension == 0 { 0 } else { scale_exponent }
+}
+
+// Float storage folds the scale difference of a dimensionless quotient into its value
+#[cfg(has_generic_const_exprs)]
+macro_rules! scale_folding_quotient {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl<
+                const MASS_EXPONENT: i16,
+                const LENGTH_EXPONENT: i16,
+                const TIME_EXPONENT: i16,
+                const CURRENT_EXPONENT: i16,
+                const TEMPERATURE_EXPONENT: i16,
+                const AMOUNT_EXPONENT: i16,
+                const LUMINOSITY_EXPONENT: i16,
+                const ANGLE_EXPONENT: i16,
+                const INFORMATION_EXPONENT: i16,
+                const SCALE_P2: i16,
+                const SCALE_P3: i16,
+                const SCALE_P5: i16,
+                const SCALE_PI: i16,
+            > QuotientScale<Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>, $T>
+                for Dimension<
+                    _M<MASS_EXPONENT>,
+                    _L<LENGTH_EXPONENT>,
+                    _T<TIME_EXPONENT>,
+                    _I<CURRENT_EXPONENT>,
+                    _Θ<TEMPERATURE_EXPONENT>,
+                    _N<AMOUNT_EXPONENT>,
+                    _J<LUMINOSITY_EXPONENT>,
+                    _A<ANGLE_EXPONENT>,
+                    _Info<INFORMATION_EXPONENT>,
+                >
+            where
+                (): crate::IsI16<{ quotient_scale_exponent(SCALE_P2, MASS_EXPONENT | LENGTH_EXPONENT | TIME_EXPONENT | CURRENT_EXPONENT | TEMPERATURE_EXPONENT | AMOUNT_EXPONENT | LUMINOSITY_EXPONENT | ANGLE_EXPONENT | INFORMATION_EXPONENT) }>,
+                (): crate::IsI16<{ quotient_scale_exponent(SCALE_P3, MASS_EXPONENT | LENGTH_EXPONENT | TIME_EXPONENT | CURRENT_EXPONENT | TEMPERATURE_EXPONENT | AMOUNT_EXPONENT | LUMINOSITY_EXPONENT | ANGLE_EXPONENT | INFORMATION_EXPONENT) }>,
+                (): crate::IsI16<{ quotient_scale_exponent(SCALE_P5, MASS_EXPONENT | LENGTH_EXPONENT | TIME_EXPONENT | CURRENT_EXPONENT | TEMPERATURE_EXPONENT | AMOUNT_EXPONENT | LUMINOSITY_EXPONENT | ANGLE_EXPONENT | INFORMATION_EXPONENT) }>,
+                (): crate::IsI16<{ quotient_scale_exponent(SCALE_PI, MASS_EXPONENT | LENGTH_EXPONENT | TIME_EXPONENT | CURRENT_EXPONENT | TEMPERATURE_EXPONENT | AMOUNT_EXPONENT | LUMINOSITY_EXPONENT | ANGLE_EXPONENT | INFORMATION_EXPONENT) }>,
+            {
+                type Output = Scale<
+                    _2<{ quotient_scale_exponent(SCALE_P2, MASS_EXPONENT | LENGTH_EXPONENT | TIME_EXPONENT | CURRENT_EXPONENT | TEMPERATURE_EXPONENT | AMOUNT_EXPONENT | LUMINOSITY_EXPONENT | ANGLE_EXPONENT | INFORMATION_EXPONENT) }>,
+                    _3<{ quotient_scale_exponent(SCALE_P3, MASS_EXPONENT | LENGTH_EXPONENT | TIME_EXPONENT | CURRENT_EXPONENT | TEMPERATURE_EXPONENT | AMOUNT_EXPONENT | LUMINOSITY_EXPONENT | ANGLE_EXPONENT | INFORMATION_EXPONENT) }>,
+                    _5<{ quotient_scale_exponent(SCALE_P5, MASS_EXPONENT | LENGTH_EXPONENT | TIME_EXPONENT | CURRENT_EXPONENT | TEMPERATURE_EXPONENT | AMOUNT_EXPONENT | LUMINOSITY_EXPONENT | ANGLE_EXPONENT | INFORMATION_EXPONENT) }>,
+                    _Pi<{ quotient_scale_exponent(SCALE_PI, MASS_EXPONENT | LENGTH_EXPONENT | TIME_EXPONENT | CURRENT_EXPONENT | TEMPERATURE_EXPONENT | AMOUNT_EXPONENT | LUMINOSITY_EXPONENT | ANGLE_EXPONENT | INFORMATION_EXPONENT) }>,
+                >;
+
+                fn fold(value: $T) -> $T {
+                    let dimension = MASS_EXPONENT
+                        | LENGTH_EXPONENT
+                        | TIME_EXPONENT
+                        | CURRENT_EXPONENT
+                        | TEMPERATURE_EXPONENT
+                        | AMOUNT_EXPONENT
+                        | LUMINOSITY_EXPONENT
+                        | ANGLE_EXPONENT
+                        | INFORMATION_EXPONENT;
+                    value
+                        * crate::api::aggregate_scale_factor_float(
+                            SCALE_P2,
+                            SCALE_P3,
+                            SCALE_P5,
+                            SCALE_PI,
+                            quotient_scale_exponent(SCALE_P2, dimension),
+                            quotient_scale_exponent(SCALE_P3, dimension),
+                            quotient_scale_exponent(SCALE_P5, dimension),
+                            quotient_scale_exponent(SCALE_PI, dimension),
+                        ) as $T
+                }
+            }
+        )*
+    };
+}
+
+// Float storage folds the scale difference of a dimensionless quotient into its value
+#[cfg(not(has_generic_const_exprs))]
+macro_rules! scale_folding_quotient {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl<
+                const MASS_EXPONENT: i16,
+                const LENGTH_EXPONENT: i16,
+                const TIME_EXPONENT: i16,
+                const CURRENT_EXPONENT: i16,
+                const TEMPERATURE_EXPONENT: i16,
+                const AMOUNT_EXPONENT: i16,
+                const LUMINOSITY_EXPONENT: i16,
+                const ANGLE_EXPONENT: i16,
+                const INFORMATION_EXPONENT: i16,
+                S,
+            > QuotientScale<S, $T>
+                for Dimension<
+                    _M<MASS_EXPONENT>,
+                    _L<LENGTH_EXPONENT>,
+                    _T<TIME_EXPONENT>,
+                    _I<CURRENT_EXPONENT>,
+                    _Θ<TEMPERATURE_EXPONENT>,
+                    _N<AMOUNT_EXPONENT>,
+                    _J<LUMINOSITY_EXPONENT>,
+                    _A<ANGLE_EXPONENT>,
+                    _Info<INFORMATION_EXPONENT>,
+                >
+            where
+                (
+                    N<MASS_EXPONENT>,
+                    N<LENGTH_EXPONENT>,
+                    N<TIME_EXPONENT>,
+                    N<CURRENT_EXPONENT>,
+                    N<TEMPERATURE_EXPONENT>,
+                    N<AMOUNT_EXPONENT>,
+                    N<LUMINOSITY_EXPONENT>,
+                    N<ANGLE_EXPONENT>,
+                    N<INFORMATION_EXPONENT>,
+                ): AllZero,
+                <(
+                    N<MASS_EXPONENT>,
+                    N<LENGTH_EXPONENT>,
+                    N<TIME_EXPONENT>,
+                    N<CURRENT_EXPONENT>,
+                    N<TEMPERATURE_EXPONENT>,
+                    N<AMOUNT_EXPONENT>,
+                    N<LUMINOSITY_EXPONENT>,
+                    N<ANGLE_EXPONENT>,
+                    N<INFORMATION_EXPONENT>,
+                ) as AllZero>::Output: ScaleFold<S, $T>,
+            {
+                type Output = <<(
+                    N<MASS_EXPONENT>,
+                    N<LENGTH_EXPONENT>,
+                    N<TIME_EXPONENT>,
+                    N<CURRENT_EXPONENT>,
+                    N<TEMPERATURE_EXPONENT>,
+                    N<AMOUNT_EXPONENT>,
+                    N<LUMINOSITY_EXPONENT>,
+                    N<ANGLE_EXPONENT>,
+                    N<INFORMATION_EXPONENT>,
+                ) as AllZero>::Output as ScaleFold<S, $T>>::Output;
+
+                fn fold(value: $T) -> $T {
+                    <<(
+                        N<MASS_EXPONENT>,
+                        N<LENGTH_EXPONENT>,
+                        N<TIME_EXPONENT>,
+                        N<CURRENT_EXPONENT>,
+                        N<TEMPERATURE_EXPONENT>,
+                        N<AMOUNT_EXPONENT>,
+                        N<LUMINOSITY_EXPONENT>,
+                        N<ANGLE_EXPONENT>,
+                        N<INFORMATION_EXPONENT>,
+                    ) as AllZero>::Output as ScaleFold<S, $T>>::fold(value)
+                }
+            }
+
+            impl<S> ScaleFold<S, $T> for N<0> {
+                type Output = S;
+
+                fn fold(value: $T) -> $T {
+                    value
+                }
+            }
+
+            impl<const SCALE_P2: i16, const SCALE_P3: i16, const SCALE_P5: i16, const SCALE_PI: i16>
+                ScaleFold<Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>, $T>
+                for N<1>
+            {
+                type Output = Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>;
+
+                fn fold(value: $T) -> $T {
+                    value
+                        * crate::api::aggregate_scale_factor_float(
+                            SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0,
+                        ) as $T
+                }
+            }
+        )*
+    };
+}
+
+/// [`QuotientScale`] for a float quotient whose dimension does (`N<1>`) or does not (`N<0>`)
+/// cancel out.
+#[cfg(not(has_generic_const_exprs))]
+#[doc(hidden)]
+pub trait ScaleFold<S, T> {
+    type Output;
+
+    fn fold(value: T) -> T;
+}
+
+scale_folding_quotient!(f32, f64);
+
+/// Overflow-checked arithmetic, for quantities with integer storage.
+///
+/// These follow the same dimensional rules as the corresponding operators: addition and
+/// subtraction are scale-strict, and multiplication produces the same type as `*`.  Each returns
+/// `None` where the integer operation on the stored values overflows.
+///
+/// ```rust
+/// # use whippyunits::quantity;
+/// let distance = quantity!(i32::MAX - 1, m, i32);
+/// assert_eq!(distance.checked_add(quantity!(1, m, i32)), Some(quantity!(i32::MAX, m, i32)));
+/// assert_eq!(distance.checked_add(quantity!(2, m, i32)), None);
+///
+/// let area = quantity!(50_000, m, i32).checked_mul(quantity!(50_000, m, i32));
+/// assert_eq!(area, None);
+/// ```
+impl<Scale, Dimension, T, Brand> Quantity<Scale, Dimension, T, Brand> {
+    /// `self + other`, or `None` on overflow.
+    pub fn checked_add(mut self, other: Self) -> Option<Self>
+    where
+        T: num_traits::CheckedAdd,
+    {
+        self.unsafe_value = self.unsafe_value.checked_add(&other.unsafe_value)?;
+        Some(self)
+    }
+
+    /// `self - other`, or `None` on overflow.
+    pub fn checked_sub(mut self, other: Self) -> Option<Self>
+    where
+        T: num_traits::CheckedSub,
+    {
+        self.unsafe_value = self.unsafe_value.checked_sub(&other.unsafe_value)?;
+        Some(self)
+    }
+
+    /// `self * other`, or `None` on overflow.
+    ///
+    /// The result has the same type as `self * other`.
+    pub fn checked_mul<OtherScale, OtherDimension, OtherBrand>(
+        self,
+        other: Quantity<OtherScale, OtherDimension, T, OtherBrand>,
+    ) -> Option<<Self as core::ops::Mul<Quantity<OtherScale, OtherDimension, T, OtherBrand>>>::Output>
+    where
+        Self: core::ops::Mul<Quantity<OtherScale, OtherDimension, T, OtherBrand>>,
+        T: num_traits::CheckedMul,
+    {
+        // The product is formed by the `Mul` impl, which gives the output its type; checking
+        // first guarantees that it cannot overflow
+        self.unsafe_value.checked_mul(&other.unsafe_value)?;
+        Some(self * other)
+    }
+}
+
+/// Cross-scale addition and subtraction.
+///
+/// The `+` and `-` operators are scale-strict.  These methods accept a right-hand side of any
+/// scale and rescale it into the scale of `self`, which is the scale of the result.  This is
+/// only available for float storage, since rescaling integer storage can truncate.
+///
+/// ```rust
+/// # use whippyunits::{quantity, unit, value};
+/// let total: unit!(m) = quantity!(1.0, m).add_rescaled(quantity!(50.0, cm));
+/// assert_eq!(value!(total, m), 1.5);
+///
+/// let difference: unit!(km) = quantity!(1.0, km).sub_rescaled(quantity!(500.0, m));
+/// assert_eq!(value!(difference, km), 0.5);
+/// ```
+impl<Scale, Dimension, T, Brand> Quantity<Scale, Dimension, T, Brand> {
+    /// `self + other`, with `other` rescaled into the scale of `self`.
+    pub fn add_rescaled<Rhs>(self, other: Rhs) -> Self
+    where
+        Rhs: AddSubCompatible<Self>,
+        Self: core::ops::Add<Output = Self>,
+    {
+        self + other.into_lhs()
+    }
+
+    /// `self - other`, with `other` rescaled into the scale of `self`.
+    pub fn sub_rescaled<Rhs>(self, other: Rhs) -> Self
+    where
+        Rhs: AddSubCompatible<Self>,
+        Self: core::ops::Sub<Output = Self>,
+    {
+        self - other.into_lhs()
+    }
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! scalar_quantity_mul_div_interface {
@@ -152,6 +602,41 @@ macro_rules! quantity_quantity_mul_div_interface {
     };
 }
 
+#[macro_export]
+#[doc(hidden)]
+macro_rules! quantity_quantity_div_interface {
+    (
+        ($($multiple_dimension_multiple_scale_params:tt)*),
+        ($($output_dimension_where_clauses:tt)*),
+        $T:ty
+    ) => {
+        impl<
+            $($multiple_dimension_multiple_scale_params)*
+        >
+            core::ops::Div<
+                $crate::multiplication_input!(RightHand, $T),
+            >
+            for $crate::multiplication_input!(LeftHand, $T)
+        where
+            $($output_dimension_where_clauses)*,
+            $crate::division_dimension!(): $crate::arithmetic::QuotientScale<$crate::division_scale!(), $T>,
+        {
+            type Output = $crate::division_output!($T);
+
+            fn div(
+                self,
+                other: $crate::multiplication_input!(RightHand, $T),
+            ) -> Self::Output {
+                Self::Output::from_unsafe_value(
+                    <$crate::division_dimension!() as $crate::arithmetic::QuotientScale<$crate::division_scale!(), $T>>::fold(
+                        self.unsafe_value / other.unsafe_value,
+                    ),
+                )
+            }
+        }
+    };
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! quantity_neg_interface {
@@ -261,10 +746,10 @@ macro_rules! _define_arithmetic_signed {
             ($($mul_output_dimension_where_clauses)*),
             *, +, mul, Mul, $T, $rescale_fn
         );
-        $crate::quantity_quantity_mul_div_interface!(
+        $crate::quantity_quantity_div_interface!(
             ($($multiple_dimension_multiple_scale_params)*),
             ($($div_output_dimension_where_clauses)*),
-            /, -, div, Div, $T, $rescale_fn
+            $T
         );
 
         // quantity-quantity comparison operations (scale-strict)
@@ -337,10 +822,10 @@ macro_rules! _define_arithmetic {
             ($($mul_output_dimension_where_clauses)*),
             *, +, mul, Mul, $T, $rescale_fn
         );
-        $crate::quantity_quantity_mul_div_interface!(
+        $crate::quantity_quantity_div_interface!(
             ($($multiple_dimension_multiple_scale_params)*),
             ($($div_output_dimension_where_clauses)*),
-            /, -, div, Div, $T, $rescale_fn
+            $T
         );
 
         // quantity-quantity comparison operations (scale-strict)
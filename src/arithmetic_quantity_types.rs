@@ -5,7 +5,7 @@ macro_rules! inverse_quantity_type {
     ($T:ty) => {
         Quantity<
             Scale<_2<{ -SCALE_P2 }>, _3<{ -SCALE_P3 }>, _5<{ -SCALE_P5 }>, _Pi<{ -SCALE_PI }>>,
-            Dimension<_M<{ -MASS_EXPONENT }>, _L<{ -LENGTH_EXPONENT }>, _T<{ -TIME_EXPONENT }>, _I<{ -CURRENT_EXPONENT }>, _Θ<{ -TEMPERATURE_EXPONENT }>, _N<{ -AMOUNT_EXPONENT }>, _J<{ -LUMINOSITY_EXPONENT }>, _A<{ -ANGLE_EXPONENT }>>,
+            Dimension<_M<{ -MASS_EXPONENT }>, _L<{ -LENGTH_EXPONENT }>, _T<{ -TIME_EXPONENT }>, _I<{ -CURRENT_EXPONENT }>, _Θ<{ -TEMPERATURE_EXPONENT }>, _N<{ -AMOUNT_EXPONENT }>, _J<{ -LUMINOSITY_EXPONENT }>, _A<{ -ANGLE_EXPONENT }>, _Info<{ -INFORMATION_EXPONENT }>>,
             $T,
             Brand
         >
@@ -27,7 +27,8 @@ macro_rules! inverse_quantity_type {
                 _Θ<INVERSE_TEMPERATURE_EXPONENT>,
                 _N<INVERSE_AMOUNT_EXPONENT>,
                 _J<INVERSE_LUMINOSITY_EXPONENT>,
-                _A<INVERSE_ANGLE_EXPONENT>
+                _A<INVERSE_ANGLE_EXPONENT>,
+                _Info<INVERSE_INFORMATION_EXPONENT>
             >,
             $T,
             Brand
@@ -41,7 +42,7 @@ macro_rules! addition_input {
     (Strict, $T:ty) => {
         Quantity<
             Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
             $T,
             Brand
         >
@@ -49,7 +50,7 @@ macro_rules! addition_input {
     (LeftHand, $T:ty) => {
         Quantity<
             Scale<_2<SCALE_P2_1>, _3<SCALE_P3_1>, _5<SCALE_P5_1>, _Pi<SCALE_PI_1>>,
-            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
             $T,
             Brand
         >
@@ -57,7 +58,7 @@ macro_rules! addition_input {
     (RightHand, $T:ty) => {
         Quantity<
             Scale<_2<SCALE_P2_2>, _3<SCALE_P3_2>, _5<SCALE_P5_2>, _Pi<SCALE_PI_2>>,
-            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
             $T,
             Brand
         >
@@ -70,7 +71,7 @@ macro_rules! multiplication_input {
     (LeftHand, $T:ty) => {
         Quantity<
             Scale<_2<SCALE_P2_1>, _3<SCALE_P3_1>, _5<SCALE_P5_1>, _Pi<SCALE_PI_1>>,
-            Dimension<_M<MASS_EXPONENT_1>, _L<LENGTH_EXPONENT_1>, _T<TIME_EXPONENT_1>, _I<CURRENT_EXPONENT_1>, _Θ<TEMPERATURE_EXPONENT_1>, _N<AMOUNT_EXPONENT_1>, _J<LUMINOSITY_EXPONENT_1>, _A<ANGLE_EXPONENT_1>>,
+            Dimension<_M<MASS_EXPONENT_1>, _L<LENGTH_EXPONENT_1>, _T<TIME_EXPONENT_1>, _I<CURRENT_EXPONENT_1>, _Θ<TEMPERATURE_EXPONENT_1>, _N<AMOUNT_EXPONENT_1>, _J<LUMINOSITY_EXPONENT_1>, _A<ANGLE_EXPONENT_1>, _Info<INFORMATION_EXPONENT_1>>,
             $T,
             Brand
         >
@@ -78,7 +79,7 @@ macro_rules! multiplication_input {
     (RightHand, $T:ty) => {
         Quantity<
             Scale<_2<SCALE_P2_2>, _3<SCALE_P3_2>, _5<SCALE_P5_2>, _Pi<SCALE_PI_2>>,
-            Dimension<_M<MASS_EXPONENT_2>, _L<LENGTH_EXPONENT_2>, _T<TIME_EXPONENT_2>, _I<CURRENT_EXPONENT_2>, _Θ<TEMPERATURE_EXPONENT_2>, _N<AMOUNT_EXPONENT_2>, _J<LUMINOSITY_EXPONENT_2>, _A<ANGLE_EXPONENT_2>>,
+            Dimension<_M<MASS_EXPONENT_2>, _L<LENGTH_EXPONENT_2>, _T<TIME_EXPONENT_2>, _I<CURRENT_EXPONENT_2>, _Θ<TEMPERATURE_EXPONENT_2>, _N<AMOUNT_EXPONENT_2>, _J<LUMINOSITY_EXPONENT_2>, _A<ANGLE_EXPONENT_2>, _Info<INFORMATION_EXPONENT_2>>,
             $T,
             Brand
         >
@@ -105,7 +106,8 @@ macro_rules! multiplication_output {
                 _Θ<{ TEMPERATURE_EXPONENT_1 $log_op TEMPERATURE_EXPONENT_2 }>,
                 _N<{ AMOUNT_EXPONENT_1 $log_op AMOUNT_EXPONENT_2 }>,
                 _J<{ LUMINOSITY_EXPONENT_1 $log_op LUMINOSITY_EXPONENT_2 }>,
-                _A<{ ANGLE_EXPONENT_1 $log_op ANGLE_EXPONENT_2 }>
+                _A<{ ANGLE_EXPONENT_1 $log_op ANGLE_EXPONENT_2 }>,
+                _Info<{ INFORMATION_EXPONENT_1 $log_op INFORMATION_EXPONENT_2 }>
             >,
             $T,
             Brand
@@ -133,10 +135,87 @@ macro_rules! multiplication_output {
                 _Θ<TEMPERATURE_EXPONENT>,
                 _N<AMOUNT_EXPONENT>,
                 _J<LUMINOSITY_EXPONENT>,
-                _A<ANGLE_EXPONENT>
+                _A<ANGLE_EXPONENT>,
+                _Info<INFORMATION_EXPONENT>
             >,
             $T,
             Brand
         >
     };
 }
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(has_generic_const_exprs)]
+macro_rules! division_dimension {
+    () => {
+        Dimension<
+            _M<{ MASS_EXPONENT_1 - MASS_EXPONENT_2 }>,
+            _L<{ LENGTH_EXPONENT_1 - LENGTH_EXPONENT_2 }>,
+            _T<{ TIME_EXPONENT_1 - TIME_EXPONENT_2 }>,
+            _I<{ CURRENT_EXPONENT_1 - CURRENT_EXPONENT_2 }>,
+            _Θ<{ TEMPERATURE_EXPONENT_1 - TEMPERATURE_EXPONENT_2 }>,
+            _N<{ AMOUNT_EXPONENT_1 - AMOUNT_EXPONENT_2 }>,
+            _J<{ LUMINOSITY_EXPONENT_1 - LUMINOSITY_EXPONENT_2 }>,
+            _A<{ ANGLE_EXPONENT_1 - ANGLE_EXPONENT_2 }>,
+            _Info<{ INFORMATION_EXPONENT_1 - INFORMATION_EXPONENT_2 }>
+        >
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(has_generic_const_exprs))]
+macro_rules! division_dimension {
+    () => {
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>
+        >
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(has_generic_const_exprs)]
+macro_rules! division_scale {
+    () => {
+        Scale<
+            _2<{ SCALE_P2_1 - SCALE_P2_2 }>,
+            _3<{ SCALE_P3_1 - SCALE_P3_2 }>,
+            _5<{ SCALE_P5_1 - SCALE_P5_2 }>,
+            _Pi<{ SCALE_PI_1 - SCALE_PI_2 }>
+        >
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+#[cfg(not(has_generic_const_exprs))]
+macro_rules! division_scale {
+    () => {
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>
+    };
+}
+
+// The scale of a quotient is decided by `QuotientScale`, which folds it into the value for a
+// dimensionless float quotient
+#[macro_export]
+#[doc(hidden)]
+macro_rules! division_output {
+    ($T:ty) => {
+        Quantity<
+            <$crate::division_dimension!() as $crate::arithmetic::QuotientScale<$crate::division_scale!(), $T>>::Output,
+            $crate::division_dimension!(),
+            $T,
+            Brand
+        >
+    };
+}
@@ -0,0 +1,168 @@
+//! Pressure altitude in the ICAO International Standard Atmosphere (ISA).
+//!
+//! These are physics helpers rather than unit conversions: they model how static pressure falls
+//! off with geopotential altitude in a standard day atmosphere, as used for altimeter settings and
+//! flight levels.
+//!
+//! ```rust
+//! use whippyunits::atmosphere::{altitude_to_pressure, pressure_to_altitude};
+//! use whippyunits::{quantity, unit, value};
+//!
+//! let altitude = pressure_to_altitude(quantity!(1013.25, hPa));
+//! assert!(value!(altitude, m).abs() < 1e-6);
+//!
+//! let pressure: unit!(Pa) = altitude_to_pressure(quantity!(5.5, km));
+//! assert!((value!(pressure, hPa) - 505.0).abs() < 0.1);
+//! ```
+//!
+//! ## Layers
+//!
+//! The ISA is piecewise: temperature falls linearly through the troposphere, is constant in the
+//! lower stratosphere above the tropopause (11 km), and rises again above 20 km.  Each layer uses
+//! the matching form of the barometric formula (power law with a lapse rate, exponential when
+//! isothermal), so results are continuous across the tropopause.  Layers up to 47 km are
+//! modelled; beyond that the highest layer is extrapolated.
+
+use crate::api::rescale;
+use crate::constants::Coherent;
+use crate::quantity::Quantity;
+use crate::quantity::{_2, _3, _5, _A, _I, _Info, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
+
+/// Pressure of any scale.
+type Pressure<const P2: i16, const P3: i16, const P5: i16, const PI: i16> = Quantity<
+    Scale<_2<P2>, _3<P3>, _5<P5>, _Pi<PI>>,
+    Dimension<_M<1>, _L<-1>, _T<-2>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+    f64,
+>;
+
+/// Length of any scale.
+type Length<const P2: i16, const P3: i16, const P5: i16, const PI: i16> = Quantity<
+    Scale<_2<P2>, _3<P3>, _5<P5>, _Pi<PI>>,
+    Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+    f64,
+>;
+
+/// ISA sea-level static pressure, `p₀` (Pa).
+pub const SEA_LEVEL_PRESSURE: Coherent<1, -1, -2, 0, 0, 0, 0, 0> = Quantity::new(101_325.0);
+
+/// ISA sea-level temperature, `T₀` (K).
+pub const SEA_LEVEL_TEMPERATURE: Coherent<0, 0, 0, 0, 1, 0, 0, 0> = Quantity::new(288.15);
+
+/// ISA temperature lapse rate in the troposphere, `L` (K·m⁻¹). Temperature *falls* by this much
+/// per meter of altitude.
+pub const TROPOSPHERE_LAPSE_RATE: Coherent<0, -1, 0, 0, 1, 0, 0, 0> = Quantity::new(0.0065);
+
+/// ISA tropopause altitude (m), above which the temperature is constant up to 20 km.
+pub const TROPOPAUSE_ALTITUDE: Coherent<0, 1, 0, 0, 0, 0, 0, 0> = Quantity::new(11_000.0);
+
+/// Standard acceleration of gravity, `g₀` (m·s⁻²).
+pub const STANDARD_GRAVITY: Coherent<0, 1, -2, 0, 0, 0, 0, 0> = Quantity::new(9.806_65);
+
+/// Specific gas constant of dry air, `R` (J·kg⁻¹·K⁻¹ = m²·s⁻²·K⁻¹).
+pub const SPECIFIC_GAS_CONSTANT: Coherent<0, 2, -2, 0, -1, 0, 0, 0> = Quantity::new(287.052_87);
+
+/// A layer of the standard atmosphere, in coherent SI units.
+struct Layer {
+    /// Geopotential altitude at the base of the layer (m).
+    base_altitude: f64,
+    /// Temperature at the base of the layer (K).
+    base_temperature: f64,
+    /// Pressure at the base of the layer (Pa).
+    base_pressure: f64,
+    /// Rate of temperature *increase* with altitude (K·m⁻¹).
+    lapse_rate: f64,
+}
+
+/// ISA layers, lowest first.  Base pressures are the ICAO tabulated values.
+const LAYERS: [Layer; 4] = [
+    // Troposphere
+    Layer {
+        base_altitude: 0.0,
+        base_temperature: SEA_LEVEL_TEMPERATURE.unsafe_value,
+        base_pressure: SEA_LEVEL_PRESSURE.unsafe_value,
+        lapse_rate: -TROPOSPHERE_LAPSE_RATE.unsafe_value,
+    },
+    // Tropopause (isothermal)
+    Layer {
+        base_altitude: TROPOPAUSE_ALTITUDE.unsafe_value,
+        base_temperature: 216.65,
+        base_pressure: 22_632.06,
+        lapse_rate: 0.0,
+    },
+    // Lower stratosphere
+    Layer {
+        base_altitude: 20_000.0,
+        base_temperature: 216.65,
+        base_pressure: 5_474.889,
+        lapse_rate: 0.001,
+    },
+    // Upper stratosphere
+    Layer {
+        base_altitude: 32_000.0,
+        base_temperature: 228.65,
+        base_pressure: 868.018_7,
+        lapse_rate: 0.0028,
+    },
+];
+
+/// Convert a static pressure to ISA pressure altitude.
+///
+/// Pressures above [SEA_LEVEL_PRESSURE] give negative altitudes.  The pressure may be given in
+/// any unit of pressure; the altitude is returned in meters.
+pub fn pressure_to_altitude<const P2: i16, const P3: i16, const P5: i16, const PI: i16>(
+    pressure: Pressure<P2, P3, P5, PI>,
+) -> Coherent<0, 1, 0, 0, 0, 0, 0, 0> {
+    let pascals: Pressure<0, 0, 0, 0> = rescale(pressure);
+    let pressure = pascals.unsafe_value;
+
+    // Pressure decreases with altitude, so the layer is the highest one whose base pressure is
+    // still at or above the given pressure.
+    let layer = LAYERS
+        .iter()
+        .rev()
+        .find(|layer| pressure <= layer.base_pressure)
+        .unwrap_or(&LAYERS[0]);
+
+    let r_over_g = SPECIFIC_GAS_CONSTANT.unsafe_value / STANDARD_GRAVITY.unsafe_value;
+    let height_in_layer = if layer.lapse_rate == 0.0 {
+        r_over_g * layer.base_temperature * libm::log(layer.base_pressure / pressure)
+    } else {
+        let exponent = -r_over_g * layer.lapse_rate;
+        layer.base_temperature / layer.lapse_rate
+            * (libm::pow(pressure / layer.base_pressure, exponent) - 1.0)
+    };
+
+    Quantity::new(layer.base_altitude + height_in_layer)
+}
+
+/// Convert an ISA pressure altitude to the corresponding static pressure.
+///
+/// This is the inverse of [pressure_to_altitude].  The altitude may be given in any unit of
+/// length; the pressure is returned in pascals.
+pub fn altitude_to_pressure<const P2: i16, const P3: i16, const P5: i16, const PI: i16>(
+    altitude: Length<P2, P3, P5, PI>,
+) -> Coherent<1, -1, -2, 0, 0, 0, 0, 0> {
+    let meters: Length<0, 0, 0, 0> = rescale(altitude);
+    let altitude = meters.unsafe_value;
+
+    let layer = LAYERS
+        .iter()
+        .rev()
+        .find(|layer| altitude >= layer.base_altitude)
+        .unwrap_or(&LAYERS[0]);
+
+    let g_over_r = STANDARD_GRAVITY.unsafe_value / SPECIFIC_GAS_CONSTANT.unsafe_value;
+    let height_in_layer = altitude - layer.base_altitude;
+    let pressure = if layer.lapse_rate == 0.0 {
+        layer.base_pressure * libm::exp(-g_over_r * height_in_layer / layer.base_temperature)
+    } else {
+        let temperature = layer.base_temperature + layer.lapse_rate * height_in_layer;
+        layer.base_pressure
+            * libm::pow(
+                temperature / layer.base_temperature,
+                -g_over_r / layer.lapse_rate,
+            )
+    };
+
+    Quantity::new(pressure)
+}
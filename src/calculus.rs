@@ -0,0 +1,96 @@
+//! Numerical calculus over series of [Quantity](crate::Quantity) values.
+//!
+//! [finite_difference] estimates the rate of change of a uniformly sampled series, with the
+//! quotient dimension worked out by the type system: differentiating positions gives
+//! velocities, and differentiating velocities gives accelerations.  [integrate_trapezoid] goes
+//! the other way, accumulating a series into the product dimension, and [cumulative_sum]
+//! keeps the running totals of a series in its own unit.
+//!
+//! ```rust
+//! use whippyunits::calculus::{finite_difference, integrate_trapezoid};
+//! use whippyunits::{quantity, unit};
+//!
+//! let positions = [quantity!(0.0, m), quantity!(2.0, m), quantity!(6.0, m)];
+//! let velocities: Vec<unit!(m / s)> = finite_difference(&positions, quantity!(0.5, s));
+//! assert_eq!(velocities, [quantity!(4.0, m / s), quantity!(8.0, m / s)]);
+//!
+//! let distance: unit!(m) = integrate_trapezoid(&velocities, quantity!(0.5, s));
+//! assert_eq!(distance, quantity!(3.0, m));
+//! ```
+
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::alloc::Vec;
+use crate::dimension_traits::Time;
+
+/// Forward differences of `values` sampled every `dt`, divided by `dt`.
+///
+/// Element `i` of the result is `(values[i + 1] - values[i]) / dt`, the average rate of change
+/// over the `i`th interval, so the result has one element fewer than `values` (and is empty if
+/// there are fewer than two).  The quotient has the combined unit of the value and time types,
+/// at their combined scale: positions in `km` sampled in `h` give velocities in `km/h`.
+pub fn finite_difference<Y, D>(values: &[Y], dt: D) -> Vec<<Y as Div<D>>::Output>
+where
+    Y: Copy + Sub<Output = Y> + Div<D>,
+    D: Time + Copy,
+{
+    values
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]) / dt)
+        .collect()
+}
+
+/// Integral of `values` sampled every `dt`, by the trapezoidal rule.
+///
+/// Each interval contributes the mean of its two endpoints times `dt`, so the result has the
+/// product dimension of the value and time types, at their combined scale: velocities in `km/h`
+/// sampled in `h` integrate to a distance in `km`.  Fewer than two samples span no interval and
+/// integrate to zero.  The values must have `f64` storage.
+pub fn integrate_trapezoid<Y, D>(values: &[Y], dt: D) -> <Y as Mul<D>>::Output
+where
+    Y: Copy + Add<Output = Y> + Mul<D>,
+    D: Time + Copy,
+    <Y as Mul<D>>::Output:
+        Default + Add<Output = <Y as Mul<D>>::Output> + Div<f64, Output = <Y as Mul<D>>::Output>,
+{
+    values
+        .windows(2)
+        .map(|pair| (pair[0] + pair[1]) * dt)
+        .fold(<Y as Mul<D>>::Output::default(), |total, doubled| {
+            total + doubled
+        })
+        / 2.0
+}
+
+/// Running totals of `values`, in their common unit.
+///
+/// Element `i` of the result is the sum of `values[0..=i]`, so the result has as many elements
+/// as `values` (and is empty if `values` is), and its last element is the total.  Since every
+/// value has the same type, no rescaling is involved: the totals are in the unit of the inputs.
+///
+/// ```rust
+/// use whippyunits::calculus::cumulative_sum;
+/// use whippyunits::quantity;
+///
+/// let legs = [quantity!(2.0, km), quantity!(3.5, km), quantity!(1.0, km)];
+/// let odometer = cumulative_sum(&legs);
+/// assert_eq!(odometer, [quantity!(2.0, km), quantity!(5.5, km), quantity!(6.5, km)]);
+/// ```
+///
+/// Values are added one at a time from the front of the series, so each total is rounded
+/// exactly as a sequential loop would round it; for float storage, long series of values of
+/// very different magnitudes accumulate rounding error in that order.
+pub fn cumulative_sum<Y>(values: &[Y]) -> Vec<Y>
+where
+    Y: Copy + Add<Output = Y>,
+{
+    let mut totals = Vec::with_capacity(values.len());
+    for &value in values {
+        let total = match totals.last() {
+            Some(&previous) => previous + value,
+            None => value,
+        };
+        totals.push(total);
+    }
+    totals
+}
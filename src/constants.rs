@@ -0,0 +1,51 @@
+//! Physical constants as typed [Quantity] values.
+//!
+//! All constants are expressed in coherent SI units (identity scale, `f64` storage), so they
+//! compose directly with quantities declared in base SI units. Values are the CODATA 2018
+//! recommended values; those fixed exactly by the 2019 SI redefinition are exact.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use whippyunits::constants::C;
+//! use whippyunits::{quantity, unit, value};
+//!
+//! let t = quantity!(2.0, s);
+//! let d: unit!(m) = C * t;
+//! assert_eq!(value!(d, m), 599_584_916.0);
+//! ```
+
+use crate::quantity::{Quantity, QuantityOf};
+
+/// Coherent SI quantity (identity scale, `f64` storage) with the given dimension exponents.
+pub(crate) type Coherent<
+    const M: i16,
+    const L: i16,
+    const T: i16,
+    const I: i16,
+    const Θ: i16,
+    const N: i16,
+    const J: i16,
+    const A: i16,
+> = QuantityOf<M, L, T, I, Θ, N, J, A, 0, 0, 0, 0, 0, f64, ()>;
+
+/// Speed of light in vacuum, `c` (m·s⁻¹). Exact.
+pub const C: Coherent<0, 1, -1, 0, 0, 0, 0, 0> = Quantity::new(299_792_458.0);
+
+/// Newtonian constant of gravitation, `G` (m³·kg⁻¹·s⁻²).
+pub const G: Coherent<-1, 3, -2, 0, 0, 0, 0, 0> = Quantity::new(6.674_30e-11);
+
+/// Planck constant, `h` (J·s = kg·m²·s⁻¹). Exact.
+pub const H: Coherent<1, 2, -1, 0, 0, 0, 0, 0> = Quantity::new(6.626_070_15e-34);
+
+/// Reduced Planck constant, `ħ = h / 2π` (J·s = kg·m²·s⁻¹).
+pub const HBAR: Coherent<1, 2, -1, 0, 0, 0, 0, 0> = Quantity::new(1.054_571_817e-34);
+
+/// Boltzmann constant, `k_B` (J·K⁻¹ = kg·m²·s⁻²·K⁻¹). Exact.
+pub const K_B: Coherent<1, 2, -2, 0, -1, 0, 0, 0> = Quantity::new(1.380_649e-23);
+
+/// Avogadro constant, `N_A` (mol⁻¹). Exact.
+pub const N_A: Coherent<0, 0, 0, 0, 0, -1, 0, 0> = Quantity::new(6.022_140_76e23);
+
+/// Elementary charge, `e` (C = A·s). Exact.
+pub const E_CHARGE: Coherent<0, 0, 1, 1, 0, 0, 0, 0> = Quantity::new(1.602_176_634e-19);
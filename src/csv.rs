@@ -0,0 +1,173 @@
+//! Write columns of [Quantity](crate::Quantity) values to CSV.
+//!
+//! Each column is written in coherent SI units (kilograms for mass), and its header is annotated
+//! with that unit in square brackets, so columns of different dimensions each carry their own
+//! unit:
+//!
+//! ```rust
+//! use whippyunits::csv::CsvWriter;
+//! use whippyunits::quantity;
+//!
+//! let csv = CsvWriter::new()
+//!     .column("length", &[quantity!(1.5, km), quantity!(250.0, km)])
+//!     .column("time", &[quantity!(2.0, min), quantity!(0.5, min)])
+//!     .column("speed", &[quantity!(12.5, m / s), quantity!(0.5, m / s)])
+//!     .to_csv_string();
+//!
+//! assert_eq!(csv, "length[m],time[s],speed[m/s]\n1500,120,12.5\n250000,30,0.5\n");
+//! ```
+//!
+//! Unit annotations use a named unit where one exists (`J`, `Pa`), and otherwise a unit literal
+//! expression of base units (`kg*m/s3`), so they can be read back with the same runtime parsers
+//! as [from_string!](crate::from_string!).
+
+use core::fmt::{self, Write};
+
+use crate::alloc::{String, Vec, format};
+use crate::api::aggregate_scale_factor_float;
+use crate::print::unit_literal_generator::coherent_unit_literal;
+use crate::quantity::QuantityOf;
+use whippyunits_core::dimension_exponents::DynDimensionExponents;
+
+struct Column {
+    header: String,
+    values: Vec<f64>,
+}
+
+/// Builder for a CSV table with one column per quantity type.
+///
+/// Columns may have different lengths; missing cells of shorter columns are left empty.
+#[derive(Default)]
+pub struct CsvWriter {
+    columns: Vec<Column>,
+}
+
+impl CsvWriter {
+    /// Create a writer with no columns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a column named `name`, holding `quantities` in coherent SI units.
+    pub fn column<
+        const MASS_EXPONENT: i16,
+        const LENGTH_EXPONENT: i16,
+        const TIME_EXPONENT: i16,
+        const CURRENT_EXPONENT: i16,
+        const TEMPERATURE_EXPONENT: i16,
+        const AMOUNT_EXPONENT: i16,
+        const LUMINOSITY_EXPONENT: i16,
+        const ANGLE_EXPONENT: i16,
+        const INFORMATION_EXPONENT: i16,
+        const SCALE_P2: i16,
+        const SCALE_P3: i16,
+        const SCALE_P5: i16,
+        const SCALE_PI: i16,
+        T,
+        Brand,
+    >(
+        mut self,
+        name: &str,
+        quantities: &[QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            T,
+            Brand,
+        >],
+    ) -> Self
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        let unit = coherent_unit_literal(DynDimensionExponents([
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+        ]));
+        let scale_factor =
+            aggregate_scale_factor_float(SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0);
+        let values = quantities
+            .iter()
+            .map(|quantity| {
+                <f64 as num_traits::NumCast>::from(quantity.unsafe_value)
+                    .expect("unable to convert numeric value to f64 for CSV output")
+                    * scale_factor
+            })
+            .collect();
+
+        self.columns.push(Column {
+            header: format!("{}[{}]", name, unit),
+            values,
+        });
+        self
+    }
+
+    /// Write the header row and all value rows to `out`.
+    pub fn write<W: Write>(&self, out: &mut W) -> fmt::Result {
+        for (i, column) in self.columns.iter().enumerate() {
+            if i > 0 {
+                out.write_char(',')?;
+            }
+            write_field(out, &column.header)?;
+        }
+        out.write_char('\n')?;
+
+        let rows = self
+            .columns
+            .iter()
+            .map(|column| column.values.len())
+            .max()
+            .unwrap_or(0);
+        for row in 0..rows {
+            for (i, column) in self.columns.iter().enumerate() {
+                if i > 0 {
+                    out.write_char(',')?;
+                }
+                if let Some(value) = column.values.get(row) {
+                    write!(out, "{}", value)?;
+                }
+            }
+            out.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    /// Write the table to a new string.
+    pub fn to_csv_string(&self) -> String {
+        let mut csv = String::new();
+        self.write(&mut csv)
+            .expect("writing to a String cannot fail");
+        csv
+    }
+}
+
+/// Write `field`, quoting it if it contains a delimiter, quote, or line break.
+fn write_field<W: Write>(out: &mut W, field: &str) -> fmt::Result {
+    if !field.contains([',', '"', '\n', '\r']) {
+        return out.write_str(field);
+    }
+    out.write_char('"')?;
+    for c in field.chars() {
+        if c == '"' {
+            out.write_char('"')?;
+        }
+        out.write_char(c)?;
+    }
+    out.write_char('"')
+}
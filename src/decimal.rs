@@ -0,0 +1,181 @@
+//! Exact decimal storage with [`BigDecimal`](bigdecimal::BigDecimal).
+//!
+//! Any storage unit can hold a `BigDecimal` value:
+//!
+//! ```rust
+//! # use whippyunits::quantity;
+//! use bigdecimal::BigDecimal;
+//! use core::str::FromStr;
+//!
+//! let distance = quantity!(BigDecimal::from_str("1.5").unwrap(), km, BigDecimal);
+//! ```
+//!
+//! Rescaling with [rescale_bigdecimal] multiplies by the exact scale factor rather than an `f64`
+//! approximation of it.  Powers of 2 and 5 (and so of 10) have terminating decimal expansions in
+//! both directions, and multiplying by powers of 3 is exact too.  Dividing by 3 is exact only when
+//! the value happens to be divisible (90 s is exactly 1.5 min, but 100 s is not a terminating
+//! number of minutes), and no factor of π is ever exact; rescales without an exact result return
+//! an [InexactRescale] error instead of silently rounding.
+
+use bigdecimal::BigDecimal;
+use bigdecimal::num_bigint::BigInt;
+use core::fmt;
+
+use crate::quantity::{Quantity, QuantityOf};
+
+/// Error returned by [rescale_bigdecimal] when the rescaled value has no exact decimal
+/// representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InexactRescale {
+    /// The exponent of 3 in the scale factor
+    pub p3: i16,
+    /// The exponent of π in the scale factor
+    pub pi: i16,
+}
+
+impl fmt::Display for InexactRescale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "rescaling by 3^{} · π^{} has no exact decimal result",
+            self.p3, self.pi
+        )
+    }
+}
+
+impl core::error::Error for InexactRescale {}
+
+/// The exact decimal value of `2^p2 · 3^p3 · 5^p5` for nonnegative `p3`.
+fn exact_scale_factor(p2: i16, p3: u32, p5: i16) -> BigDecimal {
+    // 2^-n = 5^n / 10^n and 5^-n = 2^n / 10^n, so negative powers only add decimal places
+    let mut digits = BigInt::from(3u32).pow(p3);
+    let mut decimal_places: i64 = 0;
+    if p2 >= 0 {
+        digits *= BigInt::from(2u32).pow(p2 as u32);
+    } else {
+        digits *= BigInt::from(5u32).pow(p2.unsigned_abs() as u32);
+        decimal_places += -(p2 as i64);
+    }
+    if p5 >= 0 {
+        digits *= BigInt::from(5u32).pow(p5 as u32);
+    } else {
+        digits *= BigInt::from(2u32).pow(p5.unsigned_abs() as u32);
+        decimal_places += -(p5 as i64);
+    }
+
+    BigDecimal::new(digits, decimal_places)
+}
+
+/// Rescale a `BigDecimal`-backed quantity to a different unit of the same dimension, exactly.
+///
+/// Like [`api::rescale`](crate::api::rescale), the target is inferred from the expected type.
+/// Returns [InexactRescale] if the conversion involves π, or divides by 3 a value that is not
+/// divisible by 3.
+///
+/// ```rust
+/// # use whippyunits::unit;
+/// # use whippyunits::quantity;
+/// use bigdecimal::BigDecimal;
+/// use core::str::FromStr;
+/// use whippyunits::decimal::rescale_bigdecimal;
+///
+/// let distance = quantity!(BigDecimal::from_str("1.234").unwrap(), km, BigDecimal);
+/// let meters: unit!(m, BigDecimal) = rescale_bigdecimal(distance).unwrap();
+/// assert_eq!(meters.unsafe_value, BigDecimal::from(1234));
+///
+/// // 90 s is exactly 1.5 min, but 100 s is 1.666… min
+/// let duration = quantity!(BigDecimal::from(90), s, BigDecimal);
+/// let minutes: unit!(min, BigDecimal) = rescale_bigdecimal(duration).unwrap();
+/// assert_eq!(minutes.unsafe_value, BigDecimal::from_str("1.5").unwrap());
+///
+/// let duration = quantity!(BigDecimal::from(100), s, BigDecimal);
+/// let minutes: Result<unit!(min, BigDecimal), _> = rescale_bigdecimal(duration);
+/// assert!(minutes.is_err());
+///
+/// // Converting between radians and degrees involves π
+/// let angle = quantity!(BigDecimal::from(1), rad, BigDecimal);
+/// let degrees: Result<unit!(deg, BigDecimal), _> = rescale_bigdecimal(angle);
+/// assert!(degrees.is_err());
+/// ```
+pub fn rescale_bigdecimal<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2_FROM: i16,
+    const SCALE_P2_TO: i16,
+    const SCALE_P3_FROM: i16,
+    const SCALE_P3_TO: i16,
+    const SCALE_P5_FROM: i16,
+    const SCALE_P5_TO: i16,
+    const SCALE_PI_FROM: i16,
+    const SCALE_PI_TO: i16,
+    Brand,
+>(
+    quantity: QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2_FROM,
+        SCALE_P3_FROM,
+        SCALE_P5_FROM,
+        SCALE_PI_FROM,
+        BigDecimal,
+        Brand,
+    >,
+) -> Result<
+    QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2_TO,
+        SCALE_P3_TO,
+        SCALE_P5_TO,
+        SCALE_PI_TO,
+        BigDecimal,
+        Brand,
+    >,
+    InexactRescale,
+> {
+    let (p2, p3, p5, pi) = (
+        SCALE_P2_FROM - SCALE_P2_TO,
+        SCALE_P3_FROM - SCALE_P3_TO,
+        SCALE_P5_FROM - SCALE_P5_TO,
+        SCALE_PI_FROM - SCALE_PI_TO,
+    );
+    if pi != 0 {
+        return Err(InexactRescale { p3, pi });
+    }
+
+    let scaled = quantity.unsafe_value * exact_scale_factor(p2, p3.max(0) as u32, p5);
+    if p3 >= 0 {
+        return Ok(Quantity::new(scaled.normalized()));
+    }
+
+    // Dividing by 3^n is exact only if the decimal digits are themselves divisible by 3^n
+    let divisor = BigInt::from(3u32).pow(p3.unsigned_abs() as u32);
+    let (digits, decimal_places) = scaled.into_bigint_and_exponent();
+    if (&digits % &divisor) != BigInt::from(0u32) {
+        return Err(InexactRescale { p3, pi });
+    }
+    Ok(Quantity::new(
+        BigDecimal::new(digits / divisor, decimal_places).normalized(),
+    ))
+}
@@ -53,7 +53,7 @@
 //! that "this type can be added to any other type that also represents a length".
 
 use crate::quantity::Quantity;
-use crate::quantity::{_2, _3, _5, _A, _I, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
+use crate::quantity::{_2, _3, _5, _A, _I, _Info, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
 
 /// Expands to a trait and its implementation for a specific atomic dimension.
 /// It follows the same pattern as the default declarators but focuses only on the
@@ -64,7 +64,7 @@ macro_rules! define_atomic_dimension_trait {
     (
         $mass_exp:expr, $length_exp:expr, $time_exp:expr, $current_exp:expr,
         $temperature_exp:expr, $amount_exp:expr, $luminosity_exp:expr, $angle_exp:expr,
-        $trait_name:ident
+        $information_exp:expr, $trait_name:ident
     ) => {
         /// Trait for quantities with the specified atomic dimension
         pub trait $trait_name {
@@ -84,6 +84,7 @@ macro_rules! define_atomic_dimension_trait {
                     _N<$amount_exp>,
                     _J<$luminosity_exp>,
                     _A<$angle_exp>,
+                    _Info<$information_exp>,
                 >,
                 T,
             >
@@ -93,15 +94,16 @@ macro_rules! define_atomic_dimension_trait {
     };
 }
 
-// Define traits for all 8 atomic dimensions (SI base quantities)
-define_atomic_dimension_trait!(1, 0, 0, 0, 0, 0, 0, 0, Mass);
-define_atomic_dimension_trait!(0, 1, 0, 0, 0, 0, 0, 0, Length);
-define_atomic_dimension_trait!(0, 0, 1, 0, 0, 0, 0, 0, Time);
-define_atomic_dimension_trait!(0, 0, 0, 1, 0, 0, 0, 0, Current);
-define_atomic_dimension_trait!(0, 0, 0, 0, 1, 0, 0, 0, Temperature);
-define_atomic_dimension_trait!(0, 0, 0, 0, 0, 1, 0, 0, Amount);
-define_atomic_dimension_trait!(0, 0, 0, 0, 0, 0, 1, 0, Luminosity);
-define_atomic_dimension_trait!(0, 0, 0, 0, 0, 0, 0, 1, Angle);
+// Define traits for all 9 atomic dimensions
+define_atomic_dimension_trait!(1, 0, 0, 0, 0, 0, 0, 0, 0, Mass);
+define_atomic_dimension_trait!(0, 1, 0, 0, 0, 0, 0, 0, 0, Length);
+define_atomic_dimension_trait!(0, 0, 1, 0, 0, 0, 0, 0, 0, Time);
+define_atomic_dimension_trait!(0, 0, 0, 1, 0, 0, 0, 0, 0, Current);
+define_atomic_dimension_trait!(0, 0, 0, 0, 1, 0, 0, 0, 0, Temperature);
+define_atomic_dimension_trait!(0, 0, 0, 0, 0, 1, 0, 0, 0, Amount);
+define_atomic_dimension_trait!(0, 0, 0, 0, 0, 0, 1, 0, 0, Luminosity);
+define_atomic_dimension_trait!(0, 0, 0, 0, 0, 0, 0, 1, 0, Angle);
+define_atomic_dimension_trait!(0, 0, 0, 0, 0, 0, 0, 0, 1, Information);
 
 /// Defines a trait representing a scale-generic dimension (like Length, Area, Energy).
 ///
@@ -118,11 +120,13 @@ define_atomic_dimension_trait!(0, 0, 0, 0, 0, 0, 0, 1, Angle);
 /// - [`Amount`]
 /// - [`Luminosity`]
 /// - [`Angle`]
+/// - [`Information`]
 ///
 /// ## Syntax
 ///
 /// ```rust,ignore
-/// define_generic_dimension!(TraitName, DimensionExpression);
+/// define_generic_dimension!(TraitName, DimensionExpression, ...);
+/// define_generic_dimension!(TraitName, !AtomicDimension, ...);
 /// ```
 ///
 /// Where:
@@ -130,8 +134,9 @@ define_atomic_dimension_trait!(0, 0, 0, 0, 0, 0, 0, 1, Angle);
 /// - `DimensionExpression`: A "dimension literal expression"
 ///     - A "dimension literal expression" is either:
 ///         - An atomic dimension:
-///             - `Length`, `Time`, `Mass`, `Current`, `Temperature`, `Amount`, `Luminosity`, `Angle`
-///             - Also accepts single-character symbols: `L`, `T`, `M`, `I`, `Θ`, `N`, `J`, `A`
+///             - `Length`, `Time`, `Mass`, `Current`, `Temperature`, `Amount`, `Luminosity`, `Angle`,
+///               `Information`
+///             - Also accepts the symbols: `L`, `T`, `M`, `I`, `Θ`, `N`, `J`, `A`, `Info`
 ///         - An exponentiation of an atomic dimension:
 ///             - `L^2`, `T^-1`
 ///         - A multiplication of two or more (possibly exponentiated) atomic dimensions:
@@ -141,6 +146,35 @@ define_atomic_dimension_trait!(0, 0, 0, 0, 0, 0, 0, 1, Angle);
 ///             - There may be at most one division expression in a dimension literal expression
 ///             - All terms trailing the division symbol are considered to be in the denominator
 ///
+/// Listing several dimension expressions matches any one of them.  Alternatively, prefixing
+/// atomic dimensions with `!` excludes them: the trait matches every quantity whose exponent in
+/// each excluded dimension is zero, whatever its other exponents.  Exclusions cannot be mixed
+/// with included dimensions.
+///
+/// ## Generated implementations
+///
+/// Each dimension expression generates one implementation of the trait, generic over the scale
+/// and storage type, for the quantity type with exactly those dimension exponents.  For
+/// `define_generic_dimension!(Velocity, L/T)`, that is
+///
+/// ```rust,ignore
+/// impl<const SCALE_P2: i16, /* SCALE_P3, SCALE_P5, SCALE_PI */ T> Velocity
+///     for Quantity<Scale<_2<SCALE_P2>, /* ... */>, Dimension<_M<0>, _L<1>, _T<-1>, /* ... */>, T>
+/// ```
+///
+/// Exclusions generate a single implementation that is also generic over every dimension
+/// exponent not excluded, with the excluded exponents fixed at zero.  For
+/// `define_generic_dimension!(NonMass, !Mass)`, that is
+///
+/// ```rust,ignore
+/// impl<const LENGTH_EXPONENT: i16, /* TIME_EXPONENT, ..., ANGLE_EXPONENT, SCALE_P2, ... */ T>
+///     NonMass
+///     for Quantity<Scale</* ... */>, Dimension<_M<0>, _L<LENGTH_EXPONENT>, /* ... */>, T>
+/// ```
+///
+/// Neither has a `where` clause, so the trait can be used as a plain bound without restating
+/// any constraints on the exponents.
+///
 /// ## Examples
 ///
 /// ```rust
@@ -156,6 +190,14 @@ define_atomic_dimension_trait!(0, 0, 0, 0, 0, 0, 0, 1, Angle);
 ///
 /// define_generic_dimension!(Velocity, L/T, A/T);
 ///
+/// define_generic_dimension!(NonMass, !Mass);
+/// fn assert_non_mass<Q: NonMass>(value: Q) -> Q {
+///     value
+/// }
+/// assert_non_mass(1.0m);
+/// assert_non_mass(2.0m / 1.0s);
+/// // assert_non_mass(1.0kg); // 🚫 Compile error (mass exponent is not zero)
+///
 /// // Now you can write generic functions
 /// fn calculate_area<D1: Length, D2: Length>(d1: D1, d2: D2) -> <D1 as Mul<D2>>::Output
 /// where
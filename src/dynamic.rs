@@ -0,0 +1,403 @@
+//! An object-safe interface to [Quantity] values, for plugin systems and other code that cannot
+//! be generic over quantity types.
+//!
+//! Every [Quantity] implements [QuantityLike], so quantities of different units can be handled
+//! through `&dyn QuantityLike` or `Box<dyn QuantityLike>`.  With the unit no longer in the type,
+//! dimensions are checked at runtime instead: arithmetic through the trait returns a
+//! [DynQuantity], which carries its dimension and scale exponents alongside its value.
+//!
+//! ```rust
+//! use whippyunits::dynamic::QuantityLike;
+//! use whippyunits::quantity;
+//!
+//! let distance: Box<dyn QuantityLike> = Box::new(quantity!(1.5, km));
+//! let more: &dyn QuantityLike = &quantity!(500.0, m);
+//!
+//! let total = distance.add_dyn(more).unwrap();
+//! assert_eq!(total.value_in_base(), 2000.0);
+//! assert_eq!(total.unit_string(), "m");
+//!
+//! // Quantities of different dimensions can be multiplied, but not added
+//! let force: &dyn QuantityLike = &quantity!(3.0, N);
+//! assert_eq!(distance.mul_dyn(force).unit_string(), "J");
+//! assert!(distance.add_dyn(force).is_err());
+//! ```
+//!
+//! A [DynQuantity] can also be built directly, for instance from a unit read at runtime with
+//! [parse_ucum_unit](crate::serialization::parse_ucum_unit), and converted into a concrete
+//! [Quantity] once its dimension is known to match:
+//!
+//! ```rust
+//! use whippyunits::dynamic::DynQuantity;
+//! use whippyunits::serialization::parse_ucum_unit;
+//! use whippyunits::unit;
+//!
+//! let (dimensions, scale) = parse_ucum_unit("km/s").unwrap();
+//! let speed = DynQuantity::new(1.5, dimensions, scale);
+//!
+//! let speed: unit!(m / s) = speed.try_into().unwrap();
+//! assert_eq!(speed.unsafe_value, 1500.0);
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::alloc::{Box, String};
+use crate::api::aggregate_scale_factor_float;
+use crate::print::unit_literal_generator::{
+    UnitLiteralConfig, coherent_unit_literal, generate_unit_literal,
+};
+use crate::quantity::Quantity;
+use crate::quantity::{_2, _3, _5, _A, _I, _Info, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
+use whippyunits_core::{
+    dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
+};
+
+/// Object-safe view of a quantity, with its unit described at runtime.
+///
+/// Implemented for every [Quantity] whose storage type converts to `f64`, and for
+/// [DynQuantity].  The arithmetic methods have default implementations in terms of
+/// [value_in_base](Self::value_in_base) and [dims](Self::dims), and return a [DynQuantity].
+pub trait QuantityLike {
+    /// The value in the coherent SI unit of the quantity's dimension (kilograms for mass).
+    fn value_in_base(&self) -> f64;
+
+    /// Dimension exponents, in the order M, L, T, I, Θ, N, J, A, Info.
+    fn dims(&self) -> [i16; 9];
+
+    /// A unit literal for the unit the quantity is stored in, such as `km` or `m/s`.
+    fn unit_string(&self) -> String;
+
+    /// The sum of this quantity and `other`, in coherent SI units.
+    ///
+    /// Fails if the two quantities have different dimensions.
+    fn add_dyn(
+        &self,
+        other: &dyn QuantityLike,
+    ) -> Result<Box<dyn QuantityLike>, DimensionMismatch> {
+        let (left, right) = (self.dims(), other.dims());
+        if left != right {
+            return Err(DimensionMismatch { left, right });
+        }
+        Ok(Box::new(DynQuantity::new(
+            self.value_in_base() + other.value_in_base(),
+            DynDimensionExponents(left),
+            ScaleExponents::IDENTITY,
+        )))
+    }
+
+    /// The product of this quantity and `other`, in coherent SI units.
+    ///
+    /// The dimension exponents of the result are the sums of those of the operands.
+    fn mul_dyn(&self, other: &dyn QuantityLike) -> Box<dyn QuantityLike> {
+        let (left, right) = (self.dims(), other.dims());
+        Box::new(DynQuantity::new(
+            self.value_in_base() * other.value_in_base(),
+            DynDimensionExponents(core::array::from_fn(|i| left[i] + right[i])),
+            ScaleExponents::IDENTITY,
+        ))
+    }
+}
+
+/// A quantity whose dimension is known only at runtime.
+///
+/// The value is stored in the unit given by `scale`, as for [Quantity].  Results of arithmetic
+/// through [QuantityLike] are returned as this type, in coherent SI units.
+///
+/// Quantities combine with the usual operators: `*` and `/` always succeed, while `+` and `-`
+/// check dimensions at runtime and return a `Result`.  The right-hand operand of `+` and `-` is
+/// converted to the scale of the left-hand one.
+///
+/// ```rust
+/// use whippyunits::dynamic::DynQuantity;
+/// use whippyunits_core::dimension_exponents::DynDimensionExponents;
+/// use whippyunits_core::scale_exponents::ScaleExponents;
+///
+/// let km = DynQuantity::new(1.5, DynDimensionExponents::LENGTH, ScaleExponents::_10(3));
+/// let m = DynQuantity::new(500.0, DynDimensionExponents::LENGTH, ScaleExponents::IDENTITY);
+/// assert_eq!((km + m).unwrap().value, 2.0);
+///
+/// let s = DynQuantity::new(2.0, DynDimensionExponents::TIME, ScaleExponents::IDENTITY);
+/// assert!((km - s).is_err());
+/// assert_eq!((m / s).dimensions, DynDimensionExponents([0, 1, -1, 0, 0, 0, 0, 0, 0]));
+/// ```
+///
+/// Comparisons also rescale the right-hand operand, so `1.5 km` equals `1500 m`.  Quantities of
+/// different dimensions are neither equal nor ordered: [partial_cmp](PartialOrd::partial_cmp)
+/// returns `None` for them.
+#[derive(Debug, Clone, Copy)]
+pub struct DynQuantity {
+    /// The value, in the unit given by `scale`
+    pub value: f64,
+    /// Dimension exponents, in the order M, L, T, I, Θ, N, J, A, Info
+    pub dimensions: DynDimensionExponents,
+    /// Scale of the unit the value is stored in, relative to the coherent SI unit
+    pub scale: ScaleExponents,
+}
+
+impl DynQuantity {
+    /// Create a quantity with the given value, dimension exponents, and scale.
+    pub const fn new(value: f64, dimensions: DynDimensionExponents, scale: ScaleExponents) -> Self {
+        Self {
+            value,
+            dimensions,
+            scale,
+        }
+    }
+
+    /// The value converted to the unit given by `scale`.
+    fn value_in_scale(&self, scale: ScaleExponents) -> f64 {
+        let ([f2, f3, f5, fpi], [t2, t3, t5, tpi]) = (self.scale.0, scale.0);
+        self.value * aggregate_scale_factor_float(f2, f3, f5, fpi, t2, t3, t5, tpi)
+    }
+
+    /// `other` in the scale of this quantity, if the two have the same dimension.
+    fn matching(&self, other: &Self) -> Result<f64, DimensionMismatch> {
+        if self.dimensions != other.dimensions {
+            return Err(DimensionMismatch {
+                left: self.dimensions.0,
+                right: other.dimensions.0,
+            });
+        }
+        Ok(other.value_in_scale(self.scale))
+    }
+}
+
+impl PartialEq for DynQuantity {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Quantities of the same dimension compare by their values in base units, so that both sides go
+/// through the same conversion and the comparison is symmetric whatever their scales.
+impl PartialOrd for DynQuantity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.dimensions != other.dimensions {
+            return None;
+        }
+        self.value_in_base().partial_cmp(&other.value_in_base())
+    }
+}
+
+impl Add for DynQuantity {
+    type Output = Result<DynQuantity, DimensionMismatch>;
+
+    fn add(self, other: Self) -> Self::Output {
+        let other = self.matching(&other)?;
+        Ok(Self::new(self.value + other, self.dimensions, self.scale))
+    }
+}
+
+impl Sub for DynQuantity {
+    type Output = Result<DynQuantity, DimensionMismatch>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let other = self.matching(&other)?;
+        Ok(Self::new(self.value - other, self.dimensions, self.scale))
+    }
+}
+
+impl Mul for DynQuantity {
+    type Output = DynQuantity;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self::new(
+            self.value * other.value,
+            self.dimensions + other.dimensions,
+            self.scale.mul(other.scale),
+        )
+    }
+}
+
+impl Div for DynQuantity {
+    type Output = DynQuantity;
+
+    fn div(self, other: Self) -> Self::Output {
+        Self::new(
+            self.value / other.value,
+            self.dimensions + -other.dimensions,
+            self.scale.mul(other.scale.neg()),
+        )
+    }
+}
+
+impl QuantityLike for DynQuantity {
+    fn value_in_base(&self) -> f64 {
+        self.value_in_scale(ScaleExponents::IDENTITY)
+    }
+
+    fn dims(&self) -> [i16; 9] {
+        self.dimensions.0
+    }
+
+    fn unit_string(&self) -> String {
+        if self.scale == ScaleExponents::IDENTITY {
+            coherent_unit_literal(self.dimensions)
+        } else {
+            generate_unit_literal(
+                self.dimensions,
+                self.scale,
+                UnitLiteralConfig {
+                    verbose: false,
+                    prefer_si_units: true,
+                },
+            )
+        }
+    }
+}
+
+/// Error returned when quantities of different dimensions are added or subtracted at runtime,
+/// or when a [DynQuantity] is converted into a [Quantity] of a different dimension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionMismatch {
+    /// Dimension exponents of the left-hand quantity (or of the [DynQuantity] being converted),
+    /// in the order M, L, T, I, Θ, N, J, A, Info
+    pub left: [i16; 9],
+    /// Dimension exponents of the right-hand quantity (or of the target [Quantity])
+    pub right: [i16; 9],
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mismatched dimensions {:?} and {:?} (order: M, L, T, I, Θ, N, J, A, Info)",
+            self.left, self.right
+        )
+    }
+}
+
+impl core::error::Error for DimensionMismatch {}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+> QuantityLike
+    for Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+where
+    T: Copy + num_traits::NumCast,
+{
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted to `f64`.
+    fn value_in_base(&self) -> f64 {
+        <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64")
+            * aggregate_scale_factor_float(SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0)
+    }
+
+    fn dims(&self) -> [i16; 9] {
+        [
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+        ]
+    }
+
+    fn unit_string(&self) -> String {
+        generate_unit_literal(
+            DynDimensionExponents(self.dims()),
+            ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
+            UnitLiteralConfig {
+                verbose: false,
+                prefer_si_units: true,
+            },
+        )
+    }
+}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+> TryFrom<DynQuantity>
+    for Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        f64,
+    >
+{
+    type Error = DimensionMismatch;
+
+    /// Convert to this quantity type, rescaling the value to its unit.
+    ///
+    /// Fails if the dimension of `quantity` differs from that of this type.
+    fn try_from(quantity: DynQuantity) -> Result<Self, Self::Error> {
+        let target = [
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+        ];
+        if quantity.dimensions.0 != target {
+            return Err(DimensionMismatch {
+                left: quantity.dimensions.0,
+                right: target,
+            });
+        }
+        Ok(Self::new(quantity.value_in_scale(ScaleExponents([
+            SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI,
+        ]))))
+    }
+}
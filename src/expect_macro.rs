@@ -0,0 +1,558 @@
+use crate::quantity::{_A, _I, _Info, _J, _L, _M, _N, _T, _Θ, Dimension};
+use crate::quantity::{Quantity, QuantityOf};
+
+/// Assert at compile time that a [Quantity](crate::Quantity) has exactly the given unit.
+///
+/// Both the dimension *and* the scale must match; `expect_unit!(distance, m)` will not accept a
+/// quantity stored in millimeters.  Use [expect_dimension!](crate::expect_dimension!) to check
+/// only the dimension.  The storage type and brand are not checked.
+///
+/// The check happens entirely at compile time, and a mismatch produces an ordinary type error
+/// at the assertion whose note shows the expected and actual quantity types.  The macro
+/// evaluates to `()` and does not consume the quantity.
+///
+/// ## Syntax
+///
+/// ```rust,ignore
+/// expect_unit!(quantity, unit_expression)
+/// ```
+///
+/// where `unit_expression` is a unit literal expression as accepted by [unit!](crate::unit!).
+///
+/// ## Examples
+///
+/// ```rust
+/// # fn main() {
+/// use whippyunits::{expect_unit, quantity};
+///
+/// let velocity = quantity!(10.0, m) / quantity!(2.0, s);
+/// expect_unit!(velocity, m / s); // ✅
+/// // expect_unit!(velocity, km / s); // ❌ Compile error (scale mismatch)
+/// // expect_unit!(velocity, m / s^2); // ❌ Compile error (dimension mismatch)
+/// # }
+/// ```
+#[macro_export]
+macro_rules! expect_unit {
+    ($quantity:expr, $unit:expr) => {{
+        const UNIT_INFO: (
+            whippyunits_core::dimension_exponents::DynDimensionExponents,
+            whippyunits_core::scale_exponents::ScaleExponents,
+        ) = whippyunits_proc_macros::compute_unit_dimensions!($unit);
+        const DIMENSIONS: whippyunits_core::dimension_exponents::DynDimensionExponents =
+            UNIT_INFO.0;
+        const SCALES: whippyunits_core::scale_exponents::ScaleExponents = UNIT_INFO.1;
+        $crate::expect_macro::ExpectUnit::<
+            { DIMENSIONS.0[0] },
+            { DIMENSIONS.0[1] },
+            { DIMENSIONS.0[2] },
+            { DIMENSIONS.0[3] },
+            { DIMENSIONS.0[4] },
+            { DIMENSIONS.0[5] },
+            { DIMENSIONS.0[6] },
+            { DIMENSIONS.0[7] },
+            { DIMENSIONS.0[8] },
+            { SCALES.0[0] },
+            { SCALES.0[1] },
+            { SCALES.0[2] },
+            { SCALES.0[3] },
+        >::check(&$quantity)
+    }};
+}
+
+/// Assert at compile time that a [Quantity](crate::Quantity) has the dimension of the given unit.
+///
+/// Unlike [expect_unit!](crate::expect_unit!), the scale is ignored, so any unit of the same
+/// dimension is accepted: `expect_dimension!(distance, m)` accepts meters, millimeters, and feet
+/// alike.
+///
+/// ## Examples
+///
+/// ```rust
+/// # fn main() {
+/// use whippyunits::{expect_dimension, quantity};
+///
+/// let distance = quantity!(3.0, mm);
+/// expect_dimension!(distance, m); // ✅
+/// expect_dimension!(distance, km); // ✅
+/// // expect_dimension!(distance, s); // ❌ Compile error (dimension mismatch)
+/// # }
+/// ```
+#[macro_export]
+macro_rules! expect_dimension {
+    ($quantity:expr, $unit:expr) => {{
+        const UNIT_INFO: (
+            whippyunits_core::dimension_exponents::DynDimensionExponents,
+            whippyunits_core::scale_exponents::ScaleExponents,
+        ) = whippyunits_proc_macros::compute_unit_dimensions!($unit);
+        const DIMENSIONS: whippyunits_core::dimension_exponents::DynDimensionExponents =
+            UNIT_INFO.0;
+        $crate::expect_macro::ExpectDimension::<
+            { DIMENSIONS.0[0] },
+            { DIMENSIONS.0[1] },
+            { DIMENSIONS.0[2] },
+            { DIMENSIONS.0[3] },
+            { DIMENSIONS.0[4] },
+            { DIMENSIONS.0[5] },
+            { DIMENSIONS.0[6] },
+            { DIMENSIONS.0[7] },
+            { DIMENSIONS.0[8] },
+        >::check(&$quantity)
+    }};
+}
+
+/// Assert at compile time that an equation between quantities is dimensionally homogeneous.
+///
+/// Both sides of the equation are evaluated with the ordinary quantity arithmetic, and must come
+/// out with the same dimension; their scales may differ, so `F = m * a` holds for a force in
+/// kilonewtons just as well as in newtons.  A mismatch is a compile error at the equation.
+///
+/// The variables are either bindings already in scope, or declared with their units after a
+/// `;`.  The declared form can be used anywhere an item can, including at module level, and
+/// evaluates nothing.  The in-scope form evaluates nothing either, but the equation is
+/// type-checked inside a closure, so its variables must be `Copy` (as all quantities with
+/// primitive storage types are).
+///
+/// The right-hand side may have several terms, but the terms are added with the ordinary
+/// scale-strict addition, so they must all have the same scale as well as the same dimension.
+/// Numeric constants such as the `0.5` in `0.5 * m * v * v` are dimensionless, and a side that
+/// is a bare number is treated as dimensionless.
+///
+/// ## Syntax
+///
+/// ```rust,ignore
+/// check_equation!(lhs = rhs)                                 // variables in scope
+/// check_equation!(lhs = rhs; var: unit_expression, ...)      // declared variables
+/// ```
+///
+/// where each `unit_expression` is a unit literal expression as accepted by [unit!](crate::unit!).
+///
+/// ## Examples
+///
+/// ```rust
+/// use whippyunits::check_equation;
+///
+/// check_equation!(F = m * a; F: N, m: kg, a: m / s ^ 2); // ✅
+/// check_equation!(F = m * a; F: kN, m: kg, a: m / s ^ 2); // ✅ (scales may differ)
+/// check_equation!(E = 0.5 * m * v * v + m * g * h; E: J, m: kg, v: m / s, g: m / s ^ 2, h: m); // ✅
+/// check_equation!(Re = rho * v * L / mu; Re: 1, rho: kg / m ^ 3, v: m / s, L: m, mu: Pa * s); // ✅
+/// // check_equation!(E = m * v; E: J, m: kg, v: m / s); // ❌ Compile error (dimension mismatch)
+///
+/// # fn main() {
+/// use whippyunits::quantity;
+///
+/// let distance = quantity!(100.0, m);
+/// let speed = quantity!(5.0, m / s);
+/// let time = quantity!(20.0, s);
+/// check_equation!(distance = speed * time); // ✅
+/// # }
+/// ```
+#[macro_export]
+macro_rules! check_equation {
+    // Split the equation at `=`
+    (@lhs [$($lhs:tt)*] = $($rest:tt)*) => {
+        $crate::check_equation! { @rhs [$($lhs)*] [] $($rest)* }
+    };
+    (@lhs [$($lhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::check_equation! { @lhs [$($lhs)* $next] $($rest)* }
+    };
+    // Declared variables: check the equation in a function taking each variable as a parameter
+    (@rhs [$($lhs:tt)*] [$($rhs:tt)*] ; $($var:ident : $unit:expr),+ $(,)?) => {
+        const _: () = {
+            #[allow(non_snake_case, dead_code)]
+            fn check_equation($($var: $crate::unit!($unit)),+) {
+                $crate::expect_macro::check_equation(&($($lhs)*), &($($rhs)*));
+            }
+        };
+    };
+    // Variables in scope: check the equation in a closure that is never called
+    (@rhs [$($lhs:tt)*] [$($rhs:tt)*]) => {
+        let _ = || $crate::expect_macro::check_equation(&($($lhs)*), &($($rhs)*));
+    };
+    (@rhs [$($lhs:tt)*] [$($rhs:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::check_equation! { @rhs [$($lhs)*] [$($rhs)* $next] $($rest)* }
+    };
+    ($($equation:tt)+) => {
+        $crate::check_equation! { @lhs [] $($equation)+ }
+    };
+}
+
+/// Assert that two [Quantities](crate::Quantity) of the same dimension are approximately equal.
+///
+/// The right-hand quantity is converted into the left-hand quantity's scale before comparing, so
+/// quantities of different scales may be compared directly.  The tolerance is absolute and in the
+/// left-hand quantity's unit, defaulting to `1e-5`.  On failure, both quantities are printed with
+/// their units.
+///
+/// Comparing quantities of different dimensions is a compile error.
+///
+/// ## Syntax
+///
+/// ```rust,ignore
+/// assert_quantity_approx_eq!(left, right)           // default epsilon (1e-5)
+/// assert_quantity_approx_eq!(left, right, epsilon)  // custom epsilon, in left's unit
+/// ```
+///
+/// ## Examples
+///
+/// ```rust
+/// # fn main() {
+/// use whippyunits::{assert_quantity_approx_eq, quantity};
+///
+/// assert_quantity_approx_eq!(quantity!(1.5, m), quantity!(150.0, cm));
+/// assert_quantity_approx_eq!(quantity!(1.0, km), quantity!(1001.0, m), 0.01);
+/// // assert_quantity_approx_eq!(quantity!(1.0, m), quantity!(1.0, s)); // ❌ Compile error (dimension mismatch)
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! assert_quantity_approx_eq {
+    ($left:expr, $right:expr) => {
+        $crate::assert_quantity_approx_eq!($left, $right, 1e-5)
+    };
+    ($left:expr, $right:expr, $epsilon:expr) => {
+        $crate::expect_macro::assert_quantity_approx_eq(&$left, &$right, $epsilon as f64)
+    };
+}
+
+/// Implementation of [assert_quantity_approx_eq!](crate::assert_quantity_approx_eq!).
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_quantity_approx_eq<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2_LEFT: i16,
+    const SCALE_P3_LEFT: i16,
+    const SCALE_P5_LEFT: i16,
+    const SCALE_PI_LEFT: i16,
+    const SCALE_P2_RIGHT: i16,
+    const SCALE_P3_RIGHT: i16,
+    const SCALE_P5_RIGHT: i16,
+    const SCALE_PI_RIGHT: i16,
+    T,
+    Brand,
+>(
+    left: &QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2_LEFT,
+        SCALE_P3_LEFT,
+        SCALE_P5_LEFT,
+        SCALE_PI_LEFT,
+        T,
+        Brand,
+    >,
+    right: &QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2_RIGHT,
+        SCALE_P3_RIGHT,
+        SCALE_P5_RIGHT,
+        SCALE_PI_RIGHT,
+        T,
+        Brand,
+    >,
+    epsilon: f64,
+) where
+    T: Copy + num_traits::NumCast,
+    QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2_LEFT,
+        SCALE_P3_LEFT,
+        SCALE_P5_LEFT,
+        SCALE_PI_LEFT,
+        T,
+        Brand,
+    >: core::fmt::Display,
+    QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2_LEFT,
+        SCALE_P3_LEFT,
+        SCALE_P5_LEFT,
+        SCALE_PI_LEFT,
+        f64,
+        Brand,
+    >: core::fmt::Display,
+    QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2_RIGHT,
+        SCALE_P3_RIGHT,
+        SCALE_P5_RIGHT,
+        SCALE_PI_RIGHT,
+        T,
+        Brand,
+    >: core::fmt::Display,
+{
+    let to_f64 = |value: T| {
+        <f64 as num_traits::NumCast>::from(value)
+            .expect("unable to convert numeric value to f64 for comparison")
+    };
+    let rescale_factor = crate::api::aggregate_scale_factor_float(
+        SCALE_P2_RIGHT,
+        SCALE_P3_RIGHT,
+        SCALE_P5_RIGHT,
+        SCALE_PI_RIGHT,
+        SCALE_P2_LEFT,
+        SCALE_P3_LEFT,
+        SCALE_P5_LEFT,
+        SCALE_PI_LEFT,
+    );
+    let left_value = to_f64(left.unsafe_value);
+    let right_value = to_f64(right.unsafe_value) * rescale_factor;
+    let diff = (left_value - right_value).abs();
+
+    if diff > epsilon || diff.is_nan() {
+        // Show the right-hand side in the left-hand unit as well, since that is what was compared
+        let right_rescaled = QuantityOf::<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2_LEFT,
+            SCALE_P3_LEFT,
+            SCALE_P5_LEFT,
+            SCALE_PI_LEFT,
+            f64,
+            Brand,
+        >::new(right_value);
+        panic!(
+            "assertion failed: `(left ≈ right)`\n  left:  {}\n right: {} (= {})\n  diff:  {:?} > epsilon: {:?}",
+            left, right, right_rescaled, diff, epsilon
+        );
+    }
+}
+
+/// Type-level target of [expect_unit!](crate::expect_unit!).
+///
+/// The expected exponents live on the type so that the storage type and brand of the checked
+/// quantity can still be inferred by `check`.
+#[doc(hidden)]
+pub struct ExpectUnit<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+>;
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+>
+    ExpectUnit<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    >
+{
+    pub const fn check<T, Brand>(
+        _quantity: &QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            T,
+            Brand,
+        >,
+    ) {
+    }
+}
+
+/// Type-level target of [expect_dimension!](crate::expect_dimension!).
+#[doc(hidden)]
+pub struct ExpectDimension<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+>;
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+>
+    ExpectDimension<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+    >
+{
+    pub const fn check<
+        const SCALE_P2: i16,
+        const SCALE_P3: i16,
+        const SCALE_P5: i16,
+        const SCALE_PI: i16,
+        T,
+        Brand,
+    >(
+        _quantity: &QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            T,
+            Brand,
+        >,
+    ) {
+    }
+}
+
+/// Marker for the right-hand side of a dimensionally homogeneous equation with left-hand side
+/// `Lhs`, checked by [check_equation!](crate::check_equation!).
+///
+/// Implemented when both sides have the same dimension, whatever their scales, storage types,
+/// or brands.  Bare numbers count as dimensionless.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "equation is not dimensionally homogeneous: `{Lhs}` cannot equal `{Self}`",
+    label = "right-hand side has a different dimension from the left-hand side",
+    note = "both sides of an equation must have the same dimension; only their scales may differ"
+)]
+pub trait DimensionallyHomogeneous<Lhs> {}
+
+impl<LhsScale, RhsScale, D, LhsT, RhsT, LhsBrand, RhsBrand>
+    DimensionallyHomogeneous<Quantity<LhsScale, D, LhsT, LhsBrand>>
+    for Quantity<RhsScale, D, RhsT, RhsBrand>
+{
+}
+
+/// Dimensionless quantity type, for comparing against bare numbers.
+type Dimensionless<S, T, Brand> = Quantity<
+    S,
+    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+    T,
+    Brand,
+>;
+
+macro_rules! scalar_dimensionally_homogeneous {
+    ($($T:ty),*) => {
+        $(
+            impl DimensionallyHomogeneous<$T> for $T {}
+            impl<S, Brand> DimensionallyHomogeneous<$T> for Dimensionless<S, $T, Brand> {}
+            impl<S, Brand> DimensionallyHomogeneous<Dimensionless<S, $T, Brand>> for $T {}
+        )*
+    };
+}
+
+scalar_dimensionally_homogeneous!(
+    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Implementation of [check_equation!](crate::check_equation!).
+#[doc(hidden)]
+pub const fn check_equation<Lhs, Rhs: DimensionallyHomogeneous<Lhs>>(_lhs: &Lhs, _rhs: &Rhs) {}
@@ -0,0 +1,202 @@
+//! A C-compatible representation of [Quantity] for passing quantities across an FFI boundary.
+//!
+//! A quantity's unit lives entirely in its type, which C cannot see.  [FfiQuantity] carries the
+//! unit at runtime instead, as the dimension and scale exponents alongside the value, and
+//! [Quantity::from_ffi] checks those exponents against the quantity type on the way back in.
+//!
+//! ## Layout
+//!
+//! [FfiQuantity] is `#[repr(C)]`, 40 bytes with 8-byte alignment, and matches [C_HEADER]:
+//!
+//! ```c
+//! typedef struct FfiQuantity {
+//!     double value;      /* offset 0 */
+//!     int16_t dims[9];   /* offset 8:  M, L, T, I, Θ, N, J, A, Info */
+//!     int16_t scale[4];  /* offset 26: 2, 3, 5, π */
+//! } FfiQuantity;
+//! ```
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use whippyunits::ffi::FfiQuantity;
+//! use whippyunits::{quantity, unit};
+//!
+//! let ffi: FfiQuantity = quantity!(9.81, m / s2).to_ffi();
+//! assert_eq!(ffi.dims, [0, 1, -2, 0, 0, 0, 0, 0, 0]);
+//!
+//! let acceleration = <unit!(m / s2)>::from_ffi(ffi).unwrap();
+//! assert_eq!(acceleration, quantity!(9.81, m / s2));
+//!
+//! // Quantities passed back with a different unit are rejected
+//! assert!(<unit!(mm / s2)>::from_ffi(ffi).is_err());
+//! ```
+
+use core::fmt;
+use core::mem::{align_of, offset_of, size_of};
+
+use crate::quantity::Quantity;
+use crate::quantity::{_2, _3, _5, _A, _I, _Info, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
+
+/// C declaration of [FfiQuantity], for inclusion in a generated header.
+pub const C_HEADER: &str = "\
+#include <stdint.h>
+
+/* A whippyunits quantity: value in the unit 2^scale[0] * 3^scale[1] * 5^scale[2] * pi^scale[3]
+ * times the coherent SI unit of dimension M^dims[0] L^dims[1] T^dims[2] I^dims[3]
+ * Θ^dims[4] N^dims[5] J^dims[6] A^dims[7] Info^dims[8]. */
+typedef struct FfiQuantity {
+    double value;
+    int16_t dims[9];
+    int16_t scale[4];
+} FfiQuantity;
+";
+
+/// A quantity with its unit exponents stored at runtime, laid out for the C ABI.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FfiQuantity {
+    /// The stored value, in the unit described by `dims` and `scale`
+    pub value: f64,
+    /// Dimension exponents, in the order M, L, T, I, Θ, N, J, A, Info
+    pub dims: [i16; 9],
+    /// Scale exponents, in the order 2, 3, 5, π
+    pub scale: [i16; 4],
+}
+
+// The layout is part of the C ABI documented in `C_HEADER`; it must never change silently.
+const _: () = {
+    assert!(size_of::<FfiQuantity>() == 40);
+    assert!(align_of::<FfiQuantity>() == 8);
+    assert!(offset_of!(FfiQuantity, value) == 0);
+    assert!(offset_of!(FfiQuantity, dims) == 8);
+    assert!(offset_of!(FfiQuantity, scale) == 26);
+};
+
+/// Error returned by [Quantity::from_ffi].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FromFfiError {
+    /// The exponents do not match the quantity type, in the order M, L, T, I, Θ, N, J, A,
+    /// Info, 2, 3, 5, π
+    ExponentMismatch {
+        /// Exponents of the quantity type being converted to
+        expected: [i16; 13],
+        /// Exponents found in the [FfiQuantity]
+        found: [i16; 13],
+    },
+    /// The value cannot be represented in the quantity's storage type
+    ValueOutOfRange(f64),
+}
+
+impl fmt::Display for FromFfiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromFfiError::ExponentMismatch { expected, found } => write!(
+                f,
+                "FFI quantity exponents {:?} do not match expected {:?} \
+                 (order: M, L, T, I, Θ, N, J, A, Info, 2, 3, 5, π)",
+                found, expected
+            ),
+            FromFfiError::ValueOutOfRange(value) => {
+                write!(
+                    f,
+                    "FFI quantity value {} does not fit the storage type",
+                    value
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for FromFfiError {}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>
+    Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+{
+    const FFI_DIMS: [i16; 9] = [
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+    ];
+    const FFI_SCALE: [i16; 4] = [SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI];
+
+    /// Convert this quantity to its C-compatible [FfiQuantity] representation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted to `f64`.
+    pub fn to_ffi(&self) -> FfiQuantity
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        FfiQuantity {
+            value: <f64 as num_traits::NumCast>::from(self.unsafe_value)
+                .expect("unable to convert numeric value to f64 for FFI"),
+            dims: Self::FFI_DIMS,
+            scale: Self::FFI_SCALE,
+        }
+    }
+
+    /// Convert an [FfiQuantity] back to this quantity type.
+    ///
+    /// The dimension and scale exponents must match this type exactly; no rescaling is done.
+    /// For integer storage types, a fractional value is truncated toward zero.
+    pub fn from_ffi(ffi: FfiQuantity) -> Result<Self, FromFfiError>
+    where
+        T: num_traits::NumCast,
+    {
+        if ffi.dims != Self::FFI_DIMS || ffi.scale != Self::FFI_SCALE {
+            let exponents = |dims: [i16; 9], scale: [i16; 4]| {
+                let mut exponents = [0; 13];
+                exponents[..9].copy_from_slice(&dims);
+                exponents[9..].copy_from_slice(&scale);
+                exponents
+            };
+            return Err(FromFfiError::ExponentMismatch {
+                expected: exponents(Self::FFI_DIMS, Self::FFI_SCALE),
+                found: exponents(ffi.dims, ffi.scale),
+            });
+        }
+
+        <T as num_traits::NumCast>::from(ffi.value)
+            .map(Self::new)
+            .ok_or(FromFfiError::ValueOutOfRange(ffi.value))
+    }
+}
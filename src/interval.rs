@@ -0,0 +1,93 @@
+//! Closed intervals of [Quantity] values, for bounds tracking.
+//!
+//! An [Interval] holds two endpoints of the same quantity type, always ordered so that
+//! `lo <= hi`; endpoints given the wrong way round are swapped rather than producing an empty
+//! or negative-width interval:
+//!
+//! ```rust
+//! use whippyunits::quantity;
+//!
+//! let operating = quantity!(10.0, m).interval_to(quantity!(-5.0, m));
+//! assert_eq!(operating.lo(), quantity!(-5.0, m));
+//! assert_eq!(operating.width(), quantity!(15.0, m));
+//!
+//! assert!(operating.contains(&quantity!(0.0, m)));
+//! assert_eq!(operating.clamp(quantity!(12.0, m)), quantity!(10.0, m));
+//! ```
+
+use core::ops::Sub;
+
+use crate::quantity::Quantity;
+
+/// A closed interval `[lo, hi]` of quantities of type `Q`.
+///
+/// The endpoints are ordered on construction, so `lo <= hi` always holds (unless an endpoint is
+/// NaN, in which case nothing is contained in the interval).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<Q> {
+    lo: Q,
+    hi: Q,
+}
+
+impl<Q: PartialOrd> Interval<Q> {
+    /// Create the interval between two endpoints, in either order.
+    pub fn new(a: Q, b: Q) -> Self {
+        if a > b {
+            Self { lo: b, hi: a }
+        } else {
+            Self { lo: a, hi: b }
+        }
+    }
+
+    /// Whether `quantity` lies within the interval, endpoints included.
+    pub fn contains(&self, quantity: &Q) -> bool {
+        &self.lo <= quantity && quantity <= &self.hi
+    }
+}
+
+impl<Q: PartialOrd + Copy> Interval<Q> {
+    /// The lower endpoint.
+    pub fn lo(&self) -> Q {
+        self.lo
+    }
+
+    /// The upper endpoint.
+    pub fn hi(&self) -> Q {
+        self.hi
+    }
+
+    /// The nearest quantity to `quantity` within the interval.
+    ///
+    /// Quantities inside the interval are returned unchanged; a NaN quantity stays NaN.
+    pub fn clamp(&self, quantity: Q) -> Q {
+        if quantity < self.lo {
+            self.lo
+        } else if quantity > self.hi {
+            self.hi
+        } else {
+            quantity
+        }
+    }
+
+    /// The width `hi - lo` of the interval, in the same unit as its endpoints.
+    ///
+    /// Never negative, since the endpoints are ordered on construction.
+    pub fn width(&self) -> Q
+    where
+        Q: Sub<Output = Q>,
+    {
+        self.hi - self.lo
+    }
+}
+
+impl<Scale, Dimension, T, Brand> Quantity<Scale, Dimension, T, Brand>
+where
+    Self: PartialOrd,
+{
+    /// The closed interval between this quantity and `other`, in either order.
+    ///
+    /// Equivalent to [`Interval::new(self, other)`](Interval::new).
+    pub fn interval_to(self, other: Self) -> Interval<Self> {
+        Interval::new(self, other)
+    }
+}
@@ -39,6 +39,12 @@
 //! | `std`   | Yes     | Enables standard library support (implies `alloc`) |
 //! | `alloc` | Yes     | Enables `Display`/`Debug` impls on `Quantity` (requires a global allocator) |
 //! | `serde` | Yes     | Enables `Serialize`/`Deserialize` impls, `from_json!`/`from_string!` macros, and `.fmt()` (implies `alloc`) |
+//! | `yaml`  | No      | Enables the `from_yaml!` macro (implies `std` and `serde`) |
+//! | `toml`  | No      | Enables the `from_toml!` macro (implies `std` and `serde`) |
+//! | `bigdecimal` | No | Enables exact `BigDecimal` rescaling via `decimal::rescale_bigdecimal` (implies `alloc`) |
+//! | `rkyv`  | No      | Enables zero-copy `rkyv` `Archive`/`Serialize`/`Deserialize` impls with exponent validation |
+//! | `tracing` | No    | Enables recording quantities as `tracing` fields via `Quantity::as_field` (implies `alloc`) |
+//! | `prefix-lint` | No | Warns on likely prefix case typos such as `Mm` for `mm` in unit macros |
 //! | `cge`   | No      | Enables nightly `generic_const_exprs` (requires nightly toolchain) |
 //!
 //! ## `no_std` and `no_alloc` Support
@@ -120,18 +126,60 @@ impl<const N: usize, T> GetSecondGeneric for Helper<N, T> {
     type Type = T;
 }
 
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn __format(args: core::fmt::Arguments<'_>) -> alloc::String {
+    alloc::format!("{}", args)
+}
+
+#[doc(hidden)]
+pub trait __ScalarPow {
+    fn pow<const POWER: i16>(self) -> Self;
+}
+
+impl __ScalarPow for f64 {
+    fn pow<const POWER: i16>(self) -> Self {
+        libm::pow(self, POWER as f64)
+    }
+}
+
+impl __ScalarPow for f32 {
+    fn pow<const POWER: i16>(self) -> Self {
+        libm::powf(self, POWER as f32)
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[doc(hidden)]
 mod alloc;
 
 pub mod api;
+#[cfg(feature = "rkyv")]
+pub mod archive;
 #[doc(hidden)]
 pub mod arithmetic;
 #[doc(hidden)]
 pub mod arithmetic_quantity_types;
+pub mod atmosphere;
+#[cfg(feature = "alloc")]
+pub mod calculus;
+pub mod constants;
+#[cfg(feature = "alloc")]
+pub mod csv;
+#[cfg(feature = "bigdecimal")]
+pub mod decimal;
 pub mod default_declarators;
 pub mod dimension_traits;
 #[cfg(feature = "alloc")]
+pub mod dynamic;
+#[doc(hidden)]
+pub mod expect_macro;
+pub mod ffi;
+pub mod interval;
+pub mod log_units;
+#[cfg(feature = "std")]
+pub mod preferences;
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 pub mod print;
 pub mod quantity;
@@ -141,6 +189,11 @@ pub mod rescale_macro;
 pub mod scale_conversion;
 #[cfg(feature = "serde")]
 pub mod serialization;
+pub mod stats;
+#[cfg(feature = "tracing")]
+pub mod trace;
+pub mod uncertain;
+pub mod unit_code;
 
 pub use quantity::Quantity;
 
@@ -250,7 +303,7 @@ pub use whippyunits_proc_macros::local_unit_type as local_unit;
 ///         - An exponentiation of an atomic unit:
 ///             - `m2`, `m^2`
 ///         - A multiplication of two or more (possibly exponentiated) atomic units:
-///             - `kg.m2`, `kg * m2`
+///             - `kg.m2`, `kg * m2`, `kg m2` (whitespace between units multiplies)
 ///         - A division of two such product expressions:
 ///             - `kg.m2/s2`, `kg * m2 / s^2`
 ///             - There may be at most one division expression in a unit literal expression
@@ -300,7 +353,7 @@ pub use whippyunits_proc_macros::proc_unit as unit;
 ///         - An exponentiation of an atomic unit:
 ///             - `m2`, `m^2`
 ///         - A multiplication of two or more (possibly exponentiated) atomic units:
-///             - `kg.m2`, `kg * m2`
+///             - `kg.m2`, `kg * m2`, `kg m2` (whitespace between units multiplies)
 ///         - A division of two such product expressions:
 ///             - `kg.m2/s2`, `kg * m2 / s^2`
 ///             - There may be at most one division expression in a unit literal expression
@@ -359,13 +412,85 @@ pub use whippyunits_proc_macros::proc_unit as unit;
 ///   enabling better IDE support and type inference
 /// - **More reliable constant folding**: The math is frontloaded at compile time,
 ///   with no reliance on optimization to realize that values can be interned
+///
+/// ## Prefix Case Warnings
+///
+/// A large prefix on a unit with a lowercase symbol, where the lowercase prefix also exists,
+/// is likely a typo: `Mm` (megameter) is usually meant as `mm` (millimeter).  With the opt-in
+/// `prefix-lint` feature, such units raise a `deprecated` warning suggesting the likely intent.
+/// Where the large prefix is intended, silence it with `#[allow(deprecated)]`:
+///
+/// ```rust
+/// # fn main() {
+/// # use whippyunits::quantity;
+/// #[allow(deprecated)]
+/// let earth_radius = quantity!(6.371, Mm);
+/// # }
+/// ```
 #[doc(inline)]
 pub use whippyunits_proc_macros::proc_quantity as quantity;
 pub use whippyunits_proc_macros::proc_value as value;
 
+/// Format quantities into a string, each in the unit written after its placeholder.
+///
+/// Each `{}` placeholder takes the next quantity, and is followed by the unit to display it in;
+/// the value is extracted with [value!], so the unit's dimension is checked at compile time.
+/// Placeholders may carry format specs (`{:.2}`), and `{{`/`}}` escape braces.
+///
+/// Examples:
+/// ```rust
+/// # fn main() {
+/// # use whippyunits::{quantity, value_fmt};
+/// let speed = quantity!(10.0, m / s);
+/// assert_eq!(value_fmt!("{} km/h", speed), "36 km/h");
+///
+/// let distance = quantity!(1500.0, m);
+/// let time = quantity!(90.0, s);
+/// assert_eq!(
+///     value_fmt!("{{{:.1} km}} in {} min", distance, time),
+///     "{1.5 km} in 1.5 min"
+/// );
+/// // let _ = value_fmt!("{} s", speed); // ❌ compile error (incompatible dimension)
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use whippyunits_proc_macros::proc_value_fmt as value_fmt;
+
+/// Evaluate a formula over quantities, with bare numeric literals as dimensionless factors.
+///
+/// The formula is written with `+`, `-`, `*`, `/`, parentheses, and `^` raised to an integer
+/// literal, which binds tighter than every other operator (so `0.5 * m * v ^ 2` squares only
+/// `v`).  Numeric literals scale the value without affecting the dimension, and operands may
+/// be any expression without operators: variables, paths such as `constants::G`, or method
+/// calls.  Where [output!] computes the type of an expression, `formula!` computes its value,
+/// with the dimension of the result checked by the compiler as for any other arithmetic.
+///
+/// The formula may stand alone as an expression, or bind its result with `name = formula`.
+/// Binding with `name: unit = formula` also checks that the result is in `unit`.
+///
+/// Examples:
+/// ```rust
+/// # fn main() {
+/// # use whippyunits::{formula, quantity, unit};
+/// let m = quantity!(2.0, kg);
+/// let v = quantity!(3.0, m / s);
+///
+/// formula!(kinetic_energy: J = 0.5 * m * v ^ 2);
+/// assert_eq!(kinetic_energy, quantity!(9.0, J));
+///
+/// let momentum: unit!(kg * m / s) = formula!(m * v);
+/// assert_eq!(momentum, quantity!(6.0, kg * m / s));
+/// // formula!(wrong: W = 0.5 * m * v ^ 2); // ❌ compile error (energy is not power)
+/// # }
+/// ```
+#[doc(inline)]
+pub use whippyunits_proc_macros::proc_formula as formula;
+
 pub use op_result::op_result;
 pub use op_result::output;
 
 // from_json, from_string, from_json_strict, and from_string_strict macros are exported via #[macro_export] in serialization.rs
-// value! macro is exported as a proc macro re-export
-// rescale! macro is exported via #[macro_export] in rescale_macro.rs
+// value!, value_fmt!, and formula! macros are exported as proc macro re-exports
+// rescale!, convert_slice!, and convert_slice_in_place! macros are exported via #[macro_export] in rescale_macro.rs
+// expect_unit!, expect_dimension!, check_equation!, and assert_quantity_approx_eq! macros are exported via #[macro_export] in expect_macro.rs
@@ -0,0 +1,165 @@
+//! Logarithmic ratios in decibels.
+//!
+//! Decibels are not a unit in the dimensional sense: a decibel value is the logarithm of a
+//! dimensionless ratio, so decibels add where the ratios they stand for multiply.  They are kept
+//! here as a [Decibel] wrapper and conversion functions rather than as a unit of the `quantity!`
+//! system, whose units all scale linearly.
+//!
+//! ```rust
+//! use whippyunits::log_units::{Decibel, amplitude_to_db, power_to_db};
+//! use whippyunits::quantity;
+//!
+//! let gain = quantity!(2.0, W) / quantity!(20.0, mW);
+//! assert!((power_to_db(gain) - 20.0).abs() < 1e-12);
+//!
+//! let attenuation = Decibel::from_amplitude_ratio(quantity!(0.5, V) / quantity!(5.0, V));
+//! assert!((attenuation.value() + 20.0).abs() < 1e-12);
+//! assert!((amplitude_to_db(quantity!(10.0, 1)) - 20.0).abs() < 1e-12);
+//! ```
+//!
+//! ## Power and amplitude
+//!
+//! The same ratio is a different number of decibels depending on what it is a ratio of.  A ratio
+//! of powers (or energies, or intensities) is `10·log₁₀(ratio)` decibels; a ratio of amplitudes
+//! (voltages, currents, sound pressures, field strengths) is `20·log₁₀(ratio)`, since power goes
+//! as the square of amplitude.  Every conversion therefore comes as a `power` and an `amplitude`
+//! pair, and a [Decibel] value converts back to either, so that 20 dB is a power ratio of 100 and
+//! an amplitude ratio of 10.
+
+use core::fmt;
+use core::ops::{Add, Neg, Sub};
+
+use crate::api::rescale;
+use crate::quantity::{Quantity, QuantityOf};
+
+/// Dimensionless ratio of any scale.
+type Ratio<const P2: i16, const P3: i16, const P5: i16, const PI: i16> =
+    QuantityOf<0, 0, 0, 0, 0, 0, 0, 0, 0, P2, P3, P5, PI, f64, ()>;
+
+/// A ratio expressed in decibels.
+///
+/// Whether the ratio is one of powers or of amplitudes is chosen when converting to and from
+/// ratios; the decibel value itself is the same either way.  Decibels add and subtract, which
+/// multiplies and divides the ratios they stand for.
+///
+/// ```rust
+/// use whippyunits::log_units::Decibel;
+///
+/// let gain = Decibel::new(30.0) - Decibel::new(10.0);
+/// assert_eq!(gain, Decibel::new(20.0));
+/// assert!((gain.to_power_ratio().unsafe_value - 100.0).abs() < 1e-9);
+/// assert!((gain.to_amplitude_ratio().unsafe_value - 10.0).abs() < 1e-12);
+/// assert_eq!(gain.to_string(), "20 dB");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Decibel(f64);
+
+impl Decibel {
+    /// A decibel value.
+    pub const fn new(decibels: f64) -> Self {
+        Decibel(decibels)
+    }
+
+    /// The ratio of two powers, in decibels; see [power_to_db].
+    pub fn from_power_ratio<const P2: i16, const P3: i16, const P5: i16, const PI: i16>(
+        ratio: Ratio<P2, P3, P5, PI>,
+    ) -> Self {
+        Decibel(power_to_db(ratio))
+    }
+
+    /// The ratio of two amplitudes, in decibels; see [amplitude_to_db].
+    pub fn from_amplitude_ratio<const P2: i16, const P3: i16, const P5: i16, const PI: i16>(
+        ratio: Ratio<P2, P3, P5, PI>,
+    ) -> Self {
+        Decibel(amplitude_to_db(ratio))
+    }
+
+    /// The value in decibels.
+    pub const fn value(self) -> f64 {
+        self.0
+    }
+
+    /// The power ratio this many decibels stands for; see [db_to_power].
+    pub fn to_power_ratio(self) -> Ratio<0, 0, 0, 0> {
+        db_to_power(self.0)
+    }
+
+    /// The amplitude ratio this many decibels stands for; see [db_to_amplitude].
+    pub fn to_amplitude_ratio(self) -> Ratio<0, 0, 0, 0> {
+        db_to_amplitude(self.0)
+    }
+}
+
+impl Add for Decibel {
+    type Output = Decibel;
+
+    fn add(self, other: Decibel) -> Decibel {
+        Decibel(self.0 + other.0)
+    }
+}
+
+impl Sub for Decibel {
+    type Output = Decibel;
+
+    fn sub(self, other: Decibel) -> Decibel {
+        Decibel(self.0 - other.0)
+    }
+}
+
+impl Neg for Decibel {
+    type Output = Decibel;
+
+    fn neg(self) -> Decibel {
+        Decibel(-self.0)
+    }
+}
+
+impl fmt::Display for Decibel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)?;
+        f.write_str(" dB")
+    }
+}
+
+/// Convert a ratio of powers to decibels, `10·log₁₀(ratio)`.
+///
+/// The ratio may be of any dimensionless scale, such as the quotient of `W` and `mW`; it is
+/// rescaled to a plain number first.  A ratio of zero is negative infinity decibels, and a
+/// negative ratio is NaN.
+pub fn power_to_db<const P2: i16, const P3: i16, const P5: i16, const PI: i16>(
+    ratio: Ratio<P2, P3, P5, PI>,
+) -> f64 {
+    10.0 * libm::log10(plain_ratio(ratio))
+}
+
+/// Convert a ratio of amplitudes to decibels, `20·log₁₀(ratio)`.
+///
+/// As for [power_to_db], but for field quantities such as voltage or sound pressure, whose
+/// squares are proportional to power.
+pub fn amplitude_to_db<const P2: i16, const P3: i16, const P5: i16, const PI: i16>(
+    ratio: Ratio<P2, P3, P5, PI>,
+) -> f64 {
+    20.0 * libm::log10(plain_ratio(ratio))
+}
+
+/// Convert decibels to a ratio of powers, `10^(db/10)`.
+///
+/// This is the inverse of [power_to_db].
+pub fn db_to_power(db: f64) -> Ratio<0, 0, 0, 0> {
+    Quantity::new(libm::pow(10.0, db / 10.0))
+}
+
+/// Convert decibels to a ratio of amplitudes, `10^(db/20)`.
+///
+/// This is the inverse of [amplitude_to_db].
+pub fn db_to_amplitude(db: f64) -> Ratio<0, 0, 0, 0> {
+    Quantity::new(libm::pow(10.0, db / 20.0))
+}
+
+/// A dimensionless ratio as a plain number.
+fn plain_ratio<const P2: i16, const P3: i16, const P5: i16, const PI: i16>(
+    ratio: Ratio<P2, P3, P5, PI>,
+) -> f64 {
+    let unity: Ratio<0, 0, 0, 0> = rescale(ratio);
+    unity.unsafe_value
+}
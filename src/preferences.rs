@@ -0,0 +1,199 @@
+//! Scoped, thread-local display unit preferences.
+//!
+//! By default, a [Quantity](crate::Quantity) displays in its storage unit.  A preference scope
+//! overrides the unit used by `Display` for every quantity of a given dimension on the current
+//! thread, until the returned guard is dropped:
+//!
+//! ```rust
+//! # use whippyunits::quantity;
+//! use whippyunits::preferences::unit_preference_scope;
+//! use whippyunits_core::dimension_exponents::DimensionBasis::Length;
+//!
+//! let distance = quantity!(1500.0, m);
+//! {
+//!     let _guard = unit_preference_scope(Length, "km");
+//!     assert_eq!(distance.to_string(), "1.5000 Quantity<km, f64>");
+//! }
+//! assert_eq!(distance.to_string(), "1500.0 Quantity<m, f64>");
+//! ```
+//!
+//! Scopes nest: an inner scope for the same dimension takes precedence while it is alive, and
+//! dropping it restores whatever preference was in effect before it, even if guards are dropped
+//! out of order.  Preferences never leak across threads.
+//!
+//! Only `Display` is affected; `Debug` always shows the storage unit, so that the actual type of
+//! a quantity can still be inspected.
+//!
+//! How the displayed value is written, such as its
+//! [NumberFormat](crate::print::prettyprint::NumberFormat), can be scoped in the same way
+//! with [display_config_scope].
+
+use core::cell::{Cell, RefCell};
+use core::marker::PhantomData;
+
+use crate::print::prettyprint::DisplayConfig;
+use whippyunits_core::{
+    EvaluationMode, LiteUnitExpr, dimension_exponents::DynDimensionExponents,
+    scale_exponents::ScaleExponents,
+};
+
+struct Preference {
+    id: u64,
+    dimension: DynDimensionExponents,
+    scale: ScaleExponents,
+}
+
+std::thread_local! {
+    static PREFERENCES: RefCell<Vec<Preference>> = const { RefCell::new(Vec::new()) };
+    static DISPLAY_CONFIGS: RefCell<Vec<(u64, DisplayConfig)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Guard returned by [unit_preference_scope]; the preference is reverted when it is dropped.
+///
+/// The guard is tied to the thread that created it and cannot be sent to another thread.
+#[must_use = "the preference is reverted as soon as the guard is dropped"]
+pub struct UnitPreferenceGuard {
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for UnitPreferenceGuard {
+    fn drop(&mut self) {
+        PREFERENCES.with_borrow_mut(|preferences| {
+            preferences.retain(|preference| preference.id != self.id);
+        });
+    }
+}
+
+/// Display quantities of `dimension` in `unit` on this thread until the guard is dropped.
+///
+/// `dimension` may be a [DimensionBasis](whippyunits_core::dimension_exponents::DimensionBasis)
+/// such as `Length`, or the exponents of a derived dimension.  `unit` is a unit literal
+/// expression, as accepted by the [unit!](crate::unit!) macro.
+///
+/// ```rust
+/// # use whippyunits::quantity;
+/// use whippyunits::preferences::unit_preference_scope;
+/// use whippyunits_core::Dimension;
+///
+/// let _guard = unit_preference_scope(Dimension::ENERGY.erase().exponents, "kJ");
+/// assert_eq!(quantity!(2500.0, J).to_string(), "2.5000 Quantity<kJ, f64>");
+/// ```
+///
+/// # Panics
+///
+/// Panics if `unit` cannot be parsed, is not of the given dimension, or is a nonstorage unit
+/// (such as `mi` or `degC`) that cannot be represented by a scale alone.
+pub fn unit_preference_scope(
+    dimension: impl Into<DynDimensionExponents>,
+    unit: &str,
+) -> UnitPreferenceGuard {
+    let dimension = dimension.into();
+    let expr = LiteUnitExpr::parse(unit)
+        .unwrap_or_else(|e| panic!("invalid preferred unit `{}`: {}", unit, e));
+    let evaluated = expr.evaluate_with_mode(EvaluationMode::Tolerant);
+    assert!(
+        evaluated.dimension_exponents == dimension,
+        "preferred unit `{}` does not have dimension {:?}",
+        unit,
+        dimension.0
+    );
+    assert!(
+        expr.conversion_factors() == (1.0, 0.0),
+        "preferred unit `{}` is a nonstorage unit",
+        unit
+    );
+
+    let id = NEXT_ID.replace(NEXT_ID.get() + 1);
+    PREFERENCES.with_borrow_mut(|preferences| {
+        preferences.push(Preference {
+            id,
+            dimension,
+            scale: evaluated.scale_exponents,
+        })
+    });
+    UnitPreferenceGuard {
+        id,
+        _not_send: PhantomData,
+    }
+}
+
+/// The scale of the innermost preference in effect for `dimension` on this thread, if any.
+pub fn preferred_scale(dimension: DynDimensionExponents) -> Option<ScaleExponents> {
+    PREFERENCES.with_borrow(|preferences| {
+        preferences
+            .iter()
+            .rev()
+            .find(|preference| preference.dimension == dimension)
+            .map(|preference| preference.scale)
+    })
+}
+
+/// Guard returned by [display_config_scope]; the display config is reverted when it is dropped.
+///
+/// The guard is tied to the thread that created it and cannot be sent to another thread.
+#[must_use = "the display config is reverted as soon as the guard is dropped"]
+pub struct DisplayConfigGuard {
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for DisplayConfigGuard {
+    fn drop(&mut self) {
+        DISPLAY_CONFIGS.with_borrow_mut(|configs| {
+            configs.retain(|(id, _)| *id != self.id);
+        });
+    }
+}
+
+/// Display the values of quantities with `config` on this thread until the guard is dropped.
+///
+/// The unit still follows the value, and an explicit precision (such as `{:.2}`) sets the
+/// number of digits after the decimal point of the mantissa.
+///
+/// ```rust
+/// # use whippyunits::quantity;
+/// use whippyunits::preferences::display_config_scope;
+/// use whippyunits::print::prettyprint::{DisplayConfig, NumberFormat};
+///
+/// let _guard = display_config_scope(DisplayConfig {
+///     number_format: NumberFormat::Engineering,
+///     ..DisplayConfig::default()
+/// });
+/// assert_eq!(quantity!(12345.0, m).to_string(), "12.345e3 Quantity<m, f64>");
+/// ```
+///
+/// An inner scope replaces the whole config while it is alive.  To change only some options,
+/// start from the config already in effect:
+///
+/// ```rust
+/// # use whippyunits::quantity;
+/// use whippyunits::preferences::{display_config_scope, preferred_display_config};
+/// use whippyunits::print::prettyprint::DisplayConfig;
+///
+/// let _guard = display_config_scope(DisplayConfig {
+///     mark_inexact: true,
+///     ..preferred_display_config()
+/// });
+/// assert_eq!(format!("{:.0}", quantity!(2.5, m)), "~2 Quantity<m, f64>");
+/// assert_eq!(format!("{:.0}", quantity!(2.0, m)), "2 Quantity<m, f64>");
+/// ```
+pub fn display_config_scope(config: DisplayConfig) -> DisplayConfigGuard {
+    let id = NEXT_ID.replace(NEXT_ID.get() + 1);
+    DISPLAY_CONFIGS.with_borrow_mut(|configs| configs.push((id, config)));
+    DisplayConfigGuard {
+        id,
+        _not_send: PhantomData,
+    }
+}
+
+/// The innermost display config in effect on this thread, or the default if none is.
+pub fn preferred_display_config() -> DisplayConfig {
+    DISPLAY_CONFIGS.with_borrow(|configs| {
+        configs
+            .last()
+            .map(|&(_, config)| config)
+            .unwrap_or_default()
+    })
+}
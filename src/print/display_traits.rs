@@ -10,7 +10,7 @@ macro_rules! define_display_traits {
             fmt::Display
             for Quantity<
                 Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+                Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
                 T,
                 Brand,
             >
@@ -20,6 +20,21 @@ macro_rules! define_display_traits {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 let value_f64 = <f64 as num_traits::NumCast>::from(self.unsafe_value)
                     .expect("unable to convert numeric value to f64 for display");
+                let dimensions = whippyunits_core::dimension_exponents::DynDimensionExponents([$($dimension_args)*]);
+                let scale = whippyunits_core::scale_exponents::ScaleExponents([$($scale_args)*]);
+                // Display in the preferred unit for this dimension, if one is in scope
+                #[cfg(feature = "std")]
+                let (value_f64, scale) = match $crate::preferences::preferred_scale(dimensions) {
+                    Some(preferred) => (
+                        value_f64
+                            * $crate::api::aggregate_scale_factor_float(
+                                scale.0[0], scale.0[1], scale.0[2], scale.0[3],
+                                preferred.0[0], preferred.0[1], preferred.0[2], preferred.0[3],
+                            ),
+                        preferred,
+                    ),
+                    None => (value_f64, scale),
+                };
                 #[cfg(feature = "std")]
                 let brand_name = std::any::type_name::<Brand>();
                 #[cfg(not(feature = "std"))]
@@ -28,16 +43,25 @@ macro_rules! define_display_traits {
                 let type_name = std::any::type_name::<T>();
                 #[cfg(not(feature = "std"))]
                 let type_name = "<T>";
-                let pretty = pretty_print_quantity_value(
+                #[cfg(feature = "std")]
+                let display_config = $crate::preferences::preferred_display_config();
+                #[cfg(not(feature = "std"))]
+                let display_config = $crate::print::prettyprint::DisplayConfig::default();
+                // Precision, width, and fill apply to the value only, never to the unit
+                let value = $crate::print::format_specifiers::format_value_with_formatter(
                     value_f64,
-                    whippyunits_core::dimension_exponents::DynDimensionExponents([$($dimension_args)*]),
-                    whippyunits_core::scale_exponents::ScaleExponents([$($scale_args)*]),
+                    f,
+                    display_config,
+                );
+                let unit = pretty_print_quantity_type(
+                    dimensions,
+                    scale,
                     type_name,
                     false, // Non-verbose mode for Display
                     true, // Show type in brackets for Display (now unified)
                     Some(brand_name),
                 );
-                write!(f, "{}", pretty)
+                write!(f, "{} {}", value, unit)
             }
         }
 
@@ -49,7 +73,7 @@ macro_rules! define_display_traits {
             fmt::Debug
             for Quantity<
                 Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+                Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
                 T,
                 Brand,
             >
@@ -1,4 +1,7 @@
+use core::fmt::{Alignment, Formatter};
+
 use crate::alloc::{String, ToString, Vec, format};
+use crate::print::prettyprint::{DisplayConfig, format_number, is_exact_representation};
 use whippyunits_core::{Dimension, Unit};
 
 /// Represents a parsed format specifier for unit conversion
@@ -77,6 +80,74 @@ pub fn format_with_unit(value: f64, spec: &UnitFormatSpecifier) -> Result<String
     Ok(format!("{} {}", final_value, display_unit))
 }
 
+/// A value and unit laid out in fixed-width columns, for tabular output.
+///
+/// The value is right-aligned in `value_width` columns and the unit left-aligned in `unit_width`
+/// columns, separated by a single space.  Neither is ever truncated: a value or unit wider than
+/// its column simply pushes the rest of the line to the right.  A precision given in the format
+/// string (e.g. `{:.2}`) applies to the value.
+///
+/// Created by [`Quantity::format_aligned`](crate::quantity::Quantity::format_aligned).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlignedFormatter {
+    pub value: f64,
+    pub unit: String,
+    pub value_width: usize,
+    pub unit_width: usize,
+}
+
+impl core::fmt::Display for AlignedFormatter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = match f.precision() {
+            Some(precision) => format!("{:.precision$}", self.value),
+            None => format!("{}", self.value),
+        };
+        write!(
+            f,
+            "{:>value_width$} {:<unit_width$}",
+            value,
+            self.unit,
+            value_width = self.value_width,
+            unit_width = self.unit_width,
+        )
+    }
+}
+
+/// Format the numeric part of a displayed quantity according to the flags of `f`.
+///
+/// The value is written in the number format of `config`.  A precision (e.g. `{:.2}`) sets the
+/// number of decimal places; without one the value is written to 5 significant figures.  A width
+/// pads the value with the fill character, right aligned by default as for primitive numbers.
+/// The unit that follows the value is not part of the returned string, so it is never padded or
+/// truncated.
+///
+/// With [DisplayConfig::mark_inexact], a value whose digits were rounded away is prefixed with
+/// `~`, inside the padding.
+pub fn format_value_with_formatter(value: f64, f: &Formatter<'_>, config: DisplayConfig) -> String {
+    let formatted = format_number(value, f.precision(), config.number_format);
+    let value = if config.mark_inexact && !is_exact_representation(value, &formatted) {
+        format!("~{}", formatted)
+    } else {
+        formatted
+    };
+
+    let Some(width) = f.width() else {
+        return value;
+    };
+    let padding = width.saturating_sub(value.chars().count());
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+
+    let mut padded = String::new();
+    padded.extend(core::iter::repeat_n(f.fill(), before));
+    padded.push_str(&value);
+    padded.extend(core::iter::repeat_n(f.fill(), after));
+    padded
+}
+
 // Helper functions that replace api_helpers functions with direct whippyunits-core calls
 
 /// Look up a unit literal (like "min", "h", "g", "m", "s", etc.) in the dimensions data
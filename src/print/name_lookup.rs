@@ -2,11 +2,13 @@ use crate::alloc::{String, ToString, Vec};
 use whippyunits_core::{
     scale_exponents::ScaleExponents,
     storage_unit::{
-        DimensionNames as CoreDimensionNames, generate_systematic_composite_unit_name,
+        DimensionNames as CoreDimensionNames, convert_unicode_to_ucum_format,
+        generate_systematic_composite_unit_name,
         generate_systematic_unit_name as core_generate_systematic_unit_name,
         generate_systematic_unit_name_with_scale_factors as core_generate_systematic_unit_name_with_scale_factors,
         lookup_dimension_name as core_lookup_dimension_name,
     },
+    to_ascii_notation,
 };
 
 /// Generate systematic unit name with scale factors
@@ -31,7 +33,7 @@ pub fn generate_systematic_unit_name_with_format(
     format: crate::print::prettyprint::UnitFormat,
 ) -> String {
     // Convert Vec<i16> to DynDimensionExponents for the core function
-    if exponents.len() != 8 {
+    if exponents.len() != 9 {
         return "?".to_string();
     }
 
@@ -44,6 +46,7 @@ pub fn generate_systematic_unit_name_with_format(
         exponents[5],
         exponents[6],
         exponents[7],
+        exponents[8],
     ]);
 
     // Use the centralized logic from whippyunits-core
@@ -56,17 +59,10 @@ pub fn generate_systematic_unit_name_with_format(
             // Convert Unicode format to UCUM format
             convert_unicode_to_ucum_format(&base_result)
         }
+        crate::print::prettyprint::UnitFormat::Ascii => to_ascii_notation(&base_result),
     }
 }
 
-/// Convert Unicode format unit string to UCUM format
-fn convert_unicode_to_ucum_format(unicode_unit: &str) -> String {
-    // This is a simplified conversion - in practice, you might need more sophisticated logic
-    // For now, just return the unicode format as-is since the core logic already handles
-    // the basic formatting correctly
-    unicode_unit.to_string()
-}
-
 pub type DimensionNames = CoreDimensionNames;
 
 pub fn lookup_dimension_name(exponents: Vec<i16>) -> Option<DimensionNames> {
@@ -2,15 +2,17 @@ use crate::alloc::{String, ToString, Vec, format};
 use crate::print::name_lookup::generate_systematic_unit_name;
 use crate::print::name_lookup::lookup_dimension_name;
 use crate::print::unit_literal_generator::{UnitLiteralConfig, generate_unit_literal};
-use crate::print::utils::{get_si_prefix, to_unicode_superscript};
+use crate::print::utils::{
+    get_si_prefix, to_ascii_notation, to_ascii_superscript, to_unicode_superscript,
+};
 use whippyunits_core::{
     dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
 };
 
 /// Check if a dimension is primitive (has exactly one non-zero exponent equal to 1)
-/// Primitive dimensions are the 8 SI base quantities: Mass, Length, Time, Current, Temperature, Amount, Luminosity, Angle
+/// Primitive dimensions are the 9 base quantities: Mass, Length, Time, Current, Temperature, Amount, Luminosity, Angle, Information
 fn is_primitive_dimension(exponents: Vec<i16>) -> bool {
-    if exponents.len() != 8 {
+    if exponents.len() != 9 {
         return false;
     }
 
@@ -28,6 +30,8 @@ pub enum UnitFormat {
     Unicode,
     /// UCUM format with plain text exponents and dots
     Ucum,
+    /// Plain ASCII format with `^` exponents (`m^2`, `s^-1`) and `*` separators
+    Ascii,
 }
 
 // Helper function to get unicode exponent
@@ -35,30 +39,45 @@ fn get_unicode_exponent(exp: i16) -> String {
     to_unicode_superscript(exp, false)
 }
 
-/// Helper function to format scale exponent values, using "ˀ" for i16::MIN values
-fn format_scale_exponent(scale: i16) -> String {
-    if scale == i16::MIN {
-        "ˀ".to_string()
-    } else {
-        to_unicode_superscript(scale, true)
+// Helper function to format an exponent as superscripts, or as `^n` in ASCII format
+fn format_exponent(exp: i16, show_unity: bool, format: UnitFormat) -> String {
+    match format {
+        UnitFormat::Ascii => to_ascii_superscript(exp, show_unity),
+        UnitFormat::Unicode | UnitFormat::Ucum => to_unicode_superscript(exp, show_unity),
     }
 }
 
+/// Helper function to format scale exponent values, using "ˀ" (or "^?") for i16::MIN values
+fn format_scale_exponent(scale: i16, format: UnitFormat) -> String {
+    format_exponent(scale, true, format)
+}
+
 /// Generate scale brackets with only non-zero exponents
-fn generate_scale_brackets(scale_p2: i16, scale_p3: i16, scale_p5: i16, scale_pi: i16) -> String {
+fn generate_scale_brackets(
+    scale_p2: i16,
+    scale_p3: i16,
+    scale_p5: i16,
+    scale_pi: i16,
+    format: UnitFormat,
+) -> String {
     let mut terms = Vec::new();
+    let pi = if format == UnitFormat::Ascii {
+        "pi"
+    } else {
+        "π"
+    };
 
     if scale_p2 != 0 {
-        terms.push(format!("2{}", format_scale_exponent(scale_p2)));
+        terms.push(format!("2{}", format_scale_exponent(scale_p2, format)));
     }
     if scale_p3 != 0 {
-        terms.push(format!("3{}", format_scale_exponent(scale_p3)));
+        terms.push(format!("3{}", format_scale_exponent(scale_p3, format)));
     }
     if scale_p5 != 0 {
-        terms.push(format!("5{}", format_scale_exponent(scale_p5)));
+        terms.push(format!("5{}", format_scale_exponent(scale_p5, format)));
     }
     if scale_pi != 0 {
-        terms.push(format!("π{}", format_scale_exponent(scale_pi)));
+        terms.push(format!("{}{}", pi, format_scale_exponent(scale_pi, format)));
     }
 
     if terms.is_empty() {
@@ -78,55 +97,63 @@ fn generate_dimension_brackets(
     amount_of_substance_exponent: i16,
     luminous_intensity_exponent: i16,
     angle_exponent: i16,
+    information_exponent: i16,
+    format: UnitFormat,
 ) -> String {
     let mut terms = Vec::new();
 
     if mass_exponent != 0 {
         terms.push(format!(
             "mass{}",
-            to_unicode_superscript(mass_exponent, true)
+            format_exponent(mass_exponent, true, format)
         ));
     }
     if length_exponent != 0 {
         terms.push(format!(
             "length{}",
-            to_unicode_superscript(length_exponent, true)
+            format_exponent(length_exponent, true, format)
         ));
     }
     if time_exponent != 0 {
         terms.push(format!(
             "time{}",
-            to_unicode_superscript(time_exponent, true)
+            format_exponent(time_exponent, true, format)
         ));
     }
     if electric_current_exponent != 0 {
         terms.push(format!(
             "current{}",
-            to_unicode_superscript(electric_current_exponent, true)
+            format_exponent(electric_current_exponent, true, format)
         ));
     }
     if temperature_exponent != 0 {
         terms.push(format!(
             "temperature{}",
-            to_unicode_superscript(temperature_exponent, true)
+            format_exponent(temperature_exponent, true, format)
         ));
     }
     if amount_of_substance_exponent != 0 {
         terms.push(format!(
             "amount{}",
-            to_unicode_superscript(amount_of_substance_exponent, true)
+            format_exponent(amount_of_substance_exponent, true, format)
         ));
     }
     if luminous_intensity_exponent != 0 {
         terms.push(format!(
             "luminosity{}",
-            to_unicode_superscript(luminous_intensity_exponent, true)
+            format_exponent(luminous_intensity_exponent, true, format)
         ));
     }
     if angle_exponent != 0 {
         terms.push(format!(
             "angle{}",
-            to_unicode_superscript(angle_exponent, true)
+            format_exponent(angle_exponent, true, format)
+        ));
+    }
+    if information_exponent != 0 {
+        terms.push(format!(
+            "information{}",
+            format_exponent(information_exponent, true, format)
         ));
     }
 
@@ -148,10 +175,11 @@ pub fn generate_dimension_symbols(exponents: Vec<i16>) -> String {
 
 /// Generate dimension symbols with specified format
 pub fn generate_dimension_symbols_with_format(exponents: Vec<i16>, format: UnitFormat) -> String {
-    // Dimension symbols only support Unicode format
+    // Dimension symbols support Unicode and ASCII format
     // UCUM format should use unit name generation instead
     match format {
         UnitFormat::Unicode => generate_dimension_symbols_unicode(exponents),
+        UnitFormat::Ascii => generate_dimension_symbols_ascii(exponents),
         UnitFormat::Ucum => {
             // For UCUM format, redirect to unit name generation
             crate::print::name_lookup::generate_systematic_unit_name_with_format(
@@ -163,17 +191,40 @@ pub fn generate_dimension_symbols_with_format(exponents: Vec<i16>, format: UnitF
 
 /// Generate dimension symbols in Unicode format using whippyunits-core as source of truth
 fn generate_dimension_symbols_unicode(exponents: Vec<i16>) -> String {
+    render_dimension_symbols(exponents, UnitFormat::Unicode)
+}
+
+/// Generate dimension symbols in plain ASCII format (`M*L^2*T^-2`)
+fn generate_dimension_symbols_ascii(exponents: Vec<i16>) -> String {
+    render_dimension_symbols(exponents, UnitFormat::Ascii)
+}
+
+/// Render dimension symbols with the exponents, symbols and separators of the given format
+fn render_dimension_symbols(exponents: Vec<i16>, format: UnitFormat) -> String {
+    let separator = if format == UnitFormat::Ascii {
+        "*"
+    } else {
+        "·"
+    };
+    let symbol_for = |idx: usize| {
+        let symbol = whippyunits_core::Dimension::BASIS
+            .get(idx)
+            .map(|dim| dim.symbol)
+            .unwrap_or("?");
+        if format == UnitFormat::Ascii {
+            to_ascii_notation(symbol)
+        } else {
+            symbol.to_string()
+        }
+    };
+
     let mut parts: Vec<String> = Vec::new();
 
     // First, add solved dimensions (non-zero, non--32768 exponents)
     for (idx, &exp) in exponents.iter().enumerate() {
         if exp != 0 && exp != -32768 {
-            let symbol = whippyunits_core::Dimension::BASIS
-                .get(idx)
-                .map(|dim| dim.symbol)
-                .unwrap_or("?");
-            let superscript = to_unicode_superscript(exp, false);
-            parts.push(format!("{}{}", symbol, superscript));
+            let superscript = format_exponent(exp, false, format);
+            parts.push(format!("{}{}", symbol_for(idx), superscript));
         }
     }
 
@@ -182,23 +233,23 @@ fn generate_dimension_symbols_unicode(exponents: Vec<i16>) -> String {
     for (idx, &exp) in exponents.iter().enumerate() {
         if exp == -32768 {
             // Only add unsolved dimensions
-            let symbol = whippyunits_core::Dimension::BASIS
-                .get(idx)
-                .map(|dim| dim.symbol)
-                .unwrap_or("?");
-            unsolved_parts.push(format!("{}{}", symbol, "ˀ"));
+            unsolved_parts.push(format!(
+                "{}{}",
+                symbol_for(idx),
+                format_exponent(i16::MIN, false, format)
+            ));
         }
     }
 
     // If we have unsolved dimensions, wrap them in parentheses
     if !unsolved_parts.is_empty() {
-        parts.push(format!("({})", unsolved_parts.join("·")));
+        parts.push(format!("({})", unsolved_parts.join(separator)));
     }
 
     if parts.is_empty() {
         "?".to_string()
     } else {
-        parts.join("·")
+        parts.join(separator)
     }
 }
 
@@ -235,7 +286,8 @@ define_generate_verbose_dimension_names!((
     (4, "Temperature"),
     (5, "Amount"),
     (6, "Luminosity"),
-    (7, "Angle")
+    (7, "Angle"),
+    (8, "Information")
 ));
 
 /// Calculate total power of 10 using whippyunits-core ScaleExponents
@@ -276,16 +328,16 @@ fn format_scale_factors(scale_p2: i16, scale_p3: i16, scale_p5: i16, scale_pi: i
     let mut value = 1.0;
 
     if scale_p2 != 0 {
-        value *= 2.0_f64.powi(scale_p2 as i32);
+        value *= whippyunits_core::powi(2.0, scale_p2 as i32);
     }
     if scale_p3 != 0 {
-        value *= 3.0_f64.powi(scale_p3 as i32);
+        value *= whippyunits_core::powi(3.0, scale_p3 as i32);
     }
     if scale_p5 != 0 {
-        value *= 5.0_f64.powi(scale_p5 as i32);
+        value *= whippyunits_core::powi(5.0, scale_p5 as i32);
     }
     if scale_pi != 0 {
-        value *= core::f64::consts::PI.powi(scale_pi as i32);
+        value *= whippyunits_core::powi(core::f64::consts::PI, scale_pi as i32);
     }
 
     // If the value is 1.0, no scaling needed
@@ -437,40 +489,150 @@ pub fn generate_prefixed_systematic_unit(
     }
 }
 
-/// Helper function to format floating point numbers with a reasonable number of significant figures
-fn format_float_with_sig_figs(value: f64, sig_figs: usize) -> String {
+/// Format a floating point number in positional notation to exactly `sig_figs` significant
+/// figures.
+///
+/// Trailing zeros are preserved (`1.2` to 3 significant figures is `"1.20"`), and digits before
+/// the decimal point beyond the significant ones are zeroed (`12340.0` to 3 significant figures
+/// is `"12300"`).
+pub(crate) fn format_float_with_sig_figs(value: f64, sig_figs: usize) -> String {
     if value == 0.0 {
         return "0".to_string();
     }
 
-    let abs_value = value.abs();
-    let magnitude = abs_value.log10().floor() as i32;
-    let scale_factor = 10_f64.powi(sig_figs as i32 - 1 - magnitude as i32);
-
-    let rounded = (value * scale_factor).round() / scale_factor;
-
-    // Format with appropriate precision
-    let formatted = if magnitude >= 0 {
-        // For values >= 1, show up to sig_figs digits total
-        let precision = (sig_figs as i32 - magnitude - 1).max(0) as usize;
-        format!("{:.precision$}", rounded, precision = precision)
-    } else {
-        // For values < 1, show sig_figs significant digits after decimal
-        format!(
-            "{:.precision$}",
-            rounded,
-            precision = (sig_figs as i32 + magnitude.abs()) as usize
-        )
-    };
+    let (rounded, exponent) = round_to_sig_figs(value, sig_figs);
+    let precision = (sig_figs.max(1) as i32 - 1 - exponent).max(0);
 
     // Note: The ~ symbol should only be added when the storage scale is truncated,
     // not when the stored value is truncated during formatting. This function
     // is only responsible for formatting the stored value, so we don't add ~ here.
-    formatted
+    format!("{:.precision$}", rounded, precision = precision as usize)
+}
+
+/// `value` rounded to `sig_figs` significant figures, and its decimal exponent after rounding.
+///
+/// The standard exponent formatting does the rounding, so that carries into the next decade
+/// (e.g. 9.996 -> 10.0) are reflected in the exponent.
+fn round_to_sig_figs(value: f64, sig_figs: usize) -> (f64, i32) {
+    let scientific = format!("{:.precision$e}", value, precision = sig_figs.max(1) - 1);
+    let exponent = scientific
+        .rsplit_once('e')
+        .and_then(|(_, exp)| exp.parse().ok())
+        .unwrap_or(0);
+    (scientific.parse().unwrap_or(value), exponent)
+}
+
+/// Format a floating point number to exactly `sig_figs` significant figures.
+///
+/// Trailing zeros are preserved (`1.2` to 3 significant figures is `"1.20"`). Values whose
+/// (rounded) decimal exponent is below -4, or not smaller than `sig_figs`, are written in
+/// scientific notation so that no insignificant zeros are shown (`12340.0` to 3 significant
+/// figures is `"1.23e4"`). A `sig_figs` of zero is treated as one.
+pub fn format_sig_figs(value: f64, sig_figs: usize) -> String {
+    let sig_figs = sig_figs.max(1);
+
+    if !value.is_finite() {
+        return format!("{}", value);
+    }
+
+    let (_, exponent) = round_to_sig_figs(value, sig_figs);
+    if exponent < -4 || exponent >= sig_figs as i32 {
+        format!("{:.precision$e}", value, precision = sig_figs - 1)
+    } else {
+        format_float_with_sig_figs(value, sig_figs)
+    }
+}
+
+/// Notation used for the numeric value of a displayed quantity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberFormat {
+    /// Positional notation, such as `1500.0` (default)
+    #[default]
+    Decimal,
+    /// A mantissa in `[1, 10)` and a power of ten, such as `1.5000e3`
+    Scientific,
+    /// A mantissa in `[1, 1000)` and a power of ten that is a multiple of three, such as
+    /// `12.345e3`, so that the exponent lines up with an SI prefix
+    Engineering,
+}
+
+/// Options for the numeric value of a displayed quantity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DisplayConfig {
+    /// Notation of the value
+    pub number_format: NumberFormat,
+    /// Prefix the value with `~` when the displayed digits do not exactly represent it, such as
+    /// `~2` for `2.5` written with `{:.0}`
+    pub mark_inexact: bool,
+}
+
+/// Whether `formatted`, as produced by [format_number], reads back as exactly `value`.
+pub fn is_exact_representation(value: f64, formatted: &str) -> bool {
+    value.is_nan() || formatted.parse::<f64>() == Ok(value)
+}
+
+/// Format `value` in the given notation.
+///
+/// A `precision` sets the number of digits after the decimal point (of the mantissa, in
+/// scientific and engineering notation); without one the value is written to 5 significant
+/// figures, as quantities are displayed by default.
+pub fn format_number(value: f64, precision: Option<usize>, format: NumberFormat) -> String {
+    if format == NumberFormat::Decimal || !value.is_finite() {
+        return match precision {
+            Some(precision) => format!("{:.precision$}", value),
+            None => format_float_with_sig_figs(value, 5),
+        };
+    }
+
+    let scientific = format!("{:.precision$e}", value, precision = precision.unwrap_or(4));
+    if format == NumberFormat::Scientific {
+        return scientific;
+    }
+
+    // Take the exponent after rounding, so that a carry into the next decade (999999 -> 1.0000e6)
+    // moves the value into the next group of three rather than leaving a four-digit mantissa.
+    let exponent: i32 = scientific
+        .rsplit_once('e')
+        .and_then(|(_, exp)| exp.parse().ok())
+        .unwrap_or(0);
+    let engineering_exponent = exponent - exponent.rem_euclid(3);
+    let precision = precision.unwrap_or((4 - exponent.rem_euclid(3)) as usize);
+    format!(
+        "{:.precision$}e{}",
+        value / whippyunits_core::powi(10.0, engineering_exponent),
+        engineering_exponent
+    )
 }
 
 /// Formatted string in the format: `value Quantity<systematic_literal, unit_shortname, dimension_name, [exponents and scales], type, brand>`
 pub fn pretty_print_quantity(
+    value: Option<f64>,
+    dimensions: whippyunits_core::dimension_exponents::DynDimensionExponents,
+    scale: whippyunits_core::scale_exponents::ScaleExponents,
+    type_name: &str,
+    verbose: bool,
+    show_type_in_brackets: bool,
+    brand_name: Option<&str>,
+) -> String {
+    pretty_print_quantity_with_format(
+        value,
+        dimensions,
+        scale,
+        type_name,
+        verbose,
+        show_type_in_brackets,
+        brand_name,
+        UnitFormat::Unicode,
+    )
+}
+
+/// Pretty print a quantity with the specified unit format
+///
+/// With [`UnitFormat::Ascii`] the output contains no non-ASCII characters: exponents are
+/// written `m^2` and `s^-1` rather than `m²` and `s⁻¹`.  UCUM format is not used for
+/// quantity types and renders as Unicode.
+#[allow(clippy::too_many_arguments)]
+pub fn pretty_print_quantity_with_format(
     value: Option<f64>,
     dimensions: whippyunits_core::dimension_exponents::DynDimensionExponents,
     scale: whippyunits_core::scale_exponents::ScaleExponents,
@@ -478,7 +640,22 @@ pub fn pretty_print_quantity(
     verbose: bool,
     _show_type_in_brackets: bool,
     brand_name: Option<&str>,
+    format: UnitFormat,
 ) -> String {
+    let format = if format == UnitFormat::Ascii {
+        UnitFormat::Ascii
+    } else {
+        UnitFormat::Unicode
+    };
+    // Unit literals and dimension names are generated in Unicode, then rendered for the format
+    let render = |text: String| {
+        if format == UnitFormat::Ascii {
+            to_ascii_notation(&text)
+        } else {
+            text
+        }
+    };
+
     let value_prefix = if let Some(val) = value {
         let formatted_val = format_float_with_sig_figs(val, 5);
         format!("{} ", formatted_val)
@@ -487,14 +664,14 @@ pub fn pretty_print_quantity(
     };
 
     // Generate the best unit literal using centralized logic
-    let unit_literal = generate_unit_literal(
+    let unit_literal = render(generate_unit_literal(
         dimensions,
         scale,
         UnitLiteralConfig {
             verbose,
             prefer_si_units: true,
         },
-    );
+    ));
 
     // Look up dimension name for secondary display
     let dimension_info = lookup_dimension_name(dimensions.0.to_vec());
@@ -502,14 +679,14 @@ pub fn pretty_print_quantity(
     let dimension_name = if let Some(ref info) = dimension_info {
         // For recognized composite dimensions, always use the dimension name (e.g., "Force", "Energy")
         // regardless of verbose/non-verbose mode, since these are established names
-        info.dimension_name.to_string()
+        render(info.dimension_name.to_string())
     } else {
         if verbose {
             // For unrecognized dimensions in verbose mode, generate verbose dimension names
-            generate_verbose_dimension_names(dimensions.0.to_vec())
+            render(generate_verbose_dimension_names(dimensions.0.to_vec()))
         } else {
             // For unrecognized dimensions in non-verbose mode, use dimension symbols
-            generate_dimension_symbols(dimensions.0.to_vec())
+            generate_dimension_symbols_with_format(dimensions.0.to_vec(), format)
         }
     };
 
@@ -533,7 +710,7 @@ pub fn pretty_print_quantity(
     let verbose_info = if verbose {
         format!(
             "{}{}",
-            generate_scale_brackets(scale.0[0], scale.0[1], scale.0[2], scale.0[3]),
+            generate_scale_brackets(scale.0[0], scale.0[1], scale.0[2], scale.0[3], format),
             generate_dimension_brackets(
                 dimensions.0[0],
                 dimensions.0[1],
@@ -542,7 +719,9 @@ pub fn pretty_print_quantity(
                 dimensions.0[4],
                 dimensions.0[5],
                 dimensions.0[6],
-                dimensions.0[7]
+                dimensions.0[7],
+                dimensions.0[8],
+                format
             )
         )
     } else {
@@ -585,6 +764,28 @@ pub fn pretty_print_quantity_type(
     )
 }
 
+/// Pretty print a quantity type (without value) with the specified unit format
+pub fn pretty_print_quantity_type_with_format(
+    dimensions: whippyunits_core::dimension_exponents::DynDimensionExponents,
+    scale: whippyunits_core::scale_exponents::ScaleExponents,
+    type_name: &str,
+    verbose: bool,
+    show_type_in_brackets: bool,
+    brand_name: Option<&str>,
+    format: UnitFormat,
+) -> String {
+    pretty_print_quantity_with_format(
+        None,
+        dimensions,
+        scale,
+        type_name,
+        verbose,
+        show_type_in_brackets,
+        brand_name,
+        format,
+    )
+}
+
 /// Pretty print a quantity value (with value) using the new unit types from whippyunits-core
 pub fn pretty_print_quantity_value(
     value: f64,
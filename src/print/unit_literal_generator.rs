@@ -1,6 +1,7 @@
-use crate::alloc::String;
+use crate::alloc::{String, Vec, format};
 use whippyunits_core::{
-    dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
+    EvaluationMode, LiteUnitExpr, dimension_exponents::DynDimensionExponents,
+    scale_exponents::ScaleExponents,
     storage_unit::generate_unit_literal as core_generate_unit_literal,
 };
 
@@ -17,3 +18,56 @@ pub fn generate_unit_literal(
     // Use the core implementation to ensure consistency between proc macro and inlay hints
     core_generate_unit_literal(exponents, scale_factors, config)
 }
+
+/// Symbols of the coherent SI base units, in dimension order.
+const BASE_UNIT_SYMBOLS: [&str; 9] = ["kg", "m", "s", "A", "K", "mol", "cd", "rad", "bit"];
+
+/// A parseable unit literal for the coherent SI unit of `dimension`.
+///
+/// Prefers the display name (e.g. `J`), as long as it parses back to the same unit; otherwise
+/// the unit is spelled out in base units.
+pub(crate) fn coherent_unit_literal(dimension: DynDimensionExponents) -> String {
+    if dimension.0 == [0; 9] {
+        return String::from("1");
+    }
+
+    let display = generate_unit_literal(
+        dimension,
+        ScaleExponents::IDENTITY,
+        UnitLiteralConfig {
+            verbose: false,
+            prefer_si_units: true,
+        },
+    );
+    if let Ok(expr) = LiteUnitExpr::parse(&display) {
+        let evaluated = expr.evaluate_with_mode(EvaluationMode::Tolerant);
+        if evaluated.dimension_exponents == dimension
+            && evaluated.scale_exponents == ScaleExponents::IDENTITY
+            && expr.conversion_factors() == (1.0, 0.0)
+        {
+            return display;
+        }
+    }
+
+    let terms = |sign: i16| {
+        BASE_UNIT_SYMBOLS
+            .iter()
+            .zip(dimension.0)
+            .filter(|&(_, exponent)| exponent * sign > 0)
+            .map(|(symbol, exponent)| match exponent.abs() {
+                1 => String::from(*symbol),
+                power => format!("{}{}", symbol, power),
+            })
+            .collect::<Vec<_>>()
+    };
+    let (numerator, denominator) = (terms(1), terms(-1));
+    let numerator = match numerator.len() {
+        0 => String::from("1"),
+        _ => numerator.join("*"),
+    };
+    match denominator.len() {
+        0 => numerator,
+        1 => format!("{}/{}", numerator, denominator[0]),
+        _ => format!("{}/({})", numerator, denominator.join("*")),
+    }
+}
@@ -1,5 +1,5 @@
 // Re-export the function from whippyunits-core for backward compatibility
-pub use whippyunits_core::to_unicode_superscript;
+pub use whippyunits_core::{to_ascii_notation, to_ascii_superscript, to_unicode_superscript};
 
 use whippyunits_core::SiPrefix;
 
@@ -63,6 +63,9 @@
 //! a brand (of the unit type `()`), so custom-branded quantities will not interoperate with default-declared
 //! quantities unless explicitly converted.
 
+use whippyunits_core::dimension_exponents::DynDimensionExponents;
+use whippyunits_core::num::{N, Pow, Root};
+
 #[derive(PartialEq)]
 pub struct _2<const EXP: i16 = 0>;
 /// The base-3 scale exponent of a quantity.
@@ -99,6 +102,9 @@ pub struct _J<const EXP: i16 = 0>;
 /// The angle dimension exponent of a quantity.
 #[derive(PartialEq)]
 pub struct _A<const EXP: i16 = 0>;
+/// The information dimension exponent of a quantity.
+#[derive(PartialEq)]
+pub struct _Info<const EXP: i16 = 0>;
 
 /// The scale of a quantity
 ///
@@ -110,6 +116,10 @@ pub struct _A<const EXP: i16 = 0>;
 ///  - milli: `10^-3 = _2<-3>, _3<0>, _5<-3>, _Pi<0>`
 ///  - kilo: `10^3 = _2<3>, _3<0>, _5<3>, _Pi<0>`
 ///
+/// Binary prefixes (for units of information) indicate scales of `2^10n = _2<10n>`, e.g.
+///  - kibi: `1024 = _2<10>, _3<0>, _5<0>, _Pi<0>`
+///  - mebi: `1024^2 = _2<20>, _3<0>, _5<0>, _Pi<0>`
+///
 /// Certain time units involve factors of `60^n = _2<2>, _3<1>, _5<1>, _Pi<0>`, e.g.
 ///  - minute: `60 = _2<2>, _3<1>, _5<1>, _Pi<0>`
 ///  - hour: `3600 = _2<4>, _3<2>, _5<2>, _Pi<0>`
@@ -137,6 +147,7 @@ pub struct Scale<P2 = _2<0>, P3 = _3<0>, P5 = _5<0>, PI = _Pi<0>> {
 ///  - amount: `_N<1>`
 ///  - luminosity: `_J<1>`
 ///  - angle: `_A<1>`
+///  - information: `_Info<1>`
 ///
 /// Derived dimensions have a mixture of dimension exponents:
 ///  - velocity: `_L<1>, _T<-1>`    
@@ -157,6 +168,7 @@ pub struct Dimension<
     AMOUNT = _N<0>,
     LUMINOSITY = _J<0>,
     ANGLE = _A<0>,
+    INFORMATION = _Info<0>,
 > {
     _phantom: core::marker::PhantomData<(
         MASS,
@@ -167,6 +179,7 @@ pub struct Dimension<
         AMOUNT,
         LUMINOSITY,
         ANGLE,
+        INFORMATION,
     )>,
 }
 
@@ -224,6 +237,12 @@ pub struct Dimension<
 /// whose intermixture would be nonsensical even if dimensionally-coherent.  By default quantities *do* have
 /// a brand (of the unit type `()`), so custom-branded quantities will not interoperate with default-declared
 /// quantities unless explicitly converted.
+///
+/// The structured form `Quantity<Scale<_2<P2>, _3<P3>, _5<P5>, _Pi<PI>>, Dimension<_M<M>, _L<L>, _T<T>,
+/// _I<I>, _Θ<Θ>, _N<N>, _J<J>, _A<A>, _Info<Info>>, T, Brand>` is the only representation of a quantity
+/// type.  Earlier releases spelled it as a flat list of const generic parameters (e.g. `Quantity<0, 1, 0, 0,
+/// 0, 0, 0, 0, -3, 0, -3, 0, f64>`); those forms are no longer accepted by the compiler and are only
+/// recognised by the pretty-printing tools, so that old logs and compiler output remain readable.
 #[derive(Clone, PartialEq)]
 pub struct Quantity<Scale, Dimension, T = f64, Brand = ()> {
     /// The raw numeric value of this quantity.
@@ -275,6 +294,30 @@ where
 {
 }
 
+/// A quantity whose stored value is the storage type's default, which is zero for the numeric
+/// types.
+impl<Scale, Dimension, T, Brand> Default for Quantity<Scale, Dimension, T, Brand>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Quantity {
+            unsafe_value: T::default(),
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<Scale, Dimension, T, Brand> Quantity<Scale, Dimension, T, Brand> {
+    // Like `new`, but for a scale or dimension that is only known through a trait projection
+    pub(crate) const fn from_unsafe_value(unsafe_value: T) -> Self {
+        Quantity {
+            unsafe_value,
+            _phantom: core::marker::PhantomData,
+        }
+    }
+}
+
 impl<P2, P3, P5, PI> Clone for Scale<P2, P3, P5, PI> {
     fn clone(&self) -> Self {
         Self {
@@ -283,8 +326,8 @@ impl<P2, P3, P5, PI> Clone for Scale<P2, P3, P5, PI> {
     }
 }
 
-impl<MASS, LENGTH, TIME, CURRENT, TEMPERATURE, AMOUNT, LUMINOSITY, ANGLE> Clone
-    for Dimension<MASS, LENGTH, TIME, CURRENT, TEMPERATURE, AMOUNT, LUMINOSITY, ANGLE>
+impl<MASS, LENGTH, TIME, CURRENT, TEMPERATURE, AMOUNT, LUMINOSITY, ANGLE, INFORMATION> Clone
+    for Dimension<MASS, LENGTH, TIME, CURRENT, TEMPERATURE, AMOUNT, LUMINOSITY, ANGLE, INFORMATION>
 {
     fn clone(&self) -> Self {
         Self {
@@ -365,6 +408,12 @@ impl<const EXP: i16> Clone for _A<EXP> {
     }
 }
 
+impl<const EXP: i16> Clone for _Info<EXP> {
+    fn clone(&self) -> Self {
+        Self
+    }
+}
+
 impl<
     const MASS_EXPONENT: i16,
     const LENGTH_EXPONENT: i16,
@@ -374,6 +423,7 @@ impl<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -392,6 +442,7 @@ impl<
             _N<AMOUNT_EXPONENT>,
             _J<LUMINOSITY_EXPONENT>,
             _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
         >,
         T,
         Brand,
@@ -409,6 +460,141 @@ impl<
         }
     }
 
+    /// Create a new Quantity with the given value, checking that it lies within `[min, max]`.
+    ///
+    /// For physically bounded quantities, such as absolute temperatures or masses that cannot
+    /// be negative.  The bounds may be given in any unit of the same dimension; they are
+    /// rescaled to this quantity's scale before comparing, and both are inclusive.  A NaN value
+    /// is never in range.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, unit};
+    /// let ok = <unit!(mK)>::new_bounded(300.0, &quantity!(0.0, K), &quantity!(1.0e4, K));
+    /// assert_eq!(ok, Ok(quantity!(300.0, mK)));
+    ///
+    /// let too_cold = <unit!(K)>::new_bounded(-1.0, &quantity!(0.0, K), &quantity!(1.0e4, K));
+    /// assert!(too_cold.is_err());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value or a bound cannot be converted to `f64`.
+    pub fn new_bounded<
+        const MIN_SCALE_P2: i16,
+        const MIN_SCALE_P3: i16,
+        const MIN_SCALE_P5: i16,
+        const MIN_SCALE_PI: i16,
+        const MAX_SCALE_P2: i16,
+        const MAX_SCALE_P3: i16,
+        const MAX_SCALE_P5: i16,
+        const MAX_SCALE_PI: i16,
+    >(
+        value: T,
+        min: &QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            MIN_SCALE_P2,
+            MIN_SCALE_P3,
+            MIN_SCALE_P5,
+            MIN_SCALE_PI,
+            T,
+            Brand,
+        >,
+        max: &QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            MAX_SCALE_P2,
+            MAX_SCALE_P3,
+            MAX_SCALE_P5,
+            MAX_SCALE_PI,
+            T,
+            Brand,
+        >,
+    ) -> Result<Self, OutOfBoundsError>
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        let to_f64 = |value: T| {
+            <f64 as num_traits::NumCast>::from(value)
+                .expect("unable to convert numeric value to f64 for bounds check")
+        };
+        let min_value = to_f64(min.unsafe_value)
+            * crate::api::aggregate_scale_factor_float(
+                MIN_SCALE_P2,
+                MIN_SCALE_P3,
+                MIN_SCALE_P5,
+                MIN_SCALE_PI,
+                SCALE_P2,
+                SCALE_P3,
+                SCALE_P5,
+                SCALE_PI,
+            );
+        let max_value = to_f64(max.unsafe_value)
+            * crate::api::aggregate_scale_factor_float(
+                MAX_SCALE_P2,
+                MAX_SCALE_P3,
+                MAX_SCALE_P5,
+                MAX_SCALE_PI,
+                SCALE_P2,
+                SCALE_P3,
+                SCALE_P5,
+                SCALE_PI,
+            );
+
+        let checked = to_f64(value);
+        if min_value <= checked && checked <= max_value {
+            Ok(Self::new(value))
+        } else {
+            Err(OutOfBoundsError {
+                value: checked,
+                min: min_value,
+                max: max_value,
+            })
+        }
+    }
+
+    /// Whether this quantity's value is exactly zero.
+    ///
+    /// Negative zero counts as zero, but values that are merely very small do not.  This is
+    /// intended as a predicate for serde's `skip_serializing_if`, to omit zero quantities from
+    /// compact output:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "serde")] {
+    /// # use whippyunits::quantity;
+    /// use whippyunits::unit;
+    ///
+    /// #[derive(serde::Serialize)]
+    /// struct Offset {
+    ///     #[serde(skip_serializing_if = "whippyunits::Quantity::is_zero_value")]
+    ///     x: unit!(m),
+    /// }
+    ///
+    /// let json = serde_json::to_string(&Offset { x: quantity!(0.0, m) }).unwrap();
+    /// assert_eq!(json, "{}");
+    /// # }
+    /// ```
+    pub fn is_zero_value(&self) -> bool
+    where
+        T: num_traits::Zero,
+    {
+        self.unsafe_value.is_zero()
+    }
+
     /// Format this quantity in the specified unit
     ///
     /// Returns a formatter that implements Display, allowing use with println! macros:
@@ -444,6 +630,16 @@ impl<
     /// # }
     /// ```
     ///
+    /// Units given by long name are pluralized for any value but one; in a compound unit only the
+    /// leading name is:
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(1000.0, m).fmt("kilometer").to_string(), "1 kilometer");
+    /// assert_eq!(quantity!(0.9144, m).fmt("foot").to_string(), "3 feet");
+    /// assert_eq!(quantity!(2.0, m / s).fmt("meter/second").to_string(), "2 meters/second");
+    /// ```
+    ///
     /// Dimensionally-incompatible units will print an error message, but will *not* panic:
     ///
     /// ```rust
@@ -463,11 +659,9 @@ impl<
         use crate::serialization::{
             calculate_conversion_factor, dimensions_match, parse_ucum_unit,
         };
-        use whippyunits_core::{
-            dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
-        };
+        use whippyunits_core::scale_exponents::ScaleExponents;
 
-        // Parse the target unit string using syn (same as deserialization)
+        // Parse the target unit string (same as deserialization)
         let target_dims = match parse_ucum_unit(unit) {
             Ok(dims) => dims,
             Err(_) => {
@@ -483,16 +677,7 @@ impl<
 
         // Get source dimensions and scales from const generics
         let source_dims = (
-            DynDimensionExponents([
-                MASS_EXPONENT,
-                LENGTH_EXPONENT,
-                TIME_EXPONENT,
-                CURRENT_EXPONENT,
-                TEMPERATURE_EXPONENT,
-                AMOUNT_EXPONENT,
-                LUMINOSITY_EXPONENT,
-                ANGLE_EXPONENT,
-            ]),
+            Self::dimension_exponents(),
             ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
         );
 
@@ -509,16 +694,8 @@ impl<
             };
         }
 
-        // Parse the target unit string to get UnitExpr for conversion factor calculation
-        use proc_macro2::TokenStream;
-        use syn::parse_str;
-        use whippyunits_core::{UnitExpr, calculate_unit_conversion_factors};
-
-        // Parse the target unit string into a UnitExpr
-        let target_unit_expr: UnitExpr = match (|| -> Result<UnitExpr, ()> {
-            let token_stream: TokenStream = parse_str(unit).map_err(|_| ())?;
-            syn::parse2(token_stream).map_err(|_| ())
-        })() {
+        // Parse the target unit string for conversion factor calculation
+        let target_unit_expr = match whippyunits_core::LiteUnitExpr::parse(unit) {
             Ok(expr) => expr,
             Err(_) => {
                 // Parse error - fall back to scale-only conversion
@@ -534,7 +711,7 @@ impl<
         };
 
         // Calculate nonstorage unit conversion factors (if any)
-        let (target_unit_cf, target_unit_af) = calculate_unit_conversion_factors(&target_unit_expr);
+        let (target_unit_cf, target_unit_af) = target_unit_expr.conversion_factors();
 
         // Calculate scale factor conversion (for storage unit scaling)
         let scale_conversion_factor = calculate_conversion_factor(&source_dims, &target_dims);
@@ -562,15 +739,324 @@ impl<
         }
     }
 
-    /// Get the source unit symbol for error messages (returns static string, no allocation)
+    /// Format this quantity in `unit`, like [fmt](Self::fmt), but report an unusable unit as an
+    /// error instead of writing an error message into the string.
+    ///
+    /// The value is rescaled into `unit` (the string counterpart of [value!](crate::value!)) and
+    /// followed by `unit` as written:
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// use whippyunits::serialization::SerializationError;
+    ///
+    /// assert_eq!(quantity!(5.0, m).to_string_in("km").unwrap(), "0.005 km");
+    /// assert!(matches!(
+    ///     quantity!(5.0, m).to_string_in("kg"),
+    ///     Err(SerializationError::DimensionMismatch { .. })
+    /// ));
+    /// ```
     #[cfg(feature = "serde")]
-    fn get_source_unit_symbol_static(&self) -> &'static str {
+    pub fn to_string_in(
+        &self,
+        unit: &str,
+    ) -> Result<crate::alloc::String, crate::serialization::SerializationError>
+    where
+        T: Copy + Into<f64>,
+    {
+        use crate::alloc::{String, ToString};
+        use crate::serialization::{SerializationError, validate_dimensions};
         use whippyunits_core::{
-            Dimension, dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
+            EvaluationMode, LiteUnitExpr, get_unit_info, scale_exponents::ScaleExponents,
         };
 
-        // Create the source dimensions and scales
-        let source_dimensions = DynDimensionExponents([
+        let expr = LiteUnitExpr::parse(unit)
+            .map_err(|_| SerializationError::UnknownUnitLiteral(String::from(unit)))?;
+        if let Some(unknown) = expr
+            .collect_unit_identifiers()
+            .into_iter()
+            .find(|name| get_unit_info(name).is_none())
+        {
+            return Err(SerializationError::UnknownUnit(String::from(unknown)));
+        }
+
+        let target = expr.evaluate_with_mode(EvaluationMode::Tolerant);
+        validate_dimensions(
+            &(
+                Self::dimension_exponents(),
+                ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
+            ),
+            &(target.dimension_exponents, target.scale_exponents),
+        )?;
+
+        Ok(self.fmt(unit).to_string())
+    }
+
+    /// The factor that converts this quantity's value to `unit`, or an explanation of why it
+    /// cannot be converted.
+    ///
+    /// Targets of the same dimension convert by a factor, whatever their scale.  Targets of a
+    /// different dimension are rejected with a message naming both dimensions, suitable for
+    /// showing to a user who typed the unit:
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let distance = quantity!(1.5, km);
+    /// assert_eq!(distance.explain_conversion("m"), Ok(1000.0));
+    /// assert_eq!(
+    ///     distance.explain_conversion("kg"),
+    ///     Err("cannot convert length to mass".to_string())
+    /// );
+    /// ```
+    ///
+    /// Unknown units, and units with an offset such as `degC` (which cannot be reached by a
+    /// factor alone), are also reported as errors.
+    #[cfg(feature = "alloc")]
+    pub fn explain_conversion(&self, unit: &str) -> Result<f64, crate::alloc::String> {
+        use crate::alloc::{String, format};
+        use crate::print::name_lookup::generate_systematic_unit_name;
+        use whippyunits_core::{Dimension, EvaluationMode, LiteUnitExpr, get_unit_info};
+
+        let describe = |dimension: DynDimensionExponents| -> String {
+            match Dimension::find_dimension_by_exponents(dimension) {
+                Some(named) => named.name.to_lowercase(),
+                None => {
+                    let name = generate_systematic_unit_name(dimension.0.to_vec(), false);
+                    format!(
+                        "{} quantities",
+                        name.trim_start_matches('(').trim_end_matches(')')
+                    )
+                }
+            }
+        };
+
+        let expr =
+            LiteUnitExpr::parse(unit).map_err(|_| format!("cannot parse unit `{}`", unit))?;
+        if let Some(unknown) = expr
+            .collect_unit_identifiers()
+            .into_iter()
+            .find(|name| get_unit_info(name).is_none())
+        {
+            return Err(format!("unknown unit `{}`", unknown));
+        }
+
+        let source = Self::dimension_exponents();
+        let target = expr.evaluate_with_mode(EvaluationMode::Tolerant);
+        if target.dimension_exponents != source {
+            return Err(format!(
+                "cannot convert {} to {}",
+                describe(source),
+                describe(target.dimension_exponents)
+            ));
+        }
+
+        let (conversion_factor, affine_offset) = expr.conversion_factors();
+        if affine_offset != 0.0 {
+            return Err(format!(
+                "cannot convert {} to `{}` by a factor: it has an offset",
+                describe(source),
+                unit
+            ));
+        }
+
+        let [p2, p3, p5, pi] = target.scale_exponents.0;
+        Ok(crate::api::aggregate_scale_factor_float(
+            SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, p2, p3, p5, pi,
+        ) / conversion_factor)
+    }
+
+    /// Format this quantity rounded to `sig_figs` significant figures, followed by its unit.
+    ///
+    /// Trailing zeros are kept, and very large or very small values switch to scientific
+    /// notation rather than padding with insignificant zeros:
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(0.001234, m).to_string_sigfigs(3), "0.00123 m");
+    /// assert_eq!(quantity!(12340.0, m).to_string_sigfigs(3), "1.23e4 m");
+    /// assert_eq!(quantity!(1.2, km).to_string_sigfigs(3), "1.20 km");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_string_sigfigs(&self, sig_figs: usize) -> crate::alloc::String
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        use crate::alloc::format;
+        use crate::print::prettyprint::format_sig_figs;
+        use crate::print::unit_literal_generator::{UnitLiteralConfig, generate_unit_literal};
+        use whippyunits_core::scale_exponents::ScaleExponents;
+
+        let value_f64 = <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64 for display");
+        let value = format_sig_figs(value_f64, sig_figs);
+
+        let unit_literal = generate_unit_literal(
+            Self::dimension_exponents(),
+            ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
+            UnitLiteralConfig {
+                verbose: false,
+                prefer_si_units: true,
+            },
+        );
+
+        if unit_literal.is_empty() {
+            value
+        } else {
+            format!("{} {}", value, unit_literal)
+        }
+    }
+
+    /// The base-10 order of magnitude of this quantity, i.e. `floor(log10(value))`, computed on
+    /// its value in coherent SI base units.
+    ///
+    /// Because the value is first expressed in coherent units, the result does not depend on the
+    /// quantity's scale: `1500 m` and `1.5 km` both have order 3.  Mass follows SI and uses the
+    /// kilogram as its coherent unit, so `1500 g` has order 0 (1.5 kg), not 3.
+    ///
+    /// Values within floating-point rounding error of a power of ten count as that power, so
+    /// `1e-98` has order -98 even though the nearest `f64` lies just below 10⁻⁹⁸.
+    ///
+    /// Returns `None` for zero, negative, or non-finite values, whose logarithm is undefined.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(1500.0, m).order_of_magnitude(), Some(3));
+    /// assert_eq!(quantity!(1.5, km).order_of_magnitude(), Some(3));
+    /// assert_eq!(quantity!(1500.0, g).order_of_magnitude(), Some(0));
+    /// assert_eq!(quantity!(0.0, m).order_of_magnitude(), None);
+    /// ```
+    pub fn order_of_magnitude(&self) -> Option<i32>
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        let value = <f64 as num_traits::NumCast>::from(self.unsafe_value)?
+            * crate::api::aggregate_scale_factor_float(
+                SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0,
+            );
+        if !(value > 0.0 && value.is_finite()) {
+            return None;
+        }
+
+        // A float written as a power of ten (e.g. `1e-98`) may sit an ulp below the true power,
+        // and log10 itself rounds; treat logarithms within rounding error of an integer as exact.
+        let log = libm::log10(value);
+        let nearest = libm::round(log);
+        if (log - nearest).abs() <= 4.0 * f64::EPSILON * nearest.abs().max(1.0) {
+            Some(nearest as i32)
+        } else {
+            Some(libm::floor(log) as i32)
+        }
+    }
+
+    /// This quantity as a mantissa and an SI prefix symbol, for displays that lay out the
+    /// number and unit themselves.
+    ///
+    /// The prefix applies to the metric base unit of the quantity's dimension (`m` for length,
+    /// `g` for mass, `J` for energy), and is the power-of-1000 prefix that brings the mantissa
+    /// into `[1, 1000)`, as in engineering notation.  Values beyond the largest or smallest
+    /// prefix keep a mantissa outside that range.
+    ///
+    /// Dimensions without a prefixable base unit (dimensionless quantities, unnamed derived
+    /// dimensions like velocity, and dimensions whose first unit is itself a multiple, like the
+    /// hectare) return the value in coherent SI units with an empty prefix, as do zero and
+    /// non-finite values.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(1500.0, m).magnitude_in_prefix(), (1.5, "k"));
+    /// assert_eq!(quantity!(0.25, mm).magnitude_in_prefix(), (250.0, "µ"));
+    /// assert_eq!(quantity!(2500.0, kg).magnitude_in_prefix(), (2.5, "M"));
+    /// assert_eq!(quantity!(12.0, m / s).magnitude_in_prefix(), (12.0, ""));
+    /// ```
+    pub fn magnitude_in_prefix(&self) -> (f64, &'static str)
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        use whippyunits_core::SiPrefix;
+
+        let value = <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64");
+        let Some(base_unit) = Self::prefixable_base_unit() else {
+            let coherent = crate::api::aggregate_scale_factor_float(
+                SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0,
+            );
+            return (value * coherent, "");
+        };
+
+        // Rescale straight from the stored scale to the prefixed unit's scale, so that exact
+        // prefix changes (0.25 mm as 250 µm) stay exact
+        let [p2, p3, p5, pi] = base_unit.scale.0;
+        let in_prefixed = |factor_log10: i16| {
+            let (to_p2, to_p5) = (p2 + factor_log10, p5 + factor_log10);
+            let factor = crate::api::aggregate_scale_factor_float(
+                SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, to_p2, p3, to_p5, pi,
+            );
+            // Divide by factors above one rather than multiplying by their inexact reciprocal
+            if factor < 1.0 {
+                value
+                    / crate::api::aggregate_scale_factor_float(
+                        to_p2, p3, to_p5, pi, SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI,
+                    )
+            } else {
+                value * factor
+            }
+        };
+        let magnitude = in_prefixed(0).abs();
+        if magnitude == 0.0 || !magnitude.is_finite() {
+            return (in_prefixed(0), "");
+        }
+
+        // Engineering prefixes (and no prefix) only; the largest not exceeding the value wins,
+        // falling back to the smallest for values below it
+        let candidates = || {
+            SiPrefix::ALL
+                .iter()
+                .filter(|prefix| prefix.factor_log10() % 3 == 0)
+                .map(|prefix| (prefix.factor_log10(), prefix.symbol()))
+                .chain([(0, "")])
+        };
+        let (factor_log10, symbol) = candidates()
+            .filter(|&(factor_log10, _)| libm::pow(10.0, factor_log10 as f64) <= magnitude)
+            .max_by_key(|&(factor_log10, _)| factor_log10)
+            .or_else(|| candidates().min_by_key(|&(factor_log10, _)| factor_log10))
+            .expect("SI prefixes are not empty");
+        (in_prefixed(factor_log10), symbol)
+    }
+
+    /// The SI prefix symbol of the unit this quantity is stored in, if its scale is exactly a
+    /// prefix of the metric base unit of its dimension.
+    ///
+    /// As for [magnitude_in_prefix](Self::magnitude_in_prefix), the prefix applies to the base
+    /// unit (`m` for length, `g` for mass), so kilograms give `"k"`.  A quantity stored in the
+    /// base unit itself gives `""`.  Scales that are not a power of ten matching a prefix, such as
+    /// minutes, and dimensions without a prefixable base unit give `None`.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(1.0, km).prefix_symbol(), Some("k"));
+    /// assert_eq!(quantity!(1.0, kg).prefix_symbol(), Some("k"));
+    /// assert_eq!(quantity!(1.0, s).prefix_symbol(), Some(""));
+    /// assert_eq!(quantity!(1.0, min).prefix_symbol(), None);
+    /// ```
+    pub fn prefix_symbol(&self) -> Option<&'static str> {
+        use whippyunits_core::SiPrefix;
+
+        let [p2, p3, p5, pi] = Self::prefixable_base_unit()?.scale.0;
+        let (factor_2, factor_5) = (SCALE_P2 - p2, SCALE_P5 - p5);
+        if factor_2 != factor_5 || SCALE_P3 != p3 || SCALE_PI != pi {
+            return None;
+        }
+        if factor_2 == 0 {
+            return Some("");
+        }
+        SiPrefix::ALL
+            .iter()
+            .find(|prefix| prefix.factor_log10() == factor_2)
+            .map(|prefix| prefix.symbol())
+    }
+
+    /// The dimension exponents of this quantity type.
+    pub(crate) const fn dimension_exponents() -> DynDimensionExponents {
+        DynDimensionExponents([
             MASS_EXPONENT,
             LENGTH_EXPONENT,
             TIME_EXPONENT,
@@ -579,84 +1065,2207 @@ impl<
             AMOUNT_EXPONENT,
             LUMINOSITY_EXPONENT,
             ANGLE_EXPONENT,
-        ]);
-        let source_scales = ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]);
+            INFORMATION_EXPONENT,
+        ])
+    }
 
-        // Try to find a matching unit
-        if let Some(dimension) = Dimension::find_dimension_by_exponents(source_dimensions) {
-            // Look for a unit with matching scales and conversion_factor = 1.0 (SI base units)
-            if let Some(unit) = dimension
-                .units
-                .iter()
-                .find(|unit| unit.scale == source_scales && unit.conversion_factor == 1.0)
-            {
-                return unit.symbols[0]; // Return &'static str directly
-            }
+    /// The unit that SI prefixes attach to for this quantity's dimension, if it has one.
+    ///
+    /// This is the first unit of the dimension, which for metric dimensions is the coherent unit
+    /// (or the gram or liter).
+    fn prefixable_base_unit() -> Option<&'static whippyunits_core::Unit> {
+        use whippyunits_core::{Dimension, System};
 
-            // If no exact match, try to find any unit in this dimension
-            if let Some(unit) = dimension.units.first() {
-                return unit.symbols[0]; // Return &'static str directly
-            }
-        }
+        let exponents = Self::dimension_exponents();
+        Dimension::find_dimension_by_exponents(exponents)
+            .filter(|_| exponents != DynDimensionExponents::ZERO)
+            .and_then(|dimension| dimension.units.first())
+            .filter(|unit| {
+                let [p2, p3, p5, pi] = unit.scale.0;
+                unit.system == System::Metric
+                    && unit.conversion_factor == 1.0
+                    && unit.affine_offset == 0.0
+                    && p2 <= 0
+                    && p5 <= 0
+                    && p3 == 0
+                    && pi == 0
+            })
+    }
 
-        // Fallback to dimension symbol if no unit found
-        if let Some(dimension) = Dimension::find_dimension_by_exponents(source_dimensions) {
-            return dimension.symbol; // Return &'static str directly
-        }
+    /// Format this quantity in fixed-width columns for tabular output.
+    ///
+    /// The value is right-aligned in `value_width` columns and the unit symbol left-aligned in
+    /// `unit_width` columns.  Values or units wider than their column are never truncated; they
+    /// overflow to the right instead.  A precision in the format string applies to the value.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(format!("{:.2}", quantity!(12.5, m).format_aligned(7, 4)), "  12.50 m   ");
+    /// assert_eq!(format!("{:.2}", quantity!(-3.0, km).format_aligned(7, 4)), "  -3.00 km  ");
+    /// assert_eq!(format!("{}", quantity!(1.0, mm).format_aligned(3, 2)), "  1 mm");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_aligned(
+        &self,
+        value_width: usize,
+        unit_width: usize,
+    ) -> crate::print::format_specifiers::AlignedFormatter
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        use crate::print::unit_literal_generator::{UnitLiteralConfig, generate_unit_literal};
+        use whippyunits_core::scale_exponents::ScaleExponents;
 
-        // Final fallback
-        "unknown unit"
+        let value = <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64 for display");
+        let unit = generate_unit_literal(
+            Self::dimension_exponents(),
+            ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
+            UnitLiteralConfig {
+                verbose: false,
+                prefer_si_units: true,
+            },
+        );
+
+        crate::print::format_specifiers::AlignedFormatter {
+            value,
+            unit,
+            value_width,
+            unit_width,
+        }
     }
-}
 
-/// A formatter for displaying quantities with unit conversion
-/// This is no-std compatible - uses &str instead of String
-#[cfg(feature = "serde")]
-#[doc(hidden)]
-pub struct QuantityFormatter<'a> {
-    value: f64,
-    unit: &'a str,
-    is_error: bool,
-    error_source_unit: Option<&'static str>, // Static strings from unit lookup
-}
+    /// List the symbols of every registered unit sharing this quantity's dimension.
+    ///
+    /// Units are listed in registry order, with SI-prefixable units (e.g. `m`, `g`, `s`) followed
+    /// by each of their prefixed forms.  Dimensions with several named units list all of them, so
+    /// energy yields both `J` and `Nm`.  Dimensions with no registered units yield an empty list.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let units = quantity!(1.0, m).compatible_units();
+    /// assert!(units.iter().any(|unit| unit == "km"));
+    /// assert!(units.iter().any(|unit| unit == "ft"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn compatible_units(&self) -> crate::alloc::Vec<crate::alloc::String> {
+        use crate::alloc::{String, ToString, Vec, format};
+        use whippyunits_core::{Dimension, SiPrefix, dimension_exponents::DimensionExponents};
 
-#[cfg(feature = "serde")]
-impl<'a> core::fmt::Display for QuantityFormatter<'a> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.is_error {
-            // Write error message directly to formatter (no allocation)
-            if let Some(source_unit) = self.error_source_unit {
-                write!(
-                    f,
-                    "Error: Dimension mismatch: cannot convert from {} to {}",
-                    source_unit, self.unit
-                )
-            } else {
-                write!(f, "Error: Failed to parse unit: {}", self.unit)
+        let Some(dimension) = Dimension::find_dimension_by_exponents(Self::dimension_exponents())
+        else {
+            return Vec::new();
+        };
+
+        let mut units: Vec<String> = Vec::new();
+        for unit in dimension.units {
+            let symbol = unit.symbols[0];
+            if !units.iter().any(|existing| existing == symbol) {
+                units.push(symbol.to_string());
             }
-        } else {
-            // Use the formatter's precision if specified, otherwise use default formatting
-            if let Some(precision) = f.precision() {
-                write!(
-                    f,
-                    "{:.precision$} {}",
-                    self.value,
-                    self.unit,
-                    precision = precision
-                )
-            } else {
-                write!(f, "{} {}", self.value, self.unit)
+            // Same criterion as `Dimension::find_si_unit_by_symbol`, which gates prefix parsing
+            if unit.exponents.as_basis().is_some() && !unit.has_conversion() {
+                units.extend(
+                    SiPrefix::ALL
+                        .iter()
+                        .map(|prefix| format!("{}{}", prefix.symbol(), symbol)),
+                );
             }
         }
+        units
     }
-}
-
-// from/into for dimensionless quantities
 
-// proper dimensionless quantities (all exponents are 0, scales irrelevant)
-#[doc(hidden)]
-macro_rules! define_from_dimensionless_cross_type {
-    ($source_type:ty, $target_type:ty) => {
+    /// Render this quantity's scale as its prime factorization and the decimal factor it
+    /// amounts to, for debugging unexpected conversions.
+    ///
+    /// The factor is relative to the coherent SI unit of the quantity's dimension, so a quantity
+    /// stored in minutes shows the 60 seconds per minute.  All four exponents are always shown,
+    /// including zero and negative ones.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(1.0, min).debug_scale(), "2^2 · 3^1 · 5^1 · π^0 = 60");
+    /// assert_eq!(quantity!(1.0, mm).debug_scale(), "2^-3 · 3^0 · 5^-3 · π^0 = 0.001");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn debug_scale(&self) -> crate::alloc::String {
+        let factor = crate::api::aggregate_scale_factor_float(
+            SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0,
+        );
+        crate::alloc::format!(
+            "2^{} · 3^{} · 5^{} · π^{} = {}",
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            factor
+        )
+    }
+
+    /// The name of the named derived unit with this quantity's dimension, if there is one.
+    ///
+    /// Only the dimension is compared, so the scale of the quantity does not matter: `mH` and
+    /// `kg·m²·s⁻²·A⁻²` are both inductances and simplify to `henry`.  Candidates are coherent
+    /// units (no scale or conversion factor) of registered derived dimensions; base quantities
+    /// such as length are not derived and yield `None`.  When several named units share a
+    /// dimension (e.g. joule and newton-meter for energy), the dimension's primary unit is chosen.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let inductance = quantity!(2.0, kg * m ^ 2 / (s ^ 2 * A ^ 2));
+    /// assert_eq!(inductance.simplified_name(), Some("henry"));
+    /// assert_eq!(quantity!(1.0, m / s).simplified_name(), None);
+    /// ```
+    pub fn simplified_name(&self) -> Option<&'static str> {
+        use whippyunits_core::{Dimension, scale_exponents::ScaleExponents};
+
+        let exponents = Self::dimension_exponents();
+        if Dimension::BASIS
+            .iter()
+            .any(|basis| basis.exponents == exponents)
+        {
+            return None;
+        }
+
+        // Registry order puts each dimension's primary unit first
+        Dimension::find_dimension_by_exponents(exponents)?
+            .units
+            .iter()
+            .find(|unit| {
+                unit.scale == ScaleExponents::IDENTITY
+                    && !unit.has_conversion()
+                    && !unit.has_affine_offset()
+            })
+            .map(|unit| unit.name)
+    }
+
+    /// Describe this quantity's dimension as a product of base dimensions, followed by the name
+    /// of the dimension they make up.
+    ///
+    /// Each base dimension is named with its exponent; those with positive exponents are
+    /// multiplied and those with negative exponents divided.  The result names the registered
+    /// dimension with these exponents, followed by its coherent unit's symbol when it has one:
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let power = quantity!(1.0, kg * m ^ 2 / s ^ 3);
+    /// assert_eq!(power.explain(), "mass × length² ÷ time³ = power (W)");
+    /// assert_eq!(quantity!(1.0, g).explain(), "mass = mass (kg)");
+    /// assert_eq!(quantity!(1.0, m / s).explain(), "length ÷ time = velocity");
+    /// ```
+    ///
+    /// Combinations that no registered dimension matches are reported as such:
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let jerk = quantity!(1.0, m / s ^ 3);
+    /// assert_eq!(jerk.explain(), "length ÷ time³ = no standard name");
+    /// ```
+    ///
+    /// Only the dimension is described, so the scale of the quantity does not matter.
+    #[cfg(feature = "alloc")]
+    pub fn explain(&self) -> crate::alloc::String {
+        use crate::alloc::{String, format};
+        use whippyunits_core::{
+            Dimension, scale_exponents::ScaleExponents, to_unicode_superscript,
+        };
+
+        let exponents = Self::dimension_exponents();
+        if exponents.0.iter().all(|&exponent| exponent == 0) {
+            return String::from("dimensionless");
+        }
+
+        let factor = |basis: &Dimension, exponent: i16| {
+            format!(
+                "{}{}",
+                basis.name.to_lowercase(),
+                to_unicode_superscript(exponent.unsigned_abs() as i16, false)
+            )
+        };
+        let mut breakdown = String::new();
+        for (basis, &exponent) in Dimension::BASIS.iter().zip(&exponents.0) {
+            if exponent > 0 {
+                if !breakdown.is_empty() {
+                    breakdown.push_str(" × ");
+                }
+                breakdown.push_str(&factor(basis, exponent));
+            }
+        }
+        if breakdown.is_empty() {
+            breakdown.push('1');
+        }
+        for (basis, &exponent) in Dimension::BASIS.iter().zip(&exponents.0) {
+            if exponent < 0 {
+                breakdown.push_str(" ÷ ");
+                breakdown.push_str(&factor(basis, exponent));
+            }
+        }
+
+        let Some(dimension) = Dimension::find_dimension_by_exponents(exponents) else {
+            return format!("{} = no standard name", breakdown);
+        };
+        let name = dimension.name.to_lowercase();
+        // Base dimensions always have a coherent unit, even where it is a prefixed one (kg)
+        let has_coherent_unit = Dimension::BASIS
+            .iter()
+            .any(|basis| basis.exponents == exponents)
+            || crate::print::name_lookup::lookup_dimension_name(exponents.0.to_vec())
+                .is_some_and(|names| names.unit_si_shortname_symbol.is_some());
+        if has_coherent_unit {
+            let unit = crate::print::unit_literal_generator::generate_unit_literal(
+                exponents,
+                ScaleExponents::IDENTITY,
+                crate::print::unit_literal_generator::UnitLiteralConfig {
+                    verbose: false,
+                    prefer_si_units: true,
+                },
+            );
+            format!("{} = {} ({})", breakdown, name, unit)
+        } else {
+            format!("{} = {}", breakdown, name)
+        }
+    }
+
+    /// The exponents of this quantity's scale, in the order `[2, 3, 5, π]`.
+    ///
+    /// The scale factor of the stored unit is `2^p2 · 3^p3 · 5^p5 · π^pi`, relative to the
+    /// coherent SI unit of the dimension.  See [`scale_p2`](Self::scale_p2) and its siblings
+    /// for the exponents individually.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// // 1 km = 2³ · 5³ m
+    /// assert_eq!(quantity!(1.0, km).scale_exponents(), [3, 0, 3, 0]);
+    /// ```
+    pub const fn scale_exponents(&self) -> [i16; 4] {
+        [SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]
+    }
+
+    /// The exponent of 2 in this quantity's scale.
+    pub const fn scale_p2(&self) -> i16 {
+        SCALE_P2
+    }
+
+    /// The exponent of 3 in this quantity's scale.
+    pub const fn scale_p3(&self) -> i16 {
+        SCALE_P3
+    }
+
+    /// The exponent of 5 in this quantity's scale.
+    pub const fn scale_p5(&self) -> i16 {
+        SCALE_P5
+    }
+
+    /// The exponent of π in this quantity's scale.
+    ///
+    /// This is nonzero for angle units defined as fractions of a turn, since the coherent angle
+    /// unit is the radian: a degree is `π / 180` radians, so its scale has a π exponent of `1`.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(90.0, deg).scale_pi(), 1);
+    /// assert_eq!(quantity!(1.5, rad).scale_pi(), 0);
+    /// ```
+    pub const fn scale_pi(&self) -> i16 {
+        SCALE_PI
+    }
+
+    /// Whether `Other` has the same dimension as this quantity type, regardless of scale.
+    ///
+    /// Both types' exponents are known at compile time, so this is a `const fn` and can be used
+    /// to branch in generic code or in constant evaluation.  Only the dimension is compared;
+    /// scale, storage type, and brand are ignored, so meters are commensurate with kilometers
+    /// and feet alike.
+    ///
+    /// ```rust
+    /// # use whippyunits::unit;
+    /// const LENGTH_IS_LENGTH: bool = <unit!(m)>::is_commensurate_with::<unit!(km)>();
+    /// assert!(LENGTH_IS_LENGTH);
+    /// assert!(!<unit!(m)>::is_commensurate_with::<unit!(kg)>());
+    /// ```
+    pub const fn is_commensurate_with<Other: CommensurabilityTarget>() -> bool {
+        let exponents = [
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+        ];
+        let mut i = 0;
+        while i < exponents.len() {
+            if exponents[i] != Other::DIMENSION_EXPONENTS[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// The relative difference `|self - other| / |other|` between two quantities of the same
+    /// dimension, as a plain ratio.
+    ///
+    /// `other` may have any scale; it is rescaled to this quantity's scale before comparing, so
+    /// the result does not depend on the units either quantity is stored in.  Signs are handled
+    /// through the absolute values, so the result is never negative.
+    ///
+    /// When `other` is zero the ratio is undefined and follows IEEE float division: infinite
+    /// if `self` is nonzero, and NaN if both are zero.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let measured = quantity!(1.02, m);
+    /// assert!((measured.relative_difference(&quantity!(100.0, cm)) - 0.02).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either value cannot be converted to `f64`.
+    pub fn relative_difference<
+        const OTHER_SCALE_P2: i16,
+        const OTHER_SCALE_P3: i16,
+        const OTHER_SCALE_P5: i16,
+        const OTHER_SCALE_PI: i16,
+    >(
+        &self,
+        other: &QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            OTHER_SCALE_P2,
+            OTHER_SCALE_P3,
+            OTHER_SCALE_P5,
+            OTHER_SCALE_PI,
+            T,
+            Brand,
+        >,
+    ) -> f64
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        let to_f64 = |value: T| {
+            <f64 as num_traits::NumCast>::from(value)
+                .expect("unable to convert numeric value to f64 for comparison")
+        };
+        let value = to_f64(self.unsafe_value);
+        let other_value = to_f64(other.unsafe_value)
+            * crate::api::aggregate_scale_factor_float(
+                OTHER_SCALE_P2,
+                OTHER_SCALE_P3,
+                OTHER_SCALE_P5,
+                OTHER_SCALE_PI,
+                SCALE_P2,
+                SCALE_P3,
+                SCALE_P5,
+                SCALE_PI,
+            );
+        (value - other_value).abs() / other_value.abs()
+    }
+
+    /// This quantity as a fraction of `reference`, a quantity of the same dimension.
+    ///
+    /// This is `ratio` on the values converted to `f64`, so `reference` may have any scale, and
+    /// integer quantities give exact fractions rather than truncated ones.  The result is not
+    /// clamped, so quantities beyond the reference give fractions above one and negative
+    /// quantities give negative fractions; clamp the result with [f64::clamp] for uses such as
+    /// progress bars.
+    ///
+    /// When `reference` is zero the fraction is undefined and follows IEEE float division:
+    /// infinite (with the sign of `self`) if `self` is nonzero, and NaN if both are zero.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(500.0, m).fraction_of(&quantity!(2.0, km)), 0.25);
+    /// assert_eq!(quantity!(3.0, km).fraction_of(&quantity!(2.0, km)).clamp(0.0, 1.0), 1.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either value cannot be converted to `f64`.
+    pub fn fraction_of<
+        const OTHER_SCALE_P2: i16,
+        const OTHER_SCALE_P3: i16,
+        const OTHER_SCALE_P5: i16,
+        const OTHER_SCALE_PI: i16,
+    >(
+        &self,
+        reference: &QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            OTHER_SCALE_P2,
+            OTHER_SCALE_P3,
+            OTHER_SCALE_P5,
+            OTHER_SCALE_PI,
+            T,
+            Brand,
+        >,
+    ) -> f64
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        let to_f64 = |value: T| {
+            <f64 as num_traits::NumCast>::from(value)
+                .expect("unable to convert numeric value to f64 for division")
+        };
+        let value = QuantityOf::<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            f64,
+            Brand,
+        >::new(to_f64(self.unsafe_value));
+        let reference = QuantityOf::<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            OTHER_SCALE_P2,
+            OTHER_SCALE_P3,
+            OTHER_SCALE_P5,
+            OTHER_SCALE_PI,
+            f64,
+            Brand,
+        >::new(to_f64(reference.unsafe_value));
+        value.ratio(reference).unsafe_value
+    }
+
+    /// This quantity as a percentage of `reference`, i.e. [`fraction_of`](Self::fraction_of)
+    /// times 100.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(500.0, m).percent_of(&quantity!(2.0, km)), 25.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either value cannot be converted to `f64`.
+    pub fn percent_of<
+        const OTHER_SCALE_P2: i16,
+        const OTHER_SCALE_P3: i16,
+        const OTHER_SCALE_P5: i16,
+        const OTHER_SCALE_PI: i16,
+    >(
+        &self,
+        reference: &QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            OTHER_SCALE_P2,
+            OTHER_SCALE_P3,
+            OTHER_SCALE_P5,
+            OTHER_SCALE_PI,
+            T,
+            Brand,
+        >,
+    ) -> f64
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        self.fraction_of(reference) * 100.0
+    }
+
+    /// The absolute value of this quantity, keeping its unit.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(-3.5, m).abs(), quantity!(3.5, m));
+    /// ```
+    ///
+    /// For integer storage, the absolute value of the minimum value overflows, as with the
+    /// primitive `abs`.
+    pub fn abs(self) -> Self
+    where
+        T: num_traits::Signed,
+    {
+        Quantity::new(self.unsafe_value.abs())
+    }
+
+    /// Whether this quantity's value is neither infinite nor NaN.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert!(quantity!(1.5, m).is_finite());
+    /// assert!(!quantity!(f64::INFINITY, m).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool
+    where
+        T: num_traits::Float,
+    {
+        self.unsafe_value.is_finite()
+    }
+
+    /// Whether this quantity's value is NaN.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert!(quantity!(f64::NAN, m).is_nan());
+    /// assert!(!quantity!(1.5, m).is_nan());
+    /// ```
+    pub fn is_nan(&self) -> bool
+    where
+        T: num_traits::Float,
+    {
+        self.unsafe_value.is_nan()
+    }
+
+    /// Whether this quantity's value is positive or negative infinity.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert!(quantity!(f64::NEG_INFINITY, m).is_infinite());
+    /// assert!(!quantity!(f64::NAN, m).is_infinite());
+    /// ```
+    pub fn is_infinite(&self) -> bool
+    where
+        T: num_traits::Float,
+    {
+        self.unsafe_value.is_infinite()
+    }
+
+    /// The sign of this quantity, as a dimensionless quantity.
+    ///
+    /// The value is `1` for positive quantities and `-1` for negative ones.  Zero gives `0` for
+    /// integer storage; for float storage, zero keeps its sign (`1.0` or `-1.0`) and NaN gives
+    /// NaN, as with the primitive `signum`.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let sign = quantity!(-3.5, m).signum();
+    /// assert_eq!(sign, quantity!(-1.0, 1));
+    /// ```
+    pub fn signum(self) -> QuantityOf<0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, T, Brand>
+    where
+        T: num_traits::Signed,
+    {
+        Quantity::new(self.unsafe_value.signum())
+    }
+
+    /// The lesser of this quantity and `other`, which must have the same unit.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(3.0, m).min(quantity!(2.0, m)), quantity!(2.0, m));
+    /// ```
+    ///
+    /// If the two cannot be compared (one of them is NaN), this quantity is returned.
+    pub fn min(self, other: Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        if other.unsafe_value < self.unsafe_value {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The greater of this quantity and `other`, which must have the same unit.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(3.0, m).max(quantity!(2.0, m)), quantity!(3.0, m));
+    /// ```
+    ///
+    /// If the two cannot be compared (one of them is NaN), this quantity is returned.
+    pub fn max(self, other: Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        if other.unsafe_value > self.unsafe_value {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restrict this quantity to the range `[lo, hi]`, where the bounds have the same unit.
+    ///
+    /// Quantities inside the range are returned unchanged; a NaN quantity stays NaN.  Unlike
+    /// [new_bounded](Self::new_bounded), the bounds are not rescaled, so they must be given in
+    /// exactly this quantity's unit.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let lo = quantity!(0.0, m);
+    /// let hi = quantity!(10.0, m);
+    /// assert_eq!(quantity!(12.0, m).clamp(lo, hi), hi);
+    /// assert_eq!(quantity!(-1.0, m).clamp(lo, hi), lo);
+    /// assert_eq!(quantity!(4.0, m).clamp(lo, hi), quantity!(4.0, m));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`, naming the dimension of the quantity in the message.
+    pub fn clamp(self, lo: Self, hi: Self) -> Self
+    where
+        T: PartialOrd + num_traits::NumCast,
+    {
+        if lo.unsafe_value > hi.unsafe_value {
+            use whippyunits_core::Dimension;
+
+            let dimension = Self::dimension_exponents();
+            let lo = <f64 as num_traits::NumCast>::from(lo.unsafe_value).unwrap_or(f64::NAN);
+            let hi = <f64 as num_traits::NumCast>::from(hi.unsafe_value).unwrap_or(f64::NAN);
+            match Dimension::find_dimension_by_exponents(dimension) {
+                Some(named) => panic!(
+                    "cannot clamp {} quantity: lower bound {:?} is greater than upper bound {:?}",
+                    named.name, lo, hi
+                ),
+                None => panic!(
+                    "cannot clamp quantity of dimension {:?}: lower bound {:?} is greater than upper bound {:?}",
+                    dimension.0, lo, hi
+                ),
+            }
+        }
+
+        if self.unsafe_value < lo.unsafe_value {
+            lo
+        } else if self.unsafe_value > hi.unsafe_value {
+            hi
+        } else {
+            self
+        }
+    }
+
+    /// Linearly interpolate from this quantity to `other`, which must have the same unit.
+    ///
+    /// `t = 0` gives this quantity and `t = 1` gives `other`.  `t` is not clamped: values
+    /// outside `[0, 1]` extrapolate along the same line, so `t = 2` gives `2 * other - self`.
+    /// Clamp `t` first (or the result with [clamp](Self::clamp)) to stay between the endpoints.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let start = quantity!(0.0, m);
+    /// let end = quantity!(10.0, m);
+    /// assert_eq!(start.lerp(end, 0.25), quantity!(2.5, m));
+    /// assert_eq!(start.lerp(end, 1.5), quantity!(15.0, m));
+    /// ```
+    ///
+    /// Only float storage is supported, since intermediate values are generally fractional.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t` cannot be converted to the storage type.
+    pub fn lerp(self, other: Self, t: f64) -> Self
+    where
+        T: num_traits::Float,
+    {
+        let t = <T as num_traits::NumCast>::from(t)
+            .expect("unable to convert interpolation parameter to storage type");
+        Quantity::new(self.unsafe_value + (other.unsafe_value - self.unsafe_value) * t)
+    }
+
+    /// The square root of this quantity, halving every dimension and scale exponent.
+    ///
+    /// Only quantities whose exponents are all even have a square root with whole exponents,
+    /// so an area yields a length and `m²/s²` yields a velocity.  Odd exponents are rejected at
+    /// compile time rather than rounded.  Since the scale is halved along with the dimension,
+    /// the stored value is simply square-rooted: `4 km²` becomes `2 km`.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, unit};
+    /// let side: unit!(m) = quantity!(25.0, m ^ 2).sqrt();
+    /// assert_eq!(side, quantity!(5.0, m));
+    ///
+    /// let speed: unit!(m / s) = quantity!(4.0, m ^ 2 / s ^ 2).sqrt();
+    /// assert_eq!(speed, quantity!(2.0, m / s));
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use whippyunits::quantity;
+    /// let _ = quantity!(8.0, m ^ 3).sqrt();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit the storage type `T`, such as the square root of a
+    /// negative value with integer storage.  With float storage a negative value yields NaN.
+    #[cfg(not(has_generic_const_exprs))]
+    pub fn sqrt(
+        self,
+    ) -> <RootsOf<
+        2,
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    > as QuantityExponents<T, Brand>>::Quantity
+    where
+        T: Copy + num_traits::NumCast,
+        N<MASS_EXPONENT>: Root<2>,
+        N<LENGTH_EXPONENT>: Root<2>,
+        N<TIME_EXPONENT>: Root<2>,
+        N<CURRENT_EXPONENT>: Root<2>,
+        N<TEMPERATURE_EXPONENT>: Root<2>,
+        N<AMOUNT_EXPONENT>: Root<2>,
+        N<LUMINOSITY_EXPONENT>: Root<2>,
+        N<ANGLE_EXPONENT>: Root<2>,
+        N<INFORMATION_EXPONENT>: Root<2>,
+        N<SCALE_P2>: Root<2>,
+        N<SCALE_P3>: Root<2>,
+        N<SCALE_P5>: Root<2>,
+        N<SCALE_PI>: Root<2>,
+        RootsOf<
+            2,
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        >: QuantityExponents<T, Brand>,
+    {
+        self.nth_root::<2>()
+    }
+
+    /// The square root of this quantity, halving every dimension and scale exponent.
+    ///
+    /// See the stable-Rust documentation of this method; with `generic_const_exprs` the halved
+    /// exponents are computed directly rather than looked up.
+    #[cfg(has_generic_const_exprs)]
+    pub fn sqrt(
+        self,
+    ) -> QuantityOf<
+        { root_exponent(MASS_EXPONENT, 2) },
+        { root_exponent(LENGTH_EXPONENT, 2) },
+        { root_exponent(TIME_EXPONENT, 2) },
+        { root_exponent(CURRENT_EXPONENT, 2) },
+        { root_exponent(TEMPERATURE_EXPONENT, 2) },
+        { root_exponent(AMOUNT_EXPONENT, 2) },
+        { root_exponent(LUMINOSITY_EXPONENT, 2) },
+        { root_exponent(ANGLE_EXPONENT, 2) },
+        { root_exponent(INFORMATION_EXPONENT, 2) },
+        { root_exponent(SCALE_P2, 2) },
+        { root_exponent(SCALE_P3, 2) },
+        { root_exponent(SCALE_P5, 2) },
+        { root_exponent(SCALE_PI, 2) },
+        T,
+        Brand,
+    >
+    where
+        T: Copy + num_traits::NumCast,
+        N<MASS_EXPONENT>: Root<2>,
+        N<LENGTH_EXPONENT>: Root<2>,
+        N<TIME_EXPONENT>: Root<2>,
+        N<CURRENT_EXPONENT>: Root<2>,
+        N<TEMPERATURE_EXPONENT>: Root<2>,
+        N<AMOUNT_EXPONENT>: Root<2>,
+        N<LUMINOSITY_EXPONENT>: Root<2>,
+        N<ANGLE_EXPONENT>: Root<2>,
+        N<INFORMATION_EXPONENT>: Root<2>,
+        N<SCALE_P2>: Root<2>,
+        N<SCALE_P3>: Root<2>,
+        N<SCALE_P5>: Root<2>,
+        N<SCALE_PI>: Root<2>,
+        (): crate::IsI16<{ root_exponent(MASS_EXPONENT, 2) }>,
+        (): crate::IsI16<{ root_exponent(LENGTH_EXPONENT, 2) }>,
+        (): crate::IsI16<{ root_exponent(TIME_EXPONENT, 2) }>,
+        (): crate::IsI16<{ root_exponent(CURRENT_EXPONENT, 2) }>,
+        (): crate::IsI16<{ root_exponent(TEMPERATURE_EXPONENT, 2) }>,
+        (): crate::IsI16<{ root_exponent(AMOUNT_EXPONENT, 2) }>,
+        (): crate::IsI16<{ root_exponent(LUMINOSITY_EXPONENT, 2) }>,
+        (): crate::IsI16<{ root_exponent(ANGLE_EXPONENT, 2) }>,
+        (): crate::IsI16<{ root_exponent(INFORMATION_EXPONENT, 2) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_P2, 2) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_P3, 2) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_P5, 2) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_PI, 2) }>,
+    {
+        self.nth_root::<2>()
+    }
+
+    /// The cube root of this quantity, dividing every dimension and scale exponent by 3.
+    ///
+    /// Only quantities whose exponents are all multiples of 3 have a cube root with whole
+    /// exponents, so a volume yields a length.  Other exponents are rejected at compile time
+    /// rather than rounded.  As with [`sqrt`](Self::sqrt), the scale is divided along with the
+    /// dimension, so the stored value is simply cube-rooted.  Negative values have a negative
+    /// cube root.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, unit};
+    /// let side: unit!(m) = quantity!(8.0, m ^ 3).cbrt();
+    /// assert_eq!(side, quantity!(2.0, m));
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use whippyunits::quantity;
+    /// let _ = quantity!(4.0, m ^ 2).cbrt();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit the storage type `T`.
+    #[cfg(not(has_generic_const_exprs))]
+    pub fn cbrt(
+        self,
+    ) -> <RootsOf<
+        3,
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    > as QuantityExponents<T, Brand>>::Quantity
+    where
+        T: Copy + num_traits::NumCast,
+        N<MASS_EXPONENT>: Root<3>,
+        N<LENGTH_EXPONENT>: Root<3>,
+        N<TIME_EXPONENT>: Root<3>,
+        N<CURRENT_EXPONENT>: Root<3>,
+        N<TEMPERATURE_EXPONENT>: Root<3>,
+        N<AMOUNT_EXPONENT>: Root<3>,
+        N<LUMINOSITY_EXPONENT>: Root<3>,
+        N<ANGLE_EXPONENT>: Root<3>,
+        N<INFORMATION_EXPONENT>: Root<3>,
+        N<SCALE_P2>: Root<3>,
+        N<SCALE_P3>: Root<3>,
+        N<SCALE_P5>: Root<3>,
+        N<SCALE_PI>: Root<3>,
+        RootsOf<
+            3,
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        >: QuantityExponents<T, Brand>,
+    {
+        self.nth_root::<3>()
+    }
+
+    /// The cube root of this quantity, dividing every dimension and scale exponent by 3.
+    ///
+    /// See the stable-Rust documentation of this method; with `generic_const_exprs` the divided
+    /// exponents are computed directly rather than looked up.
+    #[cfg(has_generic_const_exprs)]
+    pub fn cbrt(
+        self,
+    ) -> QuantityOf<
+        { root_exponent(MASS_EXPONENT, 3) },
+        { root_exponent(LENGTH_EXPONENT, 3) },
+        { root_exponent(TIME_EXPONENT, 3) },
+        { root_exponent(CURRENT_EXPONENT, 3) },
+        { root_exponent(TEMPERATURE_EXPONENT, 3) },
+        { root_exponent(AMOUNT_EXPONENT, 3) },
+        { root_exponent(LUMINOSITY_EXPONENT, 3) },
+        { root_exponent(ANGLE_EXPONENT, 3) },
+        { root_exponent(INFORMATION_EXPONENT, 3) },
+        { root_exponent(SCALE_P2, 3) },
+        { root_exponent(SCALE_P3, 3) },
+        { root_exponent(SCALE_P5, 3) },
+        { root_exponent(SCALE_PI, 3) },
+        T,
+        Brand,
+    >
+    where
+        T: Copy + num_traits::NumCast,
+        N<MASS_EXPONENT>: Root<3>,
+        N<LENGTH_EXPONENT>: Root<3>,
+        N<TIME_EXPONENT>: Root<3>,
+        N<CURRENT_EXPONENT>: Root<3>,
+        N<TEMPERATURE_EXPONENT>: Root<3>,
+        N<AMOUNT_EXPONENT>: Root<3>,
+        N<LUMINOSITY_EXPONENT>: Root<3>,
+        N<ANGLE_EXPONENT>: Root<3>,
+        N<INFORMATION_EXPONENT>: Root<3>,
+        N<SCALE_P2>: Root<3>,
+        N<SCALE_P3>: Root<3>,
+        N<SCALE_P5>: Root<3>,
+        N<SCALE_PI>: Root<3>,
+        (): crate::IsI16<{ root_exponent(MASS_EXPONENT, 3) }>,
+        (): crate::IsI16<{ root_exponent(LENGTH_EXPONENT, 3) }>,
+        (): crate::IsI16<{ root_exponent(TIME_EXPONENT, 3) }>,
+        (): crate::IsI16<{ root_exponent(CURRENT_EXPONENT, 3) }>,
+        (): crate::IsI16<{ root_exponent(TEMPERATURE_EXPONENT, 3) }>,
+        (): crate::IsI16<{ root_exponent(AMOUNT_EXPONENT, 3) }>,
+        (): crate::IsI16<{ root_exponent(LUMINOSITY_EXPONENT, 3) }>,
+        (): crate::IsI16<{ root_exponent(ANGLE_EXPONENT, 3) }>,
+        (): crate::IsI16<{ root_exponent(INFORMATION_EXPONENT, 3) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_P2, 3) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_P3, 3) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_P5, 3) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_PI, 3) }>,
+    {
+        self.nth_root::<3>()
+    }
+
+    /// The `DEGREE`th root of this quantity, dividing every dimension and scale exponent by
+    /// `DEGREE`.
+    ///
+    /// Generalizes [`sqrt`](Self::sqrt) and [`cbrt`](Self::cbrt): every exponent must be
+    /// divisible by `DEGREE`, or the call is rejected at compile time.  For odd degrees, negative
+    /// values have a negative root; for even degrees they yield NaN.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, unit};
+    /// let side: unit!(m) = quantity!(16.0, m ^ 4).nth_root::<4>();
+    /// assert_eq!(side, quantity!(2.0, m));
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use whippyunits::quantity;
+    /// let _ = quantity!(16.0, m ^ 4).nth_root::<3>();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit the storage type `T`.
+    #[cfg(not(has_generic_const_exprs))]
+    pub fn nth_root<const DEGREE: i16>(
+        self,
+    ) -> <RootsOf<
+        DEGREE,
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    > as QuantityExponents<T, Brand>>::Quantity
+    where
+        T: Copy + num_traits::NumCast,
+        N<MASS_EXPONENT>: Root<DEGREE>,
+        N<LENGTH_EXPONENT>: Root<DEGREE>,
+        N<TIME_EXPONENT>: Root<DEGREE>,
+        N<CURRENT_EXPONENT>: Root<DEGREE>,
+        N<TEMPERATURE_EXPONENT>: Root<DEGREE>,
+        N<AMOUNT_EXPONENT>: Root<DEGREE>,
+        N<LUMINOSITY_EXPONENT>: Root<DEGREE>,
+        N<ANGLE_EXPONENT>: Root<DEGREE>,
+        N<INFORMATION_EXPONENT>: Root<DEGREE>,
+        N<SCALE_P2>: Root<DEGREE>,
+        N<SCALE_P3>: Root<DEGREE>,
+        N<SCALE_P5>: Root<DEGREE>,
+        N<SCALE_PI>: Root<DEGREE>,
+        RootsOf<
+            DEGREE,
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        >: QuantityExponents<T, Brand>,
+    {
+        <RootsOf<
+            DEGREE,
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        > as QuantityExponents<T, Brand>>::from_value(root_value(self.unsafe_value, DEGREE))
+    }
+
+    /// The `DEGREE`th root of this quantity, dividing every dimension and scale exponent by
+    /// `DEGREE`.
+    ///
+    /// See the stable-Rust documentation of this method; with `generic_const_exprs` the divided
+    /// exponents are computed directly rather than looked up.
+    #[cfg(has_generic_const_exprs)]
+    pub fn nth_root<const DEGREE: i16>(
+        self,
+    ) -> QuantityOf<
+        { root_exponent(MASS_EXPONENT, DEGREE) },
+        { root_exponent(LENGTH_EXPONENT, DEGREE) },
+        { root_exponent(TIME_EXPONENT, DEGREE) },
+        { root_exponent(CURRENT_EXPONENT, DEGREE) },
+        { root_exponent(TEMPERATURE_EXPONENT, DEGREE) },
+        { root_exponent(AMOUNT_EXPONENT, DEGREE) },
+        { root_exponent(LUMINOSITY_EXPONENT, DEGREE) },
+        { root_exponent(ANGLE_EXPONENT, DEGREE) },
+        { root_exponent(INFORMATION_EXPONENT, DEGREE) },
+        { root_exponent(SCALE_P2, DEGREE) },
+        { root_exponent(SCALE_P3, DEGREE) },
+        { root_exponent(SCALE_P5, DEGREE) },
+        { root_exponent(SCALE_PI, DEGREE) },
+        T,
+        Brand,
+    >
+    where
+        T: Copy + num_traits::NumCast,
+        N<MASS_EXPONENT>: Root<DEGREE>,
+        N<LENGTH_EXPONENT>: Root<DEGREE>,
+        N<TIME_EXPONENT>: Root<DEGREE>,
+        N<CURRENT_EXPONENT>: Root<DEGREE>,
+        N<TEMPERATURE_EXPONENT>: Root<DEGREE>,
+        N<AMOUNT_EXPONENT>: Root<DEGREE>,
+        N<LUMINOSITY_EXPONENT>: Root<DEGREE>,
+        N<ANGLE_EXPONENT>: Root<DEGREE>,
+        N<INFORMATION_EXPONENT>: Root<DEGREE>,
+        N<SCALE_P2>: Root<DEGREE>,
+        N<SCALE_P3>: Root<DEGREE>,
+        N<SCALE_P5>: Root<DEGREE>,
+        N<SCALE_PI>: Root<DEGREE>,
+        (): crate::IsI16<{ root_exponent(MASS_EXPONENT, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(LENGTH_EXPONENT, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(TIME_EXPONENT, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(CURRENT_EXPONENT, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(TEMPERATURE_EXPONENT, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(AMOUNT_EXPONENT, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(LUMINOSITY_EXPONENT, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(ANGLE_EXPONENT, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(INFORMATION_EXPONENT, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_P2, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_P3, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_P5, DEGREE) }>,
+        (): crate::IsI16<{ root_exponent(SCALE_PI, DEGREE) }>,
+    {
+        Quantity::new(root_value(self.unsafe_value, DEGREE))
+    }
+
+    /// This quantity raised to the `POWER`th power, multiplying every dimension and scale
+    /// exponent by `POWER`.
+    ///
+    /// The inverse of [`nth_root`](Self::nth_root).  A negative power gives the reciprocal unit,
+    /// so a duration to the power `-1` is a frequency, and the zeroth power of any quantity is a
+    /// dimensionless `1`.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, unit};
+    /// let speed_squared: unit!(m ^ 2 / s ^ 2) = quantity!(3.0, m / s).pow::<2>();
+    /// assert_eq!(speed_squared, quantity!(9.0, m ^ 2 / s ^ 2));
+    ///
+    /// let frequency: unit!(Hz) = quantity!(0.5, s).pow::<-1>();
+    /// assert_eq!(frequency, quantity!(2.0, Hz));
+    ///
+    /// let one: unit!(1) = quantity!(3.0, m / s).pow::<0>();
+    /// assert_eq!(one, quantity!(1.0, 1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit the storage type `T`.  For integer storage, a
+    /// fractional result (from a negative power) is truncated toward zero.
+    #[cfg(not(has_generic_const_exprs))]
+    pub fn pow<const POWER: i16>(
+        self,
+    ) -> <PowersOf<
+        POWER,
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    > as QuantityExponents<T, Brand>>::Quantity
+    where
+        T: Copy + num_traits::NumCast,
+        N<MASS_EXPONENT>: Pow<POWER>,
+        N<LENGTH_EXPONENT>: Pow<POWER>,
+        N<TIME_EXPONENT>: Pow<POWER>,
+        N<CURRENT_EXPONENT>: Pow<POWER>,
+        N<TEMPERATURE_EXPONENT>: Pow<POWER>,
+        N<AMOUNT_EXPONENT>: Pow<POWER>,
+        N<LUMINOSITY_EXPONENT>: Pow<POWER>,
+        N<ANGLE_EXPONENT>: Pow<POWER>,
+        N<INFORMATION_EXPONENT>: Pow<POWER>,
+        N<SCALE_P2>: Pow<POWER>,
+        N<SCALE_P3>: Pow<POWER>,
+        N<SCALE_P5>: Pow<POWER>,
+        N<SCALE_PI>: Pow<POWER>,
+        PowersOf<
+            POWER,
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        >: QuantityExponents<T, Brand>,
+    {
+        let value = <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64 for power");
+        <PowersOf<
+            POWER,
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        > as QuantityExponents<T, Brand>>::from_value(
+            <T as num_traits::NumCast>::from(libm::pow(value, POWER as f64))
+                .expect("power does not fit the storage type"),
+        )
+    }
+
+    /// This quantity raised to the `POWER`th power, multiplying every dimension and scale
+    /// exponent by `POWER`.
+    ///
+    /// See the stable-Rust documentation of this method; with `generic_const_exprs` the
+    /// multiplied exponents are computed directly rather than looked up.
+    #[cfg(has_generic_const_exprs)]
+    pub fn pow<const POWER: i16>(
+        self,
+    ) -> QuantityOf<
+        { power_exponent(MASS_EXPONENT, POWER) },
+        { power_exponent(LENGTH_EXPONENT, POWER) },
+        { power_exponent(TIME_EXPONENT, POWER) },
+        { power_exponent(CURRENT_EXPONENT, POWER) },
+        { power_exponent(TEMPERATURE_EXPONENT, POWER) },
+        { power_exponent(AMOUNT_EXPONENT, POWER) },
+        { power_exponent(LUMINOSITY_EXPONENT, POWER) },
+        { power_exponent(ANGLE_EXPONENT, POWER) },
+        { power_exponent(INFORMATION_EXPONENT, POWER) },
+        { power_exponent(SCALE_P2, POWER) },
+        { power_exponent(SCALE_P3, POWER) },
+        { power_exponent(SCALE_P5, POWER) },
+        { power_exponent(SCALE_PI, POWER) },
+        T,
+        Brand,
+    >
+    where
+        T: Copy + num_traits::NumCast,
+        N<MASS_EXPONENT>: Pow<POWER>,
+        N<LENGTH_EXPONENT>: Pow<POWER>,
+        N<TIME_EXPONENT>: Pow<POWER>,
+        N<CURRENT_EXPONENT>: Pow<POWER>,
+        N<TEMPERATURE_EXPONENT>: Pow<POWER>,
+        N<AMOUNT_EXPONENT>: Pow<POWER>,
+        N<LUMINOSITY_EXPONENT>: Pow<POWER>,
+        N<ANGLE_EXPONENT>: Pow<POWER>,
+        N<INFORMATION_EXPONENT>: Pow<POWER>,
+        N<SCALE_P2>: Pow<POWER>,
+        N<SCALE_P3>: Pow<POWER>,
+        N<SCALE_P5>: Pow<POWER>,
+        N<SCALE_PI>: Pow<POWER>,
+        (): crate::IsI16<{ power_exponent(MASS_EXPONENT, POWER) }>,
+        (): crate::IsI16<{ power_exponent(LENGTH_EXPONENT, POWER) }>,
+        (): crate::IsI16<{ power_exponent(TIME_EXPONENT, POWER) }>,
+        (): crate::IsI16<{ power_exponent(CURRENT_EXPONENT, POWER) }>,
+        (): crate::IsI16<{ power_exponent(TEMPERATURE_EXPONENT, POWER) }>,
+        (): crate::IsI16<{ power_exponent(AMOUNT_EXPONENT, POWER) }>,
+        (): crate::IsI16<{ power_exponent(LUMINOSITY_EXPONENT, POWER) }>,
+        (): crate::IsI16<{ power_exponent(ANGLE_EXPONENT, POWER) }>,
+        (): crate::IsI16<{ power_exponent(INFORMATION_EXPONENT, POWER) }>,
+        (): crate::IsI16<{ power_exponent(SCALE_P2, POWER) }>,
+        (): crate::IsI16<{ power_exponent(SCALE_P3, POWER) }>,
+        (): crate::IsI16<{ power_exponent(SCALE_P5, POWER) }>,
+        (): crate::IsI16<{ power_exponent(SCALE_PI, POWER) }>,
+    {
+        let value = <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64 for power");
+        Quantity::new(
+            <T as num_traits::NumCast>::from(libm::pow(value, POWER as f64))
+                .expect("power does not fit the storage type"),
+        )
+    }
+
+    /// The reciprocal of this quantity, negating every dimension and scale exponent.
+    ///
+    /// Equivalent to [`pow::<-1>`](Self::pow), but computed as `1 / value` directly: a period
+    /// becomes a frequency and a velocity becomes a pace.  Only float storage is supported,
+    /// since the reciprocal of an integer quantity is almost never a whole number.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, unit};
+    /// let frequency: unit!(s ^ -1) = quantity!(2.0, s).recip();
+    /// assert_eq!(frequency, quantity!(0.5, s ^ -1));
+    ///
+    /// let pace: unit!(s / m) = quantity!(4.0, m / s).recip();
+    /// assert_eq!(pace, quantity!(0.25, s / m));
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use whippyunits::quantity;
+    /// let _ = quantity!(2, s, i32).recip();
+    /// ```
+    #[cfg(not(has_generic_const_exprs))]
+    pub fn recip(
+        self,
+    ) -> <PowersOf<
+        -1,
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    > as QuantityExponents<T, Brand>>::Quantity
+    where
+        T: num_traits::Float,
+        N<MASS_EXPONENT>: Pow<-1>,
+        N<LENGTH_EXPONENT>: Pow<-1>,
+        N<TIME_EXPONENT>: Pow<-1>,
+        N<CURRENT_EXPONENT>: Pow<-1>,
+        N<TEMPERATURE_EXPONENT>: Pow<-1>,
+        N<AMOUNT_EXPONENT>: Pow<-1>,
+        N<LUMINOSITY_EXPONENT>: Pow<-1>,
+        N<ANGLE_EXPONENT>: Pow<-1>,
+        N<INFORMATION_EXPONENT>: Pow<-1>,
+        N<SCALE_P2>: Pow<-1>,
+        N<SCALE_P3>: Pow<-1>,
+        N<SCALE_P5>: Pow<-1>,
+        N<SCALE_PI>: Pow<-1>,
+        PowersOf<
+            -1,
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        >: QuantityExponents<T, Brand>,
+    {
+        <PowersOf<
+            -1,
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        > as QuantityExponents<T, Brand>>::from_value(T::one() / self.unsafe_value)
+    }
+
+    /// The reciprocal of this quantity, negating every dimension and scale exponent.
+    ///
+    /// See the stable-Rust documentation of this method; with `generic_const_exprs` the
+    /// negated exponents are computed directly rather than looked up.
+    #[cfg(has_generic_const_exprs)]
+    pub fn recip(
+        self,
+    ) -> QuantityOf<
+        { power_exponent(MASS_EXPONENT, -1) },
+        { power_exponent(LENGTH_EXPONENT, -1) },
+        { power_exponent(TIME_EXPONENT, -1) },
+        { power_exponent(CURRENT_EXPONENT, -1) },
+        { power_exponent(TEMPERATURE_EXPONENT, -1) },
+        { power_exponent(AMOUNT_EXPONENT, -1) },
+        { power_exponent(LUMINOSITY_EXPONENT, -1) },
+        { power_exponent(ANGLE_EXPONENT, -1) },
+        { power_exponent(INFORMATION_EXPONENT, -1) },
+        { power_exponent(SCALE_P2, -1) },
+        { power_exponent(SCALE_P3, -1) },
+        { power_exponent(SCALE_P5, -1) },
+        { power_exponent(SCALE_PI, -1) },
+        T,
+        Brand,
+    >
+    where
+        T: num_traits::Float,
+        N<MASS_EXPONENT>: Pow<-1>,
+        N<LENGTH_EXPONENT>: Pow<-1>,
+        N<TIME_EXPONENT>: Pow<-1>,
+        N<CURRENT_EXPONENT>: Pow<-1>,
+        N<TEMPERATURE_EXPONENT>: Pow<-1>,
+        N<AMOUNT_EXPONENT>: Pow<-1>,
+        N<LUMINOSITY_EXPONENT>: Pow<-1>,
+        N<ANGLE_EXPONENT>: Pow<-1>,
+        N<INFORMATION_EXPONENT>: Pow<-1>,
+        N<SCALE_P2>: Pow<-1>,
+        N<SCALE_P3>: Pow<-1>,
+        N<SCALE_P5>: Pow<-1>,
+        N<SCALE_PI>: Pow<-1>,
+        (): crate::IsI16<{ power_exponent(MASS_EXPONENT, -1) }>,
+        (): crate::IsI16<{ power_exponent(LENGTH_EXPONENT, -1) }>,
+        (): crate::IsI16<{ power_exponent(TIME_EXPONENT, -1) }>,
+        (): crate::IsI16<{ power_exponent(CURRENT_EXPONENT, -1) }>,
+        (): crate::IsI16<{ power_exponent(TEMPERATURE_EXPONENT, -1) }>,
+        (): crate::IsI16<{ power_exponent(AMOUNT_EXPONENT, -1) }>,
+        (): crate::IsI16<{ power_exponent(LUMINOSITY_EXPONENT, -1) }>,
+        (): crate::IsI16<{ power_exponent(ANGLE_EXPONENT, -1) }>,
+        (): crate::IsI16<{ power_exponent(INFORMATION_EXPONENT, -1) }>,
+        (): crate::IsI16<{ power_exponent(SCALE_P2, -1) }>,
+        (): crate::IsI16<{ power_exponent(SCALE_P3, -1) }>,
+        (): crate::IsI16<{ power_exponent(SCALE_P5, -1) }>,
+        (): crate::IsI16<{ power_exponent(SCALE_PI, -1) }>,
+    {
+        Quantity::new(T::one() / self.unsafe_value)
+    }
+
+    /// Snap this quantity to the nearest whole multiple of the `Target` unit, keeping its type.
+    ///
+    /// The value is rescaled to `Target`'s scale, rounded to the nearest integer (ties away from
+    /// zero), and rescaled back.  Only the scale of `Target` matters; its storage type and brand
+    /// are ignored.  The return trip divides by the same factor the forward trip multiplied by,
+    /// so e.g. quantizing meters to `mm` yields the closest `f64` to an exact millimeter count
+    /// rather than accumulating the error of a separately computed inverse factor.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, unit, value};
+    /// let length = quantity!(1.23456, m).quantize_to::<unit!(mm)>();
+    /// assert_eq!(value!(length, m), 1.235);
+    /// ```
+    ///
+    /// `Target` must have the same dimension as this quantity:
+    ///
+    /// ```rust,compile_fail
+    /// # use whippyunits::{quantity, unit};
+    /// let _ = quantity!(1.23456, m).quantize_to::<unit!(ms)>();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the quantized value does not fit the storage type `T`.
+    pub fn quantize_to<Target>(&self) -> Self
+    where
+        T: Copy + num_traits::NumCast,
+        Target: QuantizeTarget<
+            Dimension<
+                _M<MASS_EXPONENT>,
+                _L<LENGTH_EXPONENT>,
+                _T<TIME_EXPONENT>,
+                _I<CURRENT_EXPONENT>,
+                _Θ<TEMPERATURE_EXPONENT>,
+                _N<AMOUNT_EXPONENT>,
+                _J<LUMINOSITY_EXPONENT>,
+                _A<ANGLE_EXPONENT>,
+                _Info<INFORMATION_EXPONENT>,
+            >,
+        >,
+    {
+        let factor = crate::api::aggregate_scale_factor_float(
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            Target::SCALE_P2,
+            Target::SCALE_P3,
+            Target::SCALE_P5,
+            Target::SCALE_PI,
+        );
+        let value = <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64 for quantization");
+        let quantized = libm::round(value * factor) / factor;
+        Self::new(
+            <T as num_traits::NumCast>::from(quantized)
+                .expect("quantized value does not fit the storage type"),
+        )
+    }
+
+    /// Get the source unit symbol for error messages (returns static string, no allocation)
+    #[cfg(feature = "serde")]
+    fn get_source_unit_symbol_static(&self) -> &'static str {
+        use whippyunits_core::{Dimension, scale_exponents::ScaleExponents};
+
+        // Create the source dimensions and scales
+        let source_dimensions = Self::dimension_exponents();
+        let source_scales = ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]);
+
+        // Try to find a matching unit
+        if let Some(dimension) = Dimension::find_dimension_by_exponents(source_dimensions) {
+            // Look for a unit with matching scales and conversion_factor = 1.0 (SI base units)
+            if let Some(unit) = dimension
+                .units
+                .iter()
+                .find(|unit| unit.scale == source_scales && unit.conversion_factor == 1.0)
+            {
+                return unit.symbols[0]; // Return &'static str directly
+            }
+
+            // If no exact match, try to find any storage unit in this dimension
+            if let Some(unit) = dimension
+                .units
+                .iter()
+                .find(|unit| unit.conversion_factor == 1.0)
+            {
+                return unit.symbols[0]; // Return &'static str directly
+            }
+        }
+
+        // Fallback to dimension symbol if no unit found
+        if let Some(dimension) = Dimension::find_dimension_by_exponents(source_dimensions) {
+            return dimension.symbol; // Return &'static str directly
+        }
+
+        // Final fallback
+        "unknown unit"
+    }
+}
+
+impl<T>
+    Quantity<
+        Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>,
+        Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+        T,
+        (),
+    >
+{
+    /// Wrap a bare number as a dimensionless quantity of unit scale.
+    ///
+    /// This is the inverse of [as_f64](Self::as_f64), for bringing plain ratios (gains,
+    /// efficiencies, counts) into quantity arithmetic without spelling out a unit:
+    ///
+    /// ```rust
+    /// # use whippyunits::{Quantity, quantity};
+    /// let efficiency = Quantity::dimensionless(0.25);
+    /// let output = quantity!(400.0, W) * efficiency;
+    /// assert_eq!(output, quantity!(100.0, W));
+    /// ```
+    pub const fn dimensionless(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const SCALE_P2: i16, const SCALE_P3: i16, const SCALE_P5: i16, const SCALE_PI: i16, T, Brand>
+    Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+        T,
+        Brand,
+    >
+{
+    /// The value of this dimensionless quantity as a bare `f64`.
+    ///
+    /// The stored value is rescaled to unit scale first, so an integer ratio of `km / m` storing 2
+    /// gives 2000:
+    ///
+    /// ```rust
+    /// # use whippyunits::{Quantity, quantity};
+    /// assert_eq!(Quantity::dimensionless(3.0).as_f64(), 3.0);
+    /// assert_eq!((quantity!(2, km, i32) / quantity!(1, m, i32)).as_f64(), 2000.0);
+    /// ```
+    ///
+    /// Only dimensionless quantities have a bare value; anything else must go through
+    /// [value!](crate::value!) with an explicit unit:
+    ///
+    /// ```rust,compile_fail
+    /// # use whippyunits::quantity;
+    /// let _ = quantity!(1.0, m).as_f64();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted to `f64`.
+    pub fn as_f64(self) -> f64
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        self.actual_value_f64()
+    }
+
+    /// Raise this dimensionless quantity to a power known only at runtime.
+    ///
+    /// Raising a dimensional quantity to a power changes its dimension, which must be known at
+    /// compile time, so only dimensionless quantities can be raised to a runtime power.  A
+    /// negative `n` yields the reciprocal power, and `n == 0` yields one.
+    ///
+    /// The power applies to the quantity's actual value, not its stored value: an integer ratio
+    /// of `km / m` storing 2 means 2000, so squaring it gives 4,000,000, stored as 4000 in the
+    /// same scale.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// let ratio = quantity!(2.0, m) / quantity!(1.0, m);
+    /// assert_eq!(ratio.pow_rt(3).unsafe_value, 8.0);
+    /// assert_eq!(ratio.pow_rt(-2).unsafe_value, 0.25);
+    /// assert_eq!(ratio.pow_rt(0).unsafe_value, 1.0);
+    /// ```
+    ///
+    /// Quantities with a dimension cannot be raised to a runtime power:
+    ///
+    /// ```rust,compile_fail
+    /// # use whippyunits::quantity;
+    /// let _ = quantity!(2.0, m).pow_rt(2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit the storage type `T`.
+    pub fn pow_rt(self, n: i32) -> Self
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        let scale = crate::api::aggregate_scale_factor_float(
+            SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0,
+        );
+        let value = <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64 for exponentiation");
+        let powered = libm::pow(value * scale, n as f64) / scale;
+        Self::new(
+            <T as num_traits::NumCast>::from(powered).expect("power does not fit the storage type"),
+        )
+    }
+
+    /// The angle whose sine is this dimensionless quantity, in radians.
+    ///
+    /// Like [`pow_rt`](Self::pow_rt), this applies to the quantity's actual value, so a ratio in
+    /// any scale may be passed.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, value};
+    /// let ratio = quantity!(1.0, m) / quantity!(2.0, m);
+    /// assert!((value!(ratio.asin(), deg) - 30.0).abs() < 1e-12);
+    /// ```
+    pub fn asin(self) -> Radians<Brand>
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        Quantity::new(libm::asin(self.actual_value_f64()))
+    }
+
+    /// The angle whose cosine is this dimensionless quantity, in radians.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, value};
+    /// let ratio = quantity!(1.0, m) / quantity!(2.0, m);
+    /// assert!((value!(ratio.acos(), deg) - 60.0).abs() < 1e-12);
+    /// ```
+    pub fn acos(self) -> Radians<Brand>
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        Quantity::new(libm::acos(self.actual_value_f64()))
+    }
+
+    /// The angle whose tangent is this dimensionless quantity, in radians.
+    ///
+    /// ```rust
+    /// # use whippyunits::{quantity, value};
+    /// let ratio = quantity!(1.0, m) / quantity!(1.0, m);
+    /// assert!((value!(ratio.atan(), deg) - 45.0).abs() < 1e-12);
+    /// ```
+    pub fn atan(self) -> Radians<Brand>
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        Quantity::new(libm::atan(self.actual_value_f64()))
+    }
+
+    fn actual_value_f64(self) -> f64
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64")
+            * crate::api::aggregate_scale_factor_float(
+                SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0,
+            )
+    }
+}
+
+/// An `f64` angle in radians, as returned by the inverse trigonometric functions.
+pub(crate) type Radians<Brand> = Quantity<
+    Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>,
+    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<1>, _Info<0>>,
+    f64,
+    Brand,
+>;
+
+/// An `f64` dimensionless ratio, as returned by the trigonometric methods.
+type Ratio<Brand> = Quantity<
+    Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>,
+    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+    f64,
+    Brand,
+>;
+
+impl<const SCALE_P2: i16, const SCALE_P3: i16, const SCALE_P5: i16, const SCALE_PI: i16, T, Brand>
+    Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<1>, _Info<0>>,
+        T,
+        Brand,
+    >
+{
+    /// The sine of this angle, as a dimensionless `f64` quantity.
+    ///
+    /// The angle is rescaled to radians first, so any angular unit may be used.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert!((quantity!(90.0, deg).sin().unsafe_value - 1.0).abs() < 1e-12);
+    /// assert!((quantity!(1.5, rad).sin().unsafe_value - 1.5f64.sin()).abs() < 1e-12);
+    /// ```
+    ///
+    /// Only pure angles have a sine:
+    ///
+    /// ```rust,compile_fail
+    /// # use whippyunits::quantity;
+    /// let _ = quantity!(1.0, m).sin();
+    /// ```
+    pub fn sin(self) -> Ratio<Brand>
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        Quantity::new(libm::sin(self.radians_f64()))
+    }
+
+    /// The cosine of this angle, as a dimensionless `f64` quantity.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert!((quantity!(60.0, deg).cos().unsafe_value - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn cos(self) -> Ratio<Brand>
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        Quantity::new(libm::cos(self.radians_f64()))
+    }
+
+    /// The tangent of this angle, as a dimensionless `f64` quantity.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert!((quantity!(45.0, deg).tan().unsafe_value - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn tan(self) -> Ratio<Brand>
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        Quantity::new(libm::tan(self.radians_f64()))
+    }
+
+    fn radians_f64(self) -> f64
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64")
+            * crate::api::aggregate_scale_factor_float(
+                SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0,
+            )
+    }
+}
+
+impl<const SCALE_P2: i16, const SCALE_P3: i16, const SCALE_P5: i16, const SCALE_PI: i16, T, Brand>
+    Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<_M<0>, _L<0>, _T<1>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+        T,
+        Brand,
+    >
+{
+    /// Split this duration into whole hours, whole minutes, and the remaining seconds.
+    ///
+    /// Hours do not wrap at 24, and any fractional part of the duration is kept in the seconds.
+    /// Negative durations are split by magnitude, with every component negated.
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(quantity!(3725.0, s).to_hms(), (1, 2, 5.0));
+    /// assert_eq!(quantity!(1.5, d).to_hms(), (36, 0, 0.0));
+    /// assert_eq!(quantity!(-90.5, s).to_hms(), (0, -1, -30.5));
+    /// ```
+    pub fn to_hms(&self) -> (i64, i64, f64)
+    where
+        T: Copy + num_traits::NumCast,
+    {
+        let seconds = <f64 as num_traits::NumCast>::from(self.unsafe_value)
+            .expect("unable to convert numeric value to f64")
+            * crate::api::aggregate_scale_factor_float(
+                SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0,
+            );
+
+        let magnitude = seconds.abs();
+        let hours = libm::floor(magnitude / 3600.0);
+        let minutes = libm::floor((magnitude - hours * 3600.0) / 60.0);
+        let remainder = magnitude - hours * 3600.0 - minutes * 60.0;
+
+        let sign = if seconds < 0.0 { -1 } else { 1 };
+        (
+            sign * hours as i64,
+            sign * minutes as i64,
+            sign as f64 * remainder,
+        )
+    }
+}
+
+/// A quantity type whose scale can serve as the resolution for
+/// [`Quantity::quantize_to`](Quantity::quantize_to).
+///
+/// Implemented for every [Quantity] of the given `Dimension`, regardless of storage type or brand.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "cannot quantize to `{Self}`: it has a different dimension",
+    label = "not a unit of the quantity's dimension"
+)]
+pub trait QuantizeTarget<Dimension> {
+    const SCALE_P2: i16;
+    const SCALE_P3: i16;
+    const SCALE_P5: i16;
+    const SCALE_PI: i16;
+}
+
+impl<
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    Dimension,
+    T,
+    Brand,
+> QuantizeTarget<Dimension>
+    for Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension,
+        T,
+        Brand,
+    >
+{
+    const SCALE_P2: i16 = SCALE_P2;
+    const SCALE_P3: i16 = SCALE_P3;
+    const SCALE_P5: i16 = SCALE_P5;
+    const SCALE_PI: i16 = SCALE_PI;
+}
+
+/// Error returned by [`Quantity::new_bounded`](Quantity::new_bounded) for a value outside its
+/// bounds.
+///
+/// All three values are in the unit of the quantity being constructed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutOfBoundsError {
+    /// The rejected value
+    pub value: f64,
+    /// The lower bound, rescaled to the quantity's unit
+    pub min: f64,
+    /// The upper bound, rescaled to the quantity's unit
+    pub max: f64,
+}
+
+impl core::fmt::Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "value {} is outside the bounds [{}, {}]",
+            self.value, self.min, self.max
+        )
+    }
+}
+
+impl core::error::Error for OutOfBoundsError {}
+
+/// An exponent divided by a root's degree, for [`Quantity::sqrt`](Quantity::sqrt),
+/// [`Quantity::cbrt`](Quantity::cbrt) and [`Quantity::nth_root`](Quantity::nth_root).
+#[cfg(has_generic_const_exprs)]
+#[doc(hidden)]
+pub const fn root_exponent(exponent: i16, degree: i16) -> i16 {
+    exponent / degree
+}
+
+/// An exponent multiplied by a power, for [`Quantity::pow`](Quantity::pow).
+#[cfg(has_generic_const_exprs)]
+#[doc(hidden)]
+pub const fn power_exponent(exponent: i16, power: i16) -> i16 {
+    exponent * power
+}
+
+/// The `degree`th root of a stored value, for [`Quantity::nth_root`](Quantity::nth_root).
+///
+/// Square and cube roots use the dedicated routines so that exact roots stay exact; odd degrees
+/// take the negative root of a negative value.
+fn root_value<T: Copy + num_traits::NumCast>(value: T, degree: i16) -> T {
+    let value = <f64 as num_traits::NumCast>::from(value)
+        .expect("unable to convert numeric value to f64 for root");
+    let root = match degree {
+        2 => libm::sqrt(value),
+        3 => libm::cbrt(value),
+        _ if degree % 2 != 0 && value < 0.0 => -libm::pow(-value, 1.0 / degree as f64),
+        _ => libm::pow(value, 1.0 / degree as f64),
+    };
+    <T as num_traits::NumCast>::from(root).expect("root does not fit the storage type")
+}
+
+/// A [Quantity] with the given exponents, for spelling out quantity types in method signatures.
+pub(crate) type QuantityOf<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+> = Quantity<
+    Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+    Dimension<
+        _M<MASS_EXPONENT>,
+        _L<LENGTH_EXPONENT>,
+        _T<TIME_EXPONENT>,
+        _I<CURRENT_EXPONENT>,
+        _Θ<TEMPERATURE_EXPONENT>,
+        _N<AMOUNT_EXPONENT>,
+        _J<LUMINOSITY_EXPONENT>,
+        _A<ANGLE_EXPONENT>,
+        _Info<INFORMATION_EXPONENT>,
+    >,
+    T,
+    Brand,
+>;
+
+/// The exponents of a [Quantity] as a tuple of type-level numbers, in the order M, L, T, I, Θ,
+/// N, J, A, Info, 2, 3, 5, π.
+///
+/// Lets [`Quantity::nth_root`](Quantity::nth_root) and [`Quantity::pow`](Quantity::pow) name
+/// their result types from [`Root`] and [`Pow`] outputs, which are types rather than const values.
+#[cfg(not(has_generic_const_exprs))]
+#[doc(hidden)]
+pub trait QuantityExponents<T, Brand> {
+    type Quantity;
+
+    fn from_value(value: T) -> Self::Quantity;
+}
+
+#[cfg(not(has_generic_const_exprs))]
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+> QuantityExponents<T, Brand>
+    for (
+        N<MASS_EXPONENT>,
+        N<LENGTH_EXPONENT>,
+        N<TIME_EXPONENT>,
+        N<CURRENT_EXPONENT>,
+        N<TEMPERATURE_EXPONENT>,
+        N<AMOUNT_EXPONENT>,
+        N<LUMINOSITY_EXPONENT>,
+        N<ANGLE_EXPONENT>,
+        N<INFORMATION_EXPONENT>,
+        N<SCALE_P2>,
+        N<SCALE_P3>,
+        N<SCALE_P5>,
+        N<SCALE_PI>,
+    )
+{
+    type Quantity = QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+        T,
+        Brand,
+    >;
+
+    fn from_value(value: T) -> Self::Quantity {
+        Quantity::new(value)
+    }
+}
+
+/// The exponents of a [Quantity] divided by `DEGREE`, for the result of a root.
+#[cfg(not(has_generic_const_exprs))]
+type RootsOf<
+    const DEGREE: i16,
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+> = (
+    <N<MASS_EXPONENT> as Root<DEGREE>>::Output,
+    <N<LENGTH_EXPONENT> as Root<DEGREE>>::Output,
+    <N<TIME_EXPONENT> as Root<DEGREE>>::Output,
+    <N<CURRENT_EXPONENT> as Root<DEGREE>>::Output,
+    <N<TEMPERATURE_EXPONENT> as Root<DEGREE>>::Output,
+    <N<AMOUNT_EXPONENT> as Root<DEGREE>>::Output,
+    <N<LUMINOSITY_EXPONENT> as Root<DEGREE>>::Output,
+    <N<ANGLE_EXPONENT> as Root<DEGREE>>::Output,
+    <N<INFORMATION_EXPONENT> as Root<DEGREE>>::Output,
+    <N<SCALE_P2> as Root<DEGREE>>::Output,
+    <N<SCALE_P3> as Root<DEGREE>>::Output,
+    <N<SCALE_P5> as Root<DEGREE>>::Output,
+    <N<SCALE_PI> as Root<DEGREE>>::Output,
+);
+
+/// The exponents of a [Quantity] multiplied by `POWER`, for the result of a power.
+#[cfg(not(has_generic_const_exprs))]
+type PowersOf<
+    const POWER: i16,
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+> = (
+    <N<MASS_EXPONENT> as Pow<POWER>>::Output,
+    <N<LENGTH_EXPONENT> as Pow<POWER>>::Output,
+    <N<TIME_EXPONENT> as Pow<POWER>>::Output,
+    <N<CURRENT_EXPONENT> as Pow<POWER>>::Output,
+    <N<TEMPERATURE_EXPONENT> as Pow<POWER>>::Output,
+    <N<AMOUNT_EXPONENT> as Pow<POWER>>::Output,
+    <N<LUMINOSITY_EXPONENT> as Pow<POWER>>::Output,
+    <N<ANGLE_EXPONENT> as Pow<POWER>>::Output,
+    <N<INFORMATION_EXPONENT> as Pow<POWER>>::Output,
+    <N<SCALE_P2> as Pow<POWER>>::Output,
+    <N<SCALE_P3> as Pow<POWER>>::Output,
+    <N<SCALE_P5> as Pow<POWER>>::Output,
+    <N<SCALE_PI> as Pow<POWER>>::Output,
+);
+
+/// A [Quantity] type whose dimension can be compared by
+/// [`Quantity::is_commensurate_with`](Quantity::is_commensurate_with).
+///
+/// Implemented for every [Quantity], regardless of scale, storage type, or brand.
+#[doc(hidden)]
+pub trait CommensurabilityTarget {
+    /// Dimension exponents, in the order M, L, T, I, Θ, N, J, A, Info
+    const DIMENSION_EXPONENTS: [i16; 9];
+}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    Scale,
+    T,
+    Brand,
+> CommensurabilityTarget
+    for Quantity<
+        Scale,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+{
+    const DIMENSION_EXPONENTS: [i16; 9] = [
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+    ];
+}
+
+/// A formatter for displaying quantities with unit conversion
+/// This is no-std compatible - uses &str instead of String
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub struct QuantityFormatter<'a> {
+    value: f64,
+    unit: &'a str,
+    is_error: bool,
+    error_source_unit: Option<&'static str>, // Static strings from unit lookup
+}
+
+#[cfg(feature = "serde")]
+impl<'a> core::fmt::Display for QuantityFormatter<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_error {
+            // Write error message directly to formatter (no allocation)
+            if let Some(source_unit) = self.error_source_unit {
+                write!(
+                    f,
+                    "Error: Dimension mismatch: cannot convert from {} to {}",
+                    source_unit, self.unit
+                )
+            } else {
+                write!(f, "Error: Failed to parse unit: {}", self.unit)
+            }
+        } else {
+            // Use the formatter's precision if specified, otherwise use default formatting
+            if let Some(precision) = f.precision() {
+                write!(f, "{:.precision$} ", self.value, precision = precision)?;
+            } else {
+                write!(f, "{} ", self.value)?;
+            }
+            // Long unit names are pluralized for any value but one ("1 foot", "2 feet")
+            if self.value == 1.0 {
+                write!(f, "{}", self.unit)
+            } else {
+                write!(f, "{}", whippyunits_core::PluralFmt(self.unit))
+            }
+        }
+    }
+}
+
+// from/into for dimensionless quantities
+
+// proper dimensionless quantities (all exponents are 0, scales irrelevant)
+#[doc(hidden)]
+macro_rules! define_from_dimensionless_cross_type {
+    ($source_type:ty, $target_type:ty) => {
         /// Converts dimensionless quantities between different numeric types with proper scaling.
         ///
         /// Performs de-scaling before type conversion to ensure unit-safe numeric extraction.
@@ -681,7 +3290,7 @@ macro_rules! define_from_dimensionless_cross_type {
             From<
                 Quantity<
                     Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>,
+                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
                     $source_type,
                 >,
             > for $target_type
@@ -689,7 +3298,7 @@ macro_rules! define_from_dimensionless_cross_type {
             fn from(
                 other: Quantity<
                     Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>,
+                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
                     $source_type,
                 >,
             ) -> $target_type {
@@ -700,7 +3309,7 @@ macro_rules! define_from_dimensionless_cross_type {
                     // Convert to f64 quantity first, then apply rescale logic
                     let f64_quantity = Quantity::<
                         Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                        Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>,
+                        Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
                         f64,
                     >::new(other.unsafe_value as f64);
                     (crate::api::rescale_f64::<
@@ -712,6 +3321,7 @@ macro_rules! define_from_dimensionless_cross_type {
                         0,
                         0,
                         0,
+                        0,
                         SCALE_P2,
                         0,
                         SCALE_P3,
@@ -755,7 +3365,7 @@ macro_rules! define_from_dimensionless {
             From<
                 Quantity<
                     Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>,
+                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
                     $type,
                 >,
             > for $type
@@ -763,7 +3373,7 @@ macro_rules! define_from_dimensionless {
             fn from(
                 other: Quantity<
                     Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>,
+                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
                     $type,
                 >,
             ) -> $type {
@@ -781,6 +3391,7 @@ macro_rules! define_from_dimensionless {
                         0,
                         0,
                         0,
+                        0,
                         SCALE_P2,
                         0,
                         SCALE_P3,
@@ -816,6 +3427,137 @@ define_from_dimensionless!(usize, rescale_usize);
 // Cross-type conversions for dimensionless quantities (all N×(N-1) pairs)
 whippyunits_proc_macros::generate_all_dimensionless_cross_type!();
 
+// ratios of same-dimension quantities (scale difference folded into the value)
+#[doc(hidden)]
+macro_rules! define_ratio {
+    ($type:ty, $rescale_fn:ident) => {
+        impl<
+            const MASS_EXPONENT: i16,
+            const LENGTH_EXPONENT: i16,
+            const TIME_EXPONENT: i16,
+            const CURRENT_EXPONENT: i16,
+            const TEMPERATURE_EXPONENT: i16,
+            const AMOUNT_EXPONENT: i16,
+            const LUMINOSITY_EXPONENT: i16,
+            const ANGLE_EXPONENT: i16,
+            const INFORMATION_EXPONENT: i16,
+            const SCALE_P2: i16,
+            const SCALE_P3: i16,
+            const SCALE_P5: i16,
+            const SCALE_PI: i16,
+            Brand,
+        >
+            Quantity<
+                Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+                Dimension<
+                    _M<MASS_EXPONENT>,
+                    _L<LENGTH_EXPONENT>,
+                    _T<TIME_EXPONENT>,
+                    _I<CURRENT_EXPONENT>,
+                    _Θ<TEMPERATURE_EXPONENT>,
+                    _N<AMOUNT_EXPONENT>,
+                    _J<LUMINOSITY_EXPONENT>,
+                    _A<ANGLE_EXPONENT>,
+                    _Info<INFORMATION_EXPONENT>,
+                >,
+                $type,
+                Brand,
+            >
+        {
+            /// Divides by a quantity of the same dimension, folding any scale difference into the value.
+            ///
+            /// Plain division (`/`) does the same for float storage, but with integer storage it
+            /// yields a dimensionless quantity that *carries* the scale difference of its operands
+            /// (e.g. `km / m` is dimensionless with a scale of 10³), which keeps integer division
+            /// lossless.  `ratio` instead rescales `self` to the scale of `other` before dividing,
+            /// so the result is an unscaled dimensionless quantity for every storage type.
+            ///
+            /// ## Examples
+            /// ```rust
+            /// # fn main() {
+            /// use whippyunits::{quantity, unit, value};
+            ///
+            /// let ratio: unit!(1) = quantity!(1.0, km).ratio(quantity!(1.0, m));
+            /// assert_eq!(value!(ratio, 1), 1000.0);
+            ///
+            /// // let _ratio = quantity!(1.0, km).ratio(quantity!(1.0, s)); // 🚫 Compile error (dimension mismatch)
+            /// # }
+            /// ```
+            pub const fn ratio<
+                const OTHER_SCALE_P2: i16,
+                const OTHER_SCALE_P3: i16,
+                const OTHER_SCALE_P5: i16,
+                const OTHER_SCALE_PI: i16,
+            >(
+                self,
+                other: Quantity<
+                    Scale<
+                        _2<OTHER_SCALE_P2>,
+                        _3<OTHER_SCALE_P3>,
+                        _5<OTHER_SCALE_P5>,
+                        _Pi<OTHER_SCALE_PI>,
+                    >,
+                    Dimension<
+                        _M<MASS_EXPONENT>,
+                        _L<LENGTH_EXPONENT>,
+                        _T<TIME_EXPONENT>,
+                        _I<CURRENT_EXPONENT>,
+                        _Θ<TEMPERATURE_EXPONENT>,
+                        _N<AMOUNT_EXPONENT>,
+                        _J<LUMINOSITY_EXPONENT>,
+                        _A<ANGLE_EXPONENT>,
+                        _Info<INFORMATION_EXPONENT>,
+                    >,
+                    $type,
+                    Brand,
+                >,
+            ) -> Quantity<
+                Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>,
+                Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+                $type,
+                Brand,
+            > {
+                let rescaled = crate::api::$rescale_fn::<
+                    MASS_EXPONENT,
+                    LENGTH_EXPONENT,
+                    TIME_EXPONENT,
+                    CURRENT_EXPONENT,
+                    TEMPERATURE_EXPONENT,
+                    AMOUNT_EXPONENT,
+                    LUMINOSITY_EXPONENT,
+                    ANGLE_EXPONENT,
+                    INFORMATION_EXPONENT,
+                    SCALE_P2,
+                    OTHER_SCALE_P2,
+                    SCALE_P3,
+                    OTHER_SCALE_P3,
+                    SCALE_P5,
+                    OTHER_SCALE_P5,
+                    SCALE_PI,
+                    OTHER_SCALE_PI,
+                    Brand,
+                >(self);
+                Quantity::new(rescaled.unsafe_value / other.unsafe_value)
+            }
+        }
+    };
+}
+
+define_ratio!(f32, rescale_f32);
+define_ratio!(f64, rescale_f64);
+define_ratio!(i8, rescale_i8);
+define_ratio!(i16, rescale_i16);
+define_ratio!(i32, rescale_i32);
+define_ratio!(i64, rescale_i64);
+define_ratio!(i128, rescale_i128);
+define_ratio!(isize, rescale_isize);
+define_ratio!(u8, rescale_u8);
+define_ratio!(u16, rescale_u16);
+define_ratio!(u32, rescale_u32);
+define_ratio!(u64, rescale_u64);
+define_ratio!(u128, rescale_u128);
+define_ratio!(usize, rescale_usize);
+
 // Cross-type conversion for radian quantities
 #[doc(hidden)]
 macro_rules! define_from_for_radians_with_scale_cross_type {
@@ -843,7 +3585,17 @@ macro_rules! define_from_for_radians_with_scale_cross_type {
             From<
                 Quantity<
                     Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<$exponent>>,
+                    Dimension<
+                        _M<0>,
+                        _L<0>,
+                        _T<0>,
+                        _I<0>,
+                        _Θ<0>,
+                        _N<0>,
+                        _J<0>,
+                        _A<$exponent>,
+                        _Info<0>,
+                    >,
                     $source_type,
                 >,
             > for $target_type
@@ -851,7 +3603,17 @@ macro_rules! define_from_for_radians_with_scale_cross_type {
             fn from(
                 other: Quantity<
                     Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<$exponent>>,
+                    Dimension<
+                        _M<0>,
+                        _L<0>,
+                        _T<0>,
+                        _I<0>,
+                        _Θ<0>,
+                        _N<0>,
+                        _J<0>,
+                        _A<$exponent>,
+                        _Info<0>,
+                    >,
                     $source_type,
                 >,
             ) -> $target_type {
@@ -862,7 +3624,17 @@ macro_rules! define_from_for_radians_with_scale_cross_type {
                     // Convert to f64 quantity first, then apply rescale logic
                     let f64_quantity = Quantity::<
                         Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                        Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<$exponent>>,
+                        Dimension<
+                            _M<0>,
+                            _L<0>,
+                            _T<0>,
+                            _I<0>,
+                            _Θ<0>,
+                            _N<0>,
+                            _J<0>,
+                            _A<$exponent>,
+                            _Info<0>,
+                        >,
                         f64,
                     >::new(other.unsafe_value as f64);
                     (crate::api::rescale_f64::<
@@ -874,6 +3646,7 @@ macro_rules! define_from_for_radians_with_scale_cross_type {
                         0,
                         0,
                         $exponent,
+                        0,
                         SCALE_P2,
                         0,
                         SCALE_P3,
@@ -922,7 +3695,17 @@ macro_rules! define_from_for_radians_with_scale {
             From<
                 Quantity<
                     Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<$exponent>>,
+                    Dimension<
+                        _M<0>,
+                        _L<0>,
+                        _T<0>,
+                        _I<0>,
+                        _Θ<0>,
+                        _N<0>,
+                        _J<0>,
+                        _A<$exponent>,
+                        _Info<0>,
+                    >,
                     $type,
                 >,
             > for $type
@@ -930,7 +3713,17 @@ macro_rules! define_from_for_radians_with_scale {
             fn from(
                 other: Quantity<
                     Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                    Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<$exponent>>,
+                    Dimension<
+                        _M<0>,
+                        _L<0>,
+                        _T<0>,
+                        _I<0>,
+                        _Θ<0>,
+                        _N<0>,
+                        _J<0>,
+                        _A<$exponent>,
+                        _Info<0>,
+                    >,
                     $type,
                 >,
             ) -> $type {
@@ -948,6 +3741,7 @@ macro_rules! define_from_for_radians_with_scale {
                         0,
                         0,
                         $exponent,
+                        0,
                         SCALE_P2,
                         0,
                         SCALE_P3,
@@ -1004,24 +3798,25 @@ macro_rules! define_from_for_radians {
                     const TEMPERATURE_EXPONENT: i16,
                     const AMOUNT_EXPONENT: i16,
                     const LUMINOSITY_EXPONENT: i16,
+                    const INFORMATION_EXPONENT: i16,
                 >
                 From<
                     Quantity<
                         Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                        Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<$exponent>>,
+                        Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<$exponent>, _Info<INFORMATION_EXPONENT>>,
                         $type,
                     >,
                 >
                 for Quantity<
                     Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<0>>,
+                    Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<0>, _Info<INFORMATION_EXPONENT>>,
                     $type,
                 >
             {
                 fn from(
                     other: Quantity<
                         Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-                        Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<$exponent>>,
+                        Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<$exponent>, _Info<INFORMATION_EXPONENT>>,
                         $type,
                     >,
                 ) -> Self {
@@ -1044,7 +3839,7 @@ macro_rules! quantity_type {
     () => {
         Quantity<
             Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
             T,
             Brand
         >
@@ -1052,7 +3847,7 @@ macro_rules! quantity_type {
     ($T:ty) => {
         Quantity<
             Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
-            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>>,
+            Dimension<_M<MASS_EXPONENT>, _L<LENGTH_EXPONENT>, _T<TIME_EXPONENT>, _I<CURRENT_EXPONENT>, _Θ<TEMPERATURE_EXPONENT>, _N<AMOUNT_EXPONENT>, _J<LUMINOSITY_EXPONENT>, _A<ANGLE_EXPONENT>, _Info<INFORMATION_EXPONENT>>,
             $T,
             Brand
         >
@@ -1,3 +1,6 @@
+use crate::quantity::Quantity;
+use crate::quantity::{_2, _3, _5, _A, _I, _Info, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
+
 /// Rescale a [Quantity](crate::Quantity) to a different unit of the same dimension.
 ///
 /// This macro provides a convenient way to rescale quantities in inline contexts where
@@ -137,3 +140,455 @@ macro_rules! rescale {
         result as $crate::unit!($unit, usize)
     }};
 }
+
+/// Rescale a [Quantity](crate::Quantity) to an SI-prefixed multiple of its coherent unit.
+///
+/// The prefix is applied to the SI unit of the quantity's dimension, keeping the quantity's value
+/// and changing only its scale: a length becomes `km` with `k`, whatever its current scale, and
+/// its stored value is divided accordingly.  This is convenient for output, where the prefix is
+/// chosen for readability rather than fixed in a type annotation.
+///
+/// ## Syntax
+///
+/// ```rust,ignore
+/// with_prefix!(quantity, prefix)
+/// ```
+///
+/// where:
+/// - `quantity`: A [quantity](crate::quantity!) of known type.
+/// - `prefix`: An SI prefix symbol, from `q` (10⁻³⁰) to `Q` (10³⁰), with micro written `u`.
+///
+/// The unit being prefixed is the coherent SI unit of the quantity's dimension (`m`, `s`, `N`,
+/// `m/s`, ...), except for mass, where SI prefixes apply to the gram rather than the kilogram:
+/// `with_prefix!(mass, k)` gives `kg` and `with_prefix!(mass, m)` gives `mg`.  Dimensionless
+/// quantities have no unit to prefix and are rejected at compile time.
+///
+/// For integer storage types, the rescaled value is truncated toward zero.
+///
+/// ## Examples
+///
+/// ```rust
+/// use whippyunits::{quantity, unit, with_prefix};
+///
+/// let distance: unit!(km) = with_prefix!(quantity!(1500.0, m), k);
+/// assert_eq!(distance, quantity!(1.5, km));
+///
+/// // Mass prefixes apply to the gram
+/// let mass: unit!(mg) = with_prefix!(quantity!(0.25, g), m);
+/// assert_eq!(mass, quantity!(250.0, mg));
+/// ```
+///
+/// ```rust,compile_fail
+/// use whippyunits::{quantity, with_prefix};
+///
+/// // Not an SI prefix
+/// let _ = with_prefix!(quantity!(1.0, m), x);
+/// ```
+#[macro_export]
+macro_rules! with_prefix {
+    ($quantity:expr, $prefix:tt) => {{
+        #[allow(unused_imports)]
+        use $crate::rescale_macro::{
+            __PrefixCoherentUnit as _, __PrefixDimensionless as _, __PrefixGram as _,
+        };
+        const __PREFIX_EXPONENT: i16 = $crate::__prefix_exponent!($prefix);
+        (&&&$crate::rescale_macro::__WithPrefix($quantity))
+            .with_prefix::<__PREFIX_EXPONENT, { __PREFIX_EXPONENT - 3 }>()
+    }};
+}
+
+/// Power of ten of an SI prefix symbol, for [`with_prefix!`](crate::with_prefix!).
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __prefix_exponent {
+    (q) => {
+        -30
+    };
+    (r) => {
+        -27
+    };
+    (y) => {
+        -24
+    };
+    (z) => {
+        -21
+    };
+    (a) => {
+        -18
+    };
+    (f) => {
+        -15
+    };
+    (p) => {
+        -12
+    };
+    (n) => {
+        -9
+    };
+    (u) => {
+        -6
+    };
+    (m) => {
+        -3
+    };
+    (c) => {
+        -2
+    };
+    (d) => {
+        -1
+    };
+    (da) => {
+        1
+    };
+    (h) => {
+        2
+    };
+    (k) => {
+        3
+    };
+    (M) => {
+        6
+    };
+    (G) => {
+        9
+    };
+    (T) => {
+        12
+    };
+    (P) => {
+        15
+    };
+    (E) => {
+        18
+    };
+    (Z) => {
+        21
+    };
+    (Y) => {
+        24
+    };
+    (R) => {
+        27
+    };
+    (Q) => {
+        30
+    };
+    ($other:tt) => {
+        compile_error!(concat!(
+            "`",
+            stringify!($other),
+            "` is not an SI prefix symbol"
+        ))
+    };
+}
+
+/// Build a closure converting raw `f64` values from one unit to another.
+///
+/// The conversion factor and affine offset are computed once, when the converter is built, so
+/// the closure is a single multiply-add; this suits hot loops converting many values between
+/// two fixed units.  Dimensional compatibility is checked at compile time, as with
+/// [`value!`](crate::value!), and affine units such as `degC` carry their offset into the
+/// closure.
+///
+/// ## Syntax
+///
+/// ```rust,ignore
+/// converter!(from_unit, to_unit) // impl Fn(f64) -> f64
+/// ```
+///
+/// ## Examples
+///
+/// ```rust
+/// use whippyunits::converter;
+///
+/// let to_kelvin = converter!(degC, K);
+/// assert_eq!(to_kelvin(100.0), 373.15);
+///
+/// let to_km = converter!(m, km);
+/// let distances: Vec<f64> = [1500.0, 250.0].into_iter().map(to_km).collect();
+/// assert_eq!(distances, [1.5, 0.25]);
+/// // let _ = converter!(m, s); // ❌ Compile error (dimension mismatch)
+/// ```
+#[macro_export]
+macro_rules! converter {
+    ($from:expr, $to:expr) => {{
+        // Never called: value! rejects incompatible dimensions at compile time
+        let _ = || $crate::value!($crate::quantity!(0.0, $from), $to);
+        $crate::rescale_macro::__converter(stringify!($from), stringify!($to))
+    }};
+}
+
+/// Build the closure for [`converter!`](crate::converter!) from its unit expressions, which the
+/// macro has already checked for dimensional compatibility.
+#[doc(hidden)]
+pub fn __converter(from: &str, to: &str) -> impl Fn(f64) -> f64 + Copy {
+    // `value` in a unit is `value * conversion_factor + affine_offset` at the unit's scale
+    let unit_factors = |unit: &str| {
+        let expr = whippyunits_core::LiteUnitExpr::parse(unit)
+            .expect("converter! unit was checked at compile time");
+        let scale = expr
+            .evaluate_with_mode(whippyunits_core::EvaluationMode::Tolerant)
+            .scale_exponents
+            .0;
+        let (conversion_factor, affine_offset) = expr.conversion_factors();
+        (conversion_factor, affine_offset, scale)
+    };
+    let (from_factor, from_offset, from_scale) = unit_factors(from);
+    let (to_factor, to_offset, to_scale) = unit_factors(to);
+    let scale_factor = crate::api::aggregate_scale_factor_float(
+        from_scale[0],
+        from_scale[1],
+        from_scale[2],
+        from_scale[3],
+        to_scale[0],
+        to_scale[1],
+        to_scale[2],
+        to_scale[3],
+    );
+
+    let factor = from_factor * scale_factor / to_factor;
+    let offset = (from_offset * scale_factor - to_offset) / to_factor;
+    move |value| value * factor + offset
+}
+
+/// Convert a slice of raw `f64` values from one unit to another.
+///
+/// For columnar data: the values are converted as by a [`converter!`](crate::converter!), whose
+/// factor and offset are computed once for the whole slice, and returned in a new `Vec`.
+/// Dimensional compatibility is checked at compile time, and affine units such as `degC` apply
+/// their offset to every element.  [`convert_slice_in_place!`](crate::convert_slice_in_place!)
+/// converts a mutable slice without allocating.
+///
+/// ## Syntax
+///
+/// ```rust,ignore
+/// convert_slice!(values, from_unit, to_unit) // Vec<f64>
+/// ```
+///
+/// ## Examples
+///
+/// ```rust
+/// use whippyunits::convert_slice;
+///
+/// let celsius = [0.0, 100.0];
+/// assert_eq!(convert_slice!(&celsius, degC, K), [273.15, 373.15]);
+/// assert_eq!(convert_slice!(&[1500.0, 250.0], m, km), [1.5, 0.25]);
+/// // let _ = convert_slice!(&celsius, degC, m); // ❌ Compile error (dimension mismatch)
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! convert_slice {
+    ($values:expr, $from:expr, $to:expr) => {
+        $crate::rescale_macro::__convert_slice($values, $crate::converter!($from, $to))
+    };
+}
+
+/// Convert a mutable slice of raw `f64` values from one unit to another, in place.
+///
+/// As [`convert_slice!`](crate::convert_slice!), but overwriting each value with its converted
+/// value rather than allocating a new `Vec`.
+///
+/// ## Syntax
+///
+/// ```rust,ignore
+/// convert_slice_in_place!(values, from_unit, to_unit)
+/// ```
+///
+/// ## Examples
+///
+/// ```rust
+/// use whippyunits::convert_slice_in_place;
+///
+/// let mut temperatures = [273.15, 373.15];
+/// convert_slice_in_place!(&mut temperatures, K, degC);
+/// assert_eq!(temperatures, [0.0, 100.0]);
+/// ```
+#[macro_export]
+macro_rules! convert_slice_in_place {
+    ($values:expr, $from:expr, $to:expr) => {
+        $crate::rescale_macro::__convert_slice_in_place($values, $crate::converter!($from, $to))
+    };
+}
+
+/// Apply a [`converter!`](crate::converter!) closure to each value, for
+/// [`convert_slice!`](crate::convert_slice!).
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub fn __convert_slice(values: &[f64], convert: impl Fn(f64) -> f64) -> crate::alloc::Vec<f64> {
+    values.iter().map(|&value| convert(value)).collect()
+}
+
+/// Apply a [`converter!`](crate::converter!) closure to each value in place, for
+/// [`convert_slice_in_place!`](crate::convert_slice_in_place!).
+#[doc(hidden)]
+pub fn __convert_slice_in_place(values: &mut [f64], convert: impl Fn(f64) -> f64) {
+    for value in values.iter_mut() {
+        *value = convert(*value);
+    }
+}
+
+// `with_prefix!` picks between the traits below by autoref specialization: the macro calls the
+// method on `&&&__WithPrefix<Q>`, so a dimensionless `Q` matches `__PrefixDimensionless` first,
+// a pure mass matches `__PrefixGram` one auto-deref later, and any other quantity falls through
+// to `__PrefixCoherentUnit`.
+
+#[doc(hidden)]
+pub struct __WithPrefix<Q>(pub Q);
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "SI prefixes do not apply to dimensionless quantities",
+    label = "dimensionless quantity has no unit to prefix"
+)]
+pub trait __PrefixableDimension {}
+
+#[doc(hidden)]
+pub trait __PrefixDimensionless {
+    fn with_prefix<const EXPONENT: i16, const GRAM_EXPONENT: i16>(&self)
+    where
+        Self: __PrefixableDimension;
+}
+
+#[doc(hidden)]
+pub trait __PrefixGram {
+    type Prefixed<const EXPONENT: i16>;
+
+    fn with_prefix<const EXPONENT: i16, const GRAM_EXPONENT: i16>(
+        &self,
+    ) -> Self::Prefixed<GRAM_EXPONENT>;
+}
+
+#[doc(hidden)]
+pub trait __PrefixCoherentUnit {
+    type Prefixed<const EXPONENT: i16>;
+
+    fn with_prefix<const EXPONENT: i16, const GRAM_EXPONENT: i16>(
+        &self,
+    ) -> Self::Prefixed<EXPONENT>;
+}
+
+impl<const SCALE_P2: i16, const SCALE_P3: i16, const SCALE_P5: i16, const SCALE_PI: i16, T, Brand>
+    __PrefixDimensionless
+    for &&__WithPrefix<
+        Quantity<
+            Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+            Dimension<_M<0>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+            T,
+            Brand,
+        >,
+    >
+{
+    fn with_prefix<const EXPONENT: i16, const GRAM_EXPONENT: i16>(&self)
+    where
+        Self: __PrefixableDimension,
+    {
+    }
+}
+
+impl<const SCALE_P2: i16, const SCALE_P3: i16, const SCALE_P5: i16, const SCALE_PI: i16, T, Brand>
+    __PrefixGram
+    for &__WithPrefix<
+        Quantity<
+            Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+            Dimension<_M<1>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+            T,
+            Brand,
+        >,
+    >
+where
+    T: Copy + num_traits::NumCast,
+{
+    type Prefixed<const EXPONENT: i16> = Quantity<
+        Scale<_2<EXPONENT>, _3<0>, _5<EXPONENT>, _Pi<0>>,
+        Dimension<_M<1>, _L<0>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
+        T,
+        Brand,
+    >;
+
+    fn with_prefix<const EXPONENT: i16, const GRAM_EXPONENT: i16>(
+        &self,
+    ) -> Self::Prefixed<GRAM_EXPONENT> {
+        Quantity::new(rescaled_value(
+            self.0.unsafe_value,
+            [SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI],
+            [GRAM_EXPONENT, 0, GRAM_EXPONENT, 0],
+        ))
+    }
+}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+> __PrefixCoherentUnit
+    for __WithPrefix<
+        Quantity<
+            Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+            Dimension<
+                _M<MASS_EXPONENT>,
+                _L<LENGTH_EXPONENT>,
+                _T<TIME_EXPONENT>,
+                _I<CURRENT_EXPONENT>,
+                _Θ<TEMPERATURE_EXPONENT>,
+                _N<AMOUNT_EXPONENT>,
+                _J<LUMINOSITY_EXPONENT>,
+                _A<ANGLE_EXPONENT>,
+                _Info<INFORMATION_EXPONENT>,
+            >,
+            T,
+            Brand,
+        >,
+    >
+where
+    T: Copy + num_traits::NumCast,
+{
+    type Prefixed<const EXPONENT: i16> = Quantity<
+        Scale<_2<EXPONENT>, _3<0>, _5<EXPONENT>, _Pi<0>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >;
+
+    fn with_prefix<const EXPONENT: i16, const GRAM_EXPONENT: i16>(
+        &self,
+    ) -> Self::Prefixed<EXPONENT> {
+        Quantity::new(rescaled_value(
+            self.0.unsafe_value,
+            [SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI],
+            [EXPONENT, 0, EXPONENT, 0],
+        ))
+    }
+}
+
+/// Convert a stored value between two scales, given as exponents of 2, 3, 5, and π.
+fn rescaled_value<T: num_traits::NumCast>(value: T, from: [i16; 4], to: [i16; 4]) -> T {
+    let value = <f64 as num_traits::NumCast>::from(value)
+        .expect("unable to convert numeric value to f64 for rescaling");
+    let factor = crate::api::aggregate_scale_factor_float(
+        from[0], from[1], from[2], from[3], to[0], to[1], to[2], to[3],
+    );
+    <T as num_traits::NumCast>::from(value * factor)
+        .expect("rescaled value does not fit the storage type")
+}
@@ -2,6 +2,7 @@
 //!
 //! [`from_json`]
 //! [`from_string`]
+//! [`ambient`]
 //!
 //! ## Format
 //!
@@ -32,18 +33,18 @@
 //!             - There may be at most one division expression in a unit literal expression
 //!             - All terms trailing the division symbol are considered to be in the denominator
 //!
-//! Runtime parsing of unit literal expressions leverages the same parser as the [unit!](crate::unit!) macro;
-//! using `from_string` or `from_json` means your binary will include the `syn` crate as a dependency.  
-//! This is technically no-std, but it is fairly heavyweight for a no-std library, and is not appropriate
-//! for sufficiently resource-constrained environments.
+//! Runtime parsing of unit literal expressions uses a lightweight parser
+//! ([LiteUnitExpr](whippyunits_core::LiteUnitExpr)) that accepts the same grammar as the
+//! [unit!](crate::unit!) macro and agrees with it exactly, without tokenizing through `syn` at runtime.
+//! It works in `no_std` environments with `alloc`.
 
 use crate::api::aggregate_scale_factor_float;
 use crate::print::name_lookup::generate_systematic_unit_name_with_format;
 use crate::print::prettyprint::UnitFormat;
-use crate::quantity::Quantity;
-use crate::quantity::{_2, _3, _5, _A, _I, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
+use crate::quantity::{_2, _3, _5, _A, _I, _Info, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
+use crate::quantity::{Quantity, QuantityOf};
 use whippyunits_core::{
-    SiPrefix, Unit, UnitEvaluationResult, UnitExpr, dimension_exponents::DynDimensionExponents,
+    LiteUnitExpr, SiPrefix, Unit, UnitEvaluationResult, dimension_exponents::DynDimensionExponents,
     scale_exponents::ScaleExponents,
 };
 
@@ -54,8 +55,6 @@ pub type UnitDimensions = (
 );
 
 use crate::alloc::{String, ToString, Vec, format, vec};
-use proc_macro2::TokenStream;
-use syn::parse_str;
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Visitor};
 
@@ -69,6 +68,7 @@ pub fn to_ucum_unit<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -86,6 +86,7 @@ pub fn to_ucum_unit<
             _N<AMOUNT_EXPONENT>,
             _J<LUMINOSITY_EXPONENT>,
             _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
         >,
         T,
     >,
@@ -102,6 +103,7 @@ where
         AMOUNT_EXPONENT,
         LUMINOSITY_EXPONENT,
         ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
     ];
     generate_systematic_unit_name_with_format(exponents, false, UnitFormat::Ucum)
 }
@@ -111,6 +113,8 @@ where
 pub enum UcumError {
     /// The dimension exponents don't match any known dimension
     UnknownDimension(whippyunits_core::dimension_exponents::DynDimensionExponents),
+    /// The unit string is not valid UCUM syntax
+    InvalidSyntax(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -132,6 +136,9 @@ impl core::fmt::Display for UcumError {
             UcumError::UnknownDimension(exponents) => {
                 write!(f, "Unknown dimension: {:?}", exponents)
             }
+            UcumError::InvalidSyntax(msg) => {
+                write!(f, "Invalid UCUM syntax: {}", msg)
+            }
         }
     }
 }
@@ -218,6 +225,7 @@ impl<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -236,6 +244,7 @@ impl<
             _N<AMOUNT_EXPONENT>,
             _J<LUMINOSITY_EXPONENT>,
             _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
         >,
         T,
         Brand,
@@ -264,6 +273,7 @@ where
                 _N<AMOUNT_EXPONENT>,
                 _J<LUMINOSITY_EXPONENT>,
                 _A<ANGLE_EXPONENT>,
+                _Info<INFORMATION_EXPONENT>,
             >,
             T,
             (),
@@ -277,6 +287,7 @@ where
             AMOUNT_EXPONENT,
             LUMINOSITY_EXPONENT,
             ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
             SCALE_P2,
             SCALE_P3,
             SCALE_P5,
@@ -291,6 +302,61 @@ where
     }
 }
 
+/// The `value` field of a serialized quantity, given either as a JSON number
+/// or as a numeric string (e.g. `"5.0"`, as emitted by APIs avoiding float
+/// precision loss).
+struct NumericValue(f64);
+
+/// Parse a stringified `value` field as f64
+fn parse_numeric_string(value: &str) -> Result<f64, SerializationError> {
+    value.trim().parse().map_err(|e| {
+        SerializationError::ParseError(format!("Failed to parse value '{}' as f64: {}", value, e))
+    })
+}
+
+impl<'de> Deserialize<'de> for NumericValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct NumericValueVisitor;
+
+        impl<'de> Visitor<'de> for NumericValueVisitor {
+            type Value = NumericValue;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a number or a numeric string")
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Self::Value, E> {
+                Ok(NumericValue(value))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                Ok(NumericValue(value as f64))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(NumericValue(value as f64))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                parse_numeric_string(value)
+                    .map(NumericValue)
+                    .map_err(E::custom)
+            }
+        }
+
+        // Only self-describing formats can say whether a number or a string comes next; the
+        // others always hold the f64 written by `serialize`
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(NumericValueVisitor)
+        } else {
+            deserializer.deserialize_f64(NumericValueVisitor)
+        }
+    }
+}
+
 /// Visitor for deserializing Quantity types
 struct QuantityVisitor<
     const MASS_EXPONENT: i16,
@@ -301,6 +367,7 @@ struct QuantityVisitor<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -320,6 +387,7 @@ struct QuantityVisitor<
                 _N<AMOUNT_EXPONENT>,
                 _J<LUMINOSITY_EXPONENT>,
                 _A<ANGLE_EXPONENT>,
+                _Info<INFORMATION_EXPONENT>,
             >,
             T,
             Brand,
@@ -336,6 +404,7 @@ impl<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -352,6 +421,7 @@ impl<
         AMOUNT_EXPONENT,
         LUMINOSITY_EXPONENT,
         ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
         SCALE_P2,
         SCALE_P3,
         SCALE_P5,
@@ -379,6 +449,7 @@ impl<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -395,6 +466,7 @@ impl<
         AMOUNT_EXPONENT,
         LUMINOSITY_EXPONENT,
         ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
         SCALE_P2,
         SCALE_P3,
         SCALE_P5,
@@ -416,13 +488,15 @@ where
             _N<AMOUNT_EXPONENT>,
             _J<LUMINOSITY_EXPONENT>,
             _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
         >,
         T,
         Brand,
     >;
 
     fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-        formatter.write_str("a map with 'value' (number) and 'unit' (string) fields")
+        formatter
+            .write_str("a map with 'value' (number or numeric string) and 'unit' (string) fields")
     }
 
     fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -440,7 +514,7 @@ where
                     if value.is_some() {
                         return Err(Error::duplicate_field("value"));
                     }
-                    value = Some(map.next_value()?);
+                    value = Some(map.next_value::<NumericValue>()?.0);
                 }
                 "unit" => {
                     if unit.is_some() {
@@ -467,6 +541,7 @@ where
             AMOUNT_EXPONENT,
             LUMINOSITY_EXPONENT,
             ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
             SCALE_P2,
             SCALE_P3,
             SCALE_P5,
@@ -490,6 +565,7 @@ where
                 _N<AMOUNT_EXPONENT>,
                 _J<LUMINOSITY_EXPONENT>,
                 _A<ANGLE_EXPONENT>,
+                _Info<INFORMATION_EXPONENT>,
             >,
             T,
             Brand,
@@ -507,6 +583,7 @@ impl<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -525,6 +602,7 @@ impl<
             _N<AMOUNT_EXPONENT>,
             _J<LUMINOSITY_EXPONENT>,
             _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
         >,
         T,
         Brand,
@@ -545,6 +623,7 @@ where
             AMOUNT_EXPONENT,
             LUMINOSITY_EXPONENT,
             ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
             SCALE_P2,
             SCALE_P3,
             SCALE_P5,
@@ -555,6 +634,278 @@ where
     }
 }
 
+/// Visitor for the bare number accepted by [ambient] and [deserialize_from_base_number]
+struct BareNumberVisitor {
+    /// Description of the expected number, naming the unit it is read in
+    expecting: &'static str,
+}
+
+impl Visitor<'_> for BareNumberVisitor {
+    type Value = f64;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<f64, E> {
+        Ok(value)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<f64, E> {
+        Ok(value as f64)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<f64, E> {
+        Ok(value as f64)
+    }
+}
+
+/// Deserialize a bare number as a quantity in the field's own ("ambient") unit.
+///
+/// For use with serde's `deserialize_with`, when the unit is known from context rather than
+/// sent alongside the value.  The ambient unit is the unit of the field's type, so a bare `5.0`
+/// becomes 5 meters in a `unit!(m)` field and 5 millimeters in a `unit!(mm)` field:
+///
+/// ```rust
+/// use whippyunits::{quantity, unit};
+///
+/// #[derive(serde::Deserialize)]
+/// struct Reading {
+///     #[serde(deserialize_with = "whippyunits::serialization::ambient")]
+///     distance: unit!(m),
+/// }
+///
+/// let reading: Reading = serde_json::from_str(r#"{"distance": 5.0}"#).unwrap();
+/// assert_eq!(reading.distance, quantity!(5.0, m));
+/// ```
+///
+/// Only a bare number is accepted; the `{"value", "unit"}` object form of the [Deserialize]
+/// impl is rejected rather than reinterpreted, since its unit could disagree with the ambient
+/// one.
+pub fn ambient<
+    'de,
+    D,
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>(
+    deserializer: D,
+) -> Result<
+    QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+        T,
+        Brand,
+    >,
+    D::Error,
+>
+where
+    D: Deserializer<'de>,
+    T: From<f64>,
+{
+    let value = deserializer.deserialize_f64(BareNumberVisitor {
+        expecting: "a bare number in the field's unit",
+    })?;
+    Ok(Quantity::new(T::from(value)))
+}
+
+/// Serialize a quantity as a bare number in coherent base units.
+///
+/// For use with serde's `serialize_with`, when a schema declares the unit externally and
+/// expects just the number.  The value is written in the coherent SI unit of the quantity's
+/// dimension, whatever the field's own scale, so a `unit!(km)` field holding 1.5 km is written
+/// as `1500.0` (meters).  Read it back with [deserialize_from_base_number]:
+///
+/// ```rust
+/// use whippyunits::{quantity, unit};
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Leg {
+///     #[serde(
+///         serialize_with = "whippyunits::serialization::serialize_as_base_number",
+///         deserialize_with = "whippyunits::serialization::deserialize_from_base_number"
+///     )]
+///     distance: unit!(km),
+/// }
+///
+/// let json = serde_json::to_string(&Leg { distance: quantity!(1.5, km) }).unwrap();
+/// assert_eq!(json, r#"{"distance":1500.0}"#);
+///
+/// let leg: Leg = serde_json::from_str(&json).unwrap();
+/// assert_eq!(leg.distance, quantity!(1.5, km));
+/// ```
+///
+/// Scales involving π (such as degrees) are converted to their coherent unit (radians) in
+/// floating point, so they round-trip only approximately.  A scale whose factor from base units
+/// cannot be computed (beyond `2^±100`, `3^±100`, `5^±100` or `π^±8`), or a value that
+/// overflows when converted, is a serialization error rather than a wrong or infinite number.
+pub fn serialize_as_base_number<
+    S,
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>(
+    quantity: &QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+        T,
+        Brand,
+    >,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Into<f64> + Copy,
+{
+    if !is_base_representable(SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI) {
+        return Err(serde::ser::Error::custom(
+            "this quantity's scale is not representable in base units",
+        ));
+    }
+
+    let value: f64 = quantity.unsafe_value.into();
+    let base_value =
+        value * aggregate_scale_factor_float(SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0);
+
+    if value.is_finite() && !base_value.is_finite() {
+        return Err(serde::ser::Error::custom(format!(
+            "{} is not representable as a finite number in base units",
+            value
+        )));
+    }
+
+    serializer.serialize_f64(base_value)
+}
+
+/// Deserialize a bare number in coherent base units as a quantity.
+///
+/// The reverse of [serialize_as_base_number], for use with serde's `deserialize_with`: the
+/// number is read in the coherent SI unit of the field's dimension and rescaled to the field's
+/// own unit, so `1500.0` becomes 1.5 km in a `unit!(km)` field.  Unlike [ambient], the number
+/// is never taken to be in the field's unit.
+///
+/// As for serialization, a field whose scale has no computable factor from base units, or a
+/// finite number that overflows when converted to the field's unit, is rejected.
+pub fn deserialize_from_base_number<
+    'de,
+    D,
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>(
+    deserializer: D,
+) -> Result<
+    QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+        T,
+        Brand,
+    >,
+    D::Error,
+>
+where
+    D: Deserializer<'de>,
+    T: From<f64>,
+{
+    let base_value = deserializer.deserialize_f64(BareNumberVisitor {
+        expecting: "a bare number in base units",
+    })?;
+
+    if !is_base_representable(SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI) {
+        return Err(serde::de::Error::custom(
+            "this field's scale is not representable in base units",
+        ));
+    }
+    let value = base_value
+        * aggregate_scale_factor_float(0, 0, 0, 0, SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI);
+
+    if base_value.is_finite() && !value.is_finite() {
+        return Err(serde::de::Error::custom(format!(
+            "{} base units is not representable as a finite number in this unit",
+            base_value
+        )));
+    }
+
+    Ok(Quantity::new(T::from(value)))
+}
+
+/// Whether the factor between a scale and coherent base units can be computed
+///
+/// The scale factor lookup tables cover powers of 2, 3 and 5 up to ±100 and powers of π up to
+/// ±8; a scale beyond them has no known factor, so its quantities have no base-unit number.
+const fn is_base_representable(scale_p2: i16, scale_p3: i16, scale_p5: i16, scale_pi: i16) -> bool {
+    scale_p2.abs() <= 100 && scale_p3.abs() <= 100 && scale_p5.abs() <= 100 && scale_pi.abs() <= 8
+}
+
 /// Serialize a quantity to JSON using UCUM format
 ///
 /// **Note**: This function requires the `std` feature. For no-std compatibility,
@@ -569,6 +920,7 @@ pub fn serialize_to_json<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -586,6 +938,7 @@ pub fn serialize_to_json<
             _N<AMOUNT_EXPONENT>,
             _J<LUMINOSITY_EXPONENT>,
             _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
         >,
         T,
     >,
@@ -601,43 +954,408 @@ where
     }))
 }
 
-// deserialize_from_json removed - not compatible with compile-time only units library
-
-/// Parse a UCUM unit string to extract dimension exponents and scale factors
-/// Returns (mass, length, time, current, temperature, amount, luminosity, angle, p2, p3, p5, pi)
-pub fn parse_ucum_unit(ucum_string: &str) -> Result<UnitDimensions, UcumError> {
-    // Handle dimensionless case
-    if ucum_string == "1" {
-        return Ok((
-            DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0]),
-            ScaleExponents([0, 0, 0, 0]),
-        ));
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>
+    Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+where
+    T: Into<f64> + Copy,
+{
+    /// Serialize this quantity to a verbose JSON object for debugging, with its dimension
+    /// exponents spelled out alongside the value and unit.
+    ///
+    /// Each nonzero exponent is listed under the lowercase name of its base dimension, in the
+    /// order mass, length, time, current, temperature, amount, luminosity, angle, information;
+    /// a dimensionless quantity has an empty `dimension` object.  The unit is the one the value is
+    /// stored in, written as in pretty-printed quantity types (e.g. `km`, `(m·s⁻¹)`).
+    ///
+    /// ```rust
+    /// # use whippyunits::quantity;
+    /// assert_eq!(
+    ///     quantity!(5.0, m).to_debug_json(),
+    ///     r#"{"value":5.0,"unit":"m","dimension":{"length":1}}"#
+    /// );
+    /// ```
+    pub fn to_debug_json(&self) -> String {
+        let unit = crate::print::unit_literal_generator::generate_unit_literal(
+            Self::dimension_exponents(),
+            ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
+            crate::print::unit_literal_generator::UnitLiteralConfig {
+                verbose: false,
+                prefer_si_units: true,
+            },
+        );
+        serde_json::to_string(&DebugJson {
+            value: self.unsafe_value.into(),
+            unit,
+            dimension: DebugDimension([
+                MASS_EXPONENT,
+                LENGTH_EXPONENT,
+                TIME_EXPONENT,
+                CURRENT_EXPONENT,
+                TEMPERATURE_EXPONENT,
+                AMOUNT_EXPONENT,
+                LUMINOSITY_EXPONENT,
+                ANGLE_EXPONENT,
+                INFORMATION_EXPONENT,
+            ]),
+        })
+        .expect("serializing a quantity to a string cannot fail")
     }
+}
 
-    // Convert the string to a TokenStream for parsing
-    let token_stream: TokenStream = parse_str(ucum_string).map_err(|_| {
-        UcumError::UnknownDimension(DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0]))
-    })?;
+/// The JSON object written by [`Quantity::to_debug_json`], with fields in output order.
+#[derive(Serialize)]
+struct DebugJson {
+    value: f64,
+    unit: String,
+    dimension: DebugDimension,
+}
 
-    // Parse the TokenStream into a UnitExpr
-    let unit_expr: UnitExpr = syn::parse2(token_stream).map_err(|_| {
-        UcumError::UnknownDimension(DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0]))
-    })?;
+/// Dimension exponents, serialized as a map from base dimension name to nonzero exponent.
+struct DebugDimension([i16; 9]);
 
-    // Evaluate the unit expression to get dimensions and scales (use tolerant mode for serialization)
-    let result: UnitEvaluationResult =
-        unit_expr.evaluate_with_mode(whippyunits_core::EvaluationMode::Tolerant);
+impl Serialize for DebugDimension {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeMap;
 
-    Ok((result.dimension_exponents, result.scale_exponents))
+        let entries = whippyunits_core::Dimension::BASIS
+            .iter()
+            .zip(self.0)
+            .filter(|&(_, exponent)| exponent != 0);
+        let mut map = serializer.serialize_map(None)?;
+        for (dimension, exponent) in entries {
+            map.serialize_entry(&dimension.name.to_lowercase(), &exponent)?;
+        }
+        map.end()
+    }
 }
 
-/// Check if two dimension vectors match (comparing both dimensions and scales)
-pub fn dimensions_match(a: &UnitDimensions, b: &UnitDimensions) -> bool {
-    a.0 == b.0
+/// A quantity as a plain value and unit string, for embedding in serde structures.
+///
+/// [Quantity] serializes itself through a custom map serializer; converting to a
+/// `QuantityRepr` instead gives an ordinary struct whose fields can be nested, renamed, or
+/// flattened like any other.  Convert with [`Quantity::to_repr`] and [`Quantity::try_from_repr`].
+///
+/// ```rust
+/// # use whippyunits::{quantity, unit};
+/// # use whippyunits::serialization::QuantityRepr;
+/// let repr = quantity!(5.0, km).to_repr();
+/// assert_eq!(repr, QuantityRepr { value: 5.0, unit: "km".into() });
+///
+/// let distance = <unit!(m)>::try_from_repr(&repr).unwrap();
+/// assert_eq!(distance, quantity!(5000.0, m));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuantityRepr {
+    /// The value, in `unit`
+    pub value: f64,
+    /// A unit literal expression, as accepted by [from_string!](crate::from_string!)
+    pub unit: String,
 }
 
-/// Validate dimensions and appropriate error if they don't match
-pub fn validate_dimensions(
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>
+    Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+{
+    /// This quantity as a [QuantityRepr].
+    ///
+    /// Where the stored unit has a symbol (`km`, `ms`), the repr keeps the stored value in that
+    /// unit.  Stored units without one, such as the scale of `km/h`, are written as the
+    /// coherent unit with the value rescaled into it (`m/s`).
+    pub fn to_repr(&self) -> QuantityRepr
+    where
+        T: Into<f64> + Copy,
+    {
+        let value: f64 = self.unsafe_value.into();
+        let symbol = crate::print::unit_literal_generator::generate_unit_literal(
+            Self::dimension_exponents(),
+            ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
+            crate::print::unit_literal_generator::UnitLiteralConfig {
+                verbose: false,
+                prefer_si_units: true,
+            },
+        );
+        // The symbol is only usable if it reads back as exactly the stored unit
+        let names_stored_unit = deserialize_core::<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        >(1.0, &symbol)
+            == Ok(1.0);
+        if names_stored_unit {
+            return QuantityRepr {
+                value,
+                unit: symbol,
+            };
+        }
+
+        let exponents = vec![
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+        ];
+        QuantityRepr {
+            value: value
+                * aggregate_scale_factor_float(SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI, 0, 0, 0, 0),
+            unit: generate_systematic_unit_name_with_format(exponents, false, UnitFormat::Ucum),
+        }
+    }
+
+    /// Convert a [QuantityRepr] to this quantity type.
+    ///
+    /// The repr's unit may be any unit of this quantity's dimension; its value is converted to
+    /// this type's unit, as for [from_string!](crate::from_string!).
+    pub fn try_from_repr(repr: &QuantityRepr) -> Result<Self, SerializationError>
+    where
+        T: From<f64>,
+    {
+        let value = deserialize_core::<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+        >(repr.value, &repr.unit)?;
+        Ok(Self::new(T::from(value)))
+    }
+
+    /// The parsed quantity, or `default` if it failed to parse, reporting the failure to
+    /// `on_error`.
+    ///
+    /// For loading configuration values that should fall back rather than abort: pass the
+    /// result of [from_string!](crate::from_string!) (or [from_json!](crate::from_json!), and
+    /// so on) along with a default and a logger.  A value written in another unit of the same
+    /// dimension is not a failure, as the parsing macros already rescale it to this type's unit
+    /// (`"5 km"` read as meters is `5000 m`).  A value of the wrong dimension, or one that does
+    /// not parse at all, is replaced by `default`, and its error passed to `on_error`.
+    ///
+    /// ```rust
+    /// # use whippyunits::{Quantity, from_string, quantity, unit};
+    /// # use whippyunits::serialization::SerializationError;
+    /// let mut warnings = Vec::new();
+    /// let mut log = |error: &SerializationError| warnings.push(error.to_string());
+    ///
+    /// let range: unit!(m) =
+    ///     Quantity::expect_unit_or_default(from_string!("5 km", m), quantity!(100.0, m), &mut log);
+    /// assert_eq!(range, quantity!(5000.0, m));
+    ///
+    /// let range: unit!(m) =
+    ///     Quantity::expect_unit_or_default(from_string!("5 s", m), quantity!(100.0, m), &mut log);
+    /// assert_eq!(range, quantity!(100.0, m));
+    /// assert_eq!(warnings, ["Dimension mismatch: expected m, got s"]);
+    /// ```
+    pub fn expect_unit_or_default(
+        parsed: Result<Self, SerializationError>,
+        default: Self,
+        on_error: impl FnOnce(&SerializationError),
+    ) -> Self {
+        parsed.unwrap_or_else(|error| {
+            on_error(&error);
+            default
+        })
+    }
+}
+
+// deserialize_from_json removed - not compatible with compile-time only units library
+
+/// Parse a UCUM unit string to extract dimension exponents and scale factors
+/// Returns (mass, length, time, current, temperature, amount, luminosity, angle, p2, p3, p5, pi)
+pub fn parse_ucum_unit(ucum_string: &str) -> Result<UnitDimensions, UcumError> {
+    // Handle dimensionless case
+    if ucum_string == "1" {
+        return Ok((
+            DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            ScaleExponents([0, 0, 0, 0]),
+        ));
+    }
+
+    let unit_expr = LiteUnitExpr::parse(ucum_string).map_err(|_| {
+        UcumError::UnknownDimension(DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0, 0]))
+    })?;
+
+    // Evaluate the unit expression to get dimensions and scales (use tolerant mode for serialization)
+    let result: UnitEvaluationResult =
+        unit_expr.evaluate_with_mode(whippyunits_core::EvaluationMode::Tolerant);
+
+    Ok((result.dimension_exponents, result.scale_exponents))
+}
+
+/// Parse a UCUM unit string like [parse_ucum_unit], but reject syntax that UCUM does not allow.
+///
+/// [parse_ucum_unit] accepts the wider grammar of the unit macros, so that `m*s`, `m^2`, and
+/// `kg * m` all parse.  This function accepts only the UCUM forms: `.` for multiplication, a
+/// trailing integer for exponents, and `/` for division.  Unknown units are rejected rather than
+/// evaluated as dimensionless; known units are evaluated as in [parse_ucum_unit], so nonstorage
+/// units such as `h` are accepted.
+///
+/// ```rust
+/// # use whippyunits::serialization::{UcumError, parse_ucum_unit_strict};
+/// assert!(parse_ucum_unit_strict("kg.m2/s2").is_ok());
+/// assert!(matches!(parse_ucum_unit_strict("m*s"), Err(UcumError::InvalidSyntax(_))));
+/// assert!(matches!(parse_ucum_unit_strict("furlongz"), Err(UcumError::InvalidSyntax(_))));
+/// ```
+///
+/// # Errors
+///
+/// `Err(UcumError::InvalidSyntax)` for the `*` and `^` operators, whitespace, unbalanced
+/// parentheses, a `/` without a denominator, annotations in `{}` (none are recognized), unknown
+/// units, or any other string that is not a unit expression.
+pub fn parse_ucum_unit_strict(ucum_string: &str) -> Result<UnitDimensions, UcumError> {
+    validate_ucum_syntax(ucum_string)?;
+
+    let unit_expr = LiteUnitExpr::parse(ucum_string)
+        .map_err(|e| UcumError::InvalidSyntax(format!("{} in '{}'", e, ucum_string)))?;
+    if let Some(unknown) = unit_expr
+        .collect_unit_identifiers()
+        .into_iter()
+        .find(|name| whippyunits_core::get_unit_info(name).is_none())
+    {
+        return Err(UcumError::InvalidSyntax(format!(
+            "unknown unit '{}' in '{}'",
+            unknown, ucum_string
+        )));
+    }
+    let result: UnitEvaluationResult =
+        unit_expr.evaluate_with_mode(whippyunits_core::EvaluationMode::Tolerant);
+
+    Ok((result.dimension_exponents, result.scale_exponents))
+}
+
+/// Reject the parts of the unit macro grammar that are not UCUM syntax
+fn validate_ucum_syntax(ucum_string: &str) -> Result<(), UcumError> {
+    let invalid = |reason: &str| {
+        Err(UcumError::InvalidSyntax(format!(
+            "{} in '{}'",
+            reason, ucum_string
+        )))
+    };
+
+    let mut depth = 0usize;
+    let mut previous = None;
+    for c in ucum_string.chars() {
+        match c {
+            '*' => return invalid("'*' is not a UCUM operator, use '.'"),
+            '^' => return invalid("'^' is not a UCUM operator, write exponents as 'm2'"),
+            '{' | '}' => return invalid("unknown annotation"),
+            c if c.is_whitespace() => return invalid("whitespace is not allowed"),
+            '(' => depth += 1,
+            ')' => {
+                depth = match depth.checked_sub(1) {
+                    Some(depth) => depth,
+                    None => return invalid("unbalanced parentheses"),
+                }
+            }
+            '/' | '.' if matches!(previous, None | Some('/' | '.' | '(')) => {
+                return invalid("operator without an operand");
+            }
+            _ => {}
+        }
+        previous = Some(c);
+    }
+
+    if depth != 0 {
+        return invalid("unbalanced parentheses");
+    }
+    if matches!(previous, None | Some('/' | '.')) {
+        return invalid("operator without an operand");
+    }
+    Ok(())
+}
+
+/// Check if two dimension vectors match (comparing both dimensions and scales)
+pub fn dimensions_match(a: &UnitDimensions, b: &UnitDimensions) -> bool {
+    a.0 == b.0
+}
+
+/// Validate dimensions and appropriate error if they don't match
+pub fn validate_dimensions(
     expected: &UnitDimensions,
     actual: &UnitDimensions,
 ) -> Result<(), SerializationError> {
@@ -654,26 +1372,69 @@ pub fn validate_dimensions(
 /// Returns (conversion_factor, affine_offset) for nonstorage units
 /// For storage units, returns (1.0, 0.0)
 fn calculate_unit_conversion_factors(unit_str: &str) -> Result<(f64, f64), SerializationError> {
-    use proc_macro2::TokenStream;
-    use syn::parse_str;
-    use whippyunits_core::{UnitExpr, calculate_unit_conversion_factors as calc_cf};
-
     // Handle dimensionless case
     if unit_str == "1" {
         return Ok((1.0, 0.0));
     }
 
-    // Parse the unit string into a UnitExpr
-    let token_stream: TokenStream = parse_str(unit_str).map_err(|_| {
-        SerializationError::ParseError(format!("Failed to parse unit string: {}", unit_str))
-    })?;
-
-    let unit_expr: UnitExpr = syn::parse2(token_stream).map_err(|_| {
+    let unit_expr = LiteUnitExpr::parse(unit_str).map_err(|_| {
         SerializationError::ParseError(format!("Failed to parse unit expression: {}", unit_str))
     })?;
 
     // Use the shared logic from whippyunits-core
-    Ok(calc_cf(&unit_expr))
+    Ok(unit_expr.conversion_factors())
+}
+
+/// Look up a unit's `(conversion_factor, affine_offset)` relative to coherent SI base units.
+///
+/// A value `v` in `unit` corresponds to `v * conversion_factor + affine_offset` in the coherent
+/// unit of the same dimension (e.g. `m`, `kg`, `K`, `m/s`).  Prefix and storage-scale factors are
+/// folded into the conversion factor, so `km` yields `(1000.0, 0.0)` and `degF` yields
+/// `(5/9, 459.67 · 5/9)`.
+///
+/// ```rust
+/// # use whippyunits::serialization::unit_conversion_factors;
+/// let (factor, offset) = unit_conversion_factors("degC").unwrap();
+/// assert_eq!((factor, offset), (1.0, 273.15));
+///
+/// let (factor, offset) = unit_conversion_factors("km").unwrap();
+/// assert_eq!((factor, offset), (1000.0, 0.0));
+///
+/// assert!(unit_conversion_factors("furlongs_per_fortnight").is_err());
+/// ```
+///
+/// # Errors
+///
+/// - `Err(SerializationError::ParseError)`: the string is not a unit literal expression
+/// - `Err(SerializationError::UnknownUnit)`: the expression names a unit that is not registered
+pub fn unit_conversion_factors(unit: &str) -> Result<(f64, f64), SerializationError> {
+    if unit == "1" {
+        return Ok((1.0, 0.0));
+    }
+
+    let unit_expr = LiteUnitExpr::parse(unit).map_err(|_| {
+        SerializationError::ParseError(format!("Failed to parse unit expression: {}", unit))
+    })?;
+
+    if let Some(unknown) = unit_expr.collect_unit_identifiers().iter().find(|ident| {
+        **ident != "power_of_10"
+            && **ident != "dimensionless"
+            && whippyunits_core::get_unit_info(ident).is_none()
+    }) {
+        return Err(SerializationError::UnknownUnit(unknown.to_string()));
+    }
+
+    let scale = unit_expr
+        .evaluate_with_mode(whippyunits_core::EvaluationMode::Tolerant)
+        .scale_exponents;
+    let scale_factor =
+        aggregate_scale_factor_float(scale.0[0], scale.0[1], scale.0[2], scale.0[3], 0, 0, 0, 0);
+    let (conversion_factor, affine_offset) = unit_expr.conversion_factors();
+
+    Ok((
+        conversion_factor * scale_factor,
+        affine_offset * scale_factor,
+    ))
 }
 
 /// Calculate conversion factor between two units with matching dimensions
@@ -708,7 +1469,7 @@ pub fn calculate_conversion_factor(from_dims: &UnitDimensions, to_dims: &UnitDim
 ///
 /// where
 ///  - `json_string`: A JSON string containing:
-///     - `"value"`: A numeric value (integer or floating point)
+///     - `"value"`: A numeric value (integer or floating point), or a string holding one
 ///     - `"unit"`: A unit literal expression
 ///        - A "unit literal expression" is either:
 ///            - An atomic unit (may include prefix):
@@ -770,6 +1531,7 @@ macro_rules! from_json {
                     { DIMENSIONS.0[5] },
                     { DIMENSIONS.0[6] },
                     { DIMENSIONS.0[7] },
+                    { DIMENSIONS.0[8] },
                     { SCALES.0[0] },
                     { SCALES.0[1] },
                     { SCALES.0[2] },
@@ -803,6 +1565,223 @@ macro_rules! from_json {
                     { DIMENSIONS.0[5] },
                     { DIMENSIONS.0[6] },
                     { DIMENSIONS.0[7] },
+                    { DIMENSIONS.0[8] },
+                    { SCALES.0[0] },
+                    { SCALES.0[1] },
+                    { SCALES.0[2] },
+                    { SCALES.0[3] },
+                    $storage_type,
+                >(value, &unit_str)
+                    as Result<
+                        whippyunits::unit!($unit, $storage_type),
+                        $crate::serialization::SerializationError,
+                    >
+            }
+            Err(e) => Err(e),
+        }
+    }};
+}
+
+/// Deserializes a [Quantity] from YAML representation.
+///
+/// The YAML counterpart of [from_json!](crate::from_json!): parses a mapping with a `value`
+/// (number or numeric string) and a `unit` (unit literal expression), and performs the same
+/// dimension validation and automatic unit conversion.
+///
+/// # Syntax
+///
+/// ```rust, ignore
+/// from_yaml!(yaml_string, target_unit)
+/// from_yaml!(yaml_string, target_unit, storage_type)
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use whippyunits::from_yaml;
+/// # use whippyunits::value;
+/// # use whippyunits::unit;
+/// # fn main() {
+/// let acceleration: unit!(m / s2) = from_yaml!("value: 9.81\nunit: m/s2", m / s2).unwrap();
+/// assert_eq!(value!(acceleration, m / s2), 9.81);
+/// let length: unit!(km) = from_yaml!("value: 5.0\nunit: m", km).unwrap();
+/// assert_eq!(value!(length, km), 0.005);
+/// assert!(from_yaml!("value: 5.0\nunit: m", kg).is_err());
+/// # }
+/// ```
+///
+/// # Error Handling
+///
+/// The macro returns a `Result<Quantity, SerializationError>`:
+/// - `Ok(quantity)`: Successfully parsed and converted quantity
+/// - `Err(SerializationError::DimensionMismatch)`: Unit dimension doesn't match target
+/// - `Err(SerializationError::InvalidFormat)`: YAML format is invalid or missing required fields
+/// - `Err(SerializationError::ParseError)`: YAML parsing failed or unit string couldn't be parsed
+#[cfg(feature = "yaml")]
+#[macro_export]
+macro_rules! from_yaml {
+    ($json:expr, $unit:expr) => {{
+        match $crate::serialization::parse_yaml_input($json) {
+            Ok((value, unit_str)) => {
+                const UNIT_INFO: (
+                    whippyunits_core::dimension_exponents::DynDimensionExponents,
+                    whippyunits_core::scale_exponents::ScaleExponents,
+                ) = whippyunits_proc_macros::compute_unit_dimensions!($unit);
+                const DIMENSIONS: whippyunits_core::dimension_exponents::DynDimensionExponents =
+                    UNIT_INFO.0;
+                const SCALES: whippyunits_core::scale_exponents::ScaleExponents = UNIT_INFO.1;
+                $crate::serialization::deserialize_core_quantity::<
+                    { DIMENSIONS.0[0] },
+                    { DIMENSIONS.0[1] },
+                    { DIMENSIONS.0[2] },
+                    { DIMENSIONS.0[3] },
+                    { DIMENSIONS.0[4] },
+                    { DIMENSIONS.0[5] },
+                    { DIMENSIONS.0[6] },
+                    { DIMENSIONS.0[7] },
+                    { DIMENSIONS.0[8] },
+                    { SCALES.0[0] },
+                    { SCALES.0[1] },
+                    { SCALES.0[2] },
+                    { SCALES.0[3] },
+                    f64,
+                >(value, &unit_str)
+                    as Result<
+                        whippyunits::unit!($unit, f64),
+                        $crate::serialization::SerializationError,
+                    >
+            }
+            Err(e) => Err(e),
+        }
+    }};
+    ($json:expr, $unit:expr, $storage_type:ty) => {{
+        match $crate::serialization::parse_yaml_input($json) {
+            Ok((value, unit_str)) => {
+                const UNIT_INFO: (
+                    whippyunits_core::dimension_exponents::DynDimensionExponents,
+                    whippyunits_core::scale_exponents::ScaleExponents,
+                ) = whippyunits_proc_macros::compute_unit_dimensions!($unit);
+                const DIMENSIONS: whippyunits_core::dimension_exponents::DynDimensionExponents =
+                    UNIT_INFO.0;
+                const SCALES: whippyunits_core::scale_exponents::ScaleExponents = UNIT_INFO.1;
+                $crate::serialization::deserialize_core_quantity::<
+                    { DIMENSIONS.0[0] },
+                    { DIMENSIONS.0[1] },
+                    { DIMENSIONS.0[2] },
+                    { DIMENSIONS.0[3] },
+                    { DIMENSIONS.0[4] },
+                    { DIMENSIONS.0[5] },
+                    { DIMENSIONS.0[6] },
+                    { DIMENSIONS.0[7] },
+                    { DIMENSIONS.0[8] },
+                    { SCALES.0[0] },
+                    { SCALES.0[1] },
+                    { SCALES.0[2] },
+                    { SCALES.0[3] },
+                    $storage_type,
+                >(value, &unit_str)
+                    as Result<
+                        whippyunits::unit!($unit, $storage_type),
+                        $crate::serialization::SerializationError,
+                    >
+            }
+            Err(e) => Err(e),
+        }
+    }};
+}
+
+/// Deserializes a [Quantity] from TOML representation.
+///
+/// The TOML counterpart of [from_json!](crate::from_json!): parses a mapping with a `value`
+/// (number or numeric string) and a `unit` (unit literal expression), and performs the same
+/// dimension validation and automatic unit conversion.
+///
+/// # Syntax
+///
+/// ```rust, ignore
+/// from_toml!(toml_string, target_unit)
+/// from_toml!(toml_string, target_unit, storage_type)
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use whippyunits::from_toml;
+/// # use whippyunits::value;
+/// # use whippyunits::unit;
+/// # fn main() {
+/// let acceleration: unit!(m / s2) = from_toml!("value = 9.81\nunit = \"m/s2\"", m / s2).unwrap();
+/// assert_eq!(value!(acceleration, m / s2), 9.81);
+/// let length: unit!(km) = from_toml!("value = 5.0\nunit = \"m\"", km).unwrap();
+/// assert_eq!(value!(length, km), 0.005);
+/// assert!(from_toml!("value = 5.0\nunit = \"m\"", kg).is_err());
+/// # }
+/// ```
+///
+/// # Error Handling
+///
+/// The macro returns a `Result<Quantity, SerializationError>`:
+/// - `Ok(quantity)`: Successfully parsed and converted quantity
+/// - `Err(SerializationError::DimensionMismatch)`: Unit dimension doesn't match target
+/// - `Err(SerializationError::InvalidFormat)`: TOML format is invalid or missing required fields
+/// - `Err(SerializationError::ParseError)`: TOML parsing failed or unit string couldn't be parsed
+#[cfg(feature = "toml")]
+#[macro_export]
+macro_rules! from_toml {
+    ($json:expr, $unit:expr) => {{
+        match $crate::serialization::parse_toml_input($json) {
+            Ok((value, unit_str)) => {
+                const UNIT_INFO: (
+                    whippyunits_core::dimension_exponents::DynDimensionExponents,
+                    whippyunits_core::scale_exponents::ScaleExponents,
+                ) = whippyunits_proc_macros::compute_unit_dimensions!($unit);
+                const DIMENSIONS: whippyunits_core::dimension_exponents::DynDimensionExponents =
+                    UNIT_INFO.0;
+                const SCALES: whippyunits_core::scale_exponents::ScaleExponents = UNIT_INFO.1;
+                $crate::serialization::deserialize_core_quantity::<
+                    { DIMENSIONS.0[0] },
+                    { DIMENSIONS.0[1] },
+                    { DIMENSIONS.0[2] },
+                    { DIMENSIONS.0[3] },
+                    { DIMENSIONS.0[4] },
+                    { DIMENSIONS.0[5] },
+                    { DIMENSIONS.0[6] },
+                    { DIMENSIONS.0[7] },
+                    { DIMENSIONS.0[8] },
+                    { SCALES.0[0] },
+                    { SCALES.0[1] },
+                    { SCALES.0[2] },
+                    { SCALES.0[3] },
+                    f64,
+                >(value, &unit_str)
+                    as Result<
+                        whippyunits::unit!($unit, f64),
+                        $crate::serialization::SerializationError,
+                    >
+            }
+            Err(e) => Err(e),
+        }
+    }};
+    ($json:expr, $unit:expr, $storage_type:ty) => {{
+        match $crate::serialization::parse_toml_input($json) {
+            Ok((value, unit_str)) => {
+                const UNIT_INFO: (
+                    whippyunits_core::dimension_exponents::DynDimensionExponents,
+                    whippyunits_core::scale_exponents::ScaleExponents,
+                ) = whippyunits_proc_macros::compute_unit_dimensions!($unit);
+                const DIMENSIONS: whippyunits_core::dimension_exponents::DynDimensionExponents =
+                    UNIT_INFO.0;
+                const SCALES: whippyunits_core::scale_exponents::ScaleExponents = UNIT_INFO.1;
+                $crate::serialization::deserialize_core_quantity::<
+                    { DIMENSIONS.0[0] },
+                    { DIMENSIONS.0[1] },
+                    { DIMENSIONS.0[2] },
+                    { DIMENSIONS.0[3] },
+                    { DIMENSIONS.0[4] },
+                    { DIMENSIONS.0[5] },
+                    { DIMENSIONS.0[6] },
+                    { DIMENSIONS.0[7] },
+                    { DIMENSIONS.0[8] },
                     { SCALES.0[0] },
                     { SCALES.0[1] },
                     { SCALES.0[2] },
@@ -902,6 +1881,7 @@ macro_rules! from_string {
                     { DIMENSIONS.0[5] },
                     { DIMENSIONS.0[6] },
                     { DIMENSIONS.0[7] },
+                    { DIMENSIONS.0[8] },
                     { SCALES.0[0] },
                     { SCALES.0[1] },
                     { SCALES.0[2] },
@@ -917,10 +1897,88 @@ macro_rules! from_string {
         }
     }};
     ($string:expr, $unit:expr, $storage_type:ty) => {{
-        match $crate::serialization::parse_string_input($string) {
-            Ok((value, unit_str)) => {
-                // Use deserialize_core_quantity to handle dimension checking and rescaling
-                // Returns Quantity directly - no need for quantity! macro
+        match $crate::serialization::parse_string_input($string) {
+            Ok((value, unit_str)) => {
+                // Use deserialize_core_quantity to handle dimension checking and rescaling
+                // Returns Quantity directly - no need for quantity! macro
+                const UNIT_INFO: (
+                    whippyunits_core::dimension_exponents::DynDimensionExponents,
+                    whippyunits_core::scale_exponents::ScaleExponents,
+                ) = whippyunits_proc_macros::compute_unit_dimensions!($unit);
+                const DIMENSIONS: whippyunits_core::dimension_exponents::DynDimensionExponents =
+                    UNIT_INFO.0;
+                const SCALES: whippyunits_core::scale_exponents::ScaleExponents = UNIT_INFO.1;
+                $crate::serialization::deserialize_core_quantity::<
+                    { DIMENSIONS.0[0] },
+                    { DIMENSIONS.0[1] },
+                    { DIMENSIONS.0[2] },
+                    { DIMENSIONS.0[3] },
+                    { DIMENSIONS.0[4] },
+                    { DIMENSIONS.0[5] },
+                    { DIMENSIONS.0[6] },
+                    { DIMENSIONS.0[7] },
+                    { DIMENSIONS.0[8] },
+                    { SCALES.0[0] },
+                    { SCALES.0[1] },
+                    { SCALES.0[2] },
+                    { SCALES.0[3] },
+                    $storage_type,
+                >(value, &unit_str)
+                    as Result<
+                        whippyunits::unit!($unit, $storage_type),
+                        $crate::serialization::SerializationError,
+                    >
+            }
+            Err(e) => Err(e),
+        }
+    }};
+}
+
+/// Creates an [Uncertain](crate::uncertain::Uncertain) quantity from a measurement string with
+/// an uncertainty.
+///
+/// # Syntax
+///
+/// ```rust, ignore
+/// from_string_uncertain!(string, target_unit);
+/// from_string_uncertain!(string, target_unit, storage_type);
+/// ```
+///
+/// where:
+///  - `string`: A measurement in either of the notations
+///     - `"<value>(<digits>) <unit expression>"`, where the digits are the uncertainty in the
+///       last decimal places of the value (`5.0(2) m` is 5.0 ± 0.2 m)
+///     - `"<value> ± <uncertainty> <unit expression>"` (or `+/-` in place of `±`)
+///  - `target_unit`: A unit literal expression, as for [from_string!](crate::from_string!)
+///  - `storage_type`: (optional) The storage type for the quantity (defaults to f64)
+///
+/// ## Examples
+///
+/// ```rust
+/// # use whippyunits::{from_string_uncertain, quantity};
+/// let length = from_string_uncertain!("5.0(2) m", m).unwrap();
+/// assert_eq!(length.value, quantity!(5.0, m));
+/// assert_eq!(length.uncertainty, quantity!(0.2, m));
+///
+/// // Both parts are converted to the target unit
+/// let length = from_string_uncertain!("5.0 ± 0.2 m", cm).unwrap();
+/// assert_eq!(length.value, quantity!(500.0, cm));
+/// assert_eq!(length.uncertainty, quantity!(20.0, cm));
+/// ```
+///
+/// # Error Handling
+///
+/// The macro returns a `Result<Uncertain<Quantity>, SerializationError>`, failing as
+/// [from_string!](crate::from_string!) does, and with
+/// `SerializationError::InvalidFormat` if the uncertainty is missing or malformed.
+#[macro_export]
+macro_rules! from_string_uncertain {
+    ($string:expr, $unit:expr) => {
+        $crate::from_string_uncertain!($string, $unit, f64)
+    };
+    ($string:expr, $unit:expr, $storage_type:ty) => {{
+        match $crate::serialization::parse_uncertain_string_input($string) {
+            Ok((value, uncertainty, unit_str)) => {
                 const UNIT_INFO: (
                     whippyunits_core::dimension_exponents::DynDimensionExponents,
                     whippyunits_core::scale_exponents::ScaleExponents,
@@ -928,7 +1986,7 @@ macro_rules! from_string {
                 const DIMENSIONS: whippyunits_core::dimension_exponents::DynDimensionExponents =
                     UNIT_INFO.0;
                 const SCALES: whippyunits_core::scale_exponents::ScaleExponents = UNIT_INFO.1;
-                $crate::serialization::deserialize_core_quantity::<
+                $crate::serialization::deserialize_core_uncertain::<
                     { DIMENSIONS.0[0] },
                     { DIMENSIONS.0[1] },
                     { DIMENSIONS.0[2] },
@@ -937,14 +1995,15 @@ macro_rules! from_string {
                     { DIMENSIONS.0[5] },
                     { DIMENSIONS.0[6] },
                     { DIMENSIONS.0[7] },
+                    { DIMENSIONS.0[8] },
                     { SCALES.0[0] },
                     { SCALES.0[1] },
                     { SCALES.0[2] },
                     { SCALES.0[3] },
                     $storage_type,
-                >(value, &unit_str)
+                >(value, uncertainty, &unit_str)
                     as Result<
-                        whippyunits::unit!($unit, $storage_type),
+                        $crate::uncertain::Uncertain<whippyunits::unit!($unit, $storage_type)>,
                         $crate::serialization::SerializationError,
                     >
             }
@@ -953,6 +2012,154 @@ macro_rules! from_string {
     }};
 }
 
+/// Reads a [Quantity] from an environment variable, falling back to a default when it is unset.
+///
+/// The variable is parsed with [from_string!](crate::from_string!) semantics, so it may be
+/// given in any unit of the target dimension (e.g. `MAX_SPEED="50 km/h"` for a target of `m/s`).
+/// This macro reports malformed values as errors; use [env_quantity!](crate::env_quantity!) to
+/// fall back to the default instead.
+///
+/// # Syntax
+///
+/// ```rust, ignore
+/// try_env_quantity!(variable_name, target_unit, default = default_value)
+/// try_env_quantity!(variable_name, target_unit, storage_type, default = default_value)
+/// ```
+///
+/// where
+///  - `variable_name`: The name of the environment variable
+///  - `target_unit`: A unit literal expression
+///  - `storage_type`: (optional) The storage type for the quantity (defaults to f64)
+///  - `default_value`: The value, in `target_unit`, to use when the variable is unset
+///
+/// ## Examples
+///
+/// ```rust,standalone_crate
+/// # use whippyunits::try_env_quantity;
+/// # use whippyunits::value;
+/// # use whippyunits::unit;
+/// # fn main() {
+/// // Normally provided by the deployment environment
+/// unsafe { std::env::set_var("DOC_MAX_SPEED", "36 km/h") };
+/// let speed: unit!(m / s) = try_env_quantity!("DOC_MAX_SPEED", m / s, default = 10.0).unwrap();
+/// assert_eq!(value!(speed, m / s), 10.0);
+///
+/// unsafe { std::env::set_var("DOC_MAX_SPEED", "fast") };
+/// assert!(try_env_quantity!("DOC_MAX_SPEED", m / s, default = 10.0).is_err());
+/// # }
+/// ```
+///
+/// # Error Handling
+///
+/// The macro returns a `Result<Quantity, SerializationError>`:
+/// - `Ok(quantity)`: The variable was parsed and converted, or was unset
+/// - `Err(SerializationError::InvalidFormat)`: The variable is not valid unicode, or
+///   its format is invalid
+/// - `Err(SerializationError::DimensionMismatch)`: Unit dimension doesn't match target
+/// - `Err(SerializationError::ParseError)`: Numeric value couldn't be parsed
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! try_env_quantity {
+    ($name:expr, $($rest:tt)*) => {
+        $crate::try_quantity_from_env_input!($crate::serialization::env_input($name), $($rest)*)
+    };
+}
+
+/// The parsing half of [try_env_quantity!](crate::try_env_quantity!), given the result of
+/// [env_input] rather than a variable name, so that it can be tested without touching the
+/// process environment.
+#[cfg(feature = "std")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! try_quantity_from_env_input {
+    ($input:expr, $unit:expr, default = $default:expr) => {{
+        match $input {
+            Ok(Some(string)) => $crate::from_string!(&string, $unit),
+            Ok(None) => Ok($crate::quantity!($default, $unit)),
+            Err(e) => Err(e),
+        }
+    }};
+    ($input:expr, $unit:expr, $storage_type:ty, default = $default:expr) => {{
+        match $input {
+            Ok(Some(string)) => $crate::from_string!(&string, $unit, $storage_type),
+            Ok(None) => Ok($crate::quantity!($default, $unit, $storage_type)),
+            Err(e) => Err(e),
+        }
+    }};
+}
+
+/// Reads a [Quantity] from an environment variable, falling back to a default when it is unset
+/// or malformed.
+///
+/// Accepts the same arguments as [try_env_quantity!](crate::try_env_quantity!), but returns the
+/// quantity directly: any value that `try_env_quantity!` would reject is replaced by the default.
+///
+/// ## Examples
+///
+/// ```rust,standalone_crate
+/// # use whippyunits::env_quantity;
+/// # use whippyunits::value;
+/// # use whippyunits::unit;
+/// # fn main() {
+/// // Normally provided by the deployment environment
+/// unsafe { std::env::set_var("DOC_TIMEOUT", "2 min") };
+/// let timeout: unit!(s) = env_quantity!("DOC_TIMEOUT", s, default = 30.0);
+/// assert_eq!(value!(timeout, s), 120.0);
+///
+/// unsafe { std::env::set_var("DOC_TIMEOUT", "2 kg") };
+/// let timeout: unit!(s) = env_quantity!("DOC_TIMEOUT", s, default = 30.0);
+/// assert_eq!(value!(timeout, s), 30.0);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! env_quantity {
+    ($name:expr, $($rest:tt)*) => {
+        $crate::quantity_from_env_input!($crate::serialization::env_input($name), $($rest)*)
+    };
+}
+
+/// The parsing half of [env_quantity!](crate::env_quantity!), given the result of [env_input].
+#[cfg(feature = "std")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! quantity_from_env_input {
+    ($input:expr, $unit:expr, default = $default:expr) => {
+        $crate::try_quantity_from_env_input!($input, $unit, default = $default)
+            .unwrap_or_else(|_| $crate::quantity!($default, $unit))
+    };
+    ($input:expr, $unit:expr, $storage_type:ty, default = $default:expr) => {
+        $crate::try_quantity_from_env_input!($input, $unit, $storage_type, default = $default)
+            .unwrap_or_else(|_| $crate::quantity!($default, $unit, $storage_type))
+    };
+}
+
+/// Read an environment variable for [try_env_quantity!](crate::try_env_quantity!)
+///
+/// Returns `Ok(None)` if the variable is unset.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub fn env_input(name: &str) -> Result<Option<String>, SerializationError> {
+    env_input_from(name, std::env::var(name))
+}
+
+/// Interpret the result of reading the environment variable `name`, as [env_input] does.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub fn env_input_from(
+    name: &str,
+    value: Result<String, std::env::VarError>,
+) -> Result<Option<String>, SerializationError> {
+    match value {
+        Ok(value) => Ok(Some(value)),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(std::env::VarError::NotUnicode(_)) => Err(SerializationError::InvalidFormat(format!(
+            "Environment variable {} is not valid unicode",
+            name
+        ))),
+    }
+}
+
 /// Parse JSON to extract value and unit string
 pub fn parse_json_input(json: &str) -> Result<(f64, String), SerializationError> {
     use serde_json;
@@ -960,9 +2167,12 @@ pub fn parse_json_input(json: &str) -> Result<(f64, String), SerializationError>
     let json_value: serde_json::Value = serde_json::from_str(json)
         .map_err(|e| SerializationError::ParseError(format!("Invalid JSON format: {}", e)))?;
 
-    let value: f64 = json_value["value"].as_f64().ok_or_else(|| {
-        SerializationError::InvalidFormat("Missing or invalid 'value' field".to_string())
-    })?;
+    let value: f64 = match &json_value["value"] {
+        serde_json::Value::String(value) => parse_numeric_string(value)?,
+        value => value.as_f64().ok_or_else(|| {
+            SerializationError::InvalidFormat("Missing or invalid 'value' field".to_string())
+        })?,
+    };
     let unit_str: String = json_value["unit"]
         .as_str()
         .ok_or_else(|| {
@@ -973,6 +2183,55 @@ pub fn parse_json_input(json: &str) -> Result<(f64, String), SerializationError>
     Ok((value, unit_str))
 }
 
+/// Parse YAML to extract value and unit string
+#[cfg(feature = "yaml")]
+pub fn parse_yaml_input(yaml: &str) -> Result<(f64, String), SerializationError> {
+    let yaml_value: serde_yaml::Value = serde_yaml::from_str(yaml)
+        .map_err(|e| SerializationError::ParseError(format!("Invalid YAML format: {}", e)))?;
+
+    let value: f64 = match &yaml_value["value"] {
+        serde_yaml::Value::String(value) => parse_numeric_string(value)?,
+        value => value.as_f64().ok_or_else(|| {
+            SerializationError::InvalidFormat("Missing or invalid 'value' field".to_string())
+        })?,
+    };
+    let unit_str: String = yaml_value["unit"]
+        .as_str()
+        .ok_or_else(|| {
+            SerializationError::InvalidFormat("Missing or invalid 'unit' field".to_string())
+        })?
+        .to_string();
+
+    Ok((value, unit_str))
+}
+
+/// Parse TOML to extract value and unit string
+#[cfg(feature = "toml")]
+pub fn parse_toml_input(toml: &str) -> Result<(f64, String), SerializationError> {
+    let toml_value: toml::Table = toml::from_str(toml)
+        .map_err(|e| SerializationError::ParseError(format!("Invalid TOML format: {}", e)))?;
+
+    let value: f64 = match toml_value.get("value") {
+        Some(toml::Value::Float(value)) => *value,
+        Some(toml::Value::Integer(value)) => *value as f64,
+        Some(toml::Value::String(value)) => parse_numeric_string(value)?,
+        _ => {
+            return Err(SerializationError::InvalidFormat(
+                "Missing or invalid 'value' field".to_string(),
+            ));
+        }
+    };
+    let unit_str: String = toml_value
+        .get("unit")
+        .and_then(toml::Value::as_str)
+        .ok_or_else(|| {
+            SerializationError::InvalidFormat("Missing or invalid 'unit' field".to_string())
+        })?
+        .to_string();
+
+    Ok((value, unit_str))
+}
+
 /// Parse string to extract value and unit string
 /// Supports both formats: "5.0 m" (with space) and "5.0m" (without space)
 pub fn parse_string_input(string: &str) -> Result<(f64, String), SerializationError> {
@@ -982,12 +2241,21 @@ pub fn parse_string_input(string: &str) -> Result<(f64, String), SerializationEr
     let parts: Vec<&str> = trimmed.split_whitespace().collect();
 
     if parts.len() >= 2 {
-        // Space-separated format: "5.0 m"
-        let value: f64 = parts[0].parse().map_err(|e| {
-            SerializationError::ParseError(format!("Failed to parse value as f64: {}", e))
-        })?;
-        let unit_str = parts[1..].join(" "); // Join remaining parts in case unit has spaces
-        return Ok((value, unit_str));
+        // Space-separated format: "5.0 m", or "5.0 kg m/s^2" where the unit itself has spaces
+        match parts[0].parse::<f64>() {
+            Ok(value) => {
+                let unit_str = parts[1..].join(" "); // Join remaining parts in case unit has spaces
+                return Ok((value, unit_str));
+            }
+            // The value runs into the unit, as in "5.0kg m/s^2": split at the numeric prefix
+            Err(_) if parts[0].starts_with(|c: char| c.is_ascii_digit() || "+-.".contains(c)) => {}
+            Err(e) => {
+                return Err(SerializationError::ParseError(format!(
+                    "Failed to parse value as f64: {}",
+                    e
+                )));
+            }
+        }
     }
 
     // No space found - try to parse by finding the boundary between number and unit
@@ -1029,6 +2297,70 @@ pub fn parse_string_input(string: &str) -> Result<(f64, String), SerializationEr
     Ok((value, unit_str))
 }
 
+/// Parse a measurement with an uncertainty into its value, uncertainty, and unit string.
+///
+/// Accepts the compact notation `5.0(2) m`, where the digits in parentheses are the uncertainty
+/// in the last digits of the value (so `5.0(2)` is `5.0 ± 0.2` and `1.234(56)` is
+/// `1.234 ± 0.056`), and the plus-minus notation `5.0 ± 0.2 m` (or `5.0 +/- 0.2 m`).  The
+/// space before the unit is optional in both.
+pub fn parse_uncertain_string_input(
+    string: &str,
+) -> Result<(f64, f64, String), SerializationError> {
+    let trimmed = string.trim();
+    let parse_value = |value_str: &str| -> Result<f64, SerializationError> {
+        value_str.trim().parse().map_err(|e| {
+            SerializationError::ParseError(format!("Failed to parse value as f64: {}", e))
+        })
+    };
+
+    let (value, uncertainty, unit_str) = if let Some((value_str, rest)) = trimmed
+        .split_once('±')
+        .or_else(|| trimmed.split_once("+/-"))
+    {
+        let (uncertainty, unit_str) = parse_string_input(rest)?;
+        (parse_value(value_str)?, uncertainty, unit_str)
+    } else if let Some((value_str, rest)) = trimmed.split_once('(') {
+        let (digits, unit_str) = rest.split_once(')').ok_or_else(|| {
+            SerializationError::InvalidFormat(format!("Unclosed uncertainty in '{}'", trimmed))
+        })?;
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(SerializationError::InvalidFormat(format!(
+                "Uncertainty in '{}' must be digits, as in 5.0(2)",
+                trimmed
+            )));
+        }
+        // The digits count in units of the value's last decimal place
+        let decimals = value_str
+            .split_once('.')
+            .map_or(0, |(_, fraction)| fraction.len());
+        let uncertainty = parse_value(digits)? / whippyunits_core::powi(10.0, decimals as i32);
+        (
+            parse_value(value_str)?,
+            uncertainty,
+            unit_str.trim().to_string(),
+        )
+    } else {
+        return Err(SerializationError::InvalidFormat(format!(
+            "No uncertainty in '{}'; expected 5.0(2) m or 5.0 ± 0.2 m",
+            trimmed
+        )));
+    };
+
+    if unit_str.is_empty() {
+        return Err(SerializationError::InvalidFormat(format!(
+            "Missing unit in '{}'",
+            trimmed
+        )));
+    }
+    if uncertainty.is_sign_negative() {
+        return Err(SerializationError::InvalidFormat(format!(
+            "Negative uncertainty in '{}'",
+            trimmed
+        )));
+    }
+    Ok((value, uncertainty, unit_str))
+}
+
 /// Core deserialization logic that handles parsing and validation
 pub fn deserialize_core<
     const MASS_EXPONENT: i16,
@@ -1039,6 +2371,7 @@ pub fn deserialize_core<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -1047,13 +2380,56 @@ pub fn deserialize_core<
     value: f64,
     unit_str: &str,
 ) -> Result<f64, SerializationError> {
+    let (unit_cf, unit_af, scale_conversion_factor) = unit_conversion_into::<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    >(unit_str)?;
+
+    // Apply nonstorage unit conversion factor and affine offset
+    let value_with_unit_conversion = (value * unit_cf) + unit_af;
+
+    // Apply scale factor conversion (for storage unit scaling)
+    Ok(value_with_unit_conversion * scale_conversion_factor)
+}
+
+/// Check that `unit_str` has the target dimension, and return the factors that convert a value
+/// in it to the target unit: its nonstorage conversion factor and affine offset, then the scale
+/// conversion factor.
+fn unit_conversion_into<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+>(
+    unit_str: &str,
+) -> Result<(f64, f64, f64), SerializationError> {
     // Parse UCUM unit to get dimensions
     let parsed_dims = parse_ucum_unit(unit_str)
         .map_err(|e| SerializationError::ParseError(format!("Failed to parse UCUM unit: {}", e)))?;
 
     // Get target dimensions from const generics
     let target_dims = (
-        DynDimensionExponents([
+        QuantityOf::<
             MASS_EXPONENT,
             LENGTH_EXPONENT,
             TIME_EXPONENT,
@@ -1062,7 +2438,14 @@ pub fn deserialize_core<
             AMOUNT_EXPONENT,
             LUMINOSITY_EXPONENT,
             ANGLE_EXPONENT,
-        ]),
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            f64,
+            (),
+        >::dimension_exponents(),
         ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
     );
 
@@ -1077,12 +2460,80 @@ pub fn deserialize_core<
     // Calculate nonstorage unit conversion factors (if any)
     let (unit_cf, unit_af) = calculate_unit_conversion_factors(unit_str)?;
 
-    // Apply nonstorage unit conversion factor and affine offset
-    let value_with_unit_conversion = (value * unit_cf) + unit_af;
-
     // Calculate scale factor conversion (for storage unit scaling)
     let scale_conversion_factor = calculate_conversion_factor(&parsed_dims, &target_dims);
-    Ok(value_with_unit_conversion * scale_conversion_factor)
+    Ok((unit_cf, unit_af, scale_conversion_factor))
+}
+
+/// Core deserialization logic for [from_string_uncertain!](crate::from_string_uncertain!):
+/// convert a value and its uncertainty in `unit_str` to the target unit.
+///
+/// The uncertainty is a difference of values, so it is converted by the unit's factors but
+/// never shifted by its affine offset (`20.0 ± 0.5 degC` is `293.15 ± 0.5 K`).
+pub fn deserialize_core_uncertain<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+>(
+    value: f64,
+    uncertainty: f64,
+    unit_str: &str,
+) -> Result<
+    crate::uncertain::Uncertain<
+        QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            T,
+            (),
+        >,
+    >,
+    SerializationError,
+>
+where
+    T: From<f64> + Copy,
+{
+    let (unit_cf, unit_af, scale_conversion_factor) = unit_conversion_into::<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    >(unit_str)?;
+
+    Ok(crate::uncertain::Uncertain::new(
+        Quantity::new(((value * unit_cf + unit_af) * scale_conversion_factor).into()),
+        Quantity::new((uncertainty * unit_cf * scale_conversion_factor).into()),
+    ))
 }
 
 /// Core deserialization logic that returns a Quantity directly (optimized version)
@@ -1096,6 +2547,7 @@ pub fn deserialize_core_quantity<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -1116,6 +2568,7 @@ pub fn deserialize_core_quantity<
             _N<AMOUNT_EXPONENT>,
             _J<LUMINOSITY_EXPONENT>,
             _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
         >,
         T,
     >,
@@ -1130,7 +2583,7 @@ where
 
     // Get target dimensions from const generics
     let target_dims = (
-        DynDimensionExponents([
+        QuantityOf::<
             MASS_EXPONENT,
             LENGTH_EXPONENT,
             TIME_EXPONENT,
@@ -1139,7 +2592,14 @@ where
             AMOUNT_EXPONENT,
             LUMINOSITY_EXPONENT,
             ANGLE_EXPONENT,
-        ]),
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            T,
+            (),
+        >::dimension_exponents(),
         ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
     );
 
@@ -1173,6 +2633,7 @@ where
             _N<AMOUNT_EXPONENT>,
             _J<LUMINOSITY_EXPONENT>,
             _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
         >,
         T,
     >::new(converted_value.into()))
@@ -1188,6 +2649,7 @@ pub fn get_quantity_dimensions<
     const AMOUNT_EXPONENT: i16,
     const LUMINOSITY_EXPONENT: i16,
     const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
     const SCALE_P2: i16,
     const SCALE_P3: i16,
     const SCALE_P5: i16,
@@ -1205,12 +2667,13 @@ pub fn get_quantity_dimensions<
             _N<AMOUNT_EXPONENT>,
             _J<LUMINOSITY_EXPONENT>,
             _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
         >,
         T,
     >,
 ) -> UnitDimensions {
     (
-        DynDimensionExponents([
+        QuantityOf::<
             MASS_EXPONENT,
             LENGTH_EXPONENT,
             TIME_EXPONENT,
@@ -1219,7 +2682,14 @@ pub fn get_quantity_dimensions<
             AMOUNT_EXPONENT,
             LUMINOSITY_EXPONENT,
             ANGLE_EXPONENT,
-        ]),
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            T,
+            (),
+        >::dimension_exponents(),
         ScaleExponents([SCALE_P2, SCALE_P3, SCALE_P5, SCALE_PI]),
     )
 }
@@ -1228,7 +2698,7 @@ pub fn get_quantity_dimensions<
 pub fn get_target_unit_dimensions(unit_literal: &str) -> UnitDimensions {
     // First try to find in unit literals
     if let Some((dimension, unit)) = lookup_unit_literal_direct(unit_literal) {
-        let (mass, length, time, current, temp, amount, lum, angle) = (
+        let (mass, length, time, current, temp, amount, lum, angle, info) = (
             dimension.exponents.0[0], // mass
             dimension.exponents.0[1], // length
             dimension.exponents.0[2], // time
@@ -1237,6 +2707,7 @@ pub fn get_target_unit_dimensions(unit_literal: &str) -> UnitDimensions {
             dimension.exponents.0[5], // amount
             dimension.exponents.0[6], // luminosity
             dimension.exponents.0[7], // angle
+            dimension.exponents.0[8], // information
         );
         let (p2, p3, p5, pi) = (
             unit.scale.0[0],
@@ -1245,7 +2716,7 @@ pub fn get_target_unit_dimensions(unit_literal: &str) -> UnitDimensions {
             unit.scale.0[3],
         );
         (
-            DynDimensionExponents([mass, length, time, current, temp, amount, lum, angle]),
+            DynDimensionExponents([mass, length, time, current, temp, amount, lum, angle, info]),
             ScaleExponents([p2, p3, p5, pi]),
         )
     } else {
@@ -1256,7 +2727,7 @@ pub fn get_target_unit_dimensions(unit_literal: &str) -> UnitDimensions {
                 .iter()
                 .find(|unit| unit.symbols.contains(&base_symbol.as_str()))
             {
-                let (mass, length, time, current, temp, amount, lum, angle) = (
+                let (mass, length, time, current, temp, amount, lum, angle, info) = (
                     base_unit.exponents.0[0], // mass
                     base_unit.exponents.0[1], // length
                     base_unit.exponents.0[2], // time
@@ -1265,6 +2736,7 @@ pub fn get_target_unit_dimensions(unit_literal: &str) -> UnitDimensions {
                     base_unit.exponents.0[5], // amount
                     base_unit.exponents.0[6], // luminosity
                     base_unit.exponents.0[7], // angle
+                    base_unit.exponents.0[8], // information
                 );
                 let inherent_scale = 0; // No inherent scale offset in the new system
 
@@ -1292,7 +2764,9 @@ pub fn get_target_unit_dimensions(unit_literal: &str) -> UnitDimensions {
                 };
 
                 (
-                    DynDimensionExponents([mass, length, time, current, temp, amount, lum, angle]),
+                    DynDimensionExponents([
+                        mass, length, time, current, temp, amount, lum, angle, info,
+                    ]),
                     ScaleExponents([p2, p3, p5, pi]),
                 )
             } else {
@@ -1373,7 +2847,7 @@ mod tests {
     fn test_json_serialization() {
         let q: Quantity<
             Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>,
-            Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>,
+            Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>, _Info<0>>,
             f64,
         > = Quantity::new(5.0);
         let json = serialize_to_json(&q).unwrap();
@@ -1388,7 +2862,7 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([0, 1, 0, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([0, 1, 0, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
@@ -1397,7 +2871,7 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([1, 0, 0, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([1, 0, 0, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
@@ -1406,10 +2880,51 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([0, 0, 1, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([0, 0, 1, 0, 0, 0, 0, 0, 0]),
+                ScaleExponents([0, 0, 0, 0])
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_ucum_unit_strict() {
+        assert_eq!(
+            parse_ucum_unit_strict("m.s").unwrap(),
+            (
+                DynDimensionExponents([0, 1, 1, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
+        assert_eq!(
+            parse_ucum_unit_strict("kg.m2/s2").unwrap(),
+            parse_ucum_unit("kg.m2/s2").unwrap()
+        );
+
+        // The tolerant parser accepts these, the strict one does not
+        for invalid in [
+            "m*s", "m^2", "kg * m", "m/", "kg/(m.s", "m.s)", "m{beats}", "", "m..s",
+        ] {
+            assert!(
+                matches!(
+                    parse_ucum_unit_strict(invalid),
+                    Err(UcumError::InvalidSyntax(_))
+                ),
+                "{invalid:?} should be rejected"
+            );
+        }
+        assert!(parse_ucum_unit("m*s").is_ok());
+
+        // Unknown units are errors, not dimensionless
+        for unknown in ["furlongz", "m.furlongz", "kg/mtr2"] {
+            assert!(
+                matches!(
+                    parse_ucum_unit_strict(unknown),
+                    Err(UcumError::InvalidSyntax(_))
+                ),
+                "{unknown:?} should be rejected"
+            );
+        }
+        assert!(parse_ucum_unit_strict("km/h").is_ok());
     }
 
     #[test]
@@ -1418,7 +2933,7 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
@@ -1430,7 +2945,7 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([0, 2, 0, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([0, 2, 0, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
@@ -1439,7 +2954,7 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([0, 0, -1, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([0, 0, -1, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
@@ -1452,7 +2967,7 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([0, 2, 0, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([0, 2, 0, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
@@ -1461,7 +2976,7 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([0, 0, -1, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([0, 0, -1, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
@@ -1470,7 +2985,7 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([1, 1, -2, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([1, 1, -2, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
@@ -1482,7 +2997,7 @@ mod tests {
         assert_eq!(
             result,
             (
-                DynDimensionExponents([1, 1, -2, 0, 0, 0, 0, 0]),
+                DynDimensionExponents([1, 1, -2, 0, 0, 0, 0, 0, 0]),
                 ScaleExponents([0, 0, 0, 0])
             )
         );
@@ -1491,15 +3006,15 @@ mod tests {
     #[test]
     fn test_dimensions_match() {
         let dims1 = (
-            DynDimensionExponents([1, 1, -2, 0, 0, 0, 0, 0]),
+            DynDimensionExponents([1, 1, -2, 0, 0, 0, 0, 0, 0]),
             ScaleExponents([0, 0, 0, 0]),
         );
         let dims2 = (
-            DynDimensionExponents([1, 1, -2, 0, 0, 0, 0, 0]),
+            DynDimensionExponents([1, 1, -2, 0, 0, 0, 0, 0, 0]),
             ScaleExponents([0, 0, 0, 0]),
         );
         let dims3 = (
-            DynDimensionExponents([1, 0, 0, 0, 0, 0, 0, 0]),
+            DynDimensionExponents([1, 0, 0, 0, 0, 0, 0, 0, 0]),
             ScaleExponents([0, 0, 0, 0]),
         );
 
@@ -1510,11 +3025,11 @@ mod tests {
     #[test]
     fn test_calculate_conversion_factor() {
         let from_dims = (
-            DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0]),
+            DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0, 0]),
             ScaleExponents([0, 0, 0, 0]),
         );
         let to_dims = (
-            DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0]),
+            DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0, 0]),
             ScaleExponents([0, 0, 0, 0]),
         );
 
@@ -0,0 +1,506 @@
+//! Running statistics over streams of [Quantity] values.
+//!
+//! [QuantityAccumulator] keeps a running count, sum, and mean of quantities in one fixed unit.
+//! Quantities of any scale with the right dimension can be pushed; each is rescaled into the
+//! accumulator's unit as it arrives, so readings from sources with different units can be
+//! combined:
+//!
+//! ```rust
+//! use whippyunits::stats::QuantityAccumulator;
+//! use whippyunits::{quantity, unit};
+//!
+//! let mut distances = QuantityAccumulator::<unit!(m)>::new();
+//! distances.push(quantity!(1.0, km));
+//! distances.push(quantity!(500.0, m));
+//! distances.push(quantity!(150_000.0, cm));
+//!
+//! assert_eq!(distances.count(), 3);
+//! assert_eq!(distances.sum(), quantity!(3000.0, m));
+//! assert_eq!(distances.mean(), Some(quantity!(1000.0, m)));
+//! ```
+//!
+//! [harmonic_mean] averages rates, such as speeds over legs of equal distance, and
+//! [weighted_mean] combines readings of differing confidence.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::api::aggregate_scale_factor_float;
+use crate::quantity::{Quantity, QuantityOf};
+
+/// Running count, sum, and mean of quantities, kept in the unit of the quantity type `Q`.
+///
+/// The running values are kept in `f64` whatever the storage type of `Q`, and converted to it
+/// when read.  The mean is updated incrementally (Welford's method) rather than derived from the
+/// sum, so it stays accurate over long streams even when the sum grows large.
+#[derive(Debug, Clone, Copy)]
+pub struct QuantityAccumulator<Q> {
+    count: usize,
+    sum: f64,
+    mean: f64,
+    _quantity: PhantomData<Q>,
+}
+
+impl<Q> QuantityAccumulator<Q> {
+    /// Create an empty accumulator.
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            mean: 0.0,
+            _quantity: PhantomData,
+        }
+    }
+
+    /// The number of quantities pushed so far.
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<Q> Default for QuantityAccumulator<Q> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>
+    QuantityAccumulator<
+        QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            T,
+            Brand,
+        >,
+    >
+where
+    T: Copy + num_traits::NumCast,
+{
+    /// Add a quantity to the accumulator, rescaling it into the accumulator's unit.
+    ///
+    /// The quantity may have any scale, but must have the accumulator's dimension and brand.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted to `f64`.
+    pub fn push<
+        const SCALE_P2_FROM: i16,
+        const SCALE_P3_FROM: i16,
+        const SCALE_P5_FROM: i16,
+        const SCALE_PI_FROM: i16,
+    >(
+        &mut self,
+        quantity: QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2_FROM,
+            SCALE_P3_FROM,
+            SCALE_P5_FROM,
+            SCALE_PI_FROM,
+            T,
+            Brand,
+        >,
+    ) {
+        let value = <f64 as num_traits::NumCast>::from(quantity.unsafe_value)
+            .expect("unable to convert numeric value to f64 for accumulation");
+        let value = value
+            * aggregate_scale_factor_float(
+                SCALE_P2_FROM,
+                SCALE_P3_FROM,
+                SCALE_P5_FROM,
+                SCALE_PI_FROM,
+                SCALE_P2,
+                SCALE_P3,
+                SCALE_P5,
+                SCALE_PI,
+            );
+
+        self.count += 1;
+        self.sum += value;
+        self.mean += (value - self.mean) / self.count as f64;
+    }
+
+    /// The sum of the quantities pushed so far, or zero if none have been.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum cannot be represented in the storage type `T`.
+    pub fn sum(
+        &self,
+    ) -> QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+        T,
+        Brand,
+    > {
+        Quantity::new(
+            <T as num_traits::NumCast>::from(self.sum)
+                .expect("accumulated sum does not fit the storage type"),
+        )
+    }
+
+    /// The mean of the quantities pushed so far, or `None` if none have been.
+    ///
+    /// For integer storage types, the mean is truncated toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mean cannot be represented in the storage type `T`.
+    pub fn mean(
+        &self,
+    ) -> Option<
+        QuantityOf<
+            MASS_EXPONENT,
+            LENGTH_EXPONENT,
+            TIME_EXPONENT,
+            CURRENT_EXPONENT,
+            TEMPERATURE_EXPONENT,
+            AMOUNT_EXPONENT,
+            LUMINOSITY_EXPONENT,
+            ANGLE_EXPONENT,
+            INFORMATION_EXPONENT,
+            SCALE_P2,
+            SCALE_P3,
+            SCALE_P5,
+            SCALE_PI,
+            T,
+            Brand,
+        >,
+    > {
+        if self.count == 0 {
+            return None;
+        }
+        Some(Quantity::new(
+            <T as num_traits::NumCast>::from(self.mean)
+                .expect("accumulated mean does not fit the storage type"),
+        ))
+    }
+}
+
+/// The harmonic mean `n / (1/x₁ + … + 1/xₙ)` of `quantities`, in their common unit.
+///
+/// This is the correct average for rates over equal amounts of their numerator, such as speeds
+/// over legs of equal distance:
+///
+/// ```rust
+/// use whippyunits::stats::harmonic_mean;
+/// use whippyunits::{quantity, value};
+///
+/// // Out at 60 km/h and back at 40 km/h averages 48 km/h, not 50 km/h
+/// let speeds = [quantity!(60.0, km / h), quantity!(40.0, km / h)];
+/// let average = harmonic_mean(&speeds).unwrap();
+/// assert!((value!(average, km / h) - 48.0).abs() < 1e-9);
+/// ```
+///
+/// The reciprocals are summed as plain values in the unit of the quantity type, so the result
+/// has the same type as the inputs.  For integer storage types, the result is truncated toward
+/// zero.
+///
+/// # Errors
+///
+/// Fails if `quantities` is empty, if any of them is zero, since its reciprocal is undefined, or
+/// if quantities of mixed sign have reciprocals that sum to zero, since the mean is then
+/// undefined.
+///
+/// # Panics
+///
+/// Panics if a value cannot be converted to `f64`, or the result cannot be represented in the
+/// storage type `T`.
+pub fn harmonic_mean<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>(
+    quantities: &[QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+        T,
+        Brand,
+    >],
+) -> Result<
+    QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+        T,
+        Brand,
+    >,
+    HarmonicMeanError,
+>
+where
+    T: Copy + num_traits::NumCast,
+{
+    if quantities.is_empty() {
+        return Err(HarmonicMeanError::Empty);
+    }
+
+    let mut reciprocal_sum = 0.0;
+    for (index, quantity) in quantities.iter().enumerate() {
+        let value = <f64 as num_traits::NumCast>::from(quantity.unsafe_value)
+            .expect("unable to convert numeric value to f64 for averaging");
+        if value == 0.0 {
+            return Err(HarmonicMeanError::Zero { index });
+        }
+        reciprocal_sum += 1.0 / value;
+    }
+    if reciprocal_sum == 0.0 {
+        return Err(HarmonicMeanError::ZeroReciprocalSum);
+    }
+
+    Ok(Quantity::new(
+        <T as num_traits::NumCast>::from(quantities.len() as f64 / reciprocal_sum)
+            .expect("harmonic mean does not fit the storage type"),
+    ))
+}
+
+/// Error returned by [harmonic_mean].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HarmonicMeanError {
+    /// No quantities were given
+    Empty,
+    /// The quantity at `index` is zero, so has no reciprocal
+    Zero {
+        /// Position of the zero quantity in the input
+        index: usize,
+    },
+    /// The reciprocals of the quantities sum to zero, so the mean is undefined
+    ZeroReciprocalSum,
+}
+
+impl fmt::Display for HarmonicMeanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HarmonicMeanError::Empty => write!(f, "cannot take the harmonic mean of no quantities"),
+            HarmonicMeanError::Zero { index } => write!(
+                f,
+                "cannot take the harmonic mean: quantity {} is zero",
+                index
+            ),
+            HarmonicMeanError::ZeroReciprocalSum => write!(
+                f,
+                "cannot take the harmonic mean: the reciprocals sum to zero"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for HarmonicMeanError {}
+
+/// The weighted mean `(w₁x₁ + … + wₙxₙ) / (w₁ + … + wₙ)` of `values`, in their common unit.
+///
+/// Each value is paired with the weight at the same position, and the result is normalized by
+/// the weight sum, so weights need not add to one:
+///
+/// ```rust
+/// use whippyunits::stats::weighted_mean;
+/// use whippyunits::quantity;
+///
+/// // Fuse two range readings, trusting the second three times as much
+/// let readings = [quantity!(10.0, m), quantity!(14.0, m)];
+/// let fused = weighted_mean(&readings, &[1.0, 3.0]).unwrap();
+/// assert_eq!(fused, quantity!(13.0, m));
+/// ```
+///
+/// Weights may be negative, as long as they do not sum to zero.  For integer storage types, the
+/// result is truncated toward zero.
+///
+/// # Errors
+///
+/// Fails if `values` and `weights` differ in length, or if the weights sum to zero (including
+/// when both are empty).
+///
+/// # Panics
+///
+/// Panics if a value cannot be converted to `f64`, or the result cannot be represented in the
+/// storage type `T`.
+pub fn weighted_mean<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>(
+    values: &[QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+        T,
+        Brand,
+    >],
+    weights: &[f64],
+) -> Result<
+    QuantityOf<
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+        T,
+        Brand,
+    >,
+    WeightedMeanError,
+>
+where
+    T: Copy + num_traits::NumCast,
+{
+    if values.len() != weights.len() {
+        return Err(WeightedMeanError::LengthMismatch {
+            values: values.len(),
+            weights: weights.len(),
+        });
+    }
+
+    let total_weight: f64 = weights.iter().sum();
+    if total_weight == 0.0 {
+        return Err(WeightedMeanError::ZeroTotalWeight);
+    }
+
+    let mut weighted_sum = 0.0;
+    for (quantity, weight) in values.iter().zip(weights) {
+        let value = <f64 as num_traits::NumCast>::from(quantity.unsafe_value)
+            .expect("unable to convert numeric value to f64 for averaging");
+        weighted_sum += weight * value;
+    }
+
+    Ok(Quantity::new(
+        <T as num_traits::NumCast>::from(weighted_sum / total_weight)
+            .expect("weighted mean does not fit the storage type"),
+    ))
+}
+
+/// Error returned by [weighted_mean].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightedMeanError {
+    /// The number of weights differs from the number of values
+    LengthMismatch {
+        /// Number of values given
+        values: usize,
+        /// Number of weights given
+        weights: usize,
+    },
+    /// The weights sum to zero, so the mean is undefined
+    ZeroTotalWeight,
+}
+
+impl fmt::Display for WeightedMeanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightedMeanError::LengthMismatch { values, weights } => write!(
+                f,
+                "cannot take the weighted mean of {} values with {} weights",
+                values, weights
+            ),
+            WeightedMeanError::ZeroTotalWeight => {
+                write!(f, "cannot take the weighted mean: the weights sum to zero")
+            }
+        }
+    }
+}
+
+impl core::error::Error for WeightedMeanError {}
@@ -0,0 +1,44 @@
+//! Recording [Quantity] values as [tracing](https://docs.rs/tracing) fields.
+//!
+//! `tracing` only records its own primitive types directly, so a quantity is recorded through
+//! one of its formatting impls.  Record it with the `%` sigil, which uses `Display` and gives the
+//! same value-and-unit string as printing the quantity:
+//!
+//! ```rust
+//! use whippyunits::quantity;
+//!
+//! let distance = quantity!(1.5, km);
+//! tracing::info!(distance = %distance, "arrived");
+//! // records distance = "1.5000 Quantity<km, f64>"
+//! ```
+//!
+//! Avoid the `?` sigil: it uses `Debug`, which spells out the full quantity type and is meant
+//! for inspecting types rather than for logs.
+//!
+//! Where a sigil is easy to forget, [Quantity::as_field] produces the `Display` field value
+//! directly:
+//!
+//! ```rust
+//! use whippyunits::quantity;
+//!
+//! let distance = quantity!(1.5, km);
+//! tracing::info!(distance = distance.as_field(), "arrived");
+//! ```
+
+use core::fmt::Display;
+
+use tracing::field::{DisplayValue, display};
+
+use crate::quantity::Quantity;
+
+impl<Scale, Dimension, T, Brand> Quantity<Scale, Dimension, T, Brand>
+where
+    Self: Display,
+{
+    /// Wrap this quantity for recording as a `tracing` field, using its `Display` output.
+    ///
+    /// Equivalent to recording it with the `%` sigil.
+    pub fn as_field(&self) -> DisplayValue<&Self> {
+        display(self)
+    }
+}
@@ -0,0 +1,22 @@
+//! Quantities paired with a measurement uncertainty.
+//!
+//! An [Uncertain] holds a measured value and its (standard) uncertainty as two quantities of
+//! the same type.  Measurements written in laboratory notation, either compact (`5.0(2) m`) or
+//! with a plus-minus sign (`5.0 ± 0.2 m`), can be read with
+//! [from_string_uncertain!](crate::from_string_uncertain!).
+
+/// A measured quantity `value ± uncertainty`, both of quantity type `Q`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uncertain<Q> {
+    /// The measured value
+    pub value: Q,
+    /// The uncertainty of the value, in the same unit; never negative
+    pub uncertainty: Q,
+}
+
+impl<Q> Uncertain<Q> {
+    /// Pair a measured value with its uncertainty.
+    pub const fn new(value: Q, uncertainty: Q) -> Self {
+        Self { value, uncertainty }
+    }
+}
@@ -0,0 +1,364 @@
+//! Compact 2-byte unit codes for quantities in size-constrained messages.
+//!
+//! Every concrete quantity type has a [UNIT_CODE](Quantity::UNIT_CODE), a 16-bit hash of its
+//! dimension and scale exponents, so a telemetry packet can carry a quantity as its raw value and
+//! a `u16` rather than a unit string or the 26 bytes of its exponents.
+//!
+//! Sender and receiver share a [UnitCodeRegistry] of the units they exchange, built with
+//! [unit_code_registry!](crate::unit_code_registry!).  When it is built, the registry chooses a
+//! hash seed under which no two registered units collide, so within the registered set each code
+//! names exactly one unit.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use whippyunits::unit_code::UnitCodeRegistry;
+//! use whippyunits::{quantity, unit, unit_code_registry};
+//!
+//! const TELEMETRY_UNITS: UnitCodeRegistry = unit_code_registry![m, km, m / s];
+//!
+//! let (code, value) = quantity!(1.5, km).to_code(&TELEMETRY_UNITS);
+//! assert_eq!(code, <unit!(km)>::UNIT_CODE);
+//!
+//! // The receiver may rescale into a different unit of the same dimension
+//! let distance = <unit!(m)>::from_code(&TELEMETRY_UNITS, code, value).unwrap();
+//! assert_eq!(distance, quantity!(1500.0, m));
+//! ```
+
+use core::fmt;
+
+use crate::quantity::Quantity;
+use crate::quantity::{_2, _3, _5, _A, _I, _Info, _J, _L, _M, _N, _Pi, _T, _Θ, Dimension, Scale};
+use whippyunits_core::{
+    dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
+};
+
+/// Unit code of the given exponents, in the order M, L, T, I, Θ, N, J, A, Info, 2, 3, 5, π.
+///
+/// This is the 32-bit FNV-1a hash of the little-endian exponents, folded to 16 bits.  The
+/// information exponent is hashed last, and only when it is nonzero, so the codes of all other
+/// units match those from before information had its own dimension.  It is part of the wire
+/// format and must never change.
+pub const fn unit_code(exponents: [i16; 13]) -> u16 {
+    seeded_unit_code(exponents, 0)
+}
+
+/// Unit code of the given exponents under a registry's hash `seed`.
+///
+/// A nonzero seed is hashed ahead of the exponents; seed 0 gives the plain [unit_code].
+pub const fn seeded_unit_code(exponents: [i16; 13], seed: u16) -> u16 {
+    let mut hash: u32 = 0x811c_9dc5;
+    if seed != 0 {
+        hash = fnv1a_exponent(hash, seed as i16);
+    }
+    let mut i = 0;
+    while i < exponents.len() {
+        if i != 8 {
+            hash = fnv1a_exponent(hash, exponents[i]);
+        }
+        i += 1;
+    }
+    if exponents[8] != 0 {
+        hash = fnv1a_exponent(hash, exponents[8]);
+    }
+    ((hash >> 16) ^ (hash & 0xffff)) as u16
+}
+
+const fn fnv1a_exponent(mut hash: u32, exponent: i16) -> u32 {
+    let bytes = exponent.to_le_bytes();
+    let mut j = 0;
+    while j < bytes.len() {
+        hash ^= bytes[j] as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        j += 1;
+    }
+    hash
+}
+
+/// A unit registered in a [UnitCodeRegistry].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitCodeEntry {
+    /// Exponents, in the order M, L, T, I, Θ, N, J, A, Info, 2, 3, 5, π
+    pub exponents: [i16; 13],
+}
+
+impl UnitCodeEntry {
+    /// The entry for a unit with the given exponents.
+    pub const fn new(exponents: [i16; 13]) -> Self {
+        UnitCodeEntry { exponents }
+    }
+
+    /// The entry for a unit evaluated by the unit macros, for
+    /// [unit_code_registry!](crate::unit_code_registry!).
+    #[doc(hidden)]
+    pub const fn from_unit_dimensions(unit: (DynDimensionExponents, ScaleExponents)) -> Self {
+        let (dimensions, scale) = (unit.0.0, unit.1.0);
+        let mut exponents = [0; 13];
+        let mut i = 0;
+        while i < 9 {
+            exponents[i] = dimensions[i];
+            i += 1;
+        }
+        while i < 13 {
+            exponents[i] = scale[i - 9];
+            i += 1;
+        }
+        Self::new(exponents)
+    }
+}
+
+/// The set of units a receiver can decode from their [unit codes](unit_code).
+///
+/// The codes form a perfect hash over the registered set: the registry uses the first hash
+/// [seed](Self::seed) under which no two different units share a code.  That is seed 0, and so
+/// each unit's [UNIT_CODE](Quantity::UNIT_CODE), unless two registered units collide there.
+/// Sender and receiver must therefore agree on the registered set, and encode with
+/// [to_code](Quantity::to_code) against the same registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitCodeRegistry {
+    entries: &'static [UnitCodeEntry],
+    seed: u16,
+}
+
+impl UnitCodeRegistry {
+    /// A registry of `entries`, choosing the first seed that gives them distinct codes.
+    ///
+    /// Registering the same unit twice is allowed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no seed gives distinct codes, which fails the build when the registry is a
+    /// `const`.  That can only happen for registries of tens of thousands of units.
+    pub const fn new(entries: &'static [UnitCodeEntry]) -> Self {
+        let mut seed: u16 = 0;
+        loop {
+            if is_perfect(entries, seed) {
+                return UnitCodeRegistry { entries, seed };
+            }
+            if seed == u16::MAX {
+                panic!("no unit code seed separates the registered units");
+            }
+            seed += 1;
+        }
+    }
+
+    /// The registered units.
+    pub const fn entries(&self) -> &'static [UnitCodeEntry] {
+        self.entries
+    }
+
+    /// The hash seed of this registry's codes.
+    pub const fn seed(&self) -> u16 {
+        self.seed
+    }
+
+    /// The code of a unit with the given exponents in this registry.
+    pub const fn code(&self, exponents: [i16; 13]) -> u16 {
+        seeded_unit_code(exponents, self.seed)
+    }
+
+    /// The exponents of the registered unit with `code`, if there is one.
+    pub fn lookup(&self, code: u16) -> Option<[i16; 13]> {
+        self.entries
+            .iter()
+            .find(|entry| self.code(entry.exponents) == code)
+            .map(|entry| entry.exponents)
+    }
+}
+
+/// Whether `seed` gives every pair of different units in `entries` a different code.
+const fn is_perfect(entries: &[UnitCodeEntry], seed: u16) -> bool {
+    let mut i = 0;
+    while i < entries.len() {
+        let code = seeded_unit_code(entries[i].exponents, seed);
+        let mut j = i + 1;
+        while j < entries.len() {
+            if code == seeded_unit_code(entries[j].exponents, seed)
+                && !exponents_eq(entries[i].exponents, entries[j].exponents)
+            {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn exponents_eq(a: [i16; 13], b: [i16; 13]) -> bool {
+    let mut i = 0;
+    while i < 13 {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Builds a [UnitCodeRegistry] of the given units.
+///
+/// ## Syntax
+///
+/// ```rust,ignore
+/// unit_code_registry![unit_1, unit_2, ...]
+/// ```
+///
+/// where each unit is a unit literal expression, as for [unit!](crate::unit!).  Assign the
+/// registry to a `const` so that its seed is chosen at compile time.
+#[macro_export]
+macro_rules! unit_code_registry {
+    ($($unit:expr),* $(,)?) => {
+        $crate::unit_code::UnitCodeRegistry::new(&[$(
+            $crate::unit_code::UnitCodeEntry::from_unit_dimensions(
+                whippyunits_proc_macros::compute_unit_dimensions!($unit),
+            )
+        ),*])
+    };
+}
+
+/// Error returned by [Quantity::from_code].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FromCodeError {
+    /// The code is not in the registry
+    UnknownCode(u16),
+    /// The code names a unit of a different dimension, in the order M, L, T, I, Θ, N, J, A,
+    /// Info
+    DimensionMismatch {
+        /// Dimension exponents of the quantity type being decoded
+        expected: [i16; 9],
+        /// Dimension exponents of the unit named by the code
+        found: [i16; 9],
+    },
+    /// The rescaled value cannot be represented in the quantity's storage type
+    ValueOutOfRange(f64),
+}
+
+impl fmt::Display for FromCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromCodeError::UnknownCode(code) => write!(f, "unknown unit code {:#06x}", code),
+            FromCodeError::DimensionMismatch { expected, found } => write!(
+                f,
+                "unit code dimension {:?} does not match expected {:?} \
+                 (order: M, L, T, I, Θ, N, J, A, Info)",
+                found, expected
+            ),
+            FromCodeError::ValueOutOfRange(value) => {
+                write!(f, "decoded value {} does not fit the storage type", value)
+            }
+        }
+    }
+}
+
+impl core::error::Error for FromCodeError {}
+
+impl<
+    const MASS_EXPONENT: i16,
+    const LENGTH_EXPONENT: i16,
+    const TIME_EXPONENT: i16,
+    const CURRENT_EXPONENT: i16,
+    const TEMPERATURE_EXPONENT: i16,
+    const AMOUNT_EXPONENT: i16,
+    const LUMINOSITY_EXPONENT: i16,
+    const ANGLE_EXPONENT: i16,
+    const INFORMATION_EXPONENT: i16,
+    const SCALE_P2: i16,
+    const SCALE_P3: i16,
+    const SCALE_P5: i16,
+    const SCALE_PI: i16,
+    T,
+    Brand,
+>
+    Quantity<
+        Scale<_2<SCALE_P2>, _3<SCALE_P3>, _5<SCALE_P5>, _Pi<SCALE_PI>>,
+        Dimension<
+            _M<MASS_EXPONENT>,
+            _L<LENGTH_EXPONENT>,
+            _T<TIME_EXPONENT>,
+            _I<CURRENT_EXPONENT>,
+            _Θ<TEMPERATURE_EXPONENT>,
+            _N<AMOUNT_EXPONENT>,
+            _J<LUMINOSITY_EXPONENT>,
+            _A<ANGLE_EXPONENT>,
+            _Info<INFORMATION_EXPONENT>,
+        >,
+        T,
+        Brand,
+    >
+{
+    const CODE_EXPONENTS: [i16; 13] = [
+        MASS_EXPONENT,
+        LENGTH_EXPONENT,
+        TIME_EXPONENT,
+        CURRENT_EXPONENT,
+        TEMPERATURE_EXPONENT,
+        AMOUNT_EXPONENT,
+        LUMINOSITY_EXPONENT,
+        ANGLE_EXPONENT,
+        INFORMATION_EXPONENT,
+        SCALE_P2,
+        SCALE_P3,
+        SCALE_P5,
+        SCALE_PI,
+    ];
+
+    /// The [unit code](crate::unit_code) of this quantity type's unit.
+    ///
+    /// This is its code in any registry with seed 0; see [UnitCodeRegistry].
+    pub const UNIT_CODE: u16 = unit_code(Self::CODE_EXPONENTS);
+
+    /// This quantity as its unit code in `registry` and its stored value.
+    pub fn to_code(&self, registry: &UnitCodeRegistry) -> (u16, T)
+    where
+        T: Copy,
+    {
+        (registry.code(Self::CODE_EXPONENTS), self.unsafe_value)
+    }
+
+    /// Decode a quantity sent as a unit code and value, looking the code up in `registry`.
+    ///
+    /// A value in another registered unit of the same dimension is rescaled to this type; a
+    /// value already in this type's unit is taken as is.  For integer storage types, a
+    /// fractional rescaled value is truncated toward zero.
+    pub fn from_code(
+        registry: &UnitCodeRegistry,
+        code: u16,
+        value: T,
+    ) -> Result<Self, FromCodeError>
+    where
+        T: num_traits::NumCast,
+    {
+        let exponents = registry
+            .lookup(code)
+            .ok_or(FromCodeError::UnknownCode(code))?;
+        if exponents == Self::CODE_EXPONENTS {
+            return Ok(Self::new(value));
+        }
+
+        let mut expected = [0; 9];
+        expected.copy_from_slice(&Self::CODE_EXPONENTS[..9]);
+        let mut found = [0; 9];
+        found.copy_from_slice(&exponents[..9]);
+        if found != expected {
+            return Err(FromCodeError::DimensionMismatch { expected, found });
+        }
+
+        let value = <f64 as num_traits::NumCast>::from(value)
+            .expect("unable to convert numeric value to f64 for rescaling");
+        let rescaled = value
+            * crate::api::aggregate_scale_factor_float(
+                exponents[9],
+                exponents[10],
+                exponents[11],
+                exponents[12],
+                SCALE_P2,
+                SCALE_P3,
+                SCALE_P5,
+                SCALE_PI,
+            );
+        <T as num_traits::NumCast>::from(rescaled)
+            .map(Self::new)
+            .ok_or(FromCodeError::ValueOutOfRange(rescaled))
+    }
+}
@@ -1,10 +1,11 @@
 #![allow(non_snake_case)]
 #![allow(unused_variables)]
 
-use whippyunits::api::rescale;
+use whippyunits::api::{rescale, rescale_i32};
 use whippyunits::default_declarators::*;
 use whippyunits::dimension_traits::define_generic_dimension;
 use whippyunits::quantity;
+use whippyunits::quantity::Quantity;
 use whippyunits::unit;
 use whippyunits::value;
 use whippyunits_core::Unit;
@@ -32,6 +33,87 @@ fn test_legal_subtraction() {
     assert_eq!(value!(result, s), 25.0);
 }
 
+#[test]
+fn test_cross_scale_addition_via_rescale() {
+    // Cross-scale addition is a compile error (see compile_fail/add_different_scales.rs);
+    // an explicit rescale on either side picks the result scale
+    let total: unit!(m) = quantity!(1.0, m) + rescale(quantity!(50.0, cm));
+    assert_eq!(value!(total, m), 1.5);
+
+    let total: unit!(cm) = rescale(quantity!(1.0, m)) + quantity!(50.0, cm);
+    assert_eq!(value!(total, cm), 150.0);
+
+    let difference: unit!(m) = quantity!(1.0, m) - rescale(quantity!(50.0, cm));
+    assert_eq!(value!(difference, m), 0.5);
+}
+
+#[test]
+fn test_cross_scale_subtraction_via_rescale() {
+    // Rescaling the right-hand side keeps the result in the left-hand scale
+    let difference: unit!(km) = quantity!(1.0, km) - rescale(quantity!(500.0, m));
+    assert_eq!(value!(difference, km), 0.5);
+
+    // Negative results are fine for signed storage
+    let difference: unit!(km) = quantity!(1.0, km) - rescale(quantity!(1500.0, m));
+    assert_eq!(value!(difference, km), -0.5);
+
+    // Integer storage truncates when rescaling into the coarser scale
+    let coarse: unit!(km, i32) = quantity!(1, km, i32) - rescale_i32(quantity!(500, m, i32));
+    assert_eq!(coarse.unsafe_value, 1);
+
+    let fine: unit!(m, i32) = rescale_i32(quantity!(1, km, i32)) - quantity!(1500, m, i32);
+    assert_eq!(fine.unsafe_value, -500);
+}
+
+#[test]
+fn test_cross_scale_addition_integer_truncation() {
+    // Rescaling integer storage to a coarser scale truncates, which is why rescaling is never
+    // implicit: rescale into the finer scale to keep the sum exact
+    let coarse: unit!(m, i32) = quantity!(1, m, i32) + rescale_i32(quantity!(50, cm, i32));
+    assert_eq!(coarse.unsafe_value, 1);
+
+    let fine: unit!(cm, i32) = rescale_i32(quantity!(1, m, i32)) + quantity!(50, cm, i32);
+    assert_eq!(fine.unsafe_value, 150);
+}
+
+#[test]
+fn test_add_rescaled() {
+    // The right-hand side is rescaled into the left-hand scale
+    let total: unit!(m) = quantity!(1.0, m).add_rescaled(quantity!(50.0, cm));
+    assert_eq!(value!(total, m), 1.5);
+
+    let total: unit!(cm) = quantity!(50.0, cm).add_rescaled(quantity!(1.0, m));
+    assert_eq!(value!(total, cm), 150.0);
+
+    let total: unit!(m, f32) = quantity!(1.0, m, f32).add_rescaled(quantity!(50.0, cm, f32));
+    assert_eq!(value!(total, m, f32), 1.5);
+
+    let difference: unit!(km) = quantity!(1.0, km).sub_rescaled(quantity!(1500.0, m));
+    assert_eq!(value!(difference, km), -0.5);
+
+    // Integer storage is scale-strict (see compile_fail/add_rescaled_integer.rs)
+    let total: unit!(m, i32) = quantity!(1, m, i32).add_rescaled(quantity!(2, m, i32));
+    assert_eq!(total.unsafe_value, 3);
+}
+
+#[test]
+fn test_add_sub_infers_right_hand_side() {
+    // The right-hand side of a quantity-quantity operator is inferred from the left-hand side
+    let a: unit!(m) = quantity!(1.0, m);
+    let b = a + Quantity::new(2.0);
+    assert_eq!(value!(b, m), 3.0);
+
+    let c = a - Quantity::new(0.5);
+    assert_eq!(value!(c, m), 0.5);
+
+    let mut d = a;
+    d += Quantity::new(2.0);
+    assert_eq!(value!(d, m), 3.0);
+
+    d -= Quantity::new(1.0);
+    assert_eq!(value!(d, m), 2.0);
+}
+
 #[test]
 fn test_scalar_from_radians() {
     let radians = 5.0.radians();
@@ -81,6 +163,32 @@ fn test_dimensionless_to_scalar_conversion() {
     assert_eq!(scalar_i32, 0); // 0.005 rounds to 0 for i32
 }
 
+#[test]
+fn test_same_dimension_ratio_folds_scale() {
+    // Float division folds the scale difference into the value, yielding an unscaled
+    // dimensionless quantity...
+    let quotient: unit!(1) = quantity!(1.0, km) / quantity!(1.0, m);
+    assert_eq!(quotient.unsafe_value, 1000.0);
+
+    let quotient: unit!(1, f32) = quantity!(1.0, m, f32) / quantity!(1.0, mm, f32);
+    assert_eq!(quotient.unsafe_value, 1000.0);
+
+    // ...while integer division carries it in the type, so that it stays lossless
+    let quotient = quantity!(6, km, i32) / quantity!(2, m, i32);
+    assert_eq!(quotient.unsafe_value, 3);
+
+    // `ratio` folds it into the value for every storage type
+    let ratio: unit!(1) = quantity!(1.0, km).ratio(quantity!(1.0, m));
+    assert_eq!(ratio.unsafe_value, 1000.0);
+    assert_eq!(value!(ratio, 1), 1000.0);
+
+    let inverse: unit!(1) = quantity!(1.0, m).ratio(quantity!(1.0, km));
+    assert_eq!(inverse.unsafe_value, 0.001);
+
+    let ratio_i32: unit!(1, i32) = quantity!(3, km, i32).ratio(quantity!(500, m, i32));
+    assert_eq!(ratio_i32.unsafe_value, 6);
+}
+
 #[test]
 fn test_radian_erasure_with_scale() {
     let radians_zero_scale: unit!(rad) = quantity!(3.14159, rad);
@@ -106,6 +214,26 @@ fn test_scalar_quantity_multiplication() {
     assert_eq!(value!(result, m), 20.0);
 }
 
+#[test]
+fn test_bare_scalar_multiplication() {
+    // Either ordering scales the value and keeps the unit
+    assert_eq!(2.0 * quantity!(3.0, m), quantity!(6.0, m));
+    assert_eq!(quantity!(3.0, m) * 2.0, quantity!(6.0, m));
+    assert_eq!(2.0f32 * quantity!(3.0, m, f32), quantity!(6.0, m, f32));
+    assert_eq!(quantity!(3.0, m, f32) * 2.0f32, quantity!(6.0, m, f32));
+    assert_eq!(2 * quantity!(3, m, i32), quantity!(6, m, i32));
+    assert_eq!(quantity!(3, m, i32) * 2, quantity!(6, m, i32));
+
+    // Scaling does not rescale: the stored value is multiplied in place
+    let scaled: unit!(km / h) = 0.5 * quantity!(36.0, km / h);
+    assert_eq!(scaled.unsafe_value, 18.0);
+    assert_eq!(value!(scaled, m / s), 5.0);
+
+    // Products of two quantities still combine dimensions
+    let area: unit!(m ^ 2) = 2.0 * quantity!(3.0, m) * quantity!(4.0, m);
+    assert_eq!(area, quantity!(24.0, m ^ 2));
+}
+
 #[test]
 fn test_scalar_quantity_division() {
     let result: unit!(m) = 5.0.meters() / 2.0;
@@ -187,6 +315,193 @@ fn test_rescale_macro() {
     assert_eq!(value!(result, mm, i32), 1000);
 }
 
+#[test]
+fn test_rescale_checked() {
+    use whippyunits::api::{RescaleError, rescale_checked};
+    type MetersI32 = unit!(m, i32);
+    type KilometersU8 = unit!(km, u8);
+    type MillimetersU8 = unit!(mm, u8);
+    type MillimetersI32 = unit!(mm, i32);
+
+    // Finer target units and whole numbers of coarser ones are exact
+    assert_eq!(
+        rescale_checked(quantity!(3, m, i32)),
+        Ok(quantity!(3000, mm, i32))
+    );
+    assert_eq!(
+        rescale_checked(quantity!(2000, mm, i32)),
+        Ok(quantity!(2, m, i32))
+    );
+    assert_eq!(
+        rescale_checked(quantity!(-180, s, i64)),
+        Ok(quantity!(-3, min, i64))
+    );
+
+    // 1500 mm is not a whole number of meters, where rescale_i32 would truncate
+    let distance: Result<MetersI32, _> = rescale_checked(quantity!(1500, mm, i32));
+    assert_eq!(distance, Err(RescaleError::LossyIntegerConversion));
+    let truncated: MetersI32 = rescale_i32(quantity!(1500, mm, i32));
+    assert_eq!(truncated, quantity!(1, m, i32));
+    let distance: Result<KilometersU8, _> = rescale_checked(quantity!(1, m, u8));
+    assert_eq!(distance, Err(RescaleError::LossyIntegerConversion));
+
+    // Results that do not fit the storage type
+    let distance: Result<MillimetersU8, _> = rescale_checked(quantity!(1, m, u8));
+    assert_eq!(distance, Err(RescaleError::Overflow));
+    let distance: Result<MillimetersI32, _> = rescale_checked(quantity!(i32::MAX, m, i32));
+    assert_eq!(distance, Err(RescaleError::Overflow));
+}
+
+#[test]
+fn test_with_prefix_macro() {
+    use whippyunits::with_prefix;
+
+    // A length rescaled to several prefixes, keeping its value
+    let length = quantity!(1500.0, m);
+    let km: unit!(km) = with_prefix!(length, k);
+    assert_eq!(km, quantity!(1.5, km));
+    let mm: unit!(mm) = with_prefix!(length, m);
+    assert_eq!(mm, quantity!(1_500_000.0, mm));
+    let um: unit!(um) = with_prefix!(length, u);
+    assert_eq!(value!(um, m), 1500.0);
+    let hm: unit!(hm) = with_prefix!(length, h);
+    assert_eq!(hm, quantity!(15.0, hm));
+    let dam: unit!(dam) = with_prefix!(length, da);
+    assert_eq!(dam, quantity!(150.0, dam));
+
+    // The prefix applies to the coherent unit, whatever the current scale
+    let km: unit!(km) = with_prefix!(quantity!(250.0, cm), k);
+    assert_eq!(value!(km, m), 2.5);
+
+    // Mass prefixes apply to the gram, not the kilogram
+    let kg: unit!(kg) = with_prefix!(quantity!(1500.0, g), k);
+    assert_eq!(kg, quantity!(1.5, kg));
+    let mg: unit!(mg) = with_prefix!(quantity!(0.002, kg), m);
+    assert_eq!(mg, quantity!(2000.0, mg));
+
+    // Compound dimensions prefix their coherent unit
+    let kn: unit!(kN) = with_prefix!(quantity!(4200.0, N), k);
+    assert_eq!(kn, quantity!(4.2, kN));
+
+    // Integer storage truncates toward zero
+    let km: unit!(km, i32) = with_prefix!(quantity!(2500, m, i32), k);
+    assert_eq!(km, quantity!(2, km, i32));
+}
+
+#[test]
+fn test_quantize_to_coarser_scale() {
+    // Meters snapped to millimeter resolution, staying in meters
+    let length: unit!(m) = quantity!(1.23456, m).quantize_to::<unit!(mm)>();
+    assert_eq!(value!(length, m), 1.235);
+    assert_eq!(value!(length, mm), 1235.0);
+
+    // Already on the grid: unchanged
+    let length = quantity!(0.042, m).quantize_to::<unit!(mm)>();
+    assert_eq!(value!(length, m), 0.042);
+
+    // Ties round away from zero, symmetrically for negative values
+    assert_eq!(
+        value!(quantity!(0.0125, m).quantize_to::<unit!(cm)>(), m),
+        0.01
+    );
+    assert_eq!(
+        value!(quantity!(0.015, m).quantize_to::<unit!(cm)>(), m),
+        0.02
+    );
+    assert_eq!(
+        value!(quantity!(-0.015, m).quantize_to::<unit!(cm)>(), m),
+        -0.02
+    );
+
+    // Coarser than the value itself rounds to zero
+    assert_eq!(value!(quantity!(0.4, m).quantize_to::<unit!(km)>(), m), 0.0);
+}
+
+#[test]
+fn test_quantize_to_integer_storage() {
+    // Target storage type is irrelevant; only its scale is used
+    let length = quantity!(1234567, um, i32).quantize_to::<unit!(mm)>();
+    assert_eq!(value!(length, um, i32), 1235000);
+}
+
+#[test]
+fn test_is_commensurate_with() {
+    // Scale is ignored; only the dimension matters
+    assert!(<unit!(m)>::is_commensurate_with::<unit!(km)>());
+    assert!(<unit!(mm, i32)>::is_commensurate_with::<unit!(km)>());
+    assert!(!<unit!(m)>::is_commensurate_with::<unit!(kg)>());
+    assert!(!<unit!(m)>::is_commensurate_with::<unit!(m ^ 2)>());
+
+    // Derived units compare by their exponents
+    assert!(<unit!(J)>::is_commensurate_with::<unit!(N * m)>());
+    assert!(!<unit!(J)>::is_commensurate_with::<unit!(W)>());
+
+    // Usable in constant evaluation
+    const { assert!(!<unit!(m)>::is_commensurate_with::<unit!(kg)>()) };
+}
+
+#[test]
+fn test_scale_prime_exponents() {
+    // 1 h = 3600 s = 2⁴ · 3² · 5² s
+    let hour = quantity!(1.0, h);
+    assert_eq!(hour.scale_p2(), 4);
+    assert_eq!(hour.scale_p3(), 2);
+    assert_eq!(hour.scale_p5(), 2);
+    assert_eq!(hour.scale_pi(), 0);
+    assert_eq!(hour.scale_exponents(), [4, 2, 2, 0]);
+
+    // 1° = π / 180 rad = 2⁻² · 3⁻² · 5⁻¹ · π rad
+    let degree = quantity!(1.0, deg);
+    assert_eq!(degree.scale_p2(), -2);
+    assert_eq!(degree.scale_p3(), -2);
+    assert_eq!(degree.scale_p5(), -1);
+    assert_eq!(degree.scale_pi(), 1);
+    assert_eq!(degree.scale_exponents(), [-2, -2, -1, 1]);
+
+    // Coherent units have no scale, whatever the storage type
+    assert_eq!(quantity!(1.0, rad).scale_exponents(), [0, 0, 0, 0]);
+    assert_eq!(quantity!(1, N, i32).scale_exponents(), [0, 0, 0, 0]);
+
+    // Usable in constant evaluation
+    const KILOMETER: unit!(km) = whippyunits::Quantity::new(1.0);
+    const { assert!(KILOMETER.scale_p5() == 3) };
+}
+
+#[test]
+fn test_order_of_magnitude() {
+    assert_eq!(quantity!(1500.0, m).order_of_magnitude(), Some(3));
+    assert_eq!(quantity!(0.003, m).order_of_magnitude(), Some(-3));
+
+    // Computed on the coherent value, independent of scale
+    assert_eq!(quantity!(3.0, mm).order_of_magnitude(), Some(-3));
+    assert_eq!(quantity!(1.5, km).order_of_magnitude(), Some(3));
+    assert_eq!(quantity!(1500, m, i32).order_of_magnitude(), Some(3));
+
+    // Mass is coherent in kilograms
+    assert_eq!(quantity!(1500.0, g).order_of_magnitude(), Some(0));
+    assert_eq!(quantity!(2.0, kg).order_of_magnitude(), Some(0));
+
+    // Exact powers of ten land on their own exponent
+    for exponent in -300..=300 {
+        let value: f64 = format!("1e{exponent}").parse().unwrap();
+        assert_eq!(
+            quantity!(value, m).order_of_magnitude(),
+            Some(exponent),
+            "1e{exponent} m"
+        );
+    }
+
+    // Just below a power of ten stays in the lower decade
+    assert_eq!(quantity!(999.999999, m).order_of_magnitude(), Some(2));
+    assert_eq!(quantity!(0.000999, m).order_of_magnitude(), Some(-4));
+
+    // Logarithm undefined
+    assert_eq!(quantity!(0.0, m).order_of_magnitude(), None);
+    assert_eq!(quantity!(-1500.0, m).order_of_magnitude(), None);
+    assert_eq!(quantity!(f64::NAN, m).order_of_magnitude(), None);
+    assert_eq!(quantity!(f64::INFINITY, m).order_of_magnitude(), None);
+}
+
 #[test]
 fn test_negative_quantities() {
     let result = -5.0.meters() + 7.0.meters();
@@ -199,6 +514,18 @@ fn test_negative_quantities() {
     assert_eq!(value!(result, m), -10.0);
 }
 
+#[test]
+fn test_unary_negation() {
+    assert_eq!(-quantity!(5.0, m), quantity!(-5.0, m));
+    assert_eq!(-quantity!(5.0, m, f32), quantity!(-5.0, m, f32));
+    assert_eq!(-quantity!(5, m, i32), quantity!(-5, m, i32));
+
+    // Negation keeps the scale and dimension of the operand
+    let velocity: unit!(km / h) = -quantity!(36.0, km / h);
+    assert_eq!(value!(velocity, m / s), -10.0);
+    assert_eq!(-(-velocity), quantity!(-36.0, km / h));
+}
+
 #[test]
 fn test_large_numbers() {
     let result = 1000000.0.meters() + 0.000001.meters();
@@ -249,6 +576,28 @@ fn test_generic_dimension_expressions() {
     assert_capacitance(quantity!(1.0, F));
 }
 
+#[test]
+fn test_generic_dimension_exclusion() {
+    define_generic_dimension!(NonMass, !Mass);
+
+    fn assert_non_mass<Q: NonMass>(_: Q) {}
+
+    assert_non_mass(quantity!(1.0, m));
+    assert_non_mass(quantity!(1.0, s));
+    assert_non_mass(quantity!(1.0, m / s));
+    assert_non_mass(quantity!(1.0, km / h));
+    assert_non_mass(quantity!(1.0, 1));
+    assert_non_mass(quantity!(1, mm, i32));
+
+    // Several exclusions must all hold
+    define_generic_dimension!(Kinematic, !M, !Temperature, !I);
+
+    fn assert_kinematic<Q: Kinematic>(_: Q) {}
+
+    assert_kinematic(quantity!(1.0, m / s ^ 2));
+    assert_kinematic(quantity!(1.0, rad / s));
+}
+
 #[test]
 fn test_dimension_symbols_in_dsl() {
     // Using UCUM syntax: implicit exponents and dot multiplication
@@ -394,8 +743,8 @@ fn test_custom_formatting() {
     assert!(distance.fmt("kg").to_string().contains("Error")); // Wrong dimension
     assert!(distance.fmt("unknown_unit").to_string().contains("Error")); // Unknown unit
 
-    assert_eq!(format!("{}", distance.fmt("kilometer")), "5 kilometer");
-    assert_eq!(format!("{}", mass.fmt("gram")), "2500 gram");
+    assert_eq!(format!("{}", distance.fmt("kilometer")), "5 kilometers");
+    assert_eq!(format!("{}", mass.fmt("gram")), "2500 grams");
 }
 
 #[test]
@@ -631,3 +980,466 @@ fn test_prefixed_aggregate_quantities() {
     let kilowatt_hour: unit!(kWh) = quantity!(1.0, kW * h);
     assert_eq!(value!(kilowatt_hour, kW * h), 1.0);
 }
+
+#[test]
+fn test_pow_rt_dimensionless() {
+    let base = quantity!(2.0, m) / quantity!(1.0, m);
+    assert_eq!(base.pow_rt(0).unsafe_value, 1.0);
+    assert_eq!(base.pow_rt(1).unsafe_value, 2.0);
+    assert_eq!(base.pow_rt(2).unsafe_value, 4.0);
+    assert_eq!(base.pow_rt(10).unsafe_value, 1024.0);
+
+    // Negative powers take the reciprocal
+    assert_eq!(base.pow_rt(-1).unsafe_value, 0.5);
+    assert_eq!(base.pow_rt(-3).unsafe_value, 0.125);
+
+    // A float quotient of the same dimension has its scale folded into the value
+    let ratio = quantity!(2.0, km) / quantity!(1.0, m);
+    let squared = ratio.pow_rt(2);
+    assert_eq!(squared.unsafe_value, 4_000_000.0);
+    let inverse: f64 = ratio.pow_rt(-1).into();
+    assert!((inverse - 0.0005).abs() < 1e-15);
+
+    // Integer storage
+    let base = quantity!(3, m, i32) / quantity!(1, m, i32);
+    assert_eq!(base.pow_rt(4).unsafe_value, 81);
+}
+
+#[test]
+fn test_to_hms() {
+    // Multi-hour durations keep accumulating hours past a day
+    assert_eq!(quantity!(3725.0, s).to_hms(), (1, 2, 5.0));
+    assert_eq!(quantity!(100000.0, s).to_hms(), (27, 46, 40.0));
+    assert_eq!(quantity!(2.0, d).to_hms(), (48, 0, 0.0));
+    assert_eq!(quantity!(90.0, min).to_hms(), (1, 30, 0.0));
+    assert_eq!(quantity!(7_384_500, ms, i64).to_hms(), (2, 3, 4.5));
+
+    // Sub-second remainders stay in the seconds
+    let (hours, minutes, seconds) = quantity!(3725.25, s).to_hms();
+    assert_eq!((hours, minutes), (1, 2));
+    assert!((seconds - 5.25).abs() < 1e-9);
+    assert_eq!(quantity!(0.75, s).to_hms(), (0, 0, 0.75));
+
+    assert_eq!(quantity!(-3725.0, s).to_hms(), (-1, -2, -5.0));
+}
+
+#[test]
+fn test_abs_and_signum() {
+    // Negative displacement keeps its unit under abs
+    let displacement = quantity!(-2.5, m);
+    let distance: unit!(m) = displacement.abs();
+    assert_eq!(distance, quantity!(2.5, m));
+    assert_eq!(quantity!(2.5, m).abs(), quantity!(2.5, m));
+    assert_eq!(quantity!(-300, mm, i32).abs(), quantity!(300, mm, i32));
+    assert_eq!(quantity!(-7, s, i64).abs(), quantity!(7, s, i64));
+    assert_eq!(quantity!(-1.5, K, f32).abs(), quantity!(1.5, K, f32));
+
+    // The sign is dimensionless and unscaled, whatever the unit it came from
+    let sign: unit!(1) = displacement.signum();
+    assert_eq!(sign, quantity!(-1.0, 1));
+    let sign: unit!(1) = quantity!(4.0, km / h).signum();
+    assert_eq!(f64::from(sign), 1.0);
+    let sign: unit!(1, i32) = quantity!(-300, mm, i32).signum();
+    assert_eq!(i32::from(sign), -1);
+    assert_eq!(i32::from(quantity!(0, mm, i32).signum()), 0);
+
+    // Multiplying by the sign restores the original quantity
+    assert_eq!(distance * displacement.signum(), displacement);
+}
+
+#[test]
+fn test_float_classification() {
+    let nan = quantity!(f64::NAN, m);
+    assert!(nan.is_nan());
+    assert!(!nan.is_finite());
+    assert!(!nan.is_infinite());
+
+    let length = quantity!(2.5, m);
+    assert!(!length.is_nan());
+    assert!(length.is_finite());
+    assert!(!length.is_infinite());
+
+    let overflowed = quantity!(f64::MAX, m) * 2.0;
+    assert!(overflowed.is_infinite());
+    assert!(!overflowed.is_finite());
+    assert!(quantity!(f64::NEG_INFINITY, km / h).is_infinite());
+
+    // Any float storage, and results of arithmetic such as 0/0
+    assert!(quantity!(f32::NAN, s, f32).is_nan());
+    let ratio = quantity!(0.0, m) / quantity!(0.0, m);
+    assert!(ratio.is_nan());
+}
+
+#[test]
+fn test_min_max_and_clamp() {
+    let short = quantity!(2.0, m);
+    let long = quantity!(5.0, m);
+    assert_eq!(short.min(long), short);
+    assert_eq!(long.min(short), short);
+    assert_eq!(short.max(long), long);
+    assert_eq!(long.max(short), long);
+    assert_eq!(
+        quantity!(-3, mm, i32).max(quantity!(1, mm, i32)),
+        quantity!(1, mm, i32)
+    );
+
+    // Values within the range are unchanged; values outside snap to the nearest bound
+    let clamped: unit!(m) = quantity!(7.5, m).clamp(short, long);
+    assert_eq!(clamped, long);
+    assert_eq!(quantity!(0.5, m).clamp(short, long), short);
+    assert_eq!(quantity!(3.0, m).clamp(short, long), quantity!(3.0, m));
+    assert_eq!(short.clamp(short, long), short);
+    assert!(f64::from(quantity!(f64::NAN, 1).clamp(quantity!(0.0, 1), quantity!(1.0, 1))).is_nan());
+}
+
+#[test]
+#[should_panic(
+    expected = "cannot clamp Length quantity: lower bound 5.0 is greater than upper bound 2.0"
+)]
+fn test_clamp_inverted_bounds_panics() {
+    let _ = quantity!(3.0, m).clamp(quantity!(5.0, m), quantity!(2.0, m));
+}
+
+#[test]
+fn test_lerp() {
+    let start = quantity!(0.0, m);
+    let end = quantity!(10.0, m);
+    let quarter: unit!(m) = start.lerp(end, 0.25);
+    assert_eq!(quarter, quantity!(2.5, m));
+    assert_eq!(start.lerp(end, 0.0), start);
+    assert_eq!(start.lerp(end, 1.0), end);
+    assert_eq!(end.lerp(start, 0.25), quantity!(7.5, m));
+
+    // Parameters outside [0, 1] extrapolate
+    assert_eq!(start.lerp(end, 1.5), quantity!(15.0, m));
+    assert_eq!(start.lerp(end, -0.5), quantity!(-5.0, m));
+
+    // The unit is kept, including its scale, and f32 storage works
+    assert_eq!(
+        quantity!(1.0, km).lerp(quantity!(3.0, km), 0.5),
+        quantity!(2.0, km)
+    );
+    assert_eq!(
+        quantity!(2.0, s, f32).lerp(quantity!(4.0, s, f32), 0.75),
+        quantity!(3.5, s, f32)
+    );
+}
+
+#[test]
+fn test_sqrt_halves_exponents() {
+    let side: unit!(m) = quantity!(25.0, m ^ 2).sqrt();
+    assert_eq!(side, quantity!(5.0, m));
+
+    let speed: unit!(m / s) = quantity!(4.0, m ^ 2 / s ^ 2).sqrt();
+    assert_eq!(speed, quantity!(2.0, m / s));
+
+    // Mixed dimensions, including mass and current
+    let root: unit!(kg * A / s) = quantity!(9.0, kg ^ 2 * A ^ 2 / s ^ 2).sqrt();
+    assert_eq!(root, quantity!(3.0, kg * A / s));
+
+    // The scale is halved along with the dimension
+    let side: unit!(km) = quantity!(4.0, km ^ 2).sqrt();
+    assert_eq!(value!(side, m), 2000.0);
+
+    // Dimensionless quantities and integer storage
+    let ratio = quantity!(16.0, m ^ 2) / quantity!(1.0, m ^ 2);
+    assert_eq!(ratio.sqrt().unsafe_value, 4.0);
+    let side: unit!(mm, i32) = quantity!(144, mm ^ 2, i32).sqrt();
+    assert_eq!(side, quantity!(12, mm, i32));
+
+    // Negative values have no real square root
+    assert!(quantity!(-4.0, m ^ 2).sqrt().unsafe_value.is_nan());
+}
+
+#[test]
+fn test_cbrt_and_nth_root_divide_exponents() {
+    let side: unit!(m) = quantity!(8.0, m ^ 3).cbrt();
+    assert_eq!(side, quantity!(2.0, m));
+
+    // Mixed dimensions and negative exponents
+    let root: unit!(kg / s) = quantity!(27.0, kg ^ 3 / s ^ 3).cbrt();
+    assert_eq!(root, quantity!(3.0, kg / s));
+
+    // The scale is divided along with the dimension
+    let side: unit!(km) = quantity!(8.0, km ^ 3).cbrt();
+    assert_eq!(value!(side, m), 2000.0);
+
+    // Odd roots of negative values are negative
+    assert_eq!(quantity!(-8.0, m ^ 3).cbrt(), quantity!(-2.0, m));
+    assert_eq!(quantity!(-32.0, m ^ 5).nth_root::<5>(), quantity!(-2.0, m));
+
+    let side: unit!(m) = quantity!(16.0, m ^ 4).nth_root::<4>();
+    assert_eq!(side, quantity!(2.0, m));
+    let area: unit!(m ^ 2) = quantity!(16.0, m ^ 4).nth_root::<2>();
+    assert_eq!(area, quantity!(4.0, m ^ 2));
+
+    // Even roots of negative values have no real value
+    assert!(
+        quantity!(-16.0, m ^ 4)
+            .nth_root::<4>()
+            .unsafe_value
+            .is_nan()
+    );
+
+    // Integer storage
+    let side: unit!(mm, i32) = quantity!(125, mm ^ 3, i32).cbrt();
+    assert_eq!(side, quantity!(5, mm, i32));
+}
+
+#[test]
+fn test_pow_multiplies_exponents() {
+    let speed_squared: unit!(m ^ 2 / s ^ 2) = quantity!(3.0, m / s).pow::<2>();
+    assert_eq!(speed_squared, quantity!(9.0, m ^ 2 / s ^ 2));
+
+    // The scale is multiplied along with the dimension
+    let volume: unit!(km ^ 3) = quantity!(2.0, km).pow::<3>();
+    assert_eq!(volume, quantity!(8.0, km ^ 3));
+    assert_eq!(value!(volume, m ^ 3), 8.0e9);
+
+    // Negative powers give the reciprocal unit
+    let frequency: unit!(Hz) = quantity!(0.5, s).pow::<-1>();
+    assert_eq!(frequency, quantity!(2.0, Hz));
+    let inverse_area: unit!(1 / m ^ 2) = quantity!(4.0, m).pow::<-2>();
+    assert_eq!(inverse_area, quantity!(0.0625, 1 / m ^ 2));
+
+    // The zeroth power is dimensionless one, whatever the unit
+    let one: unit!(1) = quantity!(3.0, km / h).pow::<0>();
+    assert_eq!(one, quantity!(1.0, 1));
+    let one: unit!(1) = quantity!(0.0, m).pow::<0>();
+    assert_eq!(one, quantity!(1.0, 1));
+
+    // Powers undo roots
+    assert_eq!(
+        quantity!(16.0, m ^ 4).nth_root::<4>().pow::<4>(),
+        quantity!(16.0, m ^ 4)
+    );
+
+    // Integer storage
+    let area: unit!(mm ^ 2, i32) = quantity!(-5, mm, i32).pow::<2>();
+    assert_eq!(area, quantity!(25, mm ^ 2, i32));
+}
+
+#[test]
+fn test_recip_negates_exponents() {
+    let frequency: unit!(s ^ -1) = quantity!(2.0, s).recip();
+    assert_eq!(frequency, quantity!(0.5, s ^ -1));
+    assert_eq!(value!(frequency, Hz), 0.5);
+
+    let pace: unit!(s / m) = quantity!(4.0, m / s).recip();
+    assert_eq!(pace, quantity!(0.25, s / m));
+
+    // The scale is negated along with the dimension
+    let per_km: unit!(1 / km) = quantity!(0.5, km).recip();
+    assert_eq!(value!(per_km, 1 / m), 0.002);
+
+    // Involutive, and agrees with pow::<-1>
+    assert_eq!(quantity!(8.0, m / s).recip().recip(), quantity!(8.0, m / s));
+    assert_eq!(
+        quantity!(8.0, m / s).recip(),
+        quantity!(8.0, m / s).pow::<-1>()
+    );
+
+    // f32 storage
+    let frequency: unit!(s ^ -1, f32) = quantity!(4.0, s, f32).recip();
+    assert_eq!(frequency, quantity!(0.25, s ^ -1, f32));
+
+    // The reciprocal of zero is infinite
+    assert_eq!(quantity!(0.0, s).recip(), quantity!(f64::INFINITY, s ^ -1));
+}
+
+#[test]
+fn test_trigonometric_methods() {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    let ratio: unit!(1) = quantity!(FRAC_PI_2, rad).sin();
+    assert_eq!(ratio, quantity!(1.0, 1));
+    assert_eq!(quantity!(0.0, rad).cos(), quantity!(1.0, 1));
+    assert!((quantity!(FRAC_PI_4, rad).tan().unsafe_value - 1.0).abs() < 1e-12);
+
+    // Other angular units are rescaled to radians first
+    assert!((quantity!(30.0, deg).sin().unsafe_value - 0.5).abs() < 1e-12);
+    assert!((quantity!(180.0, deg).cos().unsafe_value + 1.0).abs() < 1e-12);
+    assert!((quantity!(90, deg, i32).sin().unsafe_value - 1.0).abs() < 1e-12);
+
+    // Inverse functions return radians, and accept ratios in any scale
+    let angle: unit!(rad) = quantity!(1.0, 1).asin();
+    assert_eq!(angle, quantity!(FRAC_PI_2, rad));
+    assert_eq!(quantity!(1.0, 1).acos(), quantity!(0.0, rad));
+    assert!((value!(quantity!(1.0, 1).atan(), deg) - 45.0).abs() < 1e-12);
+    let ratio = quantity!(1.0, km) / quantity!(2000.0, m);
+    assert!((value!(ratio.asin(), deg) - 30.0).abs() < 1e-12);
+
+    // Round trip
+    let angle = quantity!(0.3, rad);
+    assert!((angle.sin().asin().unsafe_value - 0.3).abs() < 1e-12);
+}
+
+#[test]
+fn test_atan2() {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+    use whippyunits::api::atan2;
+
+    let angle: unit!(rad) = atan2(quantity!(1.0, m), quantity!(1.0, m));
+    assert!((angle.unsafe_value - 0.785).abs() < 1e-3);
+    assert_eq!(angle, quantity!(FRAC_PI_4, rad));
+
+    // All four quadrants
+    assert_eq!(
+        atan2(quantity!(1.0, m), quantity!(-1.0, m)),
+        quantity!(3.0 * FRAC_PI_4, rad)
+    );
+    assert_eq!(
+        atan2(quantity!(-1.0, m), quantity!(-1.0, m)),
+        quantity!(-3.0 * FRAC_PI_4, rad)
+    );
+    assert_eq!(
+        atan2(quantity!(-1.0, m), quantity!(1.0, m)),
+        quantity!(-FRAC_PI_4, rad)
+    );
+
+    // Any shared dimension and scale, including integer storage
+    assert_eq!(
+        atan2(quantity!(3.0, km / h), quantity!(0.0, km / h)),
+        quantity!(FRAC_PI_2, rad)
+    );
+    assert_eq!(
+        atan2(quantity!(0, mm, i32), quantity!(-5, mm, i32)),
+        quantity!(PI, rad)
+    );
+}
+
+#[test]
+fn test_new_bounded() {
+    use whippyunits::quantity::OutOfBoundsError;
+
+    // Absolute temperature must be non-negative
+    let room = <unit!(K)>::new_bounded(293.15, &quantity!(0.0, K), &quantity!(1.0e4, K));
+    assert_eq!(room, Ok(quantity!(293.15, K)));
+
+    let below_absolute_zero =
+        <unit!(K)>::new_bounded(-5.0, &quantity!(0.0, K), &quantity!(1.0e4, K));
+    assert_eq!(
+        below_absolute_zero,
+        Err(OutOfBoundsError {
+            value: -5.0,
+            min: 0.0,
+            max: 1.0e4,
+        })
+    );
+    assert_eq!(
+        below_absolute_zero.unwrap_err().to_string(),
+        "value -5 is outside the bounds [0, 10000]"
+    );
+
+    // Bounds in another scale are rescaled to the quantity's scale
+    let cryogenic = <unit!(mK)>::new_bounded(250.0, &quantity!(0.0, K), &quantity!(0.3, K));
+    assert_eq!(cryogenic, Ok(quantity!(250.0, mK)));
+    let too_warm = <unit!(mK)>::new_bounded(350.0, &quantity!(0.0, K), &quantity!(0.3, K));
+    assert_eq!(too_warm.unwrap_err().max, 300.0);
+
+    // Bounds are inclusive, and NaN is never in range
+    assert!(<unit!(kg)>::new_bounded(0.0, &quantity!(0.0, g), &quantity!(1.0, kg)).is_ok());
+    assert!(<unit!(kg)>::new_bounded(1.0, &quantity!(0.0, g), &quantity!(1.0, kg)).is_ok());
+    assert!(<unit!(kg)>::new_bounded(f64::NAN, &quantity!(0.0, g), &quantity!(1.0, kg)).is_err());
+
+    // Integer storage
+    let count = <unit!(mm, i32)>::new_bounded(5, &quantity!(0, mm, i32), &quantity!(1, m, i32));
+    assert_eq!(count, Ok(quantity!(5, mm, i32)));
+}
+
+#[test]
+fn test_checked_integer_arithmetic() {
+    let near_max = quantity!(i32::MAX - 10, m, i32);
+    assert_eq!(
+        near_max.checked_add(quantity!(10, m, i32)),
+        Some(quantity!(i32::MAX, m, i32))
+    );
+    assert_eq!(near_max.checked_add(quantity!(11, m, i32)), None);
+
+    let near_min = quantity!(i32::MIN + 5, m, i32);
+    assert_eq!(
+        near_min.checked_sub(quantity!(5, m, i32)),
+        Some(quantity!(i32::MIN, m, i32))
+    );
+    assert_eq!(near_min.checked_sub(quantity!(6, m, i32)), None);
+
+    // Multiplication combines dimensions and scales exactly as `*` does
+    let work = quantity!(3, kN, i64).checked_mul(quantity!(2, mm, i64));
+    assert_eq!(work, Some(quantity!(3, kN, i64) * quantity!(2, mm, i64)));
+    let area = quantity!(65_536, m, i32).checked_mul(quantity!(32_768, m, i32));
+    assert_eq!(area, None);
+    assert_eq!(
+        quantity!(65_536, m, i32).checked_mul(quantity!(32_767, m, i32)),
+        Some(quantity!(2_147_418_112, m ^ 2, i32))
+    );
+}
+
+#[test]
+fn test_converter_macro() {
+    use whippyunits::converter;
+
+    // Affine units carry their offset into the closure, in both directions
+    let to_kelvin = converter!(degC, K);
+    assert_eq!(to_kelvin(0.0), 273.15);
+    assert_eq!(to_kelvin(100.0), 373.15);
+    assert_eq!(to_kelvin(-273.15), 0.0);
+    let to_celsius = converter!(K, degC);
+    assert_eq!(to_celsius(273.15), 0.0);
+
+    // Scale-only conversions agree with value!
+    let to_km = converter!(m, km);
+    assert_eq!(to_km(1500.0), 1.5);
+    assert_eq!(to_km(2.0), value!(quantity!(2.0, m), km));
+
+    // Compound and nonstorage units
+    let to_m_per_s = converter!(km / h, m / s);
+    assert_eq!(to_m_per_s(36.0), 10.0);
+    let to_m = converter!(mi, m);
+    assert_eq!(to_m(1.0), value!(quantity!(1.0, mi), m));
+}
+
+#[test]
+fn test_convert_slice_macros() {
+    use whippyunits::{convert_slice, convert_slice_in_place, converter};
+
+    // The affine offset applies to every element
+    let celsius = [0.0, 100.0, -273.15, 21.5];
+    let kelvin = convert_slice!(&celsius, degC, K);
+    assert_eq!(kelvin.len(), celsius.len());
+    for (&k, &c) in kelvin.iter().zip(&celsius) {
+        assert_eq!(k, converter!(degC, K)(c));
+    }
+    assert_eq!(kelvin[..3], [273.15, 373.15, 0.0]);
+
+    // In place, without allocating, and back again
+    let mut column = kelvin.clone();
+    convert_slice_in_place!(&mut column, K, degC);
+    for (&back, &c) in column.iter().zip(&celsius) {
+        assert!((back - c).abs() < 1e-9);
+    }
+    let mut column = vec![32.0, 212.0];
+    convert_slice_in_place!(&mut column[1..], degF, degC);
+    assert_eq!(column, [32.0, converter!(degF, degC)(212.0)]);
+
+    // Scale-only, compound, and empty columns
+    assert_eq!(convert_slice!(&[1500.0, 250.0], m, km), [1.5, 0.25]);
+    assert_eq!(convert_slice!(&[36.0, 72.0], km / h, m / s), [10.0, 20.0]);
+    assert!(convert_slice!(&[], degC, K).is_empty());
+}
+
+#[test]
+fn test_dimensionless_constructor_and_as_f64() {
+    use whippyunits::Quantity;
+
+    assert_eq!(Quantity::dimensionless(3.0).as_f64(), 3.0);
+    assert_eq!(Quantity::dimensionless(-7).as_f64(), -7.0);
+
+    // Ratios are rescaled to unit scale before extraction
+    let ratio = 3.0.kilometers() / 1.0.meters();
+    assert_eq!(ratio.as_f64(), 3000.0);
+    assert_eq!((ratio * Quantity::dimensionless(0.5)).as_f64(), 1500.0);
+
+    // Scaling a dimensional quantity keeps its unit
+    let scaled: unit!(m) = 4.0.meters() * Quantity::dimensionless(2.5);
+    assert_eq!(value!(scaled, m), 10.0);
+}
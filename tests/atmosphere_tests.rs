@@ -0,0 +1,77 @@
+//! Tests for the ISA pressure altitude helpers in `whippyunits::atmosphere`
+
+use whippyunits::atmosphere::{
+    SEA_LEVEL_PRESSURE, TROPOPAUSE_ALTITUDE, altitude_to_pressure, pressure_to_altitude,
+};
+use whippyunits::{quantity, unit, value};
+
+fn assert_approx(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {expected} ± {tolerance}, got {actual}"
+    );
+}
+
+#[test]
+fn test_sea_level() {
+    let altitude: unit!(m) = pressure_to_altitude(quantity!(1013.25, hPa));
+    assert_approx(value!(altitude, m), 0.0, 1e-6);
+
+    let altitude = pressure_to_altitude(SEA_LEVEL_PRESSURE);
+    assert_approx(value!(altitude, m), 0.0, 1e-6);
+
+    let pressure = altitude_to_pressure(quantity!(0.0, m));
+    assert_approx(value!(pressure, Pa), 101_325.0, 1e-6);
+
+    // Higher than standard pressure is below sea level
+    assert!(value!(pressure_to_altitude(quantity!(1030.0, hPa)), m) < 0.0);
+}
+
+#[test]
+fn test_5500_meters() {
+    // ISA tables give 505.0 hPa at 5500 m
+    let pressure = altitude_to_pressure(quantity!(5500.0, m));
+    assert_approx(value!(pressure, hPa), 505.0, 0.1);
+
+    let altitude = pressure_to_altitude(pressure);
+    assert_approx(value!(altitude, m), 5500.0, 1e-6);
+
+    // Input scale does not matter
+    let altitude = pressure_to_altitude(quantity!(50.5, kPa));
+    assert_approx(value!(altitude, km), 5.5, 0.002);
+    assert_approx(
+        value!(altitude_to_pressure(quantity!(5.5, km)), hPa),
+        505.0,
+        0.1,
+    );
+}
+
+#[test]
+fn test_above_tropopause() {
+    // Continuous across the tropopause
+    let tropopause_pressure = altitude_to_pressure(TROPOPAUSE_ALTITUDE);
+    assert_approx(value!(tropopause_pressure, Pa), 22_632.06, 0.1);
+    assert_approx(
+        value!(altitude_to_pressure(quantity!(10_999.0, m)), Pa),
+        value!(altitude_to_pressure(quantity!(11_001.0, m)), Pa),
+        10.0,
+    );
+
+    // Isothermal layer: ISA tables give 120.45 hPa at 15 km and 54.75 hPa at 20 km
+    assert_approx(
+        value!(altitude_to_pressure(quantity!(15.0, km)), hPa),
+        120.45,
+        0.05,
+    );
+    assert_approx(
+        value!(pressure_to_altitude(quantity!(54.7489, hPa)), m),
+        20_000.0,
+        0.5,
+    );
+
+    // Round trips hold in every layer
+    for meters in [-500.0, 3000.0, 11_000.0, 15_000.0, 25_000.0, 40_000.0] {
+        let altitude = pressure_to_altitude(altitude_to_pressure(quantity!(meters, m)));
+        assert_approx(value!(altitude, m), meters, 1e-6);
+    }
+}
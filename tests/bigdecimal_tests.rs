@@ -0,0 +1,65 @@
+//! Tests for exact `BigDecimal` storage and rescaling
+#![cfg(feature = "bigdecimal")]
+
+use bigdecimal::BigDecimal;
+use core::str::FromStr;
+use whippyunits::decimal::{InexactRescale, rescale_bigdecimal};
+use whippyunits::{quantity, unit};
+
+type DecimalMinutes = unit!(min, BigDecimal);
+type DecimalRadians = unit!(rad, BigDecimal);
+
+fn decimal(s: &str) -> BigDecimal {
+    BigDecimal::from_str(s).unwrap()
+}
+
+#[test]
+fn test_exact_km_to_m() {
+    let distance = quantity!(decimal("1.000000000000000000000001"), km, BigDecimal);
+    let meters: unit!(m, BigDecimal) = rescale_bigdecimal(distance).unwrap();
+    // f64 cannot represent this value, let alone its product with 1000
+    assert_eq!(meters.unsafe_value, decimal("1000.000000000000000000001"));
+
+    let distance = quantity!(decimal("0.1"), km, BigDecimal);
+    let meters: unit!(m, BigDecimal) = rescale_bigdecimal(distance).unwrap();
+    assert_eq!(meters.unsafe_value, BigDecimal::from(100));
+}
+
+#[test]
+fn test_exact_m_to_km() {
+    let distance = quantity!(decimal("1234.5"), m, BigDecimal);
+    let kilometers: unit!(km, BigDecimal) = rescale_bigdecimal(distance).unwrap();
+    assert_eq!(kilometers.unsafe_value, decimal("1.2345"));
+
+    // Negative powers of 2 terminate: 1 mm = 2^-3 · 5^-3 m
+    let length = quantity!(BigDecimal::from(1), mm, BigDecimal);
+    let meters: unit!(m, BigDecimal) = rescale_bigdecimal(length).unwrap();
+    assert_eq!(meters.unsafe_value, decimal("0.001"));
+}
+
+#[test]
+fn test_exact_factors_of_three() {
+    // Multiplying by 3 is always exact
+    let duration = quantity!(decimal("2.5"), h, BigDecimal);
+    let seconds: unit!(s, BigDecimal) = rescale_bigdecimal(duration).unwrap();
+    assert_eq!(seconds.unsafe_value, BigDecimal::from(9000));
+
+    // Dividing by 3 is exact when the digits divide evenly
+    let duration = quantity!(BigDecimal::from(5400), s, BigDecimal);
+    let hours: unit!(h, BigDecimal) = rescale_bigdecimal(duration).unwrap();
+    assert_eq!(hours.unsafe_value, decimal("1.5"));
+
+    // ...and an error when they do not
+    let duration = quantity!(BigDecimal::from(1), s, BigDecimal);
+    let minutes: Result<DecimalMinutes, _> = rescale_bigdecimal(duration);
+    assert_eq!(minutes.err().unwrap(), InexactRescale { p3: -1, pi: 0 });
+}
+
+#[test]
+fn test_pi_scales_are_inexact() {
+    let angle = quantity!(BigDecimal::from(180), deg, BigDecimal);
+    let radians: Result<DecimalRadians, _> = rescale_bigdecimal(angle);
+    let error = radians.err().unwrap();
+    assert_eq!(error.pi, 1);
+    assert!(error.to_string().contains("no exact decimal result"));
+}
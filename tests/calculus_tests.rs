@@ -0,0 +1,132 @@
+//! Tests for `whippyunits::calculus`
+#![cfg(feature = "alloc")]
+
+use whippyunits::calculus::{cumulative_sum, finite_difference, integrate_trapezoid};
+use whippyunits::{quantity, unit, value};
+
+type Length = unit!(m);
+type Velocity = unit!(m / s);
+type Acceleration = unit!(m / s ^ 2);
+
+#[test]
+fn test_finite_difference_of_positions() {
+    let positions = [
+        quantity!(0.0, m),
+        quantity!(1.0, m),
+        quantity!(4.0, m),
+        quantity!(9.0, m),
+    ];
+    let velocities: Vec<Velocity> = finite_difference(&positions, quantity!(1.0, s));
+    assert_eq!(velocities.len(), positions.len() - 1);
+    assert_eq!(
+        velocities,
+        [
+            quantity!(1.0, m / s),
+            quantity!(3.0, m / s),
+            quantity!(5.0, m / s)
+        ]
+    );
+
+    // Differentiating again gives accelerations
+    let accelerations: Vec<Acceleration> = finite_difference(&velocities, quantity!(1.0, s));
+    assert_eq!(
+        accelerations,
+        [quantity!(2.0, m / s ^ 2), quantity!(2.0, m / s ^ 2)]
+    );
+}
+
+#[test]
+fn test_finite_difference_combines_scales() {
+    let positions = [quantity!(10.0, km), quantity!(70.0, km)];
+    let velocities = finite_difference(&positions, quantity!(0.5, h));
+    let speed: unit!(km / h) = velocities[0];
+    assert_eq!(velocities, [quantity!(120.0, km / h)]);
+    assert!((value!(speed, m / s) - 33.333333333333336).abs() < 1e-9);
+
+    let velocities = finite_difference(&[quantity!(0.0, m), quantity!(3.0, m)], quantity!(2.0, ms));
+    let speed: unit!(m / ms) = velocities[0];
+    assert_eq!(value!(speed, m / s), 1500.0);
+}
+
+#[test]
+fn test_finite_difference_short_series() {
+    let dt = quantity!(1.0, s);
+    assert!(finite_difference::<Length, _>(&[], dt).is_empty());
+    assert!(finite_difference(&[quantity!(5.0, m)], dt).is_empty());
+}
+
+#[test]
+fn test_integrate_trapezoid_of_constant_velocity() {
+    let velocities = [quantity!(3.0, m / s); 5];
+    let displacement: Length = integrate_trapezoid(&velocities, quantity!(0.5, s));
+    assert_eq!(displacement, quantity!(6.0, m));
+
+    // Scales combine: km/h over intervals of hours gives km
+    let velocities = [quantity!(60.0, km / h); 3];
+    let displacement: unit!(km) = integrate_trapezoid(&velocities, quantity!(0.25, h));
+    assert_eq!(displacement, quantity!(30.0, km));
+}
+
+#[test]
+fn test_integrate_trapezoid_of_linear_series() {
+    // The trapezoidal rule is exact for linear series
+    let velocities: Vec<Velocity> = (0..=10).map(|i| quantity!(2.0 * i as f64, m / s)).collect();
+    let displacement = integrate_trapezoid(&velocities, quantity!(1.0, s));
+    assert_eq!(displacement, quantity!(100.0, m));
+}
+
+#[test]
+fn test_integrate_trapezoid_short_series() {
+    let dt = quantity!(1.0, s);
+    assert_eq!(
+        integrate_trapezoid::<Velocity, _>(&[], dt),
+        quantity!(0.0, m)
+    );
+    assert_eq!(
+        integrate_trapezoid(&[quantity!(5.0, m / s)], dt),
+        quantity!(0.0, m)
+    );
+}
+
+#[test]
+fn test_cumulative_sum_running_totals() {
+    let legs = [
+        quantity!(1.0, m),
+        quantity!(2.0, m),
+        quantity!(3.0, m),
+        quantity!(4.0, m),
+    ];
+    let totals: Vec<Length> = cumulative_sum(&legs);
+    assert_eq!(
+        totals,
+        [
+            quantity!(1.0, m),
+            quantity!(3.0, m),
+            quantity!(6.0, m),
+            quantity!(10.0, m)
+        ]
+    );
+
+    // Totals stay in the unit of the series, including integer storage
+    let totals = cumulative_sum(&[quantity!(250, ms, i32), quantity!(750, ms, i32)]);
+    assert_eq!(totals, [quantity!(250, ms, i32), quantity!(1000, ms, i32)]);
+}
+
+#[test]
+fn test_cumulative_sum_short_series() {
+    assert!(cumulative_sum::<Length>(&[]).is_empty());
+    assert_eq!(cumulative_sum(&[quantity!(5.0, km)]), [quantity!(5.0, km)]);
+}
+
+#[test]
+fn test_cumulative_sum_accumulates_in_order() {
+    // Adding from the front: 1e16 absorbs each 1.0 in turn, as a sequential loop would
+    let values = [quantity!(1e16, m), quantity!(1.0, m), quantity!(1.0, m)];
+    let totals = cumulative_sum(&values);
+    assert_eq!(totals[2], quantity!(1e16, m));
+
+    // The running totals match integrating a derivative sample by sample
+    let velocities = [quantity!(2.0, m / s); 4];
+    let steps: Vec<Length> = velocities.iter().map(|&v| v * quantity!(0.5, s)).collect();
+    assert_eq!(value!(cumulative_sum(&steps)[3], m), 4.0);
+}
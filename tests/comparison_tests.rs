@@ -161,3 +161,82 @@ fn test_cross_scale_ordering_consistency() {
     assert_eq!(rescale(b_m), b_mm);
     assert_eq!(b_m, rescale(b_mm));
 }
+
+#[test]
+fn test_relative_difference() {
+    // Close quantities, across scales
+    let measured = quantity!(1.02, m);
+    assert!((measured.relative_difference(&quantity!(100.0, cm)) - 0.02).abs() < 1e-12);
+    assert!((quantity!(98.0, cm).relative_difference(&quantity!(1.0, m)) - 0.02).abs() < 1e-12);
+    assert_eq!(
+        quantity!(1.0, km).relative_difference(&quantity!(1000.0, m)),
+        0.0
+    );
+
+    // Far-apart quantities; the difference is relative to the argument
+    assert_eq!(
+        quantity!(10.0, m).relative_difference(&quantity!(1.0, m)),
+        9.0
+    );
+    assert_eq!(
+        quantity!(1.0, m).relative_difference(&quantity!(10.0, m)),
+        0.9
+    );
+
+    // Signs are handled through absolute values
+    assert_eq!(
+        quantity!(-1.0, m).relative_difference(&quantity!(1.0, m)),
+        2.0
+    );
+    assert_eq!(
+        quantity!(-3.0, s).relative_difference(&quantity!(-2.0, s)),
+        0.5
+    );
+
+    // A zero reference is undefined
+    assert_eq!(
+        quantity!(1.0, m).relative_difference(&quantity!(0.0, m)),
+        f64::INFINITY
+    );
+    assert!(
+        quantity!(0.0, m)
+            .relative_difference(&quantity!(0.0, mm))
+            .is_nan()
+    );
+
+    // Integer storage is compared in f64
+    assert_eq!(
+        quantity!(1100, mm, i32).relative_difference(&quantity!(1, m, i32)),
+        0.1
+    );
+}
+
+#[test]
+fn test_fraction_of() {
+    // Across scales
+    assert_eq!(quantity!(500.0, m).fraction_of(&quantity!(2.0, km)), 0.25);
+    assert_eq!(quantity!(500.0, m).percent_of(&quantity!(2.0, km)), 25.0);
+    assert_eq!(quantity!(2.0, km).fraction_of(&quantity!(500.0, m)), 4.0);
+    assert_eq!(quantity!(30.0, s).percent_of(&quantity!(2.0, min)), 25.0);
+
+    // Integer storage
+    assert_eq!(
+        quantity!(500, m, i32).fraction_of(&quantity!(2, km, i32)),
+        0.25
+    );
+
+    // Unclamped, with signs kept
+    assert_eq!(quantity!(3.0, m).fraction_of(&quantity!(2.0, m)), 1.5);
+    assert_eq!(quantity!(-1.0, m).percent_of(&quantity!(4.0, m)), -25.0);
+
+    // A zero reference is undefined
+    assert_eq!(
+        quantity!(1.0, m).fraction_of(&quantity!(0.0, km)),
+        f64::INFINITY
+    );
+    assert_eq!(
+        quantity!(-1.0, m).fraction_of(&quantity!(0.0, m)),
+        f64::NEG_INFINITY
+    );
+    assert!(quantity!(0.0, m).percent_of(&quantity!(0.0, m)).is_nan());
+}
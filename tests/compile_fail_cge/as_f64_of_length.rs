@@ -0,0 +1,7 @@
+// This should fail to compile: only dimensionless quantities have a bare value
+use whippyunits::{quantity, unit};
+
+fn main() {
+    let distance: unit!(m) = quantity!(1.0, m);
+    let _ = distance.as_f64();
+}
@@ -0,0 +1,7 @@
+// This should fail to compile: atan2 components must share a dimension
+use whippyunits::api::atan2;
+use whippyunits::quantity;
+
+fn main() {
+    let _ = atan2(quantity!(1.0, m), quantity!(1.0, s));
+}
@@ -0,0 +1,10 @@
+// This should fail to compile: a data rate is not a frequency, since information has its own
+// base dimension
+use whippyunits::{quantity, unit};
+
+fn main() {
+    let rate = quantity!(1.0, bit / s);
+
+    // This should fail to compile: can't assign bits per second to a frequency variable
+    let frequency: unit!(Hz) = rate;
+}
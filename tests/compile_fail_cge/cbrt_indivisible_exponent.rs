@@ -0,0 +1,7 @@
+// This should fail to compile: m^2 has no cube root with whole exponents
+use whippyunits::quantity;
+
+fn main() {
+    let area = quantity!(4.0, m ^ 2);
+    let _ = area.cbrt();
+}
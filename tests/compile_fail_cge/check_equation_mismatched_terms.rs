@@ -0,0 +1,6 @@
+// This should fail to compile: the terms of a sum must have the same dimension
+use whippyunits::check_equation;
+
+check_equation!(E = 0.5 * m * v * v + m * v; E: J, m: kg, v: m / s);
+
+fn main() {}
@@ -0,0 +1,9 @@
+// This should fail to compile: expect_dimension! ignores scale but not dimension
+use whippyunits::{expect_dimension, quantity};
+
+fn main() {
+    let distance = quantity!(5.0, mm);
+
+    // This should fail to compile: length is not time
+    expect_dimension!(distance, s);
+}
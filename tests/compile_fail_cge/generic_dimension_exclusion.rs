@@ -0,0 +1,14 @@
+// This should fail to compile: an excluded dimension does not satisfy the generic dimension
+use whippyunits::dimension_traits::define_generic_dimension;
+use whippyunits::quantity;
+
+define_generic_dimension!(NonMass, !Mass);
+
+fn assert_non_mass<Q: NonMass>(_: Q) {}
+
+fn main() {
+    assert_non_mass(quantity!(1.0, m / s));
+
+    // This should fail to compile: kg has a nonzero mass exponent
+    assert_non_mass(quantity!(1.0, kg));
+}
@@ -0,0 +1,14 @@
+// This should fail to compile: `Mm` (megameter) is likely a typo for `mm` (millimeter), and
+// the resulting warning is denied here
+#![deny(deprecated)]
+
+use whippyunits::quantity;
+
+fn main() {
+    let _thickness = quantity!(5.0, Mm);
+
+    // Intended megameters and large prefixes on uppercase units are not flagged
+    #[allow(deprecated)]
+    let _orbit = quantity!(42.0, Mm);
+    let _pressure = quantity!(5.0, MPa);
+}
@@ -0,0 +1,10 @@
+// This should fail to compile: addition is scale-strict
+use whippyunits::quantity;
+
+fn main() {
+    let meters = quantity!(1.0, m);
+    let centimeters = quantity!(50.0, cm);
+
+    // This should fail to compile - different scales
+    let _total = meters + centimeters;
+}
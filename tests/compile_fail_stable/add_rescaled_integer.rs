@@ -0,0 +1,11 @@
+// This should fail to compile: add_rescaled only rescales float storage, and points the user at
+// an explicit rescale for integer storage
+use whippyunits::quantity;
+
+fn main() {
+    let meters = quantity!(1, m, i32);
+    let centimeters = quantity!(50, cm, i32);
+
+    // This should fail to compile - rescaling integer storage can truncate
+    let _total = meters.add_rescaled(centimeters);
+}
@@ -0,0 +1,7 @@
+// This should fail to compile: quantities of different dimensions cannot be compared
+use whippyunits::{assert_quantity_approx_eq, quantity};
+
+fn main() {
+    // This should fail to compile: length is not time
+    assert_quantity_approx_eq!(quantity!(1.0, m), quantity!(1.0, s));
+}
@@ -0,0 +1,6 @@
+// This should fail to compile: momentum is not energy
+use whippyunits::check_equation;
+
+check_equation!(E = m * v; E: J, m: kg, v: m / s);
+
+fn main() {}
@@ -0,0 +1,11 @@
+// This test verifies that clamp requires bounds of the same unit as the clamped quantity:
+// a length cannot be clamped to a range of times.
+
+use whippyunits::quantity;
+
+fn main() {
+    let distance = quantity!(3.0, m);
+
+    // This should fail to compile - the bounds are times, not lengths
+    let _clamped = distance.clamp(quantity!(0.0, s), quantity!(10.0, s));
+}
@@ -0,0 +1,9 @@
+// This should fail to compile: expect_unit! checks the scale as well as the dimension
+use whippyunits::{expect_unit, quantity};
+
+fn main() {
+    let distance = quantity!(5.0, mm);
+
+    // Same dimension, but millimeters are not meters
+    expect_unit!(distance, m);
+}
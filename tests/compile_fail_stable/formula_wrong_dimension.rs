@@ -0,0 +1,10 @@
+// This should fail to compile: kinetic energy is not a power, and exponents must be integer
+// literals
+use whippyunits::{formula, quantity};
+
+fn main() {
+    let m = quantity!(2.0, kg);
+    let v = quantity!(3.0, m / s);
+    formula!(kinetic_energy: W = 0.5 * m * v ^ 2);
+    formula!(root_speed = v ^ 0.5);
+}
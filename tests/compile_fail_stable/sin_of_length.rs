@@ -0,0 +1,7 @@
+// This should fail to compile: only pure angles have a sine
+use whippyunits::quantity;
+
+fn main() {
+    let angle = quantity!(1.0, rad * m);
+    let _ = angle.sin();
+}
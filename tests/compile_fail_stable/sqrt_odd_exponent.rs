@@ -0,0 +1,7 @@
+// This should fail to compile: m^3 has no square root with whole exponents
+use whippyunits::quantity;
+
+fn main() {
+    let volume = quantity!(8.0, m ^ 3);
+    let _ = volume.sqrt();
+}
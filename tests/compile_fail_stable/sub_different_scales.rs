@@ -0,0 +1,10 @@
+// This should fail to compile: subtraction is scale-strict
+use whippyunits::quantity;
+
+fn main() {
+    let kilometers = quantity!(1.0, km);
+    let meters = quantity!(500.0, m);
+
+    // This should fail to compile - different scales
+    let _difference = kilometers - meters;
+}
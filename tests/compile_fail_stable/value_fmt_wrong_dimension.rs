@@ -0,0 +1,9 @@
+// This should fail to compile: a speed cannot be displayed in seconds, and every placeholder
+// needs a unit
+use whippyunits::{quantity, value_fmt};
+
+fn main() {
+    let speed = quantity!(10.0, m / s);
+    let _ = value_fmt!("{} km/h after {} s", speed, speed);
+    let _ = value_fmt!("{}, then {} m/s", speed, speed);
+}
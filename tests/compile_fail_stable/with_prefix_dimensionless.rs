@@ -0,0 +1,7 @@
+// This should fail to compile: a dimensionless ratio has no unit to prefix
+use whippyunits::{quantity, with_prefix};
+
+fn main() {
+    let ratio = quantity!(1500.0, m) / quantity!(1.0, m);
+    let _ = with_prefix!(ratio, k);
+}
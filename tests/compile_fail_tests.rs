@@ -8,6 +8,7 @@
 fn test_compile_failures_stable() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/compile_fail_stable/*.rs");
+    t.pass("tests/compile_pass/*.rs");
 }
 
 #[test]
@@ -15,5 +16,13 @@ fn test_compile_failures_stable() {
 fn test_compile_failures_cge() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/compile_fail_cge/*.rs");
+    t.pass("tests/compile_pass/*.rs");
 }
 
+
+#[test]
+#[cfg(feature = "prefix-lint")]
+fn test_prefix_lint() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail_prefix_lint/*.rs");
+}
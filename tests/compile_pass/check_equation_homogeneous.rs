@@ -0,0 +1,16 @@
+// Dimensionally homogeneous equations compile, whatever the scales of their two sides
+use whippyunits::check_equation;
+
+check_equation!(F = m * a; F: kN, m: kg, a: m / s ^ 2);
+check_equation!(E = 0.5 * m * v * v + m * g * h; E: J, m: kg, v: m / s, g: m / s ^ 2, h: m);
+check_equation!(Re = rho * v * L / mu; Re: 1, rho: kg / m ^ 3, v: m / s, L: m, mu: Pa * s);
+
+fn main() {
+    use whippyunits::quantity;
+
+    let distance = quantity!(100.0, m);
+    let speed = quantity!(5.0, m / s);
+    let time = quantity!(20.0, s);
+    check_equation!(distance = speed * time);
+    check_equation!(speed * time / distance = 1.0);
+}
@@ -0,0 +1,10 @@
+// Large prefixes on units of information are not mistaken for small ones
+#![deny(deprecated)]
+
+use whippyunits::quantity;
+
+fn main() {
+    let _ = quantity!(1.0, Mbit);
+    let _ = quantity!(1.0, Gbit / s);
+    let _ = quantity!(1.0, MB);
+}
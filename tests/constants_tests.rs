@@ -0,0 +1,58 @@
+//! Tests for the typed physical constants in `whippyunits::constants`
+
+use whippyunits::constants::*;
+use whippyunits::{unit, value};
+
+fn assert_approx(actual: f64, expected: f64) {
+    let rel = ((actual - expected) / expected).abs();
+    assert!(rel < 1e-9, "expected {expected}, got {actual}");
+}
+
+#[test]
+fn test_speed_of_light() {
+    let c: unit!(m / s) = C;
+    assert_eq!(value!(c, m / s), 299_792_458.0);
+}
+
+#[test]
+fn test_gravitational_constant() {
+    let g: unit!(m ^ 3 / (kg * s ^ 2)) = G;
+    assert_approx(value!(g, m ^ 3 / (kg * s ^ 2)), 6.674_30e-11);
+}
+
+#[test]
+fn test_planck_constants() {
+    let h: unit!(J * s) = H;
+    let hbar: unit!(kg * m ^ 2 / s) = HBAR;
+    assert_approx(value!(h, J * s), 6.626_070_15e-34);
+    assert_approx(value!(hbar, kg * m ^ 2 / s), 1.054_571_817e-34);
+    assert_approx(
+        value!(h, J * s) / (2.0 * core::f64::consts::PI),
+        value!(hbar, J * s),
+    );
+}
+
+#[test]
+fn test_boltzmann_constant() {
+    let k_b: unit!(J / K) = K_B;
+    assert_approx(value!(k_b, J / K), 1.380_649e-23);
+}
+
+#[test]
+fn test_avogadro_constant() {
+    let n_a: unit!(1 / mol) = N_A;
+    assert_approx(value!(n_a, 1 / mol), 6.022_140_76e23);
+}
+
+#[test]
+fn test_elementary_charge() {
+    let e: unit!(C) = E_CHARGE;
+    assert_approx(value!(e, A * s), 1.602_176_634e-19);
+}
+
+#[test]
+fn test_constants_compose_dimensionally() {
+    // k_B * N_A is the molar gas constant, J / (mol K)
+    let r: unit!(J / (mol * K)) = K_B * N_A;
+    assert_approx(value!(r, J / (mol * K)), 8.314_462_618);
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "serde")]
+//! Tests for writing quantities with `whippyunits::csv`
+
+use whippyunits::csv::CsvWriter;
+use whippyunits::{from_string, quantity, unit, value};
+
+/// Split a CSV table into `(name, unit)` headers and rows of raw cells.
+fn read_table(csv: &str) -> (Vec<(String, String)>, Vec<Vec<String>>) {
+    let mut lines = csv.lines();
+    let headers = lines
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|header| {
+            let (name, unit) = header.strip_suffix(']').unwrap().split_once('[').unwrap();
+            (name.to_string(), unit.to_string())
+        })
+        .collect();
+    let rows = lines
+        .map(|line| line.split(',').map(str::to_string).collect())
+        .collect();
+    (headers, rows)
+}
+
+fn assert_approx(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn test_write_and_reread_heterogeneous_columns() {
+    let distances = [quantity!(1.5, km), quantity!(42.195, km)];
+    let masses = [quantity!(250.0, g), quantity!(3000.0, g)];
+    let energies = [quantity!(2.0, kJ), quantity!(0.5, kJ)];
+    let viscosities = [quantity!(1.0, mPa * s), quantity!(2.5, mPa * s)];
+
+    let csv = CsvWriter::new()
+        .column("distance", &distances)
+        .column("mass", &masses)
+        .column("energy", &energies)
+        .column("viscosity", &viscosities)
+        .to_csv_string();
+
+    let (headers, rows) = read_table(&csv);
+    let names: Vec<_> = headers.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["distance", "mass", "energy", "viscosity"]);
+    // Each column carries the coherent unit of its own dimension
+    assert_eq!(headers[0].1, "m");
+    assert_eq!(headers[1].1, "kg");
+    assert_eq!(headers[2].1, "J");
+    assert_eq!(headers[3].1, "kg/(m*s)");
+    assert_eq!(rows.len(), 2);
+
+    for (row, cells) in rows.iter().enumerate() {
+        let cell = |column: usize| format!("{} {}", cells[column], headers[column].1);
+
+        let distance: unit!(km) = from_string!(&cell(0), km).unwrap();
+        assert_approx(value!(distance, km), value!(distances[row], km));
+        let mass: unit!(g) = from_string!(&cell(1), g).unwrap();
+        assert_approx(value!(mass, g), value!(masses[row], g));
+        let energy: unit!(kJ) = from_string!(&cell(2), kJ).unwrap();
+        assert_approx(value!(energy, kJ), value!(energies[row], kJ));
+        let viscosity: unit!(mPa * s) = from_string!(&cell(3), mPa * s).unwrap();
+        assert_approx(
+            value!(viscosity, mPa * s),
+            value!(viscosities[row], mPa * s),
+        );
+    }
+}
+
+#[test]
+fn test_write_ragged_columns_and_quoted_names() {
+    let csv = CsvWriter::new()
+        .column("x, y", &[quantity!(1.0, m), quantity!(2.0, m)])
+        .column("t", &[quantity!(3.0, s)])
+        .to_csv_string();
+    assert_eq!(csv, "\"x, y[m]\",t[s]\n1,3\n2,\n");
+
+    assert_eq!(CsvWriter::new().to_csv_string(), "\n");
+}
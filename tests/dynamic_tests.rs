@@ -0,0 +1,210 @@
+#![cfg(feature = "alloc")]
+//! Tests for handling quantities through `whippyunits::dynamic::QuantityLike`
+
+use whippyunits::dynamic::{DimensionMismatch, DynQuantity, QuantityLike};
+use whippyunits::serialization::parse_ucum_unit;
+use whippyunits::{quantity, unit};
+use whippyunits_core::{
+    dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
+};
+
+#[test]
+fn test_quantity_like_through_dyn() {
+    let quantities: Vec<Box<dyn QuantityLike>> = vec![
+        Box::new(quantity!(1.5, km)),
+        Box::new(quantity!(250, mm, i32)),
+        Box::new(quantity!(2.0, min)),
+        Box::new(quantity!(12.5, m / s)),
+    ];
+
+    let values: Vec<f64> = quantities.iter().map(|q| q.value_in_base()).collect();
+    assert_eq!(values, [1500.0, 0.25, 120.0, 12.5]);
+
+    let dims: Vec<[i16; 9]> = quantities.iter().map(|q| q.dims()).collect();
+    assert_eq!(
+        dims,
+        [
+            [0, 1, 0, 0, 0, 0, 0, 0, 0],
+            [0, 1, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 1, 0, 0, 0, 0, 0, 0],
+            [0, 1, -1, 0, 0, 0, 0, 0, 0],
+        ]
+    );
+
+    // Unit strings describe the unit each quantity is stored in, not the base unit
+    assert_eq!(quantities[0].unit_string(), "km");
+    assert_eq!(quantities[1].unit_string(), "mm");
+}
+
+#[test]
+fn test_add_dyn_checks_dimensions() {
+    let distance: &dyn QuantityLike = &quantity!(1.5, km);
+    let more: &dyn QuantityLike = &quantity!(250, mm, i32);
+
+    let total = distance.add_dyn(more).unwrap();
+    assert_eq!(total.value_in_base(), 1500.25);
+    assert_eq!(total.dims(), distance.dims());
+    assert_eq!(total.unit_string(), "m");
+
+    // Results can be combined further through the trait
+    let doubled = total.add_dyn(total.as_ref()).unwrap();
+    assert_eq!(doubled.value_in_base(), 3000.5);
+
+    let time: &dyn QuantityLike = &quantity!(2.0, s);
+    let error = distance.add_dyn(time).err().unwrap();
+    assert_eq!(
+        error,
+        DimensionMismatch {
+            left: [0, 1, 0, 0, 0, 0, 0, 0, 0],
+            right: [0, 0, 1, 0, 0, 0, 0, 0, 0],
+        }
+    );
+    assert!(error.to_string().contains("mismatched dimensions"));
+}
+
+#[test]
+fn test_mul_dyn_combines_dimensions() {
+    let force: &dyn QuantityLike = &quantity!(3.0, kN);
+    let distance: &dyn QuantityLike = &quantity!(2.0, m);
+
+    let work = force.mul_dyn(distance);
+    assert_eq!(work.value_in_base(), 6000.0);
+    assert_eq!(work.dims(), [1, 2, -2, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(work.unit_string(), "J");
+
+    // Multiplying by an inverse dimension cancels back to dimensionless
+    let frequency: &dyn QuantityLike = &quantity!(4.0, Hz);
+    let period: &dyn QuantityLike = &quantity!(0.5, s);
+    let cycles = frequency.mul_dyn(period);
+    assert_eq!(cycles.dims(), [0; 9]);
+    assert_eq!(cycles.value_in_base(), 2.0);
+    assert_eq!(cycles.unit_string(), "1");
+}
+
+#[test]
+fn test_dyn_quantity_reports_coherent_units() {
+    let pressure = DynQuantity::new(
+        101_325.0,
+        DynDimensionExponents([1, -1, -2, 0, 0, 0, 0, 0, 0]),
+        ScaleExponents::IDENTITY,
+    );
+    assert_eq!(pressure.value_in_base(), 101_325.0);
+    assert_eq!(pressure.unit_string(), "Pa");
+
+    let jerk = DynQuantity::new(
+        1.0,
+        DynDimensionExponents([0, 1, -3, 0, 0, 0, 0, 0, 0]),
+        ScaleExponents::IDENTITY,
+    );
+    assert_eq!(jerk.unit_string(), "m/s3");
+}
+
+fn parsed(value: f64, unit: &str) -> DynQuantity {
+    let (dimensions, scale) = parse_ucum_unit(unit).unwrap();
+    DynQuantity::new(value, dimensions, scale)
+}
+
+#[test]
+fn test_dyn_quantity_addition_checks_dimensions() {
+    let sum = (parsed(1.5, "m") + parsed(250.0, "mm")).unwrap();
+    assert_eq!(sum.value, 1.75);
+    assert_eq!(sum.dimensions, DynDimensionExponents::LENGTH);
+
+    // The result is in the scale of the left-hand operand
+    let sum = (parsed(1.0, "km") + parsed(500.0, "m")).unwrap();
+    assert_eq!(sum.value, 1.5);
+    assert_eq!(sum.unit_string(), "km");
+    let difference = (parsed(500.0, "m") - parsed(0.25, "km")).unwrap();
+    assert_eq!(difference.value, 250.0);
+
+    assert_eq!(
+        parsed(1.0, "m") + parsed(2.0, "s"),
+        Err(DimensionMismatch {
+            left: [0, 1, 0, 0, 0, 0, 0, 0, 0],
+            right: [0, 0, 1, 0, 0, 0, 0, 0, 0],
+        })
+    );
+    assert!((parsed(1.0, "m") - parsed(2.0, "s")).is_err());
+}
+
+#[test]
+fn test_dyn_quantity_multiplication_and_division() {
+    let area = parsed(3.0, "km") * parsed(2.0, "m");
+    assert_eq!(
+        area.dimensions,
+        DynDimensionExponents([0, 2, 0, 0, 0, 0, 0, 0, 0])
+    );
+    assert_eq!(area.value_in_base(), 6000.0);
+
+    let speed = parsed(3.0, "km") / parsed(2.0, "s");
+    assert_eq!(
+        speed.dimensions,
+        DynDimensionExponents([0, 1, -1, 0, 0, 0, 0, 0, 0])
+    );
+    assert_eq!(speed.value_in_base(), 1500.0);
+}
+
+#[test]
+fn test_dyn_quantity_into_quantity() {
+    let speed: unit!(m / s) = (parsed(3.0, "km") / parsed(2.0, "s")).try_into().unwrap();
+    assert_eq!(speed, quantity!(1500.0, m / s));
+
+    let speed: unit!(km / s) = parsed(12.5, "m/s").try_into().unwrap();
+    assert_eq!(speed, quantity!(0.0125, km / s));
+
+    assert_eq!(
+        <unit!(m / s)>::try_from(parsed(12.5, "m/s2")),
+        Err(DimensionMismatch {
+            left: [0, 1, -2, 0, 0, 0, 0, 0, 0],
+            right: [0, 1, -1, 0, 0, 0, 0, 0, 0],
+        })
+    );
+}
+
+#[test]
+fn test_dyn_quantities_sort_across_scales() {
+    let mut lengths = [
+        parsed(1.2, "km"),
+        parsed(30.0, "cm"),
+        parsed(500.0, "m"),
+        parsed(2.5, "mm"),
+        parsed(0.5, "km"),
+    ];
+    lengths.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let in_meters: Vec<f64> = lengths.iter().map(|l| l.value_in_base()).collect();
+    assert_eq!(in_meters, [0.0025, 0.3, 500.0, 500.0, 1200.0]);
+    // Equal lengths compare equal whatever their scale
+    assert_eq!(parsed(500.0, "m"), parsed(0.5, "km"));
+}
+
+#[test]
+fn test_dyn_quantities_of_different_dimensions_are_unordered() {
+    let (length, time) = (parsed(1.0, "m"), parsed(1.0, "s"));
+    assert_eq!(length.partial_cmp(&time), None);
+    assert_ne!(length, time);
+    assert!(!(length.lt(&time) || length.gt(&time) || length.le(&time)));
+}
+
+#[test]
+fn test_dyn_quantity_comparison_is_symmetric() {
+    // Pairs that may be equal but whose scale factors are inexact in floating point
+    let pairs = [("km", "mm"), ("deg", "rad"), ("h", "ms"), ("arcmin", "deg")];
+    for (unit_a, unit_b) in pairs {
+        for step in 0..200 {
+            let value = 0.1 * step as f64 + 0.3;
+            let a = parsed(value, unit_a);
+            let b = parsed(
+                value * a.value_in_base() / parsed(value, unit_b).value_in_base(),
+                unit_b,
+            );
+
+            assert_eq!(a == b, b == a, "{value} {unit_a} vs {unit_b}");
+            assert_eq!(
+                a.partial_cmp(&b),
+                b.partial_cmp(&a).map(|ordering| ordering.reverse()),
+                "{value} {unit_a} vs {unit_b}"
+            );
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use whippyunits::default_declarators::*;
+use whippyunits::{assert_quantity_approx_eq, expect_dimension, expect_unit, quantity};
+
+#[test]
+fn test_expect_unit_accepts_matching_unit() {
+    let velocity = quantity!(10.0, m) / quantity!(2.0, s);
+    expect_unit!(velocity, m / s);
+
+    // Storage type is not part of the check
+    let distance = quantity!(5, mm, i32);
+    expect_unit!(distance, mm);
+
+    // The quantity is borrowed, not consumed
+    let force = quantity!(3.0, kg * m / s ^ 2);
+    expect_unit!(force, N);
+    expect_unit!(force, kg * m / s ^ 2);
+    assert_eq!(force, 3.0.newtons());
+}
+
+#[test]
+fn test_expect_dimension_ignores_scale() {
+    let distance = quantity!(3.0, mm);
+    expect_dimension!(distance, m);
+    expect_dimension!(distance, km);
+    expect_dimension!(distance, ft);
+
+    let energy = 1.0.kilojoules();
+    expect_dimension!(energy, J);
+    expect_dimension!(energy, N * m);
+}
+
+#[test]
+fn test_assert_quantity_approx_eq_passes_across_scales() {
+    assert_quantity_approx_eq!(quantity!(1.5, m), quantity!(150.0, cm));
+    assert_quantity_approx_eq!(quantity!(150.0, cm), quantity!(1.5, m));
+    assert_quantity_approx_eq!(quantity!(1.0, km), quantity!(1001.0, m), 0.01);
+    assert_quantity_approx_eq!(1.0.kilojoules(), quantity!(1000.0, N * m));
+
+    // Integer storage is compared in f64
+    assert_quantity_approx_eq!(quantity!(2, m, i32), quantity!(2000, mm, i32));
+}
+
+#[test]
+#[should_panic(expected = "right: 1.4900 Quantity<m, f64> (= 149.00 Quantity<cm, f64>)")]
+fn test_assert_quantity_approx_eq_failure_shows_units() {
+    assert_quantity_approx_eq!(quantity!(150.0, cm), quantity!(1.49, m));
+}
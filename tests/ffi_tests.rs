@@ -0,0 +1,76 @@
+//! Tests for the C-compatible quantity representation in `whippyunits::ffi`
+
+use core::mem::{MaybeUninit, align_of, offset_of, size_of};
+
+use whippyunits::ffi::{C_HEADER, FfiQuantity, FromFfiError};
+use whippyunits::{quantity, unit};
+
+#[test]
+fn test_ffi_layout() {
+    assert_eq!(size_of::<FfiQuantity>(), 40);
+    assert_eq!(align_of::<FfiQuantity>(), 8);
+    assert_eq!(offset_of!(FfiQuantity, value), 0);
+    assert_eq!(offset_of!(FfiQuantity, dims), 8);
+    assert_eq!(offset_of!(FfiQuantity, scale), 26);
+
+    assert!(C_HEADER.contains("double value;"));
+    assert!(C_HEADER.contains("int16_t dims[9];"));
+    assert!(C_HEADER.contains("int16_t scale[4];"));
+}
+
+#[test]
+fn test_ffi_round_trip() {
+    let force = quantity!(12.5, kN);
+    let ffi = force.to_ffi();
+    assert_eq!(ffi.value, 12.5);
+    assert_eq!(ffi.dims, [1, 1, -2, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(ffi.scale, [3, 0, 3, 0]);
+    assert_eq!(<unit!(kN)>::from_ffi(ffi), Ok(force));
+
+    let angle = quantity!(90.0, deg);
+    assert_eq!(<unit!(deg)>::from_ffi(angle.to_ffi()), Ok(angle));
+
+    let count = quantity!(-7, mm, i32);
+    assert_eq!(<unit!(mm, i32)>::from_ffi(count.to_ffi()), Ok(count));
+
+    // Round trip through the raw bytes a C caller would see, including the trailing padding
+    let bytes: [MaybeUninit<u8>; 40] = unsafe { core::mem::transmute(ffi) };
+    let restored: FfiQuantity = unsafe { core::mem::transmute(bytes) };
+    assert_eq!(<unit!(kN)>::from_ffi(restored), Ok(force));
+}
+
+#[test]
+fn test_from_ffi_validation() {
+    let ffi = quantity!(5.0, mm).to_ffi();
+
+    // Same dimension, different scale
+    let Err(FromFfiError::ExponentMismatch { expected, found }) = <unit!(m)>::from_ffi(ffi) else {
+        panic!("scale mismatch was accepted");
+    };
+    assert_eq!(expected, [0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(found, [0, 1, 0, 0, 0, 0, 0, 0, 0, -3, 0, -3, 0]);
+
+    // Different dimension
+    assert!(matches!(
+        <unit!(ms)>::from_ffi(ffi),
+        Err(FromFfiError::ExponentMismatch { .. })
+    ));
+
+    // Values that do not fit the storage type
+    let huge = FfiQuantity {
+        value: 1e20,
+        ..quantity!(0, mm, i32).to_ffi()
+    };
+    assert_eq!(
+        <unit!(mm, i32)>::from_ffi(huge),
+        Err(FromFfiError::ValueOutOfRange(1e20))
+    );
+    let nan = FfiQuantity {
+        value: f64::NAN,
+        ..huge
+    };
+    assert!(matches!(
+        <unit!(mm, i32)>::from_ffi(nan),
+        Err(FromFfiError::ValueOutOfRange(_))
+    ));
+}
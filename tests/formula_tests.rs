@@ -0,0 +1,70 @@
+use whippyunits::constants;
+use whippyunits::{assert_quantity_approx_eq, expect_dimension, formula, quantity, unit};
+
+#[test]
+fn test_formula_kinetic_energy() {
+    let m = quantity!(2.0, kg);
+    let v = quantity!(3.0, m / s);
+
+    formula!(kinetic_energy = 0.5 * m * v ^ 2);
+    let _: unit!(J) = kinetic_energy;
+    assert_eq!(kinetic_energy, quantity!(9.0, J));
+
+    // The declared unit is checked against the result
+    formula!(checked: J = 0.5 * m * v ^ 2);
+    assert_eq!(checked, kinetic_energy);
+
+    // As an expression, without a binding
+    let energy: unit!(kg * m ^ 2 / s ^ 2) = formula!(0.5 * m * v ^ 2);
+    assert_eq!(energy, quantity!(9.0, J));
+}
+
+#[test]
+fn test_formula_literals_are_dimensionless() {
+    let m = quantity!(2.0, kg);
+    let v = quantity!(3.0, m / s);
+
+    // Integer and float coefficients scale the value but not the dimension
+    let doubled: unit!(J) = formula!(2 * m * v ^ 2);
+    assert_eq!(doubled, quantity!(36.0, J));
+    let halved: unit!(J) = formula!(m * v ^ 2 / 2);
+    assert_eq!(halved, quantity!(9.0, J));
+    expect_dimension!(formula!(1e3 * m * v ^ 2), J);
+
+    // A formula of literals alone is a plain number
+    let scalar: f64 = formula!(0.5 * 2 ^ 3 + 1);
+    assert_eq!(scalar, 5.0);
+}
+
+#[test]
+fn test_formula_precedence_and_grouping() {
+    let a = quantity!(2.0, m);
+    let b = quantity!(1.0, m);
+    let t = quantity!(2.0, s);
+
+    // `^` binds tighter than `*`, and `*` tighter than `+`
+    let area: unit!(m ^ 2) = formula!(a * b + a ^ 2);
+    assert_eq!(area, quantity!(6.0, m ^ 2));
+    let squared: unit!(m ^ 2) = formula!((a + b) ^ 2);
+    assert_eq!(squared, quantity!(9.0, m ^ 2));
+
+    // Negative exponents give reciprocal units
+    let rate: unit!(m / s) = formula!(a * t ^ -1);
+    assert_eq!(rate, quantity!(1.0, m / s));
+    let rate: unit!(m / s) = formula!(a * t ^ (-1));
+    assert_eq!(rate, quantity!(1.0, m / s));
+}
+
+#[test]
+fn test_formula_with_constants_and_method_calls() {
+    let m1 = quantity!(5.972e24, kg);
+    let m2 = quantity!(7.348e22, kg);
+    let r = quantity!(3.844e8, m);
+
+    formula!(force: N = constants::G * m1 * m2 / r ^ 2);
+    assert_quantity_approx_eq!(force, quantity!(1.982e20, N), 1e17);
+
+    let v = quantity!(-3.0, m / s);
+    let energy: unit!(J) = formula!(0.5 * m2 * v.abs() ^ 2);
+    assert_quantity_approx_eq!(energy, quantity!(3.3066e23, J), 1e19);
+}
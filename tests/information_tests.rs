@@ -0,0 +1,61 @@
+//! Tests for the units of information (`bit`, `B`) and their binary prefixes
+
+use whippyunits::api::rescale;
+use whippyunits::{quantity, unit, value};
+
+#[test]
+fn test_binary_prefixes() {
+    // A kibibyte is stored as 1024 bytes
+    let kibibyte = quantity!(1.0, KiB);
+    let bytes: unit!(B) = rescale(kibibyte);
+    assert_eq!(bytes.unsafe_value, 1024.0);
+    assert_eq!(value!(kibibyte, B), 1024.0);
+
+    // Binary prefixes are carried entirely by the power of 2: a byte is 2^3 bits
+    assert_eq!(quantity!(1.0, bit).scale_exponents(), [0, 0, 0, 0]);
+    assert_eq!(quantity!(1.0, B).scale_exponents(), [3, 0, 0, 0]);
+    assert_eq!(quantity!(1.0, Kibit).scale_exponents(), [10, 0, 0, 0]);
+    assert_eq!(quantity!(1.0, Mibit).scale_exponents(), [20, 0, 0, 0]);
+    assert_eq!(quantity!(1.0, MiB).scale_p2(), 23);
+    assert_eq!(quantity!(1.0, GiB).scale_p2(), 33);
+
+    let mebibyte: unit!(KiB) = rescale(quantity!(1.0, MiB));
+    assert_eq!(mebibyte.unsafe_value, 1024.0);
+    assert_eq!(value!(quantity!(1.0, Mibit), bit), 1_048_576.0);
+}
+
+#[test]
+fn test_decimal_prefixes() {
+    // SI prefixes keep their decimal meaning on units of information
+    assert_eq!(value!(quantity!(1.0, kB), B), 1000.0);
+    assert_eq!(value!(quantity!(1.0, Mbit), kbit), 1000.0);
+    assert_eq!(value!(quantity!(2.0, B), bit), 16.0);
+
+    let kibibyte: unit!(B) = rescale(quantity!(1.0, KiB));
+    let kilobyte: unit!(B) = rescale(quantity!(1.0, kB));
+    assert_eq!(kibibyte.unsafe_value - kilobyte.unsafe_value, 24.0);
+}
+
+#[test]
+fn test_data_rates() {
+    // 100 megabytes per second is 800 megabits per second
+    let rate = quantity!(100.0, MB / s);
+    assert_eq!(value!(rate, Mbit / s), 800.0);
+
+    // A 1 GiB download at 8 MiB/s takes 128 seconds
+    let size = quantity!(1.0, GiB);
+    let rate = quantity!(8.0, MiB / s);
+    let time: unit!(s) = rescale(size / rate);
+    assert_eq!(time.unsafe_value, 128.0);
+}
+
+#[test]
+fn test_information_dimension() {
+    // Information is its own base dimension, so a data rate is not a frequency
+    assert!(!<unit!(bit / s)>::is_commensurate_with::<unit!(Hz)>());
+    assert!(<unit!(bit / s)>::is_commensurate_with::<unit!(MB / h)>());
+
+    // Bits per bit cancel to a dimensionless ratio
+    let ratio: f64 = (quantity!(1.0, B) / quantity!(1.0, bit)).into();
+    assert_eq!(ratio, 8.0);
+}
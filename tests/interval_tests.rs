@@ -0,0 +1,47 @@
+use whippyunits::interval::Interval;
+use whippyunits::quantity;
+
+#[test]
+fn test_interval_contains() {
+    let band = quantity!(1.0, m).interval_to(quantity!(2.0, m));
+    assert_eq!(band.lo(), quantity!(1.0, m));
+    assert_eq!(band.hi(), quantity!(2.0, m));
+
+    assert!(band.contains(&quantity!(1.5, m)));
+    // Endpoints are included
+    assert!(band.contains(&quantity!(1.0, m)));
+    assert!(band.contains(&quantity!(2.0, m)));
+    assert!(!band.contains(&quantity!(0.999, m)));
+    assert!(!band.contains(&quantity!(2.001, m)));
+    assert!(!band.contains(&quantity!(f64::NAN, m)));
+
+    // A degenerate interval contains only its single point
+    let point = Interval::new(quantity!(3, s, i32), quantity!(3, s, i32));
+    assert!(point.contains(&quantity!(3, s, i32)));
+    assert!(!point.contains(&quantity!(4, s, i32)));
+    assert_eq!(point.width(), quantity!(0, s, i32));
+}
+
+#[test]
+fn test_interval_clamp() {
+    let band = Interval::new(quantity!(-10.0, mm), quantity!(10.0, mm));
+
+    assert_eq!(band.clamp(quantity!(-25.0, mm)), quantity!(-10.0, mm));
+    assert_eq!(band.clamp(quantity!(25.0, mm)), quantity!(10.0, mm));
+    assert_eq!(band.clamp(quantity!(3.5, mm)), quantity!(3.5, mm));
+    assert_eq!(band.clamp(quantity!(10.0, mm)), quantity!(10.0, mm));
+    assert!(band.clamp(quantity!(f64::NAN, mm)).unsafe_value.is_nan());
+}
+
+#[test]
+fn test_inverted_interval_is_reordered() {
+    let inverted = quantity!(5.0, kg).interval_to(quantity!(2.0, kg));
+    assert_eq!(inverted.lo(), quantity!(2.0, kg));
+    assert_eq!(inverted.hi(), quantity!(5.0, kg));
+    assert_eq!(inverted, quantity!(2.0, kg).interval_to(quantity!(5.0, kg)));
+
+    // The width is never negative
+    assert_eq!(inverted.width(), quantity!(3.0, kg));
+    assert!(inverted.contains(&quantity!(3.0, kg)));
+    assert_eq!(inverted.clamp(quantity!(0.0, kg)), quantity!(2.0, kg));
+}
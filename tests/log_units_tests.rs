@@ -0,0 +1,79 @@
+//! Tests for the decibel conversions in `whippyunits::log_units`
+
+use whippyunits::log_units::{Decibel, amplitude_to_db, db_to_amplitude, db_to_power, power_to_db};
+use whippyunits::{quantity, unit};
+
+fn assert_approx(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {expected} ± {tolerance}, got {actual}"
+    );
+}
+
+#[test]
+fn test_ratio_of_100() {
+    let ratio = quantity!(100.0, 1);
+    assert_approx(power_to_db(ratio), 20.0, 1e-12);
+    assert_approx(amplitude_to_db(ratio), 40.0, 1e-12);
+
+    assert_approx(Decibel::from_power_ratio(ratio).value(), 20.0, 1e-12);
+    assert_approx(Decibel::from_amplitude_ratio(ratio).value(), 40.0, 1e-12);
+}
+
+#[test]
+fn test_inverse_conversions() {
+    let power: unit!(1) = db_to_power(20.0);
+    assert_approx(power.unsafe_value, 100.0, 1e-9);
+    let amplitude: unit!(1) = db_to_amplitude(40.0);
+    assert_approx(amplitude.unsafe_value, 100.0, 1e-9);
+
+    // The same decibel value is a different ratio of powers than of amplitudes
+    let gain = Decibel::new(20.0);
+    assert_approx(gain.to_power_ratio().unsafe_value, 100.0, 1e-9);
+    assert_approx(gain.to_amplitude_ratio().unsafe_value, 10.0, 1e-12);
+
+    // Round trips
+    for db in [-30.0, -3.0, 0.0, 6.0, 60.0] {
+        assert_approx(power_to_db(db_to_power(db)), db, 1e-9);
+        assert_approx(amplitude_to_db(db_to_amplitude(db)), db, 1e-9);
+    }
+}
+
+#[test]
+fn test_ratios_of_quantities() {
+    // Ratios of different scales are rescaled before taking the logarithm
+    assert_approx(
+        power_to_db(quantity!(1.0, W) / quantity!(1.0, mW)),
+        30.0,
+        1e-12,
+    );
+    assert_approx(
+        amplitude_to_db(quantity!(1.0, mV) / quantity!(1.0, V)),
+        -60.0,
+        1e-12,
+    );
+
+    // Halving the power is about -3 dB, halving the amplitude about -6 dB
+    let half = quantity!(0.5, 1);
+    assert_approx(power_to_db(half), -3.0103, 1e-4);
+    assert_approx(amplitude_to_db(half), -6.0206, 1e-4);
+
+    assert_eq!(power_to_db(quantity!(0.0, 1)), f64::NEG_INFINITY);
+    assert!(power_to_db(quantity!(-1.0, 1)).is_nan());
+}
+
+#[test]
+fn test_decibel_arithmetic() {
+    // Gains in a chain add in decibels, as their ratios multiply
+    let chain = Decibel::new(20.0) + Decibel::new(10.0) - Decibel::new(5.0);
+    assert_eq!(chain, Decibel::new(25.0));
+    assert_eq!(-chain, Decibel::new(-25.0));
+    assert!(Decibel::new(3.0) > Decibel::new(-3.0));
+
+    let ratio = Decibel::from_power_ratio(quantity!(10.0, 1))
+        + Decibel::from_power_ratio(quantity!(10.0, 1));
+    assert_approx(ratio.to_power_ratio().unsafe_value, 100.0, 1e-9);
+
+    assert_eq!(Decibel::new(-3.5).to_string(), "-3.5 dB");
+    assert_eq!(format!("{:.1}", Decibel::new(6.0206)), "6.0 dB");
+}
@@ -0,0 +1,29 @@
+//! Runtime unit parsing with whippyunits built without `std`.
+//!
+//! Run with `cargo test -p whippyunits --no-default-features --features serde --test no_std_tests`;
+//! CI also builds the same configuration for a target without `std`.
+#![no_std]
+#![cfg(all(feature = "serde", not(feature = "std")))]
+
+use whippyunits::{from_string, unit, value};
+
+#[test]
+fn test_from_string_compound_unit() {
+    let acceleration: unit!(m / s ^ 2) = from_string!("9.81 m/s2", m / s ^ 2).unwrap();
+    assert_eq!(value!(acceleration, m / s ^ 2), 9.81);
+
+    let acceleration: unit!(m / s ^ 2) = from_string!("9.81m/s^2", m / s ^ 2).unwrap();
+    assert_eq!(value!(acceleration, m / s ^ 2), 9.81);
+
+    // Source units are rescaled into the target unit
+    let acceleration: unit!(m / s ^ 2) = from_string!("981 cm/s2", m / s ^ 2).unwrap();
+    assert!((value!(acceleration, m / s ^ 2) - 9.81).abs() < 1e-12);
+    let acceleration: unit!(m / s ^ 2) = from_string!("36 km/(h*s)", m / s ^ 2).unwrap();
+    assert!((value!(acceleration, m / s ^ 2) - 10.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_from_string_compound_unit_errors() {
+    assert!(from_string!("9.81 m/s", m / s ^ 2).is_err());
+    assert!(from_string!("9.81 m/", m / s ^ 2).is_err());
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "std")]
+
+use whippyunits::preferences::{
+    DisplayConfigGuard, display_config_scope, preferred_display_config, unit_preference_scope,
+};
+use whippyunits::print::prettyprint::{DisplayConfig, NumberFormat};
+use whippyunits::quantity;
+use whippyunits_core::dimension_exponents::DimensionBasis::{Length, Time};
+
+#[test]
+fn test_preference_reverts_on_drop() {
+    let distance = quantity!(1500.0, m);
+    assert_eq!(distance.to_string(), "1500.0 Quantity<m, f64>");
+
+    let guard = unit_preference_scope(Length, "km");
+    assert_eq!(distance.to_string(), "1.5000 Quantity<km, f64>");
+    // Only the preferred dimension is affected
+    assert_eq!(quantity!(2.0, s).to_string(), "2.0000 Quantity<s, f64>");
+    // Debug still shows the storage unit
+    assert!(format!("{:?}", distance).contains("Quantity<meter"));
+
+    drop(guard);
+    assert_eq!(distance.to_string(), "1500.0 Quantity<m, f64>");
+}
+
+#[test]
+fn test_nested_preference_scopes() {
+    let distance = quantity!(1500.0, m);
+    {
+        let _outer = unit_preference_scope(Length, "km");
+        {
+            let _inner = unit_preference_scope(Length, "mm");
+            assert_eq!(distance.to_string(), "1500000 Quantity<mm, f64>");
+            {
+                let _time = unit_preference_scope(Time, "ms");
+                assert_eq!(distance.to_string(), "1500000 Quantity<mm, f64>");
+                assert_eq!(quantity!(2.0, s).to_string(), "2000.0 Quantity<ms, f64>");
+            }
+            assert_eq!(quantity!(2.0, s).to_string(), "2.0000 Quantity<s, f64>");
+        }
+        assert_eq!(distance.to_string(), "1.5000 Quantity<km, f64>");
+    }
+    assert_eq!(distance.to_string(), "1500.0 Quantity<m, f64>");
+}
+
+#[test]
+fn test_preference_scopes_dropped_out_of_order() {
+    let distance = quantity!(1500.0, m);
+    let outer = unit_preference_scope(Length, "km");
+    let inner = unit_preference_scope(Length, "mm");
+
+    // Dropping the outer scope first leaves the inner one in effect
+    drop(outer);
+    assert_eq!(distance.to_string(), "1500000 Quantity<mm, f64>");
+    drop(inner);
+    assert_eq!(distance.to_string(), "1500.0 Quantity<m, f64>");
+}
+
+#[test]
+fn test_preferences_are_thread_local() {
+    let _guard = unit_preference_scope(Length, "km");
+    let other_thread = std::thread::spawn(|| quantity!(1500.0, m).to_string())
+        .join()
+        .unwrap();
+    assert_eq!(other_thread, "1500.0 Quantity<m, f64>");
+    assert_eq!(quantity!(1500.0, m).to_string(), "1.5000 Quantity<km, f64>");
+}
+
+#[test]
+#[should_panic(expected = "does not have dimension")]
+fn test_preference_dimension_mismatch() {
+    let _guard = unit_preference_scope(Length, "s");
+}
+
+#[test]
+#[should_panic(expected = "nonstorage unit")]
+fn test_preference_nonstorage_unit() {
+    let _guard = unit_preference_scope(Length, "mi");
+}
+
+fn number_format_scope(number_format: NumberFormat) -> DisplayConfigGuard {
+    display_config_scope(DisplayConfig {
+        number_format,
+        ..preferred_display_config()
+    })
+}
+
+#[test]
+fn test_number_formats_at_decade_boundary() {
+    let distance = quantity!(999999.0, m);
+    assert_eq!(distance.to_string(), "1000000 Quantity<m, f64>");
+    {
+        let _guard = number_format_scope(NumberFormat::Scientific);
+        assert_eq!(distance.to_string(), "1.0000e6 Quantity<m, f64>");
+        assert_eq!(format!("{:.2}", distance), "1.00e6 Quantity<m, f64>");
+        assert_eq!(
+            quantity!(0.00012345, m).to_string(),
+            "1.2345e-4 Quantity<m, f64>"
+        );
+    }
+    {
+        let _guard = number_format_scope(NumberFormat::Engineering);
+        assert_eq!(distance.to_string(), "1.0000e6 Quantity<m, f64>");
+        assert_eq!(format!("{:.7}", distance), "999.9990000e3 Quantity<m, f64>");
+        assert_eq!(
+            quantity!(99999.0, m).to_string(),
+            "99.999e3 Quantity<m, f64>"
+        );
+        assert_eq!(
+            quantity!(-0.00012345, m).to_string(),
+            "-123.45e-6 Quantity<m, f64>"
+        );
+    }
+    {
+        let _guard = number_format_scope(NumberFormat::Decimal);
+        assert_eq!(format!("{:.1}", distance), "999999.0 Quantity<m, f64>");
+    }
+}
+
+#[test]
+fn test_number_format_scopes_nest_and_combine_with_units() {
+    let distance = quantity!(1500.0, m);
+    let engineering = number_format_scope(NumberFormat::Engineering);
+    {
+        let _scientific = number_format_scope(NumberFormat::Scientific);
+        let _unit = unit_preference_scope(Length, "mm");
+        assert_eq!(distance.to_string(), "1.5000e6 Quantity<mm, f64>");
+        // Width pads the whole value, exponent included
+        assert_eq!(format!("{:>10}", distance), "  1.5000e6 Quantity<mm, f64>");
+    }
+    assert_eq!(distance.to_string(), "1.5000e3 Quantity<m, f64>");
+    drop(engineering);
+    assert_eq!(distance.to_string(), "1500.0 Quantity<m, f64>");
+}
+
+#[test]
+fn test_display_config_marks_inexact_values() {
+    let inexact = quantity!(2.5, m);
+    let exact = quantity!(2.0, m);
+    assert_eq!(format!("{:.0}", inexact), "2 Quantity<m, f64>");
+
+    let marked = display_config_scope(DisplayConfig {
+        mark_inexact: true,
+        ..DisplayConfig::default()
+    });
+    assert_eq!(format!("{:.0}", inexact), "~2 Quantity<m, f64>");
+    assert_eq!(format!("{:.0}", exact), "2 Quantity<m, f64>");
+    assert_eq!(format!("{:.1}", inexact), "2.5 Quantity<m, f64>");
+    // The marker sits inside the padding
+    assert_eq!(format!("{:>5.0}", inexact), "   ~2 Quantity<m, f64>");
+    // The default 5 significant figures can round too
+    assert_eq!(
+        quantity!(1.234567, m).to_string(),
+        "~1.2346 Quantity<m, f64>"
+    );
+    {
+        let _scientific = number_format_scope(NumberFormat::Scientific);
+        assert_eq!(
+            quantity!(1500.0, m).to_string(),
+            "1.5000e3 Quantity<m, f64>"
+        );
+        assert_eq!(
+            format!("{:.1}", quantity!(1550.5, m)),
+            "~1.6e3 Quantity<m, f64>"
+        );
+    }
+    drop(marked);
+    assert_eq!(format!("{:.0}", inexact), "2 Quantity<m, f64>");
+}
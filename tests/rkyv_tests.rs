@@ -0,0 +1,72 @@
+//! Round-trip and validation tests for the `rkyv` feature
+
+#![cfg(feature = "rkyv")]
+
+use rkyv::rancor::Error;
+use rkyv::util::AlignedVec;
+use whippyunits::{quantity, unit, value};
+
+#[test]
+fn test_rkyv_round_trip() {
+    let length = quantity!(5.0, mm);
+    let bytes = rkyv::to_bytes::<Error>(&length).unwrap();
+    let restored = rkyv::from_bytes::<unit!(mm), Error>(&bytes).unwrap();
+    assert_eq!(restored, length);
+
+    let speed = quantity!(12, km / h, i32);
+    let bytes = rkyv::to_bytes::<Error>(&speed).unwrap();
+    let restored = rkyv::from_bytes::<unit!(km / h, i32), Error>(&bytes).unwrap();
+    assert_eq!(value!(restored, km / h, i32), 12);
+}
+
+#[test]
+fn test_rkyv_zero_copy_access() {
+    let force = quantity!(3.5, kN);
+    let bytes = rkyv::to_bytes::<Error>(&force).unwrap();
+    let archived = rkyv::access::<rkyv::Archived<unit!(kN)>, Error>(&bytes).unwrap();
+
+    assert_eq!(archived.value().to_native(), 3.5);
+    // kN = kg·m·s⁻² scaled by 10³
+    assert_eq!(
+        archived.exponents(),
+        [1, 1, -2, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0]
+    );
+}
+
+#[test]
+fn test_rkyv_rejects_other_units() {
+    let length = quantity!(5.0, m);
+    let bytes = rkyv::to_bytes::<Error>(&length).unwrap();
+
+    // Same dimension at a different scale
+    assert!(rkyv::access::<rkyv::Archived<unit!(mm)>, Error>(&bytes).is_err());
+    // Different dimension entirely
+    assert!(rkyv::access::<rkyv::Archived<unit!(s)>, Error>(&bytes).is_err());
+    assert!(rkyv::from_bytes::<unit!(s), Error>(&bytes).is_err());
+}
+
+#[test]
+fn test_rkyv_rejects_corrupt_exponents() {
+    let length = quantity!(5.0, m);
+    let bytes = rkyv::to_bytes::<Error>(&length).unwrap();
+
+    // The exponents lead the archived struct; the value follows them.
+    for index in 0..12 {
+        let mut corrupt = AlignedVec::<16>::new();
+        corrupt.extend_from_slice(&bytes);
+        corrupt[2 * index] ^= 0x01;
+
+        let result = rkyv::access::<rkyv::Archived<unit!(m)>, Error>(&corrupt);
+        assert!(result.is_err(), "corrupt exponent {index} was accepted");
+    }
+
+    let error = {
+        let mut corrupt = AlignedVec::<16>::new();
+        corrupt.extend_from_slice(&bytes);
+        corrupt[2] = 3;
+        rkyv::access::<rkyv::Archived<unit!(m)>, Error>(&corrupt)
+            .err()
+            .unwrap()
+    };
+    assert!(error.to_string().contains("do not match expected"));
+}
@@ -0,0 +1,694 @@
+//! Tests for the runtime unit lookup helpers in `whippyunits::serialization`
+
+use whippyunits::serialization::{SerializationError, env_input_from, unit_conversion_factors};
+
+fn assert_approx(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn test_unit_conversion_factors_storage_units() {
+    assert_eq!(unit_conversion_factors("m").unwrap(), (1.0, 0.0));
+    assert_eq!(unit_conversion_factors("km").unwrap(), (1000.0, 0.0));
+
+    let (factor, offset) = unit_conversion_factors("km/h").unwrap();
+    assert_approx(factor, 1000.0 / 3600.0);
+    assert_eq!(offset, 0.0);
+}
+
+#[test]
+fn test_unit_conversion_factors_affine_units() {
+    assert_eq!(unit_conversion_factors("degC").unwrap(), (1.0, 273.15));
+
+    let (factor, offset) = unit_conversion_factors("degF").unwrap();
+    assert_approx(factor, 5.0 / 9.0);
+    // The Rankine offset is 459.67 °F
+    assert_approx(offset, 459.67 * 5.0 / 9.0);
+
+    // 212 °F is the boiling point of water
+    assert_approx(212.0 * factor + offset, 373.15);
+}
+
+#[test]
+fn test_unit_conversion_factors_errors() {
+    assert!(matches!(
+        unit_conversion_factors("parsecs"),
+        Err(SerializationError::UnknownUnit(unit)) if unit == "parsecs"
+    ));
+    assert!(matches!(
+        unit_conversion_factors("m /"),
+        Err(SerializationError::ParseError(_))
+    ));
+}
+
+// The env macros are tested through their parsing halves, given the variable's value directly:
+// setting variables with `std::env::set_var` is unsound while other test threads are running.
+
+fn env_value(value: &str) -> Result<Option<String>, SerializationError> {
+    env_input_from("TEST_VARIABLE", Ok(value.to_string()))
+}
+
+fn env_unset() -> Result<Option<String>, SerializationError> {
+    env_input_from("TEST_VARIABLE", Err(std::env::VarError::NotPresent))
+}
+
+#[test]
+fn test_env_quantity_set() {
+    use whippyunits::{quantity_from_env_input, try_quantity_from_env_input, unit, value};
+
+    let speed: unit!(m / s) =
+        try_quantity_from_env_input!(env_value("50 km/h"), m / s, default = 10.0).unwrap();
+    assert!((value!(speed, m / s) - 50.0 / 3.6).abs() < 1e-12);
+
+    let speed = quantity_from_env_input!(env_value("50 km/h"), m / s, default = 10.0);
+    assert!((value!(speed, m / s) - 50.0 / 3.6).abs() < 1e-12);
+
+    let length = quantity_from_env_input!(env_value("1.5 m"), mm, f64, default = 0.0);
+    assert_eq!(value!(length, mm), 1500.0);
+}
+
+#[test]
+fn test_env_quantity_unset() {
+    use whippyunits::{quantity_from_env_input, try_quantity_from_env_input, unit, value};
+
+    let speed: unit!(m / s) =
+        try_quantity_from_env_input!(env_unset(), m / s, default = 10.0).unwrap();
+    assert_eq!(value!(speed, m / s), 10.0);
+
+    let speed = quantity_from_env_input!(env_unset(), km / h, default = 36.0);
+    assert_eq!(value!(speed, km / h), 36.0);
+}
+
+#[test]
+fn test_env_quantity_malformed() {
+    use whippyunits::{quantity_from_env_input, try_quantity_from_env_input, value};
+
+    for malformed in ["fast", "50 kg", ""] {
+        let strict = try_quantity_from_env_input!(env_value(malformed), m / s, default = 10.0);
+        assert!(strict.is_err(), "{malformed:?} was accepted");
+
+        let lenient = quantity_from_env_input!(env_value(malformed), m / s, default = 10.0);
+        assert_eq!(value!(lenient, m / s), 10.0);
+    }
+
+    assert!(matches!(
+        try_quantity_from_env_input!(env_value("50 kg"), m / s, default = 10.0),
+        Err(SerializationError::DimensionMismatch { .. })
+    ));
+
+    // A value that is not unicode is an error, not an unset variable
+    let not_unicode = env_input_from(
+        "TEST_VARIABLE",
+        Err(std::env::VarError::NotUnicode("\u{fffd}".into())),
+    );
+    assert!(matches!(
+        try_quantity_from_env_input!(not_unicode.clone(), m / s, default = 10.0),
+        Err(SerializationError::InvalidFormat(_))
+    ));
+    let lenient = quantity_from_env_input!(not_unicode, m / s, default = 10.0);
+    assert_eq!(value!(lenient, m / s), 10.0);
+}
+
+#[test]
+fn test_env_quantity_reads_the_environment() {
+    use whippyunits::{env_quantity, try_env_quantity, unit, value};
+
+    // Only reads, so this is safe alongside other tests; PATH is set in any test environment
+    assert!(try_env_quantity!("PATH", m, default = 1.0).is_err());
+    let length: unit!(m) = env_quantity!("PATH", m, default = 1.0);
+    assert_eq!(value!(length, m), 1.0);
+
+    let unset = "WHIPPYUNITS_TEST_NEVER_SET";
+    assert_eq!(
+        value!(try_env_quantity!(unset, m, default = 2.0).unwrap(), m),
+        2.0
+    );
+}
+
+#[test]
+fn test_skip_serializing_zero_quantities() {
+    use whippyunits::{Quantity, quantity, unit};
+
+    #[derive(serde::Serialize)]
+    struct Displacement {
+        #[serde(skip_serializing_if = "Quantity::is_zero_value")]
+        x: unit!(m),
+        #[serde(skip_serializing_if = "Quantity::is_zero_value")]
+        y: unit!(m),
+        #[serde(skip_serializing_if = "Quantity::is_zero_value")]
+        z: unit!(m),
+    }
+
+    let json = serde_json::to_value(Displacement {
+        x: quantity!(1.5, m),
+        y: quantity!(0.0, m),
+        z: quantity!(-0.0, m),
+    })
+    .unwrap();
+    assert!(json.get("x").is_some());
+    assert!(json.get("y").is_none());
+    assert!(json.get("z").is_none());
+
+    // Only exact zero is skipped
+    assert!(!quantity!(1e-300, m).is_zero_value());
+    assert!(!quantity!(f64::MIN_POSITIVE / 2.0, m).is_zero_value());
+    assert!(quantity!(0, m, i32).is_zero_value());
+}
+
+#[test]
+fn test_deserialize_bare_number_in_ambient_unit() {
+    use whippyunits::serialization::ambient;
+    use whippyunits::{quantity, unit};
+
+    #[derive(serde::Deserialize)]
+    struct Telemetry {
+        #[serde(deserialize_with = "ambient")]
+        altitude: unit!(m),
+        #[serde(deserialize_with = "ambient")]
+        gap: unit!(mm),
+        #[serde(deserialize_with = "ambient")]
+        elapsed: unit!(min),
+    }
+
+    let telemetry: Telemetry =
+        serde_json::from_str(r#"{"altitude": 1200.5, "gap": 3, "elapsed": -2}"#).unwrap();
+    assert_eq!(telemetry.altitude, quantity!(1200.5, m));
+    assert_eq!(telemetry.gap, quantity!(3.0, mm));
+    assert_eq!(telemetry.elapsed, quantity!(-2.0, min));
+
+    // The self-describing object form is not accepted in place of a bare number
+    let error = serde_json::from_str::<Telemetry>(
+        r#"{"altitude": {"value": 1.0, "unit": "km"}, "gap": 3, "elapsed": 2}"#,
+    )
+    .err()
+    .unwrap();
+    assert!(
+        error
+            .to_string()
+            .contains("a bare number in the field's unit"),
+        "{}",
+        error
+    );
+
+    // Neither are strings
+    assert!(
+        serde_json::from_str::<Telemetry>(r#"{"altitude": "1.0", "gap": 3, "elapsed": 2}"#)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_us_customary_and_imperial_units_differ() {
+    use whippyunits::{from_string, quantity, unit, value};
+
+    // Unqualified symbols are US customary; `imp_` symbols are British imperial
+    let us_gallon = quantity!(1.0, gal);
+    let imperial_gallon = quantity!(1.0, imp_gal);
+    assert_approx(value!(us_gallon, L), 3.785411784);
+    assert_approx(value!(imperial_gallon, L), 4.54609);
+    assert!(us_gallon < imperial_gallon);
+
+    assert_approx(value!(quantity!(1.0, qt), L), 0.946352946);
+    assert_approx(value!(quantity!(1.0, imp_qt), L), 1.1365225);
+    assert_approx(value!(quantity!(1.0, pt), L), 0.473176473);
+    assert_approx(value!(quantity!(1.0, imp_pt), L), 0.56826125);
+    assert_approx(value!(quantity!(1.0, fl_oz), mL), 29.5735295625);
+    assert_approx(value!(quantity!(1.0, imp_fl_oz), mL), 28.4130625);
+    assert_approx(value!(quantity!(1.0, ton), kg), 907.18474);
+    assert_approx(value!(quantity!(1.0, imp_ton), kg), 1016.0469088);
+
+    // `t` is the metric tonne, not either of the tons
+    assert_eq!(value!(quantity!(1.0, t), kg), 1000.0);
+    let parsed: unit!(kg) = from_string!("2.5 t", kg).unwrap();
+    assert_approx(value!(parsed, kg), 2500.0);
+
+    // The string parser applies the same conversion factors as the declarator
+    let parsed: unit!(L) = from_string!("2 gal", L).unwrap();
+    assert_approx(value!(parsed, L), value!(quantity!(2.0, gal), L));
+    let parsed: unit!(L) = from_string!("2 imp_gal", L).unwrap();
+    assert_approx(value!(parsed, L), value!(quantity!(2.0, imp_gal), L));
+    let parsed: unit!(kg) = from_string!("3 ton", kg).unwrap();
+    assert_approx(value!(parsed, kg), value!(quantity!(3.0, ton), kg));
+
+    // Round-tripping through the storage unit preserves the magnitude
+    let volume = quantity!(5.0, imp_gal);
+    let round_tripped: unit!(L) = from_string!(&format!("{} L", value!(volume, L)), L).unwrap();
+    assert_approx(value!(round_tripped, L), 5.0 * 4.54609);
+}
+
+#[test]
+fn test_to_debug_json() {
+    use whippyunits::quantity;
+
+    assert_eq!(
+        quantity!(5.0, m).to_debug_json(),
+        r#"{"value":5.0,"unit":"m","dimension":{"length":1}}"#
+    );
+
+    // Compound units list one entry per nonzero exponent, in base dimension order
+    assert_eq!(
+        quantity!(12.5, m / s).to_debug_json(),
+        r#"{"value":12.5,"unit":"(m·s⁻¹)","dimension":{"length":1,"time":-1}}"#
+    );
+    assert_eq!(
+        quantity!(3.0, kN).to_debug_json(),
+        r#"{"value":3.0,"unit":"kN","dimension":{"mass":1,"length":1,"time":-2}}"#
+    );
+
+    // The unit is the one the value is stored in
+    assert_eq!(
+        quantity!(2, km, i32).to_debug_json(),
+        r#"{"value":2.0,"unit":"km","dimension":{"length":1}}"#
+    );
+
+    // Dimensionless quantities have no dimension entries
+    assert_eq!(
+        quantity!(0.5, 1).to_debug_json(),
+        r#"{"value":0.5,"unit":"()","dimension":{}}"#
+    );
+}
+
+#[test]
+fn test_serialize_unnamed_dimensions() {
+    use whippyunits::serialization::to_ucum_unit;
+    use whippyunits::{quantity, unit};
+
+    // No named dimension has these exponents, so the unit is written as a product of base units
+    let exotic = quantity!(2.0, m ^ 5 / kg ^ 3);
+    let json = serde_json::to_string(&exotic).unwrap();
+    assert_eq!(json, r#"{"value":2.0,"unit":"m5/kg3"}"#);
+    let round_tripped: unit!(m ^ 5 / kg ^ 3) = serde_json::from_str(&json).unwrap();
+    assert_eq!(round_tripped, exotic);
+
+    assert_eq!(to_ucum_unit(&quantity!(1.0, m ^ 40)), "m40");
+    assert_eq!(to_ucum_unit(&quantity!(1.0, s ^ 40 / m ^ 12)), "s40/m12");
+    assert_eq!(to_ucum_unit(&quantity!(1.0, m / s)), "m/s");
+    assert_eq!(to_ucum_unit(&quantity!(1.0, 1 / s ^ 2)), "1/s2");
+    assert_eq!(to_ucum_unit(&quantity!(1.0, 1)), "1");
+}
+
+#[test]
+fn test_from_string_uncertain() {
+    use whippyunits::serialization::parse_uncertain_string_input;
+    use whippyunits::{from_string_uncertain, quantity, value};
+
+    let compact = from_string_uncertain!("5.0(2) m", m).unwrap();
+    assert_eq!(compact.value, quantity!(5.0, m));
+    assert_eq!(compact.uncertainty, quantity!(0.2, m));
+
+    let plus_minus = from_string_uncertain!("5.0 ± 0.2 m", m).unwrap();
+    assert_eq!(plus_minus, compact);
+    assert_eq!(from_string_uncertain!("5.0 +/- 0.2m", m).unwrap(), compact);
+
+    // In compact notation the digits count in the value's last decimal place
+    let parse = |s| parse_uncertain_string_input(s).unwrap();
+    assert_eq!(parse("1.234(56) kg"), (1.234, 0.056, "kg".to_string()));
+    assert_eq!(parse("12(3)s"), (12.0, 3.0, "s".to_string()));
+    assert_eq!(parse("6.67430(15) m"), (6.6743, 0.00015, "m".to_string()));
+
+    // Uncertainties scale with the unit but are not shifted by an offset
+    let temperature = from_string_uncertain!("20.0(5) degC", K).unwrap();
+    assert_approx(value!(temperature.value, K), 293.15);
+    assert_approx(value!(temperature.uncertainty, K), 0.5);
+    let length = from_string_uncertain!("5.0(2) km", m).unwrap();
+    assert_approx(value!(length.uncertainty, m), 200.0);
+}
+
+#[test]
+fn test_from_string_uncertain_errors() {
+    use whippyunits::from_string_uncertain;
+    use whippyunits::serialization::SerializationError;
+
+    let invalid = |result| matches!(result, Err(SerializationError::InvalidFormat(_)));
+    assert!(invalid(from_string_uncertain!("5.0 m", m)));
+    assert!(invalid(from_string_uncertain!("5.0(2 m", m)));
+    assert!(invalid(from_string_uncertain!("5.0(0.2) m", m)));
+    assert!(invalid(from_string_uncertain!("5.0(2)", m)));
+    assert!(invalid(from_string_uncertain!("5.0 ± -0.2 m", m)));
+    assert!(matches!(
+        from_string_uncertain!("5.0(2) s", m),
+        Err(SerializationError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_deserialize_stringified_value() {
+    use whippyunits::{from_json, unit};
+
+    let numeric: unit!(km) = serde_json::from_str(r#"{"value": 5.0, "unit": "m"}"#).unwrap();
+    let stringified: unit!(km) = serde_json::from_str(r#"{"value": "5.0", "unit": "m"}"#).unwrap();
+    assert_eq!(stringified, numeric);
+
+    let numeric: unit!(km) = from_json!(r#"{"value": 5.0, "unit": "m"}"#, km).unwrap();
+    let stringified: unit!(km) = from_json!(r#"{"value": "5.0", "unit": "m"}"#, km).unwrap();
+    assert_eq!(stringified, numeric);
+
+    assert!(matches!(
+        from_json!(r#"{"value": "five", "unit": "m"}"#, m),
+        Err(SerializationError::ParseError(_))
+    ));
+    assert!(serde_json::from_str::<unit!(m)>(r#"{"value": "five", "unit": "m"}"#).is_err());
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_from_yaml() {
+    use whippyunits::{from_json, from_yaml, unit, value};
+
+    let acceleration: unit!(m / s2) = from_yaml!("value: 9.81\nunit: m/s2", m / s2).unwrap();
+    assert_eq!(value!(acceleration, m / s2), 9.81);
+
+    let from_yaml: unit!(km) = from_yaml!("value: 5\nunit: m", km).unwrap();
+    let from_json: unit!(km) = from_json!(r#"{"value": 5, "unit": "m"}"#, km).unwrap();
+    assert_eq!(from_yaml, from_json);
+
+    assert!(matches!(
+        from_yaml!("value: 5.0\nunit: m", kg),
+        Err(SerializationError::DimensionMismatch { .. })
+    ));
+    assert!(matches!(
+        from_yaml!("unit: m", m),
+        Err(SerializationError::InvalidFormat(_))
+    ));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_from_toml() {
+    use whippyunits::{from_json, from_toml, unit, value};
+
+    let acceleration: unit!(m / s2) = from_toml!("value = 9.81\nunit = \"m/s2\"", m / s2).unwrap();
+    assert_eq!(value!(acceleration, m / s2), 9.81);
+
+    let from_toml: unit!(km) = from_toml!("value = 5\nunit = \"m\"", km).unwrap();
+    let from_json: unit!(km) = from_json!(r#"{"value": 5, "unit": "m"}"#, km).unwrap();
+    assert_eq!(from_toml, from_json);
+
+    assert!(matches!(
+        from_toml!("value = 5.0\nunit = \"m\"", kg),
+        Err(SerializationError::DimensionMismatch { .. })
+    ));
+    assert!(matches!(
+        from_toml!("value = \"five\"\nunit = \"m\"", m),
+        Err(SerializationError::ParseError(_))
+    ));
+}
+
+#[test]
+fn test_quantity_repr_round_trip() {
+    use whippyunits::serialization::QuantityRepr;
+    use whippyunits::{quantity, unit, value};
+
+    // The repr keeps the stored unit where it has a symbol
+    let repr = quantity!(5.0, km).to_repr();
+    assert_eq!(
+        repr,
+        QuantityRepr {
+            value: 5.0,
+            unit: "km".to_string()
+        }
+    );
+    assert_eq!(
+        <unit!(km)>::try_from_repr(&repr).unwrap(),
+        quantity!(5.0, km)
+    );
+    assert_eq!(quantity!(250.0, ms).to_repr().unit, "ms");
+    assert_eq!(quantity!(3.0, m / s).to_repr().unit, "m/s");
+
+    // Stored units without a symbol are written in the coherent unit
+    let repr = quantity!(36.0, km / h).to_repr();
+    assert_eq!(repr.unit, "m/s");
+    assert!((repr.value - 10.0).abs() < 1e-12);
+    let speed = <unit!(km / h)>::try_from_repr(&repr).unwrap();
+    assert!((value!(speed, km / h) - 36.0).abs() < 1e-12);
+
+    // Any unit of the right dimension converts
+    let repr = QuantityRepr {
+        value: 1.5,
+        unit: "km".to_string(),
+    };
+    assert_eq!(
+        <unit!(m)>::try_from_repr(&repr).unwrap(),
+        quantity!(1500.0, m)
+    );
+    let repr = QuantityRepr {
+        value: 1.5,
+        unit: "s".to_string(),
+    };
+    assert!(matches!(
+        <unit!(m)>::try_from_repr(&repr),
+        Err(SerializationError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_quantity_repr_nests_in_serde_structures() {
+    use serde::{Deserialize, Serialize};
+    use whippyunits::serialization::QuantityRepr;
+    use whippyunits::{quantity, unit};
+
+    #[derive(Serialize, Deserialize)]
+    struct Leg {
+        name: String,
+        #[serde(flatten)]
+        distance: QuantityRepr,
+    }
+
+    let leg = Leg {
+        name: "climb".to_string(),
+        distance: quantity!(2.5, km).to_repr(),
+    };
+    let json = serde_json::to_string(&leg).unwrap();
+    assert_eq!(json, r#"{"name":"climb","value":2.5,"unit":"km"}"#);
+
+    let leg: Leg = serde_json::from_str(&json).unwrap();
+    let distance = <unit!(m)>::try_from_repr(&leg.distance).unwrap();
+    assert_eq!(distance, quantity!(2500.0, m));
+}
+
+#[test]
+fn test_nautical_and_survey_units() {
+    use whippyunits::{from_string, quantity, unit, value};
+
+    assert_eq!(value!(quantity!(1.0, nmi), m), 1852.0);
+    assert_approx(value!(quantity!(1.0, kn), m / s), 1852.0 / 3600.0);
+    assert_approx(value!(quantity!(1.0, kn), km / h), 1.852);
+    assert_approx(value!(quantity!(1.0, ftm), m), 1.8288);
+    assert_approx(value!(quantity!(1.0, survey_ft), m), 1200.0 / 3937.0);
+
+    // A knot is a nautical mile per hour
+    let distance: unit!(km) = quantity!(3.0, kn) * quantity!(2.0, h);
+    assert_approx(value!(distance, nmi), 6.0);
+
+    // The survey foot is two parts per million longer than the international foot, which
+    // adds up to about a meter over the extent of a state plane coordinate zone
+    let international = value!(quantity!(1.0, ft), m);
+    let survey = value!(quantity!(1.0, survey_ft), m);
+    assert!(survey > international);
+    assert_approx(survey / international, 1.000002);
+    let span = 1_640_000.0;
+    let difference = value!(quantity!(span, survey_ft), m) - value!(quantity!(span, ft), m);
+    assert!(
+        (difference - 1.0).abs() < 0.01,
+        "difference was {} m",
+        difference
+    );
+
+    // The string parser applies the same conversion factors as the declarator
+    let parsed: unit!(m) = from_string!("2 nmi", m).unwrap();
+    assert_approx(value!(parsed, m), 3704.0);
+    let parsed: unit!(m / s) = from_string!("10 kn", m / s).unwrap();
+    assert_approx(value!(parsed, m / s), value!(quantity!(10.0, kn), m / s));
+    let parsed: unit!(m) = from_string!("1000 survey_ft", m).unwrap();
+    assert_approx(value!(parsed, m), 1_200_000.0 / 3937.0);
+    let parsed: unit!(m) = from_string!("2 ftm", m).unwrap();
+    assert_approx(value!(parsed, m), 3.6576);
+}
+
+#[test]
+fn test_whitespace_separated_units_multiply() {
+    use whippyunits::serialization::parse_string_input;
+    use whippyunits::{from_string, quantity, unit, value};
+
+    // In the macros, as in `kg * m / s^2`
+    let force: unit!(kg m / s ^ 2) = quantity!(2.0, N);
+    assert_eq!(force, quantity!(2.0, kg * m / s ^ 2));
+    assert_eq!(quantity!(3.0, N m), quantity!(3.0, N * m));
+    assert_approx(value!(quantity!(1.0, kW h), J), 3.6e6);
+
+    // The first whitespace separates the value from the unit; the rest belongs to the unit
+    assert_eq!(
+        parse_string_input("9.81 kg m / s^2").unwrap(),
+        (9.81, "kg m / s^2".to_string())
+    );
+    assert_eq!(
+        parse_string_input("9.81kg m/s^2").unwrap(),
+        (9.81, "kg m/s^2".to_string())
+    );
+    let parsed: unit!(N) = from_string!("9.81 kg m / s^2", N).unwrap();
+    assert_approx(value!(parsed, N), 9.81);
+    let parsed: unit!(N) = from_string!("9.81kg m/s^2", N).unwrap();
+    assert_approx(value!(parsed, N), 9.81);
+
+    // `m s` is a meter-second, and a doubled space changes nothing
+    let parsed: unit!(m * s) = from_string!("2 m s", m * s).unwrap();
+    assert_eq!(parsed, quantity!(2.0, m * s));
+    let parsed: unit!(m * s) = from_string!("2 m  s", m * s).unwrap();
+    assert_eq!(parsed, quantity!(2.0, m * s));
+    assert!(matches!(
+        from_string!("2 m s", ms),
+        Err(SerializationError::DimensionMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_expect_unit_or_default() {
+    use whippyunits::{Quantity, from_json, from_string, quantity, unit};
+
+    let mut errors = Vec::new();
+    let default = quantity!(30.0, s);
+
+    // A matching unit is used as is, without reporting anything
+    let timeout: unit!(s) =
+        Quantity::expect_unit_or_default(from_string!("45 s", s), default, |error| {
+            errors.push(error.clone())
+        });
+    assert_eq!(timeout, quantity!(45.0, s));
+    assert!(errors.is_empty());
+
+    // A unit of the same dimension but another scale is rescaled, not a mismatch
+    let timeout: unit!(s) =
+        Quantity::expect_unit_or_default(from_string!("2 min", s), default, |error| {
+            errors.push(error.clone())
+        });
+    assert_eq!(timeout, quantity!(120.0, s));
+    let timeout: unit!(s) = Quantity::expect_unit_or_default(
+        from_json!(r#"{"value": 1500, "unit": "ms"}"#, s),
+        default,
+        |error| errors.push(error.clone()),
+    );
+    assert_eq!(timeout, quantity!(1.5, s));
+    assert!(errors.is_empty());
+
+    // A unit of another dimension falls back to the default, reporting the mismatch
+    let timeout: unit!(s) =
+        Quantity::expect_unit_or_default(from_string!("45 m", s), default, |error| {
+            errors.push(error.clone())
+        });
+    assert_eq!(timeout, default);
+    assert!(matches!(
+        errors.as_slice(),
+        [SerializationError::DimensionMismatch { .. }]
+    ));
+
+    // As does a value that does not parse
+    let timeout: unit!(s) =
+        Quantity::expect_unit_or_default(from_string!("soon", s), default, |error| {
+            errors.push(error.clone())
+        });
+    assert_eq!(timeout, default);
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn test_serialize_as_base_number() {
+    use whippyunits::quantity::{_2, _L, _M, Dimension, Quantity, Scale};
+    use whippyunits::serialization::{deserialize_from_base_number, serialize_as_base_number};
+    use whippyunits::{quantity, unit};
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Route {
+        #[serde(
+            serialize_with = "serialize_as_base_number",
+            deserialize_with = "deserialize_from_base_number"
+        )]
+        distance: unit!(km),
+        #[serde(
+            serialize_with = "serialize_as_base_number",
+            deserialize_with = "deserialize_from_base_number"
+        )]
+        duration: unit!(min),
+        #[serde(
+            serialize_with = "serialize_as_base_number",
+            deserialize_with = "deserialize_from_base_number"
+        )]
+        speed: unit!(m / s),
+    }
+
+    // Each field is written as a bare number in base units: meters, seconds, meters per second
+    let route = Route {
+        distance: quantity!(2.5, km),
+        duration: quantity!(3.0, min),
+        speed: quantity!(12.0, m / s),
+    };
+    let json = serde_json::to_string(&route).unwrap();
+    assert_eq!(json, r#"{"distance":2500.0,"duration":180.0,"speed":12.0}"#);
+
+    // Bare numbers are read back as base units, whatever the field's scale
+    let parsed: Route = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, route);
+    let parsed: Route =
+        serde_json::from_str(r#"{"distance": 42195, "duration": 60, "speed": -1}"#).unwrap();
+    assert_eq!(parsed.distance, quantity!(42.195, km));
+    assert_eq!(parsed.duration, quantity!(1.0, min));
+    assert_eq!(parsed.speed, quantity!(-1.0, m / s));
+
+    // Only bare numbers are accepted
+    let error = serde_json::from_str::<Route>(
+        r#"{"distance": {"value": 1.0, "unit": "km"}, "duration": 60, "speed": 1}"#,
+    )
+    .err()
+    .unwrap();
+    assert!(
+        error.to_string().contains("a bare number in base units"),
+        "{}",
+        error
+    );
+
+    // A scale beyond the scale factor tables has no base-unit number
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Huge {
+        #[serde(
+            serialize_with = "serialize_as_base_number",
+            deserialize_with = "deserialize_from_base_number"
+        )]
+        length: Quantity<Scale<_2<101>>, Dimension<_M<0>, _L<1>>, f64, ()>,
+    }
+    let huge = Huge {
+        length: Quantity::new(1.0),
+    };
+    let error = serde_json::to_string(&huge).err().unwrap();
+    assert!(error.to_string().contains("not representable"), "{}", error);
+    let error = serde_json::from_str::<Huge>(r#"{"length": 1.0}"#)
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("not representable"), "{}", error);
+
+    // Values that overflow on conversion are an error, not an infinity
+    #[derive(serde::Serialize)]
+    struct Far {
+        #[serde(serialize_with = "serialize_as_base_number")]
+        distance: unit!(Gm),
+    }
+    let far = Far {
+        distance: quantity!(f64::MAX, Gm),
+    };
+    assert!(serde_json::to_string(&far).is_err());
+}
+
+#[test]
+fn test_quantity_round_trips_through_non_self_describing_format() {
+    use whippyunits::{quantity, unit};
+
+    let distance = quantity!(1.5, m);
+    let bytes = bincode::serialize(&distance).unwrap();
+    let round_tripped: unit!(m) = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(round_tripped, distance);
+
+    // Decoding into another unit of the same dimension rescales as for JSON
+    let in_millimeters: unit!(mm) = bincode::deserialize(&bytes).unwrap();
+    assert_approx(in_millimeters.unsafe_value, 1500.0);
+}
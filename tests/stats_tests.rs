@@ -0,0 +1,153 @@
+use whippyunits::stats::{
+    HarmonicMeanError, QuantityAccumulator, WeightedMeanError, harmonic_mean, weighted_mean,
+};
+use whippyunits::{quantity, unit, value};
+
+#[test]
+fn test_accumulator_rescales_mixed_units() {
+    let mut lengths = QuantityAccumulator::<unit!(m)>::new();
+    assert_eq!(lengths.count(), 0);
+    assert_eq!(lengths.mean(), None);
+    assert_eq!(lengths.sum(), quantity!(0.0, m));
+
+    lengths.push(quantity!(2.0, km));
+    lengths.push(quantity!(500.0, m));
+    lengths.push(quantity!(50_000.0, cm));
+    lengths.push(quantity!(1_000_000.0, mm));
+
+    assert_eq!(lengths.count(), 4);
+    assert_eq!(lengths.sum(), quantity!(4000.0, m));
+    assert_eq!(lengths.mean(), Some(quantity!(1000.0, m)));
+}
+
+#[test]
+fn test_accumulator_keeps_its_own_scale() {
+    // Accumulating in minutes, pushing seconds and hours
+    let mut durations = QuantityAccumulator::<unit!(min)>::new();
+    durations.push(quantity!(90.0, s));
+    durations.push(quantity!(0.05, h));
+
+    let mean: unit!(min) = durations.mean().unwrap();
+    assert!((value!(mean, min) - 2.25).abs() < 1e-12);
+    assert!((value!(durations.sum(), min) - 4.5).abs() < 1e-12);
+}
+
+#[test]
+fn test_accumulator_mean_is_stable() {
+    // A large offset with small variations: the mean should not drift
+    let mut readings = QuantityAccumulator::<unit!(m)>::new();
+    for i in 0..1_000_000 {
+        let offset = if i % 2 == 0 { 0.001 } else { -0.001 };
+        readings.push(quantity!(1e9 + offset, m));
+    }
+    let mean = value!(readings.mean().unwrap(), m);
+    assert!((mean - 1e9).abs() < 1e-6, "mean drifted to {}", mean);
+}
+
+#[test]
+fn test_accumulator_integer_storage() {
+    let mut lengths = QuantityAccumulator::<unit!(mm, i32)>::new();
+    lengths.push(quantity!(1, m, i32));
+    lengths.push(quantity!(250, mm, i32));
+    lengths.push(quantity!(3, cm, i32));
+
+    assert_eq!(lengths.sum(), quantity!(1280, mm, i32));
+    // 1280 / 3 = 426.67, truncated
+    assert_eq!(lengths.mean(), Some(quantity!(426, mm, i32)));
+}
+
+#[test]
+fn test_harmonic_mean_of_speeds() {
+    // Equal distances at 30, 60, and 90 km/h: 3 / (1/30 + 1/60 + 1/90) = 540/11 km/h
+    let speeds = [
+        quantity!(30.0, km / h),
+        quantity!(60.0, km / h),
+        quantity!(90.0, km / h),
+    ];
+    let average = harmonic_mean(&speeds).unwrap();
+    assert!((value!(average, km / h) - 540.0 / 11.0).abs() < 1e-12);
+
+    // The mean of equal rates is that rate, in the same unit
+    let same = [quantity!(12.5, m / s); 4];
+    assert_eq!(harmonic_mean(&same), Ok(quantity!(12.5, m / s)));
+}
+
+#[test]
+fn test_harmonic_mean_errors() {
+    let stalled = [quantity!(10.0, m / s), quantity!(0.0, m / s)];
+    assert_eq!(harmonic_mean(&stalled[..0]), Err(HarmonicMeanError::Empty));
+
+    let error = harmonic_mean(&stalled).unwrap_err();
+    assert_eq!(error, HarmonicMeanError::Zero { index: 1 });
+    assert!(error.to_string().contains("quantity 1 is zero"));
+
+    // 1/2 + 1/-2 = 0, so the mean would be infinite
+    let opposed = [quantity!(2.0, m / s), quantity!(-2.0, m / s)];
+    assert_eq!(
+        harmonic_mean(&opposed),
+        Err(HarmonicMeanError::ZeroReciprocalSum)
+    );
+}
+
+#[test]
+fn test_harmonic_mean_integer_storage() {
+    // 2 / (1/3 + 1/6) = 4 exactly
+    let rates = [quantity!(3, Hz, i32), quantity!(6, Hz, i32)];
+    assert_eq!(harmonic_mean(&rates), Ok(quantity!(4, Hz, i32)));
+}
+
+#[test]
+fn test_weighted_mean_of_readings() {
+    // (1·293 + 2·296 + 1·298) / 4 = 295.75
+    let temperatures = [
+        quantity!(293.0, K),
+        quantity!(296.0, K),
+        quantity!(298.0, K),
+    ];
+    let fused = weighted_mean(&temperatures, &[1.0, 2.0, 1.0]).unwrap();
+    assert!((value!(fused, K) - 295.75).abs() < 1e-12);
+
+    // Weights are normalized, so scaling them all changes nothing
+    let rescaled = weighted_mean(&temperatures, &[0.25, 0.5, 0.25]).unwrap();
+    assert!((value!(rescaled, K) - 295.75).abs() < 1e-12);
+
+    // The result stays in the inputs' unit
+    let legs = [quantity!(1.5, km), quantity!(3.0, km)];
+    let mean: unit!(km) = weighted_mean(&legs, &[2.0, 1.0]).unwrap();
+    assert_eq!(mean, quantity!(2.0, km));
+    assert_eq!(value!(mean, m), 2000.0);
+}
+
+#[test]
+fn test_weighted_mean_errors() {
+    let readings = [quantity!(1.0, s), quantity!(2.0, s)];
+
+    let error = weighted_mean(&readings, &[1.0]).unwrap_err();
+    assert_eq!(
+        error,
+        WeightedMeanError::LengthMismatch {
+            values: 2,
+            weights: 1
+        }
+    );
+    assert!(error.to_string().contains("2 values with 1 weights"));
+
+    assert_eq!(
+        weighted_mean(&readings, &[1.0, -1.0]),
+        Err(WeightedMeanError::ZeroTotalWeight)
+    );
+    assert_eq!(
+        weighted_mean(&readings[..0], &[]),
+        Err(WeightedMeanError::ZeroTotalWeight)
+    );
+}
+
+#[test]
+fn test_weighted_mean_integer_storage() {
+    // (3·10 + 1·20) / 4 = 12.5, truncated to 12
+    let counts = [quantity!(10, mol, i32), quantity!(20, mol, i32)];
+    assert_eq!(
+        weighted_mean(&counts, &[3.0, 1.0]),
+        Ok(quantity!(12, mol, i32))
+    );
+}
@@ -55,3 +55,447 @@ fn test_format_method() {
     println!("Debug format: {:?}", distance);
     println!("{:?}", quantity!(1.0, rot / min));
 }
+
+#[test]
+fn test_to_string_sigfigs() {
+    // Values below 1 keep the leading zeros, which are not significant
+    assert_eq!(quantity!(0.001234, m).to_string_sigfigs(3), "0.00123 m");
+
+    // Large values switch to scientific notation rather than padding with zeros
+    assert_eq!(quantity!(12340.0, m).to_string_sigfigs(3), "1.23e4 m");
+
+    // Trailing zeros are significant and preserved
+    assert_eq!(quantity!(1.2, km).to_string_sigfigs(3), "1.20 km");
+    assert_eq!(quantity!(100.0, s).to_string_sigfigs(3), "100 s");
+
+    // Rounding that carries into the next decade adjusts the exponent
+    assert_eq!(quantity!(9.996, m).to_string_sigfigs(3), "10.0 m");
+    assert_eq!(quantity!(999.6, m).to_string_sigfigs(3), "1.00e3 m");
+
+    // Very small values and negative values
+    assert_eq!(quantity!(0.00001234, m).to_string_sigfigs(2), "1.2e-5 m");
+    assert_eq!(quantity!(-0.001234, m).to_string_sigfigs(3), "-0.00123 m");
+
+    // Integer storage
+    assert_eq!(quantity!(12345, mm, i32).to_string_sigfigs(2), "1.2e4 mm");
+}
+
+#[test]
+fn test_compatible_units() {
+    let contains = |units: &[String], symbol: &str| units.iter().any(|unit| unit == symbol);
+
+    // Length lists the SI unit, its prefixed forms, and the non-SI units
+    let length = quantity!(1.0, km).compatible_units();
+    for symbol in ["m", "mm", "km", "in", "ft", "yd", "mi"] {
+        assert!(contains(&length, symbol), "missing {symbol} in {length:?}");
+    }
+    assert_eq!(length[0], "m");
+    assert!(!contains(&length, "s"));
+
+    // Energy matches several named derived units, including the N·m form
+    let energy = quantity!(1.0, J).compatible_units();
+    for symbol in ["J", "Nm", "eV", "cal", "kWh"] {
+        assert!(contains(&energy, symbol), "missing {symbol} in {energy:?}");
+    }
+    assert!(!contains(&energy, "m"));
+    let mut deduped = energy.clone();
+    deduped.sort();
+    deduped.dedup();
+    assert_eq!(deduped.len(), energy.len());
+
+    // Velocity's only named unit is the knot
+    assert_eq!(quantity!(1.0, m / s).compatible_units(), ["kn"]);
+
+    // Unregistered compound dimensions have no named units
+    assert!(quantity!(1.0, m / s ^ 2).compatible_units().is_empty());
+}
+
+#[test]
+fn test_format_aligned_columns() {
+    let rows = [
+        format!("{:.2}", quantity!(0.5, m).format_aligned(9, 3)),
+        format!("{:.2}", quantity!(12.5, km).format_aligned(9, 3)),
+        format!("{:.2}", quantity!(-1234.0, mm).format_aligned(9, 3)),
+        format!("{:.2}", quantity!(98765.4321, m).format_aligned(9, 3)),
+    ];
+    assert_eq!(rows[0], "     0.50 m  ");
+    assert_eq!(rows[1], "    12.50 km ");
+    assert_eq!(rows[2], " -1234.00 mm ");
+    assert_eq!(rows[3], " 98765.43 m  ");
+
+    // Every row lines up: same total width, units starting in the same column
+    assert!(rows.iter().all(|row| row.chars().count() == 13));
+    assert!(rows.iter().all(|row| row.chars().nth(9) == Some(' ')));
+}
+
+#[test]
+fn test_format_aligned_overflow() {
+    // Values wider than their column are not truncated
+    assert_eq!(
+        format!("{:.1}", quantity!(123456.0, m).format_aligned(4, 2)),
+        "123456.0 m "
+    );
+    // Neither are units wider than their column
+    assert_eq!(
+        format!("{:.1}", quantity!(2.0, m / s).format_aligned(4, 1)),
+        " 2.0 (m·s⁻¹)"
+    );
+    // Dimensionless quantities are labelled "()"
+    assert_eq!(
+        format!("{}", quantity!(3.0, 1).format_aligned(2, 3)),
+        " 3 () "
+    );
+}
+
+#[test]
+fn test_display_precision_and_width() {
+    let distance = quantity!(1.23456, m);
+    assert_eq!(format!("{}", distance), "1.2346 Quantity<m, f64>");
+    assert_eq!(format!("{:.2}", distance), "1.23 Quantity<m, f64>");
+    assert_eq!(format!("{:.0}", distance), "1 Quantity<m, f64>");
+    assert_eq!(
+        format!("{:.4}", quantity!(-2.5, km)),
+        "-2.5000 Quantity<km, f64>"
+    );
+
+    // Width, fill, and alignment pad the value only, never the unit
+    assert_eq!(format!("{:8.2}", distance), "    1.23 Quantity<m, f64>");
+    assert_eq!(format!("{:<8.2}", distance), "1.23     Quantity<m, f64>");
+    assert_eq!(format!("{:*^8.1}", distance), "**1.2*** Quantity<m, f64>");
+    assert_eq!(format!("{:_>10}", distance), "____1.2346 Quantity<m, f64>");
+
+    // A value wider than the width is not truncated
+    assert_eq!(
+        format!("{:3.3}", quantity!(1234.5, mm)),
+        "1234.500 Quantity<mm, f64>"
+    );
+}
+
+#[test]
+fn test_simplified_name() {
+    // kg·m²·s⁻²·A⁻² is the henry, regardless of scale
+    assert_eq!(
+        quantity!(1.0, kg * m ^ 2 / (s ^ 2 * A ^ 2)).simplified_name(),
+        Some("henry")
+    );
+    assert_eq!(quantity!(1.0, mH).simplified_name(), Some("henry"));
+    assert_eq!(quantity!(1.0, V * s / A).simplified_name(), Some("henry"));
+
+    // Energy has both the joule and the newton-meter; the joule is primary
+    assert_eq!(quantity!(1.0, N * m).simplified_name(), Some("joule"));
+    assert_eq!(quantity!(1.0, kW).simplified_name(), Some("watt"));
+
+    // No named unit, or not a derived unit
+    assert_eq!(quantity!(1.0, m / s).simplified_name(), None);
+    assert_eq!(quantity!(1.0, kg / m ^ 3).simplified_name(), None);
+    assert_eq!(quantity!(1.0, m).simplified_name(), None);
+}
+
+#[test]
+fn test_explain_conversion() {
+    // Same dimension, different scale: the factor to the target unit
+    assert_eq!(quantity!(1.5, km).explain_conversion("km"), Ok(1.0));
+    assert_eq!(quantity!(1.5, km).explain_conversion("m"), Ok(1000.0));
+    assert_eq!(quantity!(1.0, s).explain_conversion("ms"), Ok(1000.0));
+    let to_feet = quantity!(1.0, m).explain_conversion("ft").unwrap();
+    assert!((to_feet - 1.0 / 0.3048).abs() < 1e-12);
+    let to_kmh = quantity!(1.0, m / s).explain_conversion("km/h").unwrap();
+    assert!((to_kmh - 3.6).abs() < 1e-12);
+
+    // Different dimension: an explanation naming both dimensions
+    assert_eq!(
+        quantity!(1.5, km).explain_conversion("kg"),
+        Err("cannot convert length to mass".to_string())
+    );
+    assert_eq!(
+        quantity!(2.0, J).explain_conversion("W"),
+        Err("cannot convert energy to power".to_string())
+    );
+    assert_eq!(
+        quantity!(1.0, m / s).explain_conversion("s"),
+        Err("cannot convert velocity to time".to_string())
+    );
+    assert_eq!(
+        quantity!(1.0, m / s ^ 2).explain_conversion("s"),
+        Err("cannot convert m·s⁻² quantities to time".to_string())
+    );
+
+    // Targets that cannot be reached by a factor
+    assert!(quantity!(1.0, m).explain_conversion("furlongs").is_err());
+    assert!(quantity!(1.0, m).explain_conversion("m /").is_err());
+    assert!(quantity!(300.0, K).explain_conversion("degC").is_err());
+}
+
+#[test]
+fn test_explain() {
+    // A named derived dimension, with its coherent unit
+    assert_eq!(
+        quantity!(1.0, kg * m ^ 2 / s ^ 3).explain(),
+        "mass × length² ÷ time³ = power (W)"
+    );
+    assert_eq!(
+        quantity!(3.0, kW).explain(),
+        "mass × length² ÷ time³ = power (W)"
+    );
+    assert_eq!(
+        quantity!(1.0, V * s / A).explain(),
+        "mass × length² ÷ time² ÷ current² = inductance (H)"
+    );
+
+    // Base dimensions, and named dimensions without a coherent named unit
+    assert_eq!(quantity!(1.0, km).explain(), "length = length (m)");
+    assert_eq!(quantity!(1.0, kg).explain(), "mass = mass (kg)");
+    assert_eq!(quantity!(250.0, g).explain(), "mass = mass (kg)");
+    assert_eq!(quantity!(1.0, Hz).explain(), "1 ÷ time = frequency (Hz)");
+    assert_eq!(quantity!(1.0, m / s).explain(), "length ÷ time = velocity");
+
+    // Unnamed compounds
+    assert_eq!(
+        quantity!(1.0, m / s ^ 2).explain(),
+        "length ÷ time² = no standard name"
+    );
+    assert_eq!(
+        quantity!(1.0, kg * K / mol).explain(),
+        "mass × temperature ÷ amount = no standard name"
+    );
+
+    assert_eq!(
+        (quantity!(1.0, m) / quantity!(1.0, km)).explain(),
+        "dimensionless"
+    );
+}
+
+#[test]
+fn test_magnitude_in_prefix() {
+    // Length across magnitudes, from any storage scale
+    assert_eq!(quantity!(5.0, nm).magnitude_in_prefix(), (5.0, "n"));
+    assert_eq!(quantity!(0.25, mm).magnitude_in_prefix(), (250.0, "µ"));
+    assert_eq!(quantity!(1.0, mm).magnitude_in_prefix(), (1.0, "m"));
+    assert_eq!(quantity!(12.0, m).magnitude_in_prefix(), (12.0, ""));
+    assert_eq!(quantity!(999.0, m).magnitude_in_prefix(), (999.0, ""));
+    assert_eq!(quantity!(1000.0, m).magnitude_in_prefix(), (1.0, "k"));
+    assert_eq!(quantity!(42.195, km).magnitude_in_prefix(), (42.195, "k"));
+    assert_eq!(quantity!(384_400.0, km).magnitude_in_prefix(), (384.4, "M"));
+    assert_eq!(quantity!(-1500.0, m).magnitude_in_prefix(), (-1.5, "k"));
+    assert_eq!(quantity!(3, km, i32).magnitude_in_prefix(), (3.0, "k"));
+
+    // Beyond the largest prefix, the mantissa grows instead
+    let (mantissa, prefix) = quantity!(1e33, m).magnitude_in_prefix();
+    assert!((mantissa - 1000.0).abs() < 1e-9);
+    assert_eq!(prefix, "Q");
+
+    // Mass prefixes apply to the gram
+    assert_eq!(quantity!(0.5, kg).magnitude_in_prefix(), (500.0, ""));
+
+    // Units that do not take prefixes
+    assert_eq!(quantity!(0.0, km).magnitude_in_prefix(), (0.0, ""));
+    assert_eq!(quantity!(36.0, km / h).magnitude_in_prefix().1, "");
+    assert_eq!(quantity!(2.0, hect).magnitude_in_prefix(), (20000.0, ""));
+}
+
+#[test]
+fn test_prefix_symbol() {
+    assert_eq!(quantity!(1.0, km).prefix_symbol(), Some("k"));
+    assert_eq!(quantity!(1.0, mm).prefix_symbol(), Some("m"));
+    assert_eq!(quantity!(1.0, um).prefix_symbol(), Some("µ"));
+    assert_eq!(quantity!(1.0, m).prefix_symbol(), Some(""));
+    assert_eq!(quantity!(1.0, dam).prefix_symbol(), Some("da"));
+
+    // Mass prefixes apply to the gram, so the kilogram base carries one
+    assert_eq!(quantity!(1.0, kg).prefix_symbol(), Some("k"));
+    assert_eq!(quantity!(1.0, g).prefix_symbol(), Some(""));
+    assert_eq!(quantity!(1.0, mg).prefix_symbol(), Some("m"));
+
+    // Scales with factors of 3 or π are not prefixes
+    assert_eq!(quantity!(1.0, min).prefix_symbol(), None);
+    assert_eq!(quantity!(1.0, h).prefix_symbol(), None);
+    assert_eq!(quantity!(1.0, deg).prefix_symbol(), None);
+
+    // Neither are dimensions without a prefixable base unit
+    assert_eq!(quantity!(1.0, km / s).prefix_symbol(), None);
+    assert_eq!(quantity!(1.0, hect).prefix_symbol(), None);
+}
+
+#[test]
+fn test_debug_scale() {
+    // Minutes mix powers of 2, 3, and 5
+    assert_eq!(
+        quantity!(1.0, min).debug_scale(),
+        "2^2 · 3^1 · 5^1 · π^0 = 60"
+    );
+    // Kilometers are a pure power of 10
+    assert_eq!(
+        quantity!(1.0, km).debug_scale(),
+        "2^3 · 3^0 · 5^3 · π^0 = 1000"
+    );
+    // Coherent units have an empty factorization
+    assert_eq!(
+        quantity!(1.0, m / s).debug_scale(),
+        "2^0 · 3^0 · 5^0 · π^0 = 1"
+    );
+    // Negative exponents and the π term
+    assert_eq!(
+        quantity!(1.0, ms).debug_scale(),
+        "2^-3 · 3^0 · 5^-3 · π^0 = 0.001"
+    );
+    let degrees = quantity!(1.0, deg).debug_scale();
+    assert!(
+        degrees.starts_with("2^-2 · 3^-2 · 5^-1 · π^1 = 0.01745329"),
+        "{}",
+        degrees
+    );
+}
+
+#[test]
+fn test_fmt_long_names_pluralize() {
+    // Regular plurals
+    assert_eq!(quantity!(1000.0, m).fmt("meter").to_string(), "1000 meters");
+    assert_eq!(
+        quantity!(2.5, km).fmt("kilometer").to_string(),
+        "2.5 kilometers"
+    );
+    assert_eq!(
+        quantity!(1.0, km).fmt("kilometer").to_string(),
+        "1 kilometer"
+    );
+
+    // Irregular plurals
+    assert_eq!(quantity!(0.6096, m).fmt("foot").to_string(), "2 feet");
+    assert_eq!(quantity!(0.3048, m).fmt("foot").to_string(), "1 foot");
+    assert_eq!(quantity!(5.08, cm).fmt("inch").to_string(), "2 inches");
+
+    // Invariant plurals, with and without a prefix
+    assert_eq!(quantity!(2.0, kHz).fmt("hertz").to_string(), "2000 hertz");
+    assert_eq!(
+        quantity!(2.0, kHz).fmt("kilohertz").to_string(),
+        "2 kilohertz"
+    );
+
+    // Compound units pluralize only their leading term
+    assert_eq!(
+        quantity!(2.0, m / s).fmt("meter/second").to_string(),
+        "2 meters/second"
+    );
+    assert_eq!(
+        format!("{:.1}", quantity!(2.0, m / s).fmt("foot/second")),
+        "6.6 feet/second"
+    );
+
+    // Symbols are never pluralized
+    assert_eq!(quantity!(1000.0, m).fmt("km").to_string(), "1 km");
+    assert_eq!(
+        quantity!(2.0, m).fmt("ft").to_string(),
+        "6.561679790026247 ft"
+    );
+}
+
+#[test]
+fn test_to_string_in() {
+    use whippyunits::serialization::SerializationError;
+
+    assert_eq!(quantity!(5.0, m).to_string_in("km").unwrap(), "0.005 km");
+    assert_eq!(quantity!(2.5, km).to_string_in("m").unwrap(), "2500 m");
+    assert_eq!(
+        quantity!(36.0, km / h).to_string_in("m/s").unwrap(),
+        "10 m/s"
+    );
+    // Matches `fmt` for units it can convert to, including long names
+    assert_eq!(
+        quantity!(2.0, m).to_string_in("meter").unwrap(),
+        quantity!(2.0, m).fmt("meter").to_string()
+    );
+
+    assert_eq!(
+        quantity!(5.0, m).to_string_in("kg"),
+        Err(SerializationError::DimensionMismatch {
+            expected: (
+                whippyunits_core::dimension_exponents::DynDimensionExponents::LENGTH,
+                whippyunits_core::scale_exponents::ScaleExponents::IDENTITY,
+            ),
+            actual: (
+                whippyunits_core::dimension_exponents::DynDimensionExponents::MASS,
+                whippyunits_core::scale_exponents::ScaleExponents::IDENTITY,
+            ),
+        })
+    );
+    assert_eq!(
+        quantity!(5.0, m).to_string_in("furlongs"),
+        Err(SerializationError::UnknownUnit("furlongs".to_string()))
+    );
+    assert!(matches!(
+        quantity!(5.0, m).to_string_in("m//s"),
+        Err(SerializationError::UnknownUnitLiteral(_))
+    ));
+}
+
+#[test]
+fn test_ascii_unit_format() {
+    use whippyunits::print::prettyprint::{
+        UnitFormat, generate_dimension_symbols_with_format, pretty_print_quantity_type_with_format,
+    };
+    use whippyunits_core::{
+        dimension_exponents::DynDimensionExponents, scale_exponents::ScaleExponents,
+    };
+
+    assert_eq!(whippyunits_core::to_ascii_superscript(2, false), "^2");
+    assert_eq!(whippyunits_core::to_ascii_superscript(-1, false), "^-1");
+    assert_eq!(whippyunits_core::to_ascii_superscript(1, false), "");
+    assert_eq!(
+        whippyunits_core::to_ascii_notation("kg·m²·s⁻¹"),
+        "kg*m^2*s^-1"
+    );
+
+    // Unnamed dimension with a temperature exponent and an unresolved current exponent
+    let exponents = vec![0, 1, -2, i16::MIN, 3, 0, 0, 0];
+    let symbols = generate_dimension_symbols_with_format(exponents.clone(), UnitFormat::Ascii);
+    assert_eq!(symbols, "L*T^-2*Theta^3*(I^?)");
+    assert!(!generate_dimension_symbols_with_format(exponents, UnitFormat::Unicode).is_ascii());
+
+    let cases = [
+        (
+            DynDimensionExponents([0, 2, 0, 0, 0, 0, 0, 0, 0]),
+            ScaleExponents::IDENTITY,
+        ),
+        (
+            DynDimensionExponents([0, 1, -2, 0, 0, 0, 0, 0, 0]),
+            ScaleExponents([-6, 0, -6, 0]),
+        ),
+        (
+            DynDimensionExponents([1, 0, -1, 0, 3, 0, 0, 0, 0]),
+            ScaleExponents([0, 0, 0, 0]),
+        ),
+        (
+            DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 1, 0]),
+            ScaleExponents([-2, -2, -1, 1]),
+        ),
+        (
+            DynDimensionExponents([1, 2, -3, -2, 0, 0, 0, 0, 0]),
+            ScaleExponents::IDENTITY,
+        ),
+    ];
+    for (dimensions, scale) in cases {
+        for verbose in [false, true] {
+            let ascii = pretty_print_quantity_type_with_format(
+                dimensions,
+                scale,
+                "f64",
+                verbose,
+                false,
+                None,
+                UnitFormat::Ascii,
+            );
+            assert!(ascii.is_ascii(), "non-ASCII output {ascii:?}");
+        }
+    }
+
+    let area = pretty_print_quantity_type_with_format(
+        DynDimensionExponents([0, 2, 0, 0, 0, 0, 0, 0, 0]),
+        ScaleExponents::IDENTITY,
+        "f64",
+        false,
+        false,
+        None,
+        UnitFormat::Ascii,
+    );
+    assert_eq!(area, "Quantity<m^2, f64>");
+}
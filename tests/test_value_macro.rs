@@ -157,3 +157,58 @@ fn test_value_macro_with_time_units() {
     let val_f32_min: f32 = value!(time_f32, min, f32);
     assert_eq!(val_f32_min, 1.0f32);
 }
+
+#[test]
+fn test_quantity_macro_with_unit_coefficient() {
+    // The coefficient belongs to the unit, not the value: 2 × (4.184 J)
+    let energy = quantity!(2.0, 4.184 J);
+    assert!((value!(energy, J) - 8.368).abs() < 1e-12);
+
+    let distance = quantity!(3.0, 100 km);
+    assert_eq!(value!(distance, km), 300.0);
+    assert_eq!(value!(distance, m), 300_000.0);
+
+    let speed = quantity!(1.0, 3.6 km / h);
+    assert!((value!(speed, m / s) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_value_fmt_macro_single_value() {
+    let speed = quantity!(10.0, m / s);
+    assert_eq!(value_fmt!("{} km/h", speed), "36 km/h");
+    assert_eq!(value_fmt!("{} m/s", speed), "10 m/s");
+    assert_eq!(value_fmt!("{:.2} mm/ms", speed), "10.00 mm/ms");
+    assert_eq!(value_fmt!("Speed: {}km/h!", speed), "Speed: 36km/h!");
+
+    // Units with exponents and parentheses
+    let acceleration = quantity!(2.0, m / s ^ 2);
+    assert_eq!(value_fmt!("({} m/s^2)", acceleration), "(2 m/s^2)");
+    assert_eq!(value_fmt!("{} (m/s)/s", acceleration), "2 (m/s)/s");
+
+    // Nonstorage units
+    assert_eq!(value_fmt!("{} ft", quantity!(0.3048, m)), "1 ft");
+}
+
+#[test]
+fn test_value_fmt_macro_multiple_values() {
+    let distance = quantity!(1500.0, m);
+    let time = quantity!(90.0, s);
+    assert_eq!(
+        value_fmt!("{} km in {} min", distance, time),
+        "1.5 km in 1.5 min"
+    );
+    assert_eq!(
+        value_fmt!("{:.1} km, {:.0} s, {} m", distance, time, distance),
+        "1.5 km, 90 s, 1500 m"
+    );
+
+    // Escaped braces are not placeholders
+    assert_eq!(
+        value_fmt!("{{distance}}: {} km {{}}", distance),
+        "{distance}: 1.5 km {}"
+    );
+    assert_eq!(value_fmt!("{{{} m}}", distance), "{1500 m}");
+
+    // No placeholders at all
+    assert_eq!(value_fmt!("{{}} plain"), "{} plain");
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "tracing")]
+//! Tests for recording quantities as `tracing` fields
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use whippyunits::quantity;
+
+/// A subscriber that records the fields of every event as strings.
+#[derive(Clone, Default)]
+struct FieldRecorder(Arc<Mutex<Vec<(String, String)>>>);
+
+impl Visit for FieldRecorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .lock()
+            .unwrap()
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+impl Subscriber for FieldRecorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+    fn event(&self, event: &Event<'_>) {
+        event.record(&mut self.clone());
+    }
+    fn enter(&self, _: &Id) {}
+    fn exit(&self, _: &Id) {}
+}
+
+fn recorded_fields(log: impl FnOnce()) -> Vec<(String, String)> {
+    let recorder = FieldRecorder::default();
+    tracing::subscriber::with_default(recorder.clone(), log);
+    recorder.0.lock().unwrap().clone()
+}
+
+#[test]
+fn test_quantity_records_display_string() {
+    let distance = quantity!(1.5, km);
+    let fields = recorded_fields(|| {
+        tracing::info!(with_sigil = %distance, as_field = distance.as_field());
+    });
+
+    let expected = distance.to_string();
+    assert_eq!(
+        fields,
+        [
+            ("with_sigil".to_string(), expected.clone()),
+            ("as_field".to_string(), expected),
+        ]
+    );
+}
+
+#[test]
+fn test_debug_sigil_records_debug_string() {
+    let distance = quantity!(1.5, km);
+    let fields = recorded_fields(|| tracing::info!(distance = ?distance));
+
+    assert_eq!(fields, [("distance".to_string(), format!("{distance:?}"))]);
+    assert_ne!(fields[0].1, distance.to_string());
+}
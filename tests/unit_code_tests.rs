@@ -0,0 +1,133 @@
+//! Tests for the compact unit codes in `whippyunits::unit_code`
+
+use whippyunits::unit_code::{
+    FromCodeError, UnitCodeEntry, UnitCodeRegistry, seeded_unit_code, unit_code,
+};
+use whippyunits::{quantity, unit, unit_code_registry};
+
+const REGISTRY: UnitCodeRegistry = unit_code_registry![m, km, mm, s, ms, m / s, kN, kg];
+
+#[test]
+fn test_unit_codes_are_distinct_and_stable() {
+    let codes = [
+        <unit!(m)>::UNIT_CODE,
+        <unit!(km)>::UNIT_CODE,
+        <unit!(mm)>::UNIT_CODE,
+        <unit!(s)>::UNIT_CODE,
+        <unit!(m / s)>::UNIT_CODE,
+        <unit!(kN)>::UNIT_CODE,
+        <unit!(Hz)>::UNIT_CODE,
+        <unit!(bit / s)>::UNIT_CODE,
+    ];
+    for (i, a) in codes.iter().enumerate() {
+        for b in &codes[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+
+    // The code depends only on the unit, not the storage type or brand
+    assert_eq!(<unit!(m, i32)>::UNIT_CODE, <unit!(m)>::UNIT_CODE);
+    assert_eq!(
+        <unit!(m)>::UNIT_CODE,
+        unit_code([0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+    );
+    // Part of the wire format
+    assert_eq!(<unit!(m)>::UNIT_CODE, 49946);
+}
+
+#[test]
+fn test_unit_code_round_trip() {
+    let force = quantity!(12.5, kN);
+    let (code, value) = force.to_code(&REGISTRY);
+    assert_eq!(code, <unit!(kN)>::UNIT_CODE);
+    assert_eq!(value, 12.5);
+    assert_eq!(<unit!(kN)>::from_code(&REGISTRY, code, value), Ok(force));
+
+    let speed = quantity!(3.0, m / s);
+    let (code, value) = speed.to_code(&REGISTRY);
+    assert_eq!(<unit!(m / s)>::from_code(&REGISTRY, code, value), Ok(speed));
+
+    let (code, value) = quantity!(-7, mm, i32).to_code(&REGISTRY);
+    assert_eq!(
+        <unit!(mm, i32)>::from_code(&REGISTRY, code, value),
+        Ok(quantity!(-7, mm, i32))
+    );
+
+    // Rescaled into a different registered unit of the same dimension
+    let (code, value) = quantity!(1.5, km).to_code(&REGISTRY);
+    assert_eq!(
+        <unit!(m)>::from_code(&REGISTRY, code, value),
+        Ok(quantity!(1500.0, m))
+    );
+    let (code, value) = quantity!(2500, ms, i32).to_code(&REGISTRY);
+    assert_eq!(
+        <unit!(s, i32)>::from_code(&REGISTRY, code, value),
+        Ok(quantity!(2, s, i32))
+    );
+}
+
+#[test]
+fn test_from_code_errors() {
+    // Not registered, even though the receiving type has this unit
+    let (code, value) = quantity!(1.0, h).to_code(&REGISTRY);
+    assert_eq!(
+        <unit!(h)>::from_code(&REGISTRY, code, value),
+        Err(FromCodeError::UnknownCode(code))
+    );
+    assert_eq!(REGISTRY.lookup(code), None);
+
+    let (code, value) = quantity!(1.0, s).to_code(&REGISTRY);
+    assert_eq!(
+        <unit!(m)>::from_code(&REGISTRY, code, value),
+        Err(FromCodeError::DimensionMismatch {
+            expected: [0, 1, 0, 0, 0, 0, 0, 0, 0],
+            found: [0, 0, 1, 0, 0, 0, 0, 0, 0],
+        })
+    );
+
+    let (code, value) = quantity!(100_000_000.0, km).to_code(&REGISTRY);
+    assert!(matches!(
+        <unit!(mm, i32)>::from_code(&REGISTRY, code, value as i32),
+        Err(FromCodeError::ValueOutOfRange(_))
+    ));
+}
+
+#[test]
+fn test_registry_entries() {
+    assert_eq!(REGISTRY.entries().len(), 8);
+    assert_eq!(
+        REGISTRY.lookup(<unit!(kg)>::UNIT_CODE),
+        Some([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+    );
+    // Registering a unit twice is harmless
+    const DUPLICATED: UnitCodeRegistry = unit_code_registry![m, m];
+    assert_eq!(
+        DUPLICATED.lookup(<unit!(m)>::UNIT_CODE).map(|e| e[1]),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_registry_separates_colliding_units() {
+    // Two different units whose codes happen to collide under the default seed
+    const COLLIDING: &[UnitCodeEntry] = &[
+        UnitCodeEntry::new([0, -3, -2, 0, 0, 0, 0, 0, 0, -2, 0, -1, 0]),
+        UnitCodeEntry::new([0, -3, 1, 0, 0, 0, 0, 0, 0, 1, 0, 3, 0]),
+    ];
+    const SEPARATED: UnitCodeRegistry = UnitCodeRegistry::new(COLLIDING);
+    let (a, b) = (COLLIDING[0].exponents, COLLIDING[1].exponents);
+    assert_eq!(unit_code(a), unit_code(b));
+
+    assert_ne!(SEPARATED.seed(), 0);
+    assert_ne!(SEPARATED.code(a), SEPARATED.code(b));
+    assert_eq!(SEPARATED.code(a), seeded_unit_code(a, SEPARATED.seed()));
+    assert_eq!(SEPARATED.lookup(SEPARATED.code(a)), Some(a));
+    assert_eq!(SEPARATED.lookup(SEPARATED.code(b)), Some(b));
+
+    // Without collisions the registry keeps each unit's own code
+    assert_eq!(REGISTRY.seed(), 0);
+    assert_eq!(
+        REGISTRY.code([0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+        <unit!(m)>::UNIT_CODE
+    );
+}
@@ -30,6 +30,7 @@ const KNOWN_EXCEPTIONS: &[&str] = &[
     "nm",   // nanometer vs Newton-meter (Nm)
     "pc",   // parsec vs picocoulomb (pC)
     "ev",   // electron-volt (eV) vs exavolt (EV)
+    "kn",   // knot vs kilonewton (kN)
 ];
 
 /// Collect all concrete unit symbols from the codebase (case-insensitive)
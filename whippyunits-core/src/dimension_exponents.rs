@@ -4,10 +4,10 @@ use crate::num::N;
 
 /// Axis of dimensions vector space.
 ///
-/// All dimensions live in a 8 axis vector space.
-/// For example, `m^2/s` would be vector `[0, 2, -1, 0, 0, 0, 0, 0]`.
+/// All dimensions live in a 9 axis vector space.
+/// For example, `m^2/s` would be vector `[0, 2, -1, 0, 0, 0, 0, 0, 0]`.
 /// These axis are defined by the
-/// [International System of Quantities](https://en.wikipedia.org/wiki/International_System_of_Quantities). Additionally, we add angles as their own axis.
+/// [International System of Quantities](https://en.wikipedia.org/wiki/International_System_of_Quantities). Additionally, we add angles and information as their own axes.
 ///
 /// Addition of dimensioned quantities is only defined if their dimensions
 /// are the same vector.
@@ -23,11 +23,12 @@ pub enum DimensionBasis {
     Amount,
     Luminosity,
     Angle,
+    Information,
 }
 
 impl DimensionBasis {
     /// List of all dimension basis.
-    pub const ALL: [Self; 8] = [
+    pub const ALL: [Self; 9] = [
         Self::Mass,
         Self::Length,
         Self::Time,
@@ -36,6 +37,7 @@ impl DimensionBasis {
         Self::Amount,
         Self::Luminosity,
         Self::Angle,
+        Self::Information,
     ];
 
     /// Convert a basis to it's exponents.
@@ -51,6 +53,7 @@ impl DimensionBasis {
             Self::Amount => DynDimensionExponents::AMOUNT,
             Self::Luminosity => DynDimensionExponents::LUMINOSITY,
             Self::Angle => DynDimensionExponents::ANGLE,
+            Self::Information => DynDimensionExponents::INFORMATION,
         }
     }
 
@@ -65,10 +68,17 @@ impl DimensionBasis {
             Self::Amount => "N",
             Self::Luminosity => "J",
             Self::Angle => "A",
+            Self::Information => "Info",
         }
     }
 }
 
+impl From<DimensionBasis> for DynDimensionExponents {
+    fn from(basis: DimensionBasis) -> Self {
+        basis.exponents()
+    }
+}
+
 /// Trait for type level or dynamic dimension exponents.
 ///
 /// Dimension exponents are tracked at the type level most of the time
@@ -86,7 +96,7 @@ pub trait DimensionExponents: exponents_seal::Sealed + 'static {
 
     /// Get the basis this value represents.
     ///
-    /// A basis is one of the eight axis in the space defining all dimensions.
+    /// A basis is one of the nine axis in the space defining all dimensions.
     ///
     /// This returns `None` for negative basis.
     fn as_basis(&self) -> Option<DimensionBasis> {
@@ -99,6 +109,7 @@ pub trait DimensionExponents: exponents_seal::Sealed + 'static {
             Some(5) => Some(DimensionBasis::Amount),
             Some(6) => Some(DimensionBasis::Luminosity),
             Some(7) => Some(DimensionBasis::Angle),
+            Some(8) => Some(DimensionBasis::Information),
             Some(_) => unreachable!(),
             None => None,
         }
@@ -129,6 +140,7 @@ pub struct TypeDimensionExponents<
     Amount = _N<0>,
     Luminosity = _J<0>,
     Angle = _A<0>,
+    Information = _Info<0>,
 > {
     #[allow(clippy::type_complexity)]
     _phantom: core::marker::PhantomData<(
@@ -140,18 +152,20 @@ pub struct TypeDimensionExponents<
         Amount,
         Luminosity,
         Angle,
+        Information,
     )>,
 }
 
-pub type Zero = dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0]);
-pub type Mass = dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0]);
-pub type Length = dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0]);
-pub type Time = dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0]);
-pub type Current = dimension_exponents!([0, 0, 0, 1, 0, 0, 0, 0]);
-pub type Temperature = dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0]);
-pub type Amount = dimension_exponents!([0, 0, 0, 0, 0, 1, 0, 0]);
-pub type Luminosity = dimension_exponents!([0, 0, 0, 0, 0, 0, 1, 0]);
-pub type Angle = dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1]);
+pub type Zero = dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0, 0]);
+pub type Mass = dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0, 0]);
+pub type Length = dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0, 0]);
+pub type Time = dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0, 0]);
+pub type Current = dimension_exponents!([0, 0, 0, 1, 0, 0, 0, 0, 0]);
+pub type Temperature = dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0, 0]);
+pub type Amount = dimension_exponents!([0, 0, 0, 0, 0, 1, 0, 0, 0]);
+pub type Luminosity = dimension_exponents!([0, 0, 0, 0, 0, 0, 1, 0, 0]);
+pub type Angle = dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1, 0]);
+pub type Information = dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0, 1]);
 
 /// Construct [`TypeDimensionExponents`] type from an array of exponents.
 #[macro_export]
@@ -164,7 +178,8 @@ macro_rules! dimension_exponents {
         $h:expr,
         $n:expr,
         $j:expr,
-        $a:expr $(,)?
+        $a:expr,
+        $info:expr $(,)?
     ]) => {
         $crate::dimension_exponents::TypeDimensionExponents::<
             $crate::dimension_exponents::_M<$m>,
@@ -175,6 +190,7 @@ macro_rules! dimension_exponents {
             $crate::dimension_exponents::_N<$n>,
             $crate::dimension_exponents::_J<$j>,
             $crate::dimension_exponents::_A<$a>,
+            $crate::dimension_exponents::_Info<$info>,
         >
     };
 }
@@ -198,6 +214,7 @@ __exponents_impl!({
                 AMOUNT_EXP,
                 LUMINOSITY_EXP,
                 ANGLE_EXP,
+                INFORMATION_EXP,
             ])
         }
     }
@@ -267,19 +284,23 @@ pub struct _J<const EXP: i16 = 0>;
 /// The angle dimension exponent of a quantity.
 pub struct _A<const EXP: i16 = 0>;
 
+/// The information dimension exponent of a quantity.
+pub struct _Info<const EXP: i16 = 0>;
+
 /// Dimension vector.
 ///
 /// We choose this labelling of the space's axes.
 /// ```text
-/// [mass, length, time, current, temperature, amount, luminosity, angle]
+/// [mass, length, time, current, temperature, amount, luminosity, angle, information]
 /// ```
-/// We consider angles to be a seperate axis to distingish them from dimensionless.
+/// We consider angles to be a seperate axis to distingish them from dimensionless, and likewise
+/// information, so that a data rate is not a frequency.
 ///
 /// Each dimensional quantity exists at some unique integer lattice point in this space.
-/// For example `m/s` would be vector `[0, 1, -1, 0, 0, 0, 0, 0]`. The numbers are the
+/// For example `m/s` would be vector `[0, 1, -1, 0, 0, 0, 0, 0, 0]`. The numbers are the
 /// exponents found in the written units.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DynDimensionExponents(pub [i16; 8]);
+pub struct DynDimensionExponents(pub [i16; 9]);
 
 impl DimensionExponents for DynDimensionExponents {
     fn value(&self) -> Self {
@@ -298,6 +319,7 @@ impl DynDimensionExponents {
     pub const AMOUNT: Self = Amount::new().value_const();
     pub const LUMINOSITY: Self = Luminosity::new().value_const();
     pub const ANGLE: Self = Angle::new().value_const();
+    pub const INFORMATION: Self = Information::new().value_const();
 }
 
 impl Mul<i16> for DynDimensionExponents {
@@ -313,6 +335,7 @@ impl Mul<i16> for DynDimensionExponents {
             self.0[5] * rhs,
             self.0[6] * rhs,
             self.0[7] * rhs,
+            self.0[8] * rhs,
         ])
     }
 }
@@ -332,6 +355,7 @@ impl<T: DimensionExponents> Add<T> for DynDimensionExponents {
             self.0[5] + rhs.0[5],
             self.0[6] + rhs.0[6],
             self.0[7] + rhs.0[7],
+            self.0[8] + rhs.0[8],
         ])
     }
 }
@@ -342,7 +366,7 @@ impl Neg for DynDimensionExponents {
     fn neg(self) -> Self {
         Self([
             -self.0[0], -self.0[1], -self.0[2], -self.0[3], -self.0[4], -self.0[5], -self.0[6],
-            -self.0[7],
+            -self.0[7], -self.0[8],
         ])
     }
 }
@@ -387,6 +411,7 @@ macro_rules! __exponents_impl {
             const AMOUNT_EXP: i16,
             const LUMINOSITY_EXP: i16,
             const ANGLE_EXP: i16,
+            const INFORMATION_EXP: i16,
         > __exponents!() {
             $($t)*
         }
@@ -409,6 +434,7 @@ macro_rules! __exponents_impl_trait {
             const AMOUNT_EXP: i16,
             const LUMINOSITY_EXP: i16,
             const ANGLE_EXP: i16,
+            const INFORMATION_EXP: i16,
         > $trait for __exponents!() {
             $($t)*
         }
@@ -431,6 +457,7 @@ macro_rules! __exponents_impl_op {
             const AMOUNT_EXP: i16,
             const LUMINOSITY_EXP: i16,
             const ANGLE_EXP: i16,
+            const INFORMATION_EXP: i16,
             const MASS_EXP1: i16,
             const LENGTH_EXP1: i16,
             const TIME_EXP1: i16,
@@ -439,6 +466,7 @@ macro_rules! __exponents_impl_op {
             const AMOUNT_EXP1: i16,
             const LUMINOSITY_EXP1: i16,
             const ANGLE_EXP1: i16,
+            const INFORMATION_EXP1: i16,
             const MASS_EXP2: i16,
             const LENGTH_EXP2: i16,
             const TIME_EXP2: i16,
@@ -447,6 +475,7 @@ macro_rules! __exponents_impl_op {
             const AMOUNT_EXP2: i16,
             const LUMINOSITY_EXP2: i16,
             const ANGLE_EXP2: i16,
+            const INFORMATION_EXP2: i16,
         > $op<__exponents!(@rhs)> for __exponents!(@lhs)
 where
     N<MASS_EXP1>: Add<N<MASS_EXP2>, Output = N<MASS_EXP>>,
@@ -457,6 +486,7 @@ where
     N<AMOUNT_EXP1>: Add<N<AMOUNT_EXP2>, Output = N<AMOUNT_EXP>>,
     N<LUMINOSITY_EXP1>: Add<N<LUMINOSITY_EXP2>, Output = N<LUMINOSITY_EXP>>,
     N<ANGLE_EXP1>: Add<N<ANGLE_EXP2>, Output = N<ANGLE_EXP>>,
+    N<INFORMATION_EXP1>: Add<N<INFORMATION_EXP2>, Output = N<INFORMATION_EXP>>,
 {
             $($t)*
         }
@@ -475,6 +505,7 @@ macro_rules! __exponents {
             AMOUNT_EXP,
             LUMINOSITY_EXP,
             ANGLE_EXP,
+            INFORMATION_EXP,
         ])
     };
     (@lhs) => {
@@ -487,6 +518,7 @@ macro_rules! __exponents {
             AMOUNT_EXP1,
             LUMINOSITY_EXP1,
             ANGLE_EXP1,
+            INFORMATION_EXP1,
         ])
     };
     (@rhs) => {
@@ -499,6 +531,7 @@ macro_rules! __exponents {
             AMOUNT_EXP2,
             LUMINOSITY_EXP2,
             ANGLE_EXP2,
+            INFORMATION_EXP2,
         ])
     };
 }
@@ -510,28 +543,28 @@ mod tests {
 
     #[test]
     fn exponent_type_to_value() {
-        let exp = <dimension_exponents!([1, -2, 3, -4, 5, -6, 7, -8])>::new().value();
+        let exp = <dimension_exponents!([1, -2, 3, -4, 5, -6, 7, -8, 9])>::new().value();
         assert_eq!(
             exp.value(),
-            DynDimensionExponents([1, -2, 3, -4, 5, -6, 7, -8])
+            DynDimensionExponents([1, -2, 3, -4, 5, -6, 7, -8, 9])
         );
     }
 
     #[test]
     fn exponents_can_be_types() {
-        let exp = <dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])>::new();
+        let exp = <dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0, 0])>::new();
         assert_eq!(exp.as_basis().unwrap(), DimensionBasis::Length);
 
-        let exp = exp + <dimension_exponents!([0, 0, 2, 0, 0, 0, 0, 0])>::new();
+        let exp = exp + <dimension_exponents!([0, 0, 2, 0, 0, 0, 0, 0, 0])>::new();
         assert_eq!(format!("{}", exp.value()), "L^1 * T^2");
     }
 
     #[test]
     fn exponents_can_be_values() {
-        let exp = DynDimensionExponents([1, 0, 0, 0, 0, 0, 0, 0]);
+        let exp = DynDimensionExponents([1, 0, 0, 0, 0, 0, 0, 0, 0]);
         assert_eq!(exp.as_basis().unwrap(), DimensionBasis::Mass);
 
-        let exp = exp + DynDimensionExponents([0, 0, 2, 0, 0, 0, 0, 0]);
+        let exp = exp + DynDimensionExponents([0, 0, 2, 0, 0, 0, 0, 0, 0]);
         assert_eq!(format!("{exp}"), "M^1 * T^2");
     }
 
@@ -541,7 +574,7 @@ mod tests {
         let b = DynDimensionExponents::LENGTH;
         let c = b + a;
 
-        assert_eq!(c, DynDimensionExponents([1, 1, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(c, DynDimensionExponents([1, 1, 0, 0, 0, 0, 0, 0, 0]));
     }
 
     #[test]
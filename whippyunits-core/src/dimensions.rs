@@ -1,7 +1,7 @@
 use crate::dimension_exponents::{
     DimensionExponents, DynDimensionExponents, TypeDimensionExponents,
 };
-use crate::prefix::SiPrefix;
+use crate::prefix::{BinaryPrefix, SiPrefix};
 use crate::units::Unit;
 
 /// A dimension and its associated units.
@@ -29,7 +29,7 @@ impl Dimension {
             .find(|dim| dim.symbol == name_or_symbol || dim.name == name_or_symbol)
     }
 
-    /// Find a unit by its symbol across all dimensions.
+    /// Find a unit by its name across all dimensions.
     pub fn find_unit_by_name(name: &str) -> Option<(&'static Unit, &'static Self)> {
         Self::ALL.iter().find_map(|dimension| {
             dimension
@@ -81,6 +81,22 @@ impl Dimension {
         })
     }
 
+    /// Find a unit of information by a symbol or name with a binary prefix (`KiB`, `mebibit`).
+    pub fn find_binary_prefixed_unit(
+        literal: &str,
+    ) -> Option<(&'static Unit, &'static BinaryPrefix)> {
+        let units = Dimension::INFORMATION.units_erased;
+        if let Some((prefix, base)) = BinaryPrefix::strip_any_prefix_symbol(literal)
+            && let Some(unit) = units.iter().find(|unit| unit.symbols.contains(&base))
+        {
+            return Some((unit, prefix));
+        }
+
+        let (prefix, base) = BinaryPrefix::strip_any_prefix_name(literal)?;
+        let unit = units.iter().find(|unit| unit.name == base)?;
+        Some((unit, prefix))
+    }
+
     /// Find a dimension by its exponents.
     pub fn find_dimension_by_exponents(
         exponents: DynDimensionExponents,
@@ -191,7 +207,7 @@ impl Dimension {
     pub const ALL: &[Self] = &Self::ALL_FIXED;
 
     /// Atomic dimensions.
-    pub const BASIS: [Self; 8] = [
+    pub const BASIS: [Self; 9] = [
         Dimension::MASS.erase(),
         Dimension::LENGTH.erase(),
         Dimension::TIME.erase(),
@@ -200,9 +216,10 @@ impl Dimension {
         Dimension::AMOUNT.erase(),
         Dimension::LUMINOSITY.erase(),
         Dimension::ANGLE.erase(),
+        Dimension::INFORMATION.erase(),
     ];
 
-    const ALL_FIXED: [Self; 28] = [
+    const ALL_FIXED: [Self; 30] = [
         Dimension::MASS.erase(),
         Dimension::LENGTH.erase(),
         Dimension::TIME.erase(),
@@ -211,8 +228,10 @@ impl Dimension {
         Dimension::AMOUNT.erase(),
         Dimension::LUMINOSITY.erase(),
         Dimension::ANGLE.erase(),
+        Dimension::INFORMATION.erase(),
         Dimension::AREA.erase(),
         Dimension::VOLUME.erase(),
+        Dimension::VELOCITY.erase(),
         Dimension::FREQUENCY.erase(),
         Dimension::FORCE.erase(),
         Dimension::ENERGY.erase(),
@@ -243,6 +262,7 @@ impl<
     const AMOUNT_EXP: i16,
     const LUMINOSITY_EXP: i16,
     const ANGLE_EXP: i16,
+    const INFORMATION_EXP: i16,
 >
     Dimension<
         crate::dimension_exponents!([
@@ -254,6 +274,7 @@ impl<
             AMOUNT_EXP,
             LUMINOSITY_EXP,
             ANGLE_EXP,
+            INFORMATION_EXP,
         ]),
     >
 {
@@ -268,12 +289,13 @@ impl<
     }
 }
 
-impl Dimension<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0, 0])> {
     pub const MASS: Self = __dim!(Self {
         name: "Mass",
         symbol: "M",
         units: &[
             Unit::GRAM,
+            Unit::TONNE,
             Unit::GRAIN,
             Unit::CARAT,
             Unit::OUNCE,
@@ -281,12 +303,13 @@ impl Dimension<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
             Unit::TROY_POUND,
             Unit::POUND,
             Unit::STONE,
-            Unit::TON
+            Unit::TON,
+            Unit::LONG_TON
         ],
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0, 0])> {
     pub const LENGTH: Self = __dim!(Self {
         name: "Length",
         symbol: "L",
@@ -294,7 +317,9 @@ impl Dimension<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
             Unit::METER,
             Unit::INCH,
             Unit::FOOT,
+            Unit::SURVEY_FOOT,
             Unit::YARD,
+            Unit::FATHOM,
             Unit::MILE,
             Unit::NAUTICAL_MILE,
             Unit::ASTRONOMICAL_UNIT,
@@ -304,7 +329,7 @@ impl Dimension<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0, 0])> {
     pub const TIME: Self = __dim!(Self {
         name: "Time",
         symbol: "T",
@@ -320,7 +345,7 @@ impl Dimension<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 0, 0, 1, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 0, 0, 1, 0, 0, 0, 0, 0])> {
     pub const CURRENT: Self = __dim!(Self {
         name: "Current",
         symbol: "I",
@@ -328,7 +353,7 @@ impl Dimension<crate::dimension_exponents!([0, 0, 0, 1, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0, 0])> {
     pub const TEMPERATURE: Self = __dim!(Self {
         name: "Temperature",
         symbol: "θ",
@@ -336,7 +361,7 @@ impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 1, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 1, 0, 0, 0])> {
     pub const AMOUNT: Self = __dim!(Self {
         name: "Amount",
         symbol: "N",
@@ -344,7 +369,7 @@ impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 1, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 1, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 1, 0, 0])> {
     pub const LUMINOSITY: Self = __dim!(Self {
         name: "Luminosity",
         symbol: "Cd",
@@ -352,7 +377,7 @@ impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 1, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1])> {
+impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1, 0])> {
     pub const ANGLE: Self = __dim!(Self {
         name: "Angle",
         symbol: "A",
@@ -367,7 +392,15 @@ impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 2, 0, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0, 1])> {
+    pub const INFORMATION: Self = __dim!(Self {
+        name: "Information",
+        symbol: "Info",
+        units: &[Unit::BIT, Unit::BYTE],
+    });
+}
+
+impl Dimension<crate::dimension_exponents!([0, 2, 0, 0, 0, 0, 0, 0, 0])> {
     pub const AREA: Self = __dim!(Self {
         name: "Area",
         symbol: "L²",
@@ -375,7 +408,7 @@ impl Dimension<crate::dimension_exponents!([0, 2, 0, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0, 0])> {
     pub const VOLUME: Self = __dim!(Self {
         name: "Volume",
         symbol: "L³",
@@ -403,7 +436,15 @@ impl Dimension<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 0, -1, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 1, -1, 0, 0, 0, 0, 0, 0])> {
+    pub const VELOCITY: Self = __dim!(Self {
+        name: "Velocity",
+        symbol: "LT⁻¹",
+        units: &[Unit::KNOT],
+    });
+}
+
+impl Dimension<crate::dimension_exponents!([0, 0, -1, 0, 0, 0, 0, 0, 0])> {
     pub const FREQUENCY: Self = __dim!(Self {
         name: "Frequency",
         symbol: "T⁻¹",
@@ -411,7 +452,7 @@ impl Dimension<crate::dimension_exponents!([0, 0, -1, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, 1, -2, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, 1, -2, 0, 0, 0, 0, 0, 0])> {
     pub const FORCE: Self = __dim!(Self {
         name: "Force",
         symbol: "MLT⁻²",
@@ -419,7 +460,7 @@ impl Dimension<crate::dimension_exponents!([1, 1, -2, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0, 0])> {
     pub const ENERGY: Self = __dim!(Self {
         name: "Energy",
         symbol: "ML²T⁻²",
@@ -436,7 +477,7 @@ impl Dimension<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, 2, -3, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, 2, -3, 0, 0, 0, 0, 0, 0])> {
     pub const POWER: Self = __dim!(Self {
         name: "Power",
         symbol: "ML²T⁻³",
@@ -444,7 +485,7 @@ impl Dimension<crate::dimension_exponents!([1, 2, -3, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0, 0])> {
     pub const PRESSURE: Self = __dim!(Self {
         name: "Pressure",
         symbol: "ML⁻¹T⁻²",
@@ -458,7 +499,7 @@ impl Dimension<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 0, 1, 1, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 0, 1, 1, 0, 0, 0, 0, 0])> {
     pub const ELECTRIC_CHARGE: Self = __dim!(Self {
         name: "Electric Charge",
         symbol: "TI",
@@ -466,7 +507,7 @@ impl Dimension<crate::dimension_exponents!([0, 0, 1, 1, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, 2, -3, -1, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, 2, -3, -1, 0, 0, 0, 0, 0])> {
     pub const ELECTRIC_POTENTIAL: Self = __dim!(Self {
         name: "Electric Potential",
         symbol: "ML²T⁻³I⁻¹",
@@ -474,7 +515,7 @@ impl Dimension<crate::dimension_exponents!([1, 2, -3, -1, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([-1, -2, 4, 2, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([-1, -2, 4, 2, 0, 0, 0, 0, 0])> {
     pub const CAPACITANCE: Self = __dim!(Self {
         name: "Capacitance",
         symbol: "M⁻¹L⁻²T⁴I²",
@@ -482,7 +523,7 @@ impl Dimension<crate::dimension_exponents!([-1, -2, 4, 2, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, 2, -3, -2, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, 2, -3, -2, 0, 0, 0, 0, 0])> {
     pub const ELECTRIC_RESISTANCE: Self = __dim!(Self {
         name: "Electric Resistance",
         symbol: "ML²T⁻³I⁻²",
@@ -490,7 +531,7 @@ impl Dimension<crate::dimension_exponents!([1, 2, -3, -2, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([-1, -2, 3, 2, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([-1, -2, 3, 2, 0, 0, 0, 0, 0])> {
     pub const ELECTRIC_CONDUCTANCE: Self = __dim!(Self {
         name: "Electric Conductance",
         symbol: "M⁻¹L⁻²T³I²",
@@ -498,7 +539,7 @@ impl Dimension<crate::dimension_exponents!([-1, -2, 3, 2, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, 2, -2, -2, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, 2, -2, -2, 0, 0, 0, 0, 0])> {
     pub const INDUCTANCE: Self = __dim!(Self {
         name: "Inductance",
         symbol: "ML²T⁻²I⁻²",
@@ -506,7 +547,7 @@ impl Dimension<crate::dimension_exponents!([1, 2, -2, -2, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, 0, -2, -1, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, 0, -2, -1, 0, 0, 0, 0, 0])> {
     pub const MAGNETIC_FIELD: Self = __dim!(Self {
         name: "Magnetic Field",
         symbol: "MT⁻²I⁻¹",
@@ -514,7 +555,7 @@ impl Dimension<crate::dimension_exponents!([1, 0, -2, -1, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, 2, -2, -1, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, 2, -2, -1, 0, 0, 0, 0, 0])> {
     pub const MAGNETIC_FLUX: Self = __dim!(Self {
         name: "Magnetic Flux",
         symbol: "ML²T⁻²I⁻¹",
@@ -522,7 +563,7 @@ impl Dimension<crate::dimension_exponents!([1, 2, -2, -1, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, -2, 0, 0, 0, 0, 1, 0])> {
+impl Dimension<crate::dimension_exponents!([0, -2, 0, 0, 0, 0, 1, 0, 0])> {
     pub const ILLUMINANCE: Self = __dim!(Self {
         name: "Illuminance",
         symbol: "L⁻²Cd",
@@ -530,7 +571,7 @@ impl Dimension<crate::dimension_exponents!([0, -2, 0, 0, 0, 0, 1, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, -3, 0, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, -3, 0, 0, 0, 0, 0, 0, 0])> {
     pub const VOLUME_MASS_DENSITY: Self = __dim!(Self {
         name: "Volume Mass Density",
         symbol: "ML⁻³",
@@ -540,7 +581,7 @@ impl Dimension<crate::dimension_exponents!([1, -3, 0, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, -1, 0, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, -1, 0, 0, 0, 0, 0, 0, 0])> {
     pub const LINEAR_MASS_DENSITY: Self = __dim!(Self {
         name: "Linear Mass Density",
         symbol: "ML⁻¹",
@@ -550,7 +591,7 @@ impl Dimension<crate::dimension_exponents!([1, -1, 0, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([1, -1, 1, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([1, -1, 1, 0, 0, 0, 0, 0, 0])> {
     pub const DYNAMIC_VISCOSITY: Self = __dim!(Self {
         name: "Dynamic Viscosity",
         symbol: "ML⁻¹T⁻¹",
@@ -560,7 +601,7 @@ impl Dimension<crate::dimension_exponents!([1, -1, 1, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 2, -1, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 2, -1, 0, 0, 0, 0, 0, 0])> {
     pub const KINEMATIC_VISCOSITY: Self = __dim!(Self {
         name: "Kinematic Viscosity",
         symbol: "L²T⁻¹",
@@ -568,7 +609,7 @@ impl Dimension<crate::dimension_exponents!([0, 2, -1, 0, 0, 0, 0, 0])> {
     });
 }
 
-impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0])> {
+impl Dimension<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0, 0])> {
     pub const NONE: Self = __dim!(Self {
         name: "dimensionless",
         symbol: "1",
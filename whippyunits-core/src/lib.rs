@@ -19,6 +19,7 @@ use alloc::string::ToString;
 
 pub mod dimension_exponents;
 mod dimensions;
+pub mod lite_parser;
 pub mod num;
 pub mod parser;
 mod prefix;
@@ -27,11 +28,32 @@ pub mod storage_unit;
 mod units;
 
 pub use dimensions::*;
+pub use lite_parser::*;
 pub use parser::*;
 pub use prefix::*;
 pub use storage_unit::*;
 pub use units::*;
 
+/// `base` raised to the integer power `exponent`.
+///
+/// `f64::powi` is only available with `std`, so this is the same binary exponentiation that
+/// `powi` lowers to, for use in `no_std` builds.
+pub fn powi(mut base: f64, exponent: i32) -> f64 {
+    let mut remaining = exponent.unsigned_abs();
+    let mut result = 1.0;
+    loop {
+        if remaining & 1 == 1 {
+            result *= base;
+        }
+        remaining /= 2;
+        if remaining == 0 {
+            break;
+        }
+        base *= base;
+    }
+    if exponent < 0 { 1.0 / result } else { result }
+}
+
 pub struct CapitalizedFmt<'r>(pub &'r str);
 
 impl core::fmt::Display for CapitalizedFmt<'_> {
@@ -47,30 +69,49 @@ impl core::fmt::Display for CapitalizedFmt<'_> {
 }
 
 /// Convert a singular unit name to its plural form.
+///
+/// Registered units, with or without an SI prefix name (e.g. `kilohertz`), use their
+/// [plural](Unit::plural) field; any other name has an `s` appended.
 pub fn make_plural(singular: &str) -> String {
-    // Handle exceptions to the "add s" rule
-    match singular {
-        "inch" => "inches".to_string(),
-        "foot" => "feet".to_string(),
-        "henry" => "henries".to_string(),
-        "stone" => "stone".to_string(),
-        "lux" => "lux".to_string(),
-        "candela" => "candela".to_string(),
-        "fahrenheit" => "fahrenheit".to_string(),
-        "rankine" => "rankine".to_string(),
-        "psi" => "psi".to_string(),
-        "horsepower" => "horsepower".to_string(),
-        "torr" => "torr".to_string(),
-        "bar" => "bar".to_string(),
-        "celsius" => "celsius".to_string(),
-        "kelvin" => "kelvin".to_string(),
-        _ => {
-            // Default: just add 's' (works for 99% of unit names)
-            format!("{}s", singular)
+    match registered_plural(singular) {
+        Some((prefix, plural)) => format!("{}{}", prefix, plural),
+        None => format!("{}s", singular),
+    }
+}
+
+/// Displays a long unit name or unit expression in its plural form.
+///
+/// Only the leading term of a compound expression is pluralized, as in "meters/second".  Terms
+/// that are not registered unit names (such as symbols like `ft`) are written unchanged.
+pub struct PluralFmt<'r>(pub &'r str);
+
+impl core::fmt::Display for PluralFmt<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let split = self
+            .0
+            .find(|c: char| !(c.is_alphabetic() || c == '_'))
+            .unwrap_or(self.0.len());
+        let (leading, rest) = self.0.split_at(split);
+
+        match registered_plural(leading) {
+            Some((prefix, plural)) => write!(f, "{}{}{}", prefix, plural, rest),
+            None => write!(f, "{}", self.0),
         }
     }
 }
 
+/// The plural of a registered unit name, split into its SI prefix name (possibly empty) and the
+/// plural of the unit.
+fn registered_plural(name: &str) -> Option<(&str, &'static str)> {
+    if let Some((unit, _)) = Dimension::find_unit_by_name(name) {
+        return Some(("", unit.plural));
+    }
+
+    let (_, base) = SiPrefix::strip_any_prefix_name(name)?;
+    let (unit, _) = Dimension::find_unit_by_name(base)?;
+    Some((&name[..name.len() - base.len()], unit.plural))
+}
+
 /// Generate the declarator trait name for a unit based on its system, dimension, and properties.
 ///
 /// This function generates trait names like:
@@ -154,3 +195,69 @@ pub fn to_unicode_superscript(num: i16, show_unity: bool) -> String {
         .replace('8', "⁸")
         .replace('9', "⁹")
 }
+
+/// Convert any integer to plain ASCII exponent notation (`^2`, `^-1`)
+/// Returns empty string for unity exponent (1) unless show_unity is true
+/// Returns "^?" for unknown values (i16::MIN)
+pub fn to_ascii_superscript(num: i16, show_unity: bool) -> String {
+    if num == i16::MIN {
+        return "^?".to_string();
+    }
+
+    if num == 1 && !show_unity {
+        return String::new();
+    }
+
+    format!("^{}", num)
+}
+
+/// Convert a unit string rendered with Unicode notation to plain ASCII
+///
+/// Runs of superscript digits become `^` exponents (`m²` to `m^2`, `s⁻¹` to `s^-1`), the
+/// unknown exponent `ˀ` becomes `^?`, and the `·` separator becomes `*`.  The few non-ASCII
+/// symbols used in unit and dimension names are spelled out (`µ` to `u`, `Ω` to `Ohm`,
+/// `θ` to `Theta`, `π` to `pi`); any other non-ASCII character is replaced by `?`.
+pub fn to_ascii_notation(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut in_exponent = false;
+
+    for c in text.chars() {
+        let digit = match c {
+            '⁻' => Some('-'),
+            '⁰' => Some('0'),
+            '¹' => Some('1'),
+            '²' => Some('2'),
+            '³' => Some('3'),
+            '⁴' => Some('4'),
+            '⁵' => Some('5'),
+            '⁶' => Some('6'),
+            '⁷' => Some('7'),
+            '⁸' => Some('8'),
+            '⁹' => Some('9'),
+            _ => None,
+        };
+
+        if let Some(digit) = digit {
+            if !in_exponent {
+                result.push('^');
+                in_exponent = true;
+            }
+            result.push(digit);
+            continue;
+        }
+        in_exponent = false;
+
+        match c {
+            'ˀ' => result.push_str("^?"),
+            '·' => result.push('*'),
+            'µ' | 'μ' => result.push('u'),
+            'Ω' => result.push_str("Ohm"),
+            'θ' | 'Θ' => result.push_str("Theta"),
+            'π' => result.push_str("pi"),
+            c if c.is_ascii() => result.push(c),
+            _ => result.push('?'),
+        }
+    }
+
+    result
+}
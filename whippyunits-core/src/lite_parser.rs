@@ -0,0 +1,564 @@
+//! A lightweight unit expression parser that does not depend on `syn`.
+//!
+//! [LiteUnitExpr] accepts the same grammar as [UnitExpr](crate::UnitExpr) and evaluates to the
+//! same dimensions, scales, and conversion factors, so unit strings parsed at runtime agree with
+//! the `unit!` and `quantity!` macros without tokenizing through `syn` and `proc-macro2`.
+//!
+//! The tokenizer follows Rust's lexical rules for the subset of tokens that can appear in a unit
+//! expression: identifiers (excluding keywords), decimal integer and float literals (with optional
+//! `_` separators and type suffixes), parentheses, and the `*`, `.`, `/`, `^`, and `-` operators.
+//! Whitespace only separates tokens, so `kg m` (a product, like `kg * m`) and `kg  m` parse alike.
+
+#[cfg(not(test))]
+use alloc::boxed::Box;
+#[cfg(not(test))]
+use alloc::string::String;
+#[cfg(not(test))]
+use alloc::vec::Vec;
+
+use core::str::FromStr;
+
+use crate::parser::{
+    EvaluationMode, UnitEvaluationResult, div_conversion_factors, evaluate_unit,
+    mul_conversion_factors, pow_conversion_factors, unit_conversion_factors,
+};
+
+/// A unit expression parsed without `syn`
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiteUnitExpr<'a> {
+    /// A unit identifier with an exponent, e.g. `s2` or `m`
+    Unit {
+        name: &'a str,
+        exponent: i16,
+    },
+    /// A dimensionless numeric coefficient, e.g. the `4.184` in `4.184 J`
+    Coefficient(f64),
+    Mul(Box<LiteUnitExpr<'a>>, Box<LiteUnitExpr<'a>>),
+    Div(Box<LiteUnitExpr<'a>>, Box<LiteUnitExpr<'a>>),
+    Pow(Box<LiteUnitExpr<'a>>, i16),
+}
+
+/// Error returned when a string is not a valid unit expression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiteParseError {
+    /// Byte offset of the first token that could not be parsed
+    pub position: usize,
+}
+
+impl core::fmt::Display for LiteParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid unit expression at byte {}", self.position)
+    }
+}
+
+impl<'a> LiteUnitExpr<'a> {
+    /// Parse a unit expression such as `m/s2`, `kg * m^2 / s^2`, or `4.184 J`
+    pub fn parse(input: &'a str) -> Result<Self, LiteParseError> {
+        let mut parser = Parser {
+            tokens: tokenize(input)?,
+            cursor: 0,
+            end: input.len(),
+        };
+        let expr = parser.parse_expr()?;
+        match parser.tokens.get(parser.cursor) {
+            Some(&(_, position)) => Err(LiteParseError { position }),
+            None => Ok(expr),
+        }
+    }
+
+    /// Whether this term is a bare number (a coefficient or power-of-10 scale factor)
+    fn is_numeric(&self) -> bool {
+        match self {
+            LiteUnitExpr::Coefficient(_) => true,
+            LiteUnitExpr::Unit { name, .. } => *name == "power_of_10",
+            LiteUnitExpr::Pow(base, _) => base.is_numeric(),
+            LiteUnitExpr::Mul(_, _) | LiteUnitExpr::Div(_, _) => false,
+        }
+    }
+
+    /// Collect all unit identifiers used in this expression
+    pub fn collect_unit_identifiers(&self) -> Vec<&'a str> {
+        let mut identifiers = Vec::new();
+        self.collect_identifiers_recursive(&mut identifiers);
+        identifiers
+    }
+
+    fn collect_identifiers_recursive(&self, identifiers: &mut Vec<&'a str>) {
+        match self {
+            LiteUnitExpr::Unit { name, .. } => identifiers.push(name),
+            LiteUnitExpr::Coefficient(_) => {}
+            LiteUnitExpr::Mul(a, b) | LiteUnitExpr::Div(a, b) => {
+                a.collect_identifiers_recursive(identifiers);
+                b.collect_identifiers_recursive(identifiers);
+            }
+            LiteUnitExpr::Pow(base, _) => base.collect_identifiers_recursive(identifiers),
+        }
+    }
+
+    /// Evaluate the unit expression with a specific evaluation mode
+    pub fn evaluate_with_mode(&self, mode: EvaluationMode) -> UnitEvaluationResult {
+        match self {
+            LiteUnitExpr::Unit { name, exponent } => evaluate_unit(name, *exponent, mode),
+            LiteUnitExpr::Coefficient(_) => UnitEvaluationResult::DIMENSIONLESS,
+            LiteUnitExpr::Mul(a, b) => a.evaluate_with_mode(mode).mul(b.evaluate_with_mode(mode)),
+            LiteUnitExpr::Div(a, b) => a.evaluate_with_mode(mode).div(b.evaluate_with_mode(mode)),
+            LiteUnitExpr::Pow(base, exponent) => base.evaluate_with_mode(mode).pow(*exponent),
+        }
+    }
+
+    /// Calculate the conversion factor and affine offset of this expression
+    ///
+    /// Equivalent to [calculate_unit_conversion_factors](crate::calculate_unit_conversion_factors)
+    /// for the corresponding [UnitExpr](crate::UnitExpr).
+    pub fn conversion_factors(&self) -> (f64, f64) {
+        match self {
+            LiteUnitExpr::Unit { name, exponent } => unit_conversion_factors(name, *exponent),
+            LiteUnitExpr::Coefficient(coefficient) => (*coefficient, 0.0),
+            LiteUnitExpr::Mul(a, b) => {
+                mul_conversion_factors(a.conversion_factors(), b.conversion_factors())
+            }
+            LiteUnitExpr::Div(a, b) => {
+                div_conversion_factors(a.conversion_factors(), b.conversion_factors())
+            }
+            LiteUnitExpr::Pow(base, exponent) => {
+                pow_conversion_factors(base.conversion_factors(), *exponent)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Ident(&'a str),
+    /// An integer literal's digits, without its suffix
+    Int(&'a str),
+    /// A float literal's digits, without its suffix
+    Float(&'a str),
+    Punct(char),
+    OpenParen,
+    CloseParen,
+}
+
+/// Identifiers that `syn` refuses to parse as an `Ident`
+const KEYWORDS: &[&str] = &[
+    "_", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "Self", "self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_alphabetic()
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c == '_' || c.is_alphanumeric()
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token<'_>, usize)>, LiteParseError> {
+    let mut tokens = Vec::new();
+    let mut rest = input;
+
+    while let Some(c) = rest.chars().next() {
+        let position = input.len() - rest.len();
+        let len = if c.is_whitespace() {
+            c.len_utf8()
+        } else if is_ident_start(c) {
+            let len = ident_len(rest);
+            tokens.push((Token::Ident(&rest[..len]), position));
+            len
+        } else if c.is_ascii_digit() {
+            let (token, len) = number(rest);
+            tokens.push((token, position));
+            len
+        } else {
+            let token = match c {
+                '(' => Token::OpenParen,
+                ')' => Token::CloseParen,
+                c if c.is_ascii_punctuation() => Token::Punct(c),
+                _ => return Err(LiteParseError { position }),
+            };
+            tokens.push((token, position));
+            c.len_utf8()
+        };
+        rest = &rest[len..];
+    }
+
+    Ok(tokens)
+}
+
+fn ident_len(input: &str) -> usize {
+    input
+        .find(|c: char| !is_ident_continue(c))
+        .unwrap_or(input.len())
+}
+
+fn digits_len(input: &str) -> usize {
+    input
+        .find(|c: char| !(c.is_ascii_digit() || c == '_'))
+        .unwrap_or(input.len())
+}
+
+/// Lex a decimal number literal starting at the beginning of `input`
+///
+/// Like Rust, a `.` only belongs to the number if it is not followed by another `.` or an
+/// identifier (so `1.m` is `1`, `.`, `m`), and an `e` only starts an exponent if digits follow.
+fn number(input: &str) -> (Token<'_>, usize) {
+    let mut len = digits_len(input);
+    let mut is_float = false;
+
+    let after_int = &input[len..];
+    if let Some(fraction) = after_int.strip_prefix('.')
+        && !fraction.starts_with('.')
+        && !fraction.starts_with(is_ident_start)
+    {
+        is_float = true;
+        len += 1;
+        if fraction.starts_with(|c: char| c.is_ascii_digit()) {
+            len += digits_len(fraction);
+        }
+    }
+
+    let after_fraction = &input[len..];
+    if let Some(exponent) = after_fraction
+        .strip_prefix('e')
+        .or_else(|| after_fraction.strip_prefix('E'))
+    {
+        let signed = exponent
+            .strip_prefix('+')
+            .or_else(|| exponent.strip_prefix('-'))
+            .unwrap_or(exponent);
+        let exponent_digits = &signed[..digits_len(signed)];
+        if exponent_digits.contains(|c: char| c.is_ascii_digit()) {
+            is_float = true;
+            len = input.len() - signed.len() + exponent_digits.len();
+        }
+    }
+
+    let digits = &input[..len];
+    // A suffix (e.g. the `u8` in `10u8`) is part of the literal but not of its value
+    if input[len..].starts_with(is_ident_start) {
+        len += ident_len(&input[len..]);
+    }
+
+    if is_float {
+        (Token::Float(digits), len)
+    } else {
+        (Token::Int(digits), len)
+    }
+}
+
+struct Parser<'a> {
+    tokens: Vec<(Token<'a>, usize)>,
+    cursor: usize,
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.cursor).map(|(token, _)| *token)
+    }
+
+    fn peek_punct(&self, c: char) -> bool {
+        self.peek() == Some(Token::Punct(c))
+    }
+
+    fn peek_ident(&self) -> bool {
+        matches!(self.peek(), Some(Token::Ident(ident)) if !KEYWORDS.contains(&ident))
+    }
+
+    /// Peek a possibly negated literal, returning its digits and whether it is a float
+    fn peek_literal(&self) -> Option<(bool, &'a str, bool)> {
+        let literal = |token| match token {
+            Some(&(Token::Int(digits), _)) => Some((digits, false)),
+            Some(&(Token::Float(digits), _)) => Some((digits, true)),
+            _ => None,
+        };
+        if self.peek_punct('-') {
+            literal(self.tokens.get(self.cursor + 1)).map(|(d, float)| (true, d, float))
+        } else {
+            literal(self.tokens.get(self.cursor)).map(|(d, float)| (false, d, float))
+        }
+    }
+
+    fn error(&self) -> LiteParseError {
+        LiteParseError {
+            position: self
+                .tokens
+                .get(self.cursor)
+                .map_or(self.end, |&(_, position)| position),
+        }
+    }
+
+    /// Consume a literal and parse its value, which must be an integer literal if `N` is
+    fn parse_literal<N: FromStr>(&mut self, allow_float: bool) -> Result<N, LiteParseError> {
+        let Some((negative, digits, is_float)) = self.peek_literal() else {
+            return Err(self.error());
+        };
+        if is_float && !allow_float {
+            return Err(self.error());
+        }
+
+        let mut value = String::new();
+        if negative {
+            value.push('-');
+        }
+        value.extend(digits.chars().filter(|&c| c != '_'));
+        let value = value.parse().map_err(|_| self.error())?;
+
+        self.cursor += if negative { 2 } else { 1 };
+        Ok(value)
+    }
+
+    fn parse_expr(&mut self) -> Result<LiteUnitExpr<'a>, LiteParseError> {
+        let mut left = self.parse_factor()?;
+
+        // As in `UnitExpr`, at most one `/` is allowed at each level of parentheses
+        if self.peek_punct('/') {
+            self.cursor += 1;
+            let right = self.parse_factor()?;
+            left = LiteUnitExpr::Div(Box::new(left), Box::new(right));
+
+            if self.peek_punct('/') {
+                return Err(self.error());
+            }
+        }
+
+        Ok(left)
+    }
+
+    fn parse_factor(&mut self) -> Result<LiteUnitExpr<'a>, LiteParseError> {
+        let mut left = self.parse_power()?;
+        let mut previous_is_numeric = left.is_numeric();
+
+        // Handle both * and . as multiplication operators (UCUM format uses .)
+        // Juxtaposed units also multiply, as in `kg m / s^2`, however much whitespace separates
+        // them; a numeric term may also be followed directly by a unit or a parenthesized
+        // expression, as in `4.184 J` or `10^3 m`
+        loop {
+            if self.peek_punct('*') || self.peek_punct('.') {
+                self.cursor += 1;
+            } else if !(self.peek_ident()
+                || (previous_is_numeric && self.peek() == Some(Token::OpenParen)))
+            {
+                break;
+            }
+            let right = self.parse_power()?;
+            previous_is_numeric = right.is_numeric();
+            left = LiteUnitExpr::Mul(Box::new(left), Box::new(right));
+        }
+
+        Ok(left)
+    }
+
+    fn parse_power(&mut self) -> Result<LiteUnitExpr<'a>, LiteParseError> {
+        let base = self.parse_atom()?;
+
+        if self.peek_punct('^') {
+            self.cursor += 1;
+            let exponent = self.parse_literal(false)?;
+            Ok(LiteUnitExpr::Pow(Box::new(base), exponent))
+        } else {
+            Ok(base)
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<LiteUnitExpr<'a>, LiteParseError> {
+        if self.peek() == Some(Token::OpenParen) {
+            self.cursor += 1;
+            let inner = self.parse_expr()?;
+            if self.peek() != Some(Token::CloseParen) {
+                return Err(self.error());
+            }
+            self.cursor += 1;
+            return Ok(inner);
+        }
+
+        match self.peek_literal() {
+            // Numeric coefficients like "4.184" in "4.184 J"
+            Some((_, _, true)) => return Ok(LiteUnitExpr::Coefficient(self.parse_literal(true)?)),
+            Some((_, _, false)) => {
+                let base_value: i32 = self.parse_literal(false)?;
+
+                // Handle power-of-10 expressions like "10^4", and "10" as "10^1"
+                let exponent = if self.peek_punct('^') {
+                    self.cursor += 1;
+                    Some(self.parse_literal::<i32>(false)?)
+                } else {
+                    None
+                };
+                return Ok(match (base_value, exponent) {
+                    (10, exponent) => LiteUnitExpr::Unit {
+                        name: "power_of_10",
+                        exponent: exponent.unwrap_or(1) as i16,
+                    },
+                    (_, Some(exponent)) => LiteUnitExpr::Pow(
+                        Box::new(LiteUnitExpr::Coefficient(base_value as f64)),
+                        i16::try_from(exponent).map_err(|_| self.error())?,
+                    ),
+                    (_, None) => LiteUnitExpr::Coefficient(base_value as f64),
+                });
+            }
+            None => {}
+        }
+
+        let ident = match self.peek() {
+            Some(Token::Ident(ident)) if self.peek_ident() => ident,
+            _ => return Err(self.error()),
+        };
+        self.cursor += 1;
+
+        // Check for implicit exponent notation (UCUM format like "s2" instead of "s^2")
+        if let Some(pos) = ident.find(|c: char| c.is_ascii_digit())
+            && let Ok(exponent) = ident[pos..].parse::<i16>()
+        {
+            return Ok(LiteUnitExpr::Unit {
+                name: &ident[..pos],
+                exponent,
+            });
+        }
+        Ok(LiteUnitExpr::Unit {
+            name: ident,
+            exponent: 1,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::{Dimension, UnitExpr, calculate_unit_conversion_factors};
+
+    /// Assert that the lite parser and the `syn` parser agree on `input`
+    fn assert_matches_syn(input: &str) {
+        let syn_expr = syn::parse_str::<UnitExpr>(input);
+        let lite_expr = LiteUnitExpr::parse(input);
+        let (syn_expr, lite_expr) = match (syn_expr, lite_expr) {
+            (Ok(syn_expr), Ok(lite_expr)) => (syn_expr, lite_expr),
+            (Err(_), Err(_)) => return,
+            (syn_expr, lite_expr) => panic!(
+                "{input:?}: syn {}, lite {:?}",
+                if syn_expr.is_ok() { "parsed" } else { "failed" },
+                lite_expr
+            ),
+        };
+
+        for mode in [EvaluationMode::Strict, EvaluationMode::Tolerant] {
+            let syn_result = syn_expr.evaluate_with_mode(mode);
+            let lite_result = lite_expr.evaluate_with_mode(mode);
+            assert_eq!(
+                syn_result.dimension_exponents, lite_result.dimension_exponents,
+                "{input:?}"
+            );
+            assert_eq!(
+                syn_result.scale_exponents, lite_result.scale_exponents,
+                "{input:?}"
+            );
+        }
+        assert_eq!(
+            calculate_unit_conversion_factors(&syn_expr),
+            lite_expr.conversion_factors(),
+            "{input:?}"
+        );
+        let syn_identifiers: Vec<_> = syn_expr
+            .collect_unit_identifiers()
+            .iter()
+            .map(|ident| ident.to_string())
+            .collect();
+        assert_eq!(
+            syn_identifiers,
+            lite_expr.collect_unit_identifiers(),
+            "{input:?}"
+        );
+    }
+
+    #[test]
+    fn compound_units_match_syn() {
+        for input in [
+            "m/s2",
+            "m/s^2",
+            "m / s ^ 2",
+            "kg.m2/s2",
+            "kg * m^2 / s^2",
+            "m/s*s",
+            "(kg * m) / (s * s)",
+            "(m/s)/s",
+            "m^-2",
+            "m^ -2",
+            "1 / s",
+            "1/km",
+            "10^3 m",
+            "10 m",
+            "10^-3 g",
+            "2^3 m",
+            "4.184 J",
+            "4.184J",
+            "3.6 km / h",
+            "1e3 m",
+            "1.5e-3 s",
+            "1_000 m",
+            "100 km",
+            "1.m",
+            "-2 m",
+            "degC",
+            "degF / s",
+            "mi/h",
+            "kilometer / hour",
+            "µm2",
+            "um2",
+            "Ω.m",
+            "kW.h",
+            "mmHg",
+            "m2x",
+            "kg m / s^2",
+            "kg  m/s2",
+            "m s",
+            "N m",
+            "kW h",
+            "10^3 m s",
+            "4.184 J s",
+        ] {
+            assert_matches_syn(input);
+        }
+    }
+
+    #[test]
+    fn registered_symbols_match_syn() {
+        for dimension in Dimension::ALL {
+            for unit in dimension.units {
+                for symbol in unit.symbols.iter().chain([&unit.name]) {
+                    assert_matches_syn(symbol);
+                    assert_matches_syn(&std::format!("k{symbol}2 / {symbol}^3"));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_expressions_are_rejected() {
+        for input in [
+            "", "m /", "m (s)", "m ** 2", "m^2.5", "(m", "m)", "in", "m^in", "m..s", "m - s",
+            "m[2]", "m/s/s", "kg/m/s2",
+        ] {
+            assert!(LiteUnitExpr::parse(input).is_err(), "{input:?}");
+            assert_matches_syn(input);
+        }
+    }
+
+    #[test]
+    fn error_position_points_at_unexpected_token() {
+        assert_eq!(
+            LiteUnitExpr::parse("m / s )"),
+            Err(LiteParseError { position: 6 })
+        );
+        assert_eq!(
+            LiteUnitExpr::parse("m /"),
+            Err(LiteParseError { position: 3 })
+        );
+        assert_eq!(
+            LiteUnitExpr::parse("m/s/s"),
+            Err(LiteParseError { position: 3 })
+        );
+    }
+}
@@ -15,6 +15,9 @@
 /// - Addition: `<A as Add<B>>::Output`
 /// - Subtraction: `<A as Sub<B>>::Output`
 /// - Negation: `<X as Neg>::Output`
+/// - Exact division: `<X as Root<K>>::Output` (`X` divisible by `K` only)
+/// - Multiplication: `<X as Pow<K>>::Output`
+/// - Zero test: `<(X, Y, ..) as AllZero>::Output`
 ///
 /// Without the `cge` feature the operations are limited to inputs in the range -200 to 200.
 ///
@@ -33,6 +36,38 @@ impl<const X: i16> Num for N<X> {
     const N: i16 = X;
 }
 
+/// Exact division of a type-level number by `K`, as needed for a `K`th root.
+///
+/// Only implemented when `K` divides the number, so that a remainder is rejected at compile
+/// time rather than truncated.
+pub trait Root<const K: i16> {
+    /// This number divided by `K`.
+    type Output;
+}
+
+/// Multiplication of a type-level number by `K`, as needed for a `K`th power.
+pub trait Pow<const K: i16> {
+    /// This number multiplied by `K`.
+    type Output;
+}
+
+/// Whether every number in a tuple of type-level numbers is zero, e.g. whether a dimension
+/// cancels out entirely.
+///
+/// Implemented for tuples of nine numbers.
+pub trait AllZero {
+    /// `N<1>` if every number is zero, else `N<0>`.
+    type Output;
+}
+
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "exponent is not divisible by the degree of the root",
+    label = "indivisible exponent",
+    note = "taking a root of a quantity (e.g. a cube root) requires every exponent to be divisible by its degree"
+)]
+pub trait __Divisible {}
+
 mod num_seal {
     pub trait Sealed {}
 
@@ -55,7 +90,7 @@ pub trait __IntoNum {
 
 #[cfg(feature = "cge")]
 mod cge {
-    use super::N;
+    use super::{__Divisible, AllZero, N, Pow, Root};
 
     impl<const A: i16, const B: i16> core::ops::Add<N<B>> for N<A>
     where
@@ -89,13 +124,59 @@ mod cge {
             N
         }
     }
+
+    pub struct Divisible<const IS_DIVISIBLE: bool>;
+
+    impl __Divisible for Divisible<true> {}
+
+    impl<const X: i16, const K: i16> Root<K> for N<X>
+    where
+        Divisible<{ X % K == 0 }>: __Divisible,
+        [(); { X / K } as usize]:,
+    {
+        type Output = N<{ X / K }>;
+    }
+
+    impl<const X: i16, const K: i16> Pow<K> for N<X>
+    where
+        [(); { X * K } as usize]:,
+    {
+        type Output = N<{ X * K }>;
+    }
+
+    pub struct Zero<const IS_ZERO: bool>;
+
+    pub trait IsZero {
+        type Output;
+    }
+
+    impl IsZero for Zero<true> {
+        type Output = N<1>;
+    }
+
+    impl IsZero for Zero<false> {
+        type Output = N<0>;
+    }
+
+    macro_rules! __impl_all_zero {
+        ($($x:ident)*) => {
+            impl<$(const $x: i16),*> AllZero for ($(N<$x>,)*)
+            where
+                Zero<{ ($($x)|*) == 0 }>: IsZero,
+            {
+                type Output = <Zero<{ ($($x)|*) == 0 }> as IsZero>::Output;
+            }
+        };
+    }
+
+    __impl_all_zero!(A B C D E F G H I);
 }
 
 // Stable polyfill backed by typenum.
 #[cfg(not(feature = "cge"))]
 mod stable {
-    use super::{__AsTypenum, __IntoNum, N};
-    use core::ops::{Add, Neg, Sub};
+    use super::{__AsTypenum, __Divisible, __IntoNum, AllZero, N, Pow, Root};
+    use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
     macro_rules! __link_num_to_typenum {
         ($($num:literal => $repr:ty),* $(,)?) => {
@@ -1371,6 +1452,74 @@ mod stable {
             <<N<X> as __AsTypenum>::Repr as Neg>::Output::into_num()
         }
     }
+
+    // A zero remainder is the only divisible case; any other fails with the diagnostic on
+    // `__Divisible`
+    impl __Divisible for typenum::Z0 {}
+
+    impl<const X: i16, const K: i16> Root<K> for N<X>
+    where
+        N<X>: __AsTypenum,
+        N<K>: __AsTypenum,
+        <N<X> as __AsTypenum>::Repr:
+            Rem<<N<K> as __AsTypenum>::Repr> + Div<<N<K> as __AsTypenum>::Repr>,
+        <<N<X> as __AsTypenum>::Repr as Rem<<N<K> as __AsTypenum>::Repr>>::Output: __Divisible,
+        <<N<X> as __AsTypenum>::Repr as Div<<N<K> as __AsTypenum>::Repr>>::Output: __IntoNum,
+    {
+        type Output =
+            <<<N<X> as __AsTypenum>::Repr as Div<<N<K> as __AsTypenum>::Repr>>::Output as __IntoNum>::Num;
+    }
+
+    impl<const X: i16, const K: i16> Pow<K> for N<X>
+    where
+        N<X>: __AsTypenum,
+        N<K>: __AsTypenum,
+        <N<X> as __AsTypenum>::Repr: Mul<<N<K> as __AsTypenum>::Repr>,
+        <<N<X> as __AsTypenum>::Repr as Mul<<N<K> as __AsTypenum>::Repr>>::Output: __IntoNum,
+    {
+        type Output =
+            <<<N<X> as __AsTypenum>::Repr as Mul<<N<K> as __AsTypenum>::Repr>>::Output as __IntoNum>::Num;
+    }
+
+    // Zero test over a cons list of typenum integers, which unlike `N` can be told apart by
+    // their shape
+    pub trait AllZeroRepr {
+        type Output;
+    }
+
+    impl AllZeroRepr for () {
+        type Output = N<1>;
+    }
+
+    impl<Rest: AllZeroRepr> AllZeroRepr for (typenum::Z0, Rest) {
+        type Output = Rest::Output;
+    }
+
+    impl<U: typenum::Unsigned + typenum::NonZero, Rest> AllZeroRepr for (typenum::PInt<U>, Rest) {
+        type Output = N<0>;
+    }
+
+    impl<U: typenum::Unsigned + typenum::NonZero, Rest> AllZeroRepr for (typenum::NInt<U>, Rest) {
+        type Output = N<0>;
+    }
+
+    macro_rules! __impl_all_zero {
+        ($($x:ident)*) => {
+            impl<$(const $x: i16),*> AllZero for ($(N<$x>,)*)
+            where
+                $(N<$x>: __AsTypenum,)*
+                __impl_all_zero!(@repr $($x)*): AllZeroRepr,
+            {
+                type Output = <__impl_all_zero!(@repr $($x)*) as AllZeroRepr>::Output;
+            }
+        };
+        (@repr) => { () };
+        (@repr $x:ident $($rest:ident)*) => {
+            (<N<$x> as __AsTypenum>::Repr, __impl_all_zero!(@repr $($rest)*))
+        };
+    }
+
+    __impl_all_zero!(A B C D E F G H I);
 }
 
 #[cfg(test)]
@@ -1435,4 +1584,42 @@ mod tests {
         assert::<N<200>, N<-200>>();
         assert::<N<-200>, N<200>>();
     }
+
+    #[test]
+    fn can_divide_const_numbers_exactly() {
+        fn assert<N: Root<K, Output = O>, const K: i16, O>() {}
+
+        assert::<N<0>, 3, N<0>>();
+        assert::<N<3>, 3, N<1>>();
+        assert::<N<-6>, 3, N<-2>>();
+        assert::<N<8>, 4, N<2>>();
+        assert::<N<5>, 1, N<5>>();
+        assert::<N<10>, -5, N<-2>>();
+        assert::<N<198>, 3, N<66>>();
+        assert::<N<-200>, 5, N<-40>>();
+    }
+
+    #[test]
+    fn can_multiply_const_numbers() {
+        fn assert<N: Pow<K, Output = O>, const K: i16, O>() {}
+
+        assert::<N<0>, 3, N<0>>();
+        assert::<N<1>, 0, N<0>>();
+        assert::<N<1>, 2, N<2>>();
+        assert::<N<-1>, 2, N<-2>>();
+        assert::<N<3>, -2, N<-6>>();
+        assert::<N<-3>, -3, N<9>>();
+        assert::<N<100>, 2, N<200>>();
+        assert::<N<-40>, 5, N<-200>>();
+    }
+
+    #[test]
+    fn can_test_const_numbers_for_zero() {
+        fn assert<X: AllZero<Output = O>, O>() {}
+
+        assert::<(N<0>, N<0>, N<0>, N<0>, N<0>, N<0>, N<0>, N<0>, N<0>), N<1>>();
+        assert::<(N<1>, N<0>, N<0>, N<0>, N<0>, N<0>, N<0>, N<0>, N<0>), N<0>>();
+        assert::<(N<0>, N<0>, N<0>, N<0>, N<0>, N<0>, N<0>, N<0>, N<-1>), N<0>>();
+        assert::<(N<0>, N<0>, N<200>, N<0>, N<-200>, N<0>, N<0>, N<0>, N<0>), N<0>>();
+    }
 }
@@ -1,15 +1,18 @@
 #[cfg(not(test))]
 extern crate alloc;
 
+#[cfg(feature = "parser")]
 use syn::parse::{Parse, ParseStream, Result};
+#[cfg(feature = "parser")]
 use syn::token::{Caret, Dot, Slash, Star};
-use syn::{Ident, LitInt};
+#[cfg(feature = "parser")]
+use syn::{Ident, LitFloat, LitInt};
 
-#[cfg(not(test))]
+#[cfg(all(not(test), feature = "parser"))]
 use alloc::boxed::Box;
-#[cfg(not(test))]
+#[cfg(all(not(test), feature = "parser"))]
 use alloc::string::ToString;
-#[cfg(not(test))]
+#[cfg(all(not(test), feature = "parser"))]
 use alloc::vec::Vec;
 
 use crate::{
@@ -19,6 +22,7 @@ use crate::{
 };
 
 /// Represents a unit with optional exponent
+#[cfg(feature = "parser")]
 #[derive(Debug, Clone)]
 pub struct UnitExprUnit {
     pub name: Ident,
@@ -43,46 +47,82 @@ pub struct UnitEvaluationResult {
 }
 
 /// Represents a unit expression that can be parsed
+#[cfg(feature = "parser")]
 #[derive(Clone)]
 pub enum UnitExpr {
     Unit(UnitExprUnit),
+    /// A dimensionless numeric coefficient, e.g. the `4.184` in `4.184 J`.
+    ///
+    /// Coefficients belong to the unit, not to the quantity's value: they are folded into the
+    /// conversion factor, so `quantity!(2.0, 4.184 J)` is `8.368 J`.
+    Coefficient(f64),
     Mul(Box<UnitExpr>, Box<UnitExpr>),
     Div(Box<UnitExpr>, Box<UnitExpr>),
     Pow(Box<UnitExpr>, LitInt),
 }
 
+/// Error message for a unit expression with more than one `/` at the same level.
+#[cfg(feature = "parser")]
+const REPEATED_DIVISION_ERROR: &str = "a unit expression may contain at most one `/`, and every \
+     term after it is in the denominator; write `m/s^2` or `m/(s*s)` rather than `m/s/s`";
+
+#[cfg(feature = "parser")]
 impl Parse for UnitExpr {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut left = Self::parse_factor(input)?;
 
-        while input.peek(Slash) {
+        // Every term after the `/` is in the denominator, so a second `/` is ambiguous
+        if input.peek(Slash) {
             let _slash: Slash = input.parse()?;
             let right = Self::parse_factor(input)?;
             left = UnitExpr::Div(Box::new(left), Box::new(right));
+
+            if input.peek(Slash) {
+                return Err(input.error(REPEATED_DIVISION_ERROR));
+            }
         }
 
         Ok(left)
     }
 }
 
+#[cfg(feature = "parser")]
 impl UnitExpr {
     fn parse_factor(input: ParseStream) -> Result<Self> {
         let mut left = Self::parse_power(input)?;
+        let mut previous_is_numeric = left.is_numeric();
 
         // Handle both * and . as multiplication operators (UCUM format uses .)
-        while input.peek(Star) || input.peek(Dot) {
+        // Juxtaposed units also multiply, as in `kg m / s^2`, however much whitespace separates
+        // them; a numeric term may also be followed directly by a unit or a parenthesized
+        // expression, as in `4.184 J` or `10^3 m`
+        loop {
             if input.peek(Star) {
                 let _star: Star = input.parse()?;
             } else if input.peek(Dot) {
                 let _dot: Dot = input.parse()?;
+            } else if !(input.peek(Ident) || (previous_is_numeric && input.peek(syn::token::Paren)))
+            {
+                break;
             }
             let right = Self::parse_power(input)?;
+            previous_is_numeric = right.is_numeric();
             left = UnitExpr::Mul(Box::new(left), Box::new(right));
         }
 
         Ok(left)
     }
 
+    /// Whether this term is a bare number (a coefficient or power-of-10 scale factor)
+    fn is_numeric(&self) -> bool {
+        match self {
+            UnitExpr::Coefficient(_) => true,
+            UnitExpr::Unit(unit) => unit.name == "power_of_10",
+            UnitExpr::Pow(base, _) => base.is_numeric(),
+            UnitExpr::Mul(_, _) | UnitExpr::Div(_, _) => false,
+        }
+    }
+
     /// Collect all unit identifiers used in this expression
     pub fn collect_unit_identifiers(&self) -> Vec<Ident> {
         let mut identifiers = Vec::new();
@@ -95,6 +135,7 @@ impl UnitExpr {
             UnitExpr::Unit(unit) => {
                 identifiers.push(unit.name.clone());
             }
+            UnitExpr::Coefficient(_) => {}
             UnitExpr::Mul(a, b) => {
                 a.collect_identifiers_recursive(identifiers);
                 b.collect_identifiers_recursive(identifiers);
@@ -126,6 +167,10 @@ impl UnitExpr {
             let content;
             syn::parenthesized!(content in input);
             content.parse()
+        } else if input.peek(LitFloat) {
+            // Numeric coefficients like "4.184" in "4.184 J"
+            let lit: LitFloat = input.parse()?;
+            Ok(UnitExpr::Coefficient(lit.base10_parse()?))
         } else if input.peek(syn::LitInt) {
             // Handle numeric literals like "1" in "1 / m" or "10" in "10^4 m"
             let lit: syn::LitInt = input.parse()?;
@@ -147,12 +192,9 @@ impl UnitExpr {
                         exponent: exponent as i16,
                     }))
                 } else {
-                    // For other bases, treat as regular power expression
+                    // For other bases, treat as a power of a numeric coefficient
                     Ok(UnitExpr::Pow(
-                        Box::new(UnitExpr::Unit(UnitExprUnit {
-                            name: syn::Ident::new("dimensionless", proc_macro2::Span::call_site()),
-                            exponent: 1,
-                        })),
+                        Box::new(UnitExpr::Coefficient(base_value as f64)),
                         exponent_lit,
                     ))
                 }
@@ -165,11 +207,8 @@ impl UnitExpr {
                         exponent: 1,
                     }))
                 } else {
-                    // Other numeric literals - treat as dimensionless
-                    Ok(UnitExpr::Unit(UnitExprUnit {
-                        name: syn::Ident::new("dimensionless", proc_macro2::Span::call_site()),
-                        exponent: 1,
-                    }))
+                    // Other numeric literals are dimensionless coefficients (e.g. "1" in "1 / m")
+                    Ok(UnitExpr::Coefficient(base_value as f64))
                 }
             }
         } else {
@@ -177,7 +216,7 @@ impl UnitExpr {
 
             // Check for implicit exponent notation (UCUM format like "s2" instead of "s^2")
             let ident_str = ident.to_string();
-            if let Some(pos) = ident_str.chars().position(|c| c.is_ascii_digit()) {
+            if let Some(pos) = ident_str.find(|c: char| c.is_ascii_digit()) {
                 let base_name = &ident_str[..pos];
                 let exp_str = &ident_str[pos..];
                 if let Ok(exp) = exp_str.parse::<i16>() {
@@ -239,6 +278,15 @@ impl UnitExpr {
                 }
                 None
             }
+            UnitExpr::Coefficient(coefficient) => {
+                if *coefficient != 1.0 {
+                    return Some(alloc::format!(
+                        "Numeric coefficient '{}' cannot be used in `unit!` macro. Use `quantity!` macro instead, or use a storage unit.",
+                        coefficient
+                    ));
+                }
+                None
+            }
             UnitExpr::Mul(a, b) => a
                 .validate_strict_recursive()
                 .or_else(|| b.validate_strict_recursive()),
@@ -272,6 +320,15 @@ impl UnitExpr {
                 }
                 None
             }
+            UnitExpr::Coefficient(coefficient) => {
+                if *coefficient != 1.0 {
+                    return Some(std::format!(
+                        "Numeric coefficient '{}' cannot be used in `unit!` macro. Use `quantity!` macro instead, or use a storage unit.",
+                        coefficient
+                    ));
+                }
+                None
+            }
             UnitExpr::Mul(a, b) => a
                 .validate_strict_recursive()
                 .or_else(|| b.validate_strict_recursive()),
@@ -293,111 +350,128 @@ impl UnitExpr {
     /// Evaluate the unit expression with a specific evaluation mode
     pub fn evaluate_with_mode(&self, mode: EvaluationMode) -> UnitEvaluationResult {
         match self {
-            UnitExpr::Unit(unit) => {
-                // Handle special power-of-10 scale factors
-                if unit.name.to_string() == "power_of_10" {
-                    return UnitEvaluationResult {
-                        dimension_exponents: DynDimensionExponents::ZERO,
-                        scale_exponents: ScaleExponents::_10(unit.exponent),
-                    };
-                }
+            UnitExpr::Unit(unit) => evaluate_unit(&unit.name.to_string(), unit.exponent, mode),
+            UnitExpr::Coefficient(_) => UnitEvaluationResult::DIMENSIONLESS,
+            UnitExpr::Mul(a, b) => a.evaluate_with_mode(mode).mul(b.evaluate_with_mode(mode)),
+            UnitExpr::Div(a, b) => a.evaluate_with_mode(mode).div(b.evaluate_with_mode(mode)),
+            UnitExpr::Pow(base, exp) => base
+                .evaluate_with_mode(mode)
+                .pow(exp.base10_parse().unwrap()),
+        }
+    }
+}
 
-                if let Some(unit_info) = get_unit_info(&unit.name.to_string()) {
-                    // In strict mode, nonstorage units should have been caught by validate_strict()
-                    // But we still need to handle them here for safety - treat as unknown/dimensionless
-                    if mode == EvaluationMode::Strict && unit_info.conversion_factor != 1.0 {
-                        // This shouldn't happen if validate_strict() was called, but handle gracefully
-                        return UnitEvaluationResult {
-                            dimension_exponents: DynDimensionExponents::ZERO,
-                            scale_exponents: ScaleExponents::IDENTITY,
-                        };
-                    }
-                    // Get the dimension exponents and scale exponents from the unit
-                    let mut dimension_exponents = unit_info.exponents.value();
-                    let mut scale_exponents = unit_info.scale;
-
-                    // Check if this is a prefixed unit and adjust scale factors accordingly
-                    // BUT ONLY if the unit name is NOT a valid unit symbol by itself
-                    let is_valid_unit_symbol =
-                        Dimension::find_unit_by_symbol(&unit.name.to_string()).is_some();
-                    if !is_valid_unit_symbol {
-                        // Try all prefixes until we find one with a valid base unit
-                        for prefix in SiPrefix::ALL {
-                            // Try prefix symbol first (e.g., "kW" -> "W")
-                            if let Some(base) = prefix.strip_prefix_symbol(&unit.name.to_string()) {
-                                if !base.is_empty() {
-                                    // Check if the base unit exists
-                                    if Dimension::find_unit_by_symbol(base).is_some() {
-                                        let prefix_factor = prefix.factor_log10();
-                                        // Apply the prefix factor to the scale factors (powers of 2 and 5 for log10)
-                                        scale_exponents =
-                                            scale_exponents.mul(ScaleExponents::_10(prefix_factor));
-                                        break;
-                                    }
-                                }
-                            }
-                            // Try prefix name (e.g., "kilowatt" -> "watt")
-                            if let Some(base) = prefix.strip_prefix_name(&unit.name.to_string()) {
-                                if !base.is_empty() {
-                                    // Check if the base unit exists by name
-                                    if Dimension::find_unit_by_name(base).is_some() {
-                                        let prefix_factor = prefix.factor_log10();
-                                        // Apply the prefix factor to the scale factors (powers of 2 and 5 for log10)
-                                        scale_exponents =
-                                            scale_exponents.mul(ScaleExponents::_10(prefix_factor));
-                                        break;
-                                    }
-                                }
-                            }
-                        }
-                    }
+impl UnitEvaluationResult {
+    /// The result for a dimensionless, unscaled term such as a numeric coefficient
+    pub(crate) const DIMENSIONLESS: Self = UnitEvaluationResult {
+        dimension_exponents: DynDimensionExponents::ZERO,
+        scale_exponents: ScaleExponents::IDENTITY,
+    };
+
+    pub(crate) fn mul(self, rhs: Self) -> Self {
+        UnitEvaluationResult {
+            dimension_exponents: self.dimension_exponents + rhs.dimension_exponents,
+            scale_exponents: self.scale_exponents.mul(rhs.scale_exponents),
+        }
+    }
 
-                    // Apply the unit exponent to both dimension and scale exponents
-                    if unit.exponent != 1 {
-                        dimension_exponents = dimension_exponents * unit.exponent;
-                        scale_exponents = scale_exponents.scalar_exp(unit.exponent);
-                    }
+    pub(crate) fn div(self, rhs: Self) -> Self {
+        UnitEvaluationResult {
+            dimension_exponents: self.dimension_exponents + (-rhs.dimension_exponents),
+            scale_exponents: self.scale_exponents.mul(rhs.scale_exponents.neg()),
+        }
+    }
 
-                    UnitEvaluationResult {
-                        dimension_exponents,
-                        scale_exponents,
-                    }
-                } else {
-                    // Handle dimensionless or unknown units
-                    UnitEvaluationResult {
-                        dimension_exponents: DynDimensionExponents::ZERO,
-                        scale_exponents: ScaleExponents::IDENTITY,
+    pub(crate) fn pow(self, exponent: i16) -> Self {
+        UnitEvaluationResult {
+            dimension_exponents: self.dimension_exponents * exponent,
+            scale_exponents: self.scale_exponents.scalar_exp(exponent),
+        }
+    }
+}
+
+/// Evaluate a single (possibly prefixed and exponentiated) unit identifier
+///
+/// In strict mode, nonstorage units are treated as unknown/dimensionless.
+pub(crate) fn evaluate_unit(
+    name: &str,
+    exponent: i16,
+    mode: EvaluationMode,
+) -> UnitEvaluationResult {
+    // Handle special power-of-10 scale factors
+    if name == "power_of_10" {
+        return UnitEvaluationResult {
+            dimension_exponents: DynDimensionExponents::ZERO,
+            scale_exponents: ScaleExponents::_10(exponent),
+        };
+    }
+
+    let Some(unit_info) = get_unit_info(name) else {
+        // Handle dimensionless or unknown units
+        return UnitEvaluationResult::DIMENSIONLESS;
+    };
+
+    // In strict mode, nonstorage units should have been caught by validate_strict()
+    // But we still need to handle them here for safety - treat as unknown/dimensionless
+    if mode == EvaluationMode::Strict && unit_info.conversion_factor != 1.0 {
+        // This shouldn't happen if validate_strict() was called, but handle gracefully
+        return UnitEvaluationResult::DIMENSIONLESS;
+    }
+    // Get the dimension exponents and scale exponents from the unit
+    let mut dimension_exponents = unit_info.exponents.value();
+    let mut scale_exponents = unit_info.scale;
+
+    // Check if this is a prefixed unit and adjust scale factors accordingly
+    // BUT ONLY if the unit name is NOT a valid unit symbol by itself
+    let is_valid_unit_symbol = Dimension::find_unit_by_symbol(name).is_some();
+    let binary_prefixed = if is_valid_unit_symbol {
+        None
+    } else {
+        Dimension::find_binary_prefixed_unit(name)
+    };
+    if let Some((_unit, prefix)) = binary_prefixed {
+        // Binary prefixes (e.g., "KiB" -> "B") are powers of 2^10, so only the power of 2 changes
+        scale_exponents = scale_exponents.mul(ScaleExponents::_2(prefix.factor_log2()));
+    } else if !is_valid_unit_symbol {
+        // Try all prefixes until we find one with a valid base unit
+        for prefix in SiPrefix::ALL {
+            // Try prefix symbol first (e.g., "kW" -> "W")
+            if let Some(base) = prefix.strip_prefix_symbol(name) {
+                if !base.is_empty() {
+                    // Check if the base unit exists
+                    if Dimension::find_unit_by_symbol(base).is_some() {
+                        let prefix_factor = prefix.factor_log10();
+                        // Apply the prefix factor to the scale factors (powers of 2 and 5 for log10)
+                        scale_exponents = scale_exponents.mul(ScaleExponents::_10(prefix_factor));
+                        break;
                     }
                 }
             }
-            UnitExpr::Mul(a, b) => {
-                let result_a = a.evaluate_with_mode(mode);
-                let result_b = b.evaluate_with_mode(mode);
-                UnitEvaluationResult {
-                    dimension_exponents: result_a.dimension_exponents
-                        + result_b.dimension_exponents,
-                    scale_exponents: result_a.scale_exponents.mul(result_b.scale_exponents),
-                }
-            }
-            UnitExpr::Div(a, b) => {
-                let result_a = a.evaluate_with_mode(mode);
-                let result_b = b.evaluate_with_mode(mode);
-                UnitEvaluationResult {
-                    dimension_exponents: result_a.dimension_exponents
-                        + (-result_b.dimension_exponents),
-                    scale_exponents: result_a.scale_exponents.mul(result_b.scale_exponents.neg()),
-                }
-            }
-            UnitExpr::Pow(base, exp) => {
-                let result = base.evaluate_with_mode(mode);
-                let exp_val: i16 = exp.base10_parse().unwrap();
-                UnitEvaluationResult {
-                    dimension_exponents: result.dimension_exponents * exp_val,
-                    scale_exponents: result.scale_exponents.scalar_exp(exp_val),
+            // Try prefix name (e.g., "kilowatt" -> "watt")
+            if let Some(base) = prefix.strip_prefix_name(name) {
+                if !base.is_empty() {
+                    // Check if the base unit exists by name
+                    if Dimension::find_unit_by_name(base).is_some() {
+                        let prefix_factor = prefix.factor_log10();
+                        // Apply the prefix factor to the scale factors (powers of 2 and 5 for log10)
+                        scale_exponents = scale_exponents.mul(ScaleExponents::_10(prefix_factor));
+                        break;
+                    }
                 }
             }
         }
     }
+
+    // Apply the unit exponent to both dimension and scale exponents
+    if exponent != 1 {
+        dimension_exponents = dimension_exponents * exponent;
+        scale_exponents = scale_exponents.scalar_exp(exponent);
+    }
+
+    UnitEvaluationResult {
+        dimension_exponents,
+        scale_exponents,
+    }
 }
 
 /// Calculate conversion factor and affine offset from a parsed unit expression
@@ -408,90 +482,112 @@ impl UnitExpr {
 /// - Multiplication: conversion factors multiply, affine offsets propagate
 /// - Division: conversion factors divide, affine offsets are divided
 /// - Exponentiation: conversion factors are raised to power, affine offsets are multiplied
+#[cfg(feature = "parser")]
 pub fn calculate_unit_conversion_factors(expr: &UnitExpr) -> (f64, f64) {
     calculate_conversion_factors_recursive(expr)
 }
 
 /// Recursively calculate conversion factors and affine offsets from a UnitExpr
+#[cfg(feature = "parser")]
 fn calculate_conversion_factors_recursive(expr: &UnitExpr) -> (f64, f64) {
     match expr {
-        UnitExpr::Unit(unit) => {
-            // Skip special units
-            if unit.name.to_string() == "power_of_10" || unit.name.to_string() == "dimensionless" {
-                return (1.0, 0.0);
-            }
+        UnitExpr::Unit(unit) => unit_conversion_factors(&unit.name.to_string(), unit.exponent),
+        // Coefficients fold directly into the conversion factor
+        UnitExpr::Coefficient(coefficient) => (*coefficient, 0.0),
+        UnitExpr::Mul(a, b) => mul_conversion_factors(
+            calculate_conversion_factors_recursive(a),
+            calculate_conversion_factors_recursive(b),
+        ),
+        UnitExpr::Div(a, b) => div_conversion_factors(
+            calculate_conversion_factors_recursive(a),
+            calculate_conversion_factors_recursive(b),
+        ),
+        UnitExpr::Pow(base, exp) => pow_conversion_factors(
+            calculate_conversion_factors_recursive(base),
+            exp.base10_parse().unwrap_or(1),
+        ),
+    }
+}
 
-            if let Some(unit_info) = get_unit_info(&unit.name.to_string()) {
-                let mut conversion_factor = unit_info.conversion_factor;
-                let mut affine_offset = unit_info.affine_offset;
+/// Conversion factor and affine offset of a single unit identifier
+pub(crate) fn unit_conversion_factors(name: &str, exponent: i16) -> (f64, f64) {
+    // Skip special units
+    if name == "power_of_10" || name == "dimensionless" {
+        return (1.0, 0.0);
+    }
 
-                // Apply exponent if present
-                if unit.exponent != 1 {
-                    // For exponents, conversion factor is raised to the power
-                    conversion_factor = conversion_factor.powi(unit.exponent as i32);
-                    // Affine offset is multiplied by the exponent (for temperature scales, etc.)
-                    affine_offset = affine_offset * unit.exponent as f64;
-                }
+    if let Some(unit_info) = get_unit_info(name) {
+        let mut conversion_factor = unit_info.conversion_factor;
+        let mut affine_offset = unit_info.affine_offset;
 
-                (conversion_factor, affine_offset)
-            } else {
-                // Unknown unit - assume storage unit
-                (1.0, 0.0)
-            }
-        }
-        UnitExpr::Mul(a, b) => {
-            let (cf_a, af_a) = calculate_conversion_factors_recursive(a);
-            let (cf_b, af_b) = calculate_conversion_factors_recursive(b);
-            // For multiplication: conversion factors multiply
-            // Affine offsets: only one unit should have an affine offset (typically temperature)
-            // If both have affine offsets, we combine them (though this is unusual)
-            // The affine offset applies to the entire product
-            let total_affine = if af_a != 0.0 && af_b != 0.0 {
-                // Both have affine offsets - this is unusual but we'll combine them
-                // This typically doesn't make physical sense, but we handle it
-                af_a * cf_b + af_b * cf_a
-            } else if af_a != 0.0 {
-                // If a has affine offset, it applies to the whole product
-                af_a * cf_b
-            } else {
-                // If b has affine offset, it applies to the whole product
-                af_b * cf_a
-            };
-            (cf_a * cf_b, total_affine)
-        }
-        UnitExpr::Div(a, b) => {
-            let (cf_a, af_a) = calculate_conversion_factors_recursive(a);
-            let (cf_b, af_b) = calculate_conversion_factors_recursive(b);
-            // For division: conversion factors divide
-            // Affine offsets: if denominator has affine offset, it's complex
-            // If numerator has affine offset, it's divided by the denominator's conversion factor
-            if af_b != 0.0 {
-                // Denominator has affine offset - this is complex and typically invalid
-                // For now, we'll treat it as an error case by returning a large value
-                // In practice, this should be caught earlier, but we handle it gracefully
-                (cf_a / cf_b, af_a / cf_b)
-            } else {
-                let total_affine = if af_a != 0.0 {
-                    // Numerator affine offset is divided by denominator conversion factor
-                    af_a / cf_b
-                } else {
-                    0.0
-                };
-                (cf_a / cf_b, total_affine)
-            }
-        }
-        UnitExpr::Pow(base, exp) => {
-            let (cf, af) = calculate_conversion_factors_recursive(base);
-            let exp_val: i16 = exp.base10_parse().unwrap_or(1);
-            // Conversion factor raised to power
-            let new_cf = cf.powi(exp_val as i32);
-            // Affine offset multiplied by exponent
-            let new_af = af * exp_val as f64;
-            (new_cf, new_af)
+        // Apply exponent if present
+        if exponent != 1 {
+            // For exponents, conversion factor is raised to the power
+            conversion_factor = crate::powi(conversion_factor, exponent as i32);
+            // Affine offset is multiplied by the exponent (for temperature scales, etc.)
+            affine_offset = affine_offset * exponent as f64;
         }
+
+        (conversion_factor, affine_offset)
+    } else {
+        // Unknown unit - assume storage unit
+        (1.0, 0.0)
     }
 }
 
+pub(crate) fn mul_conversion_factors(
+    (cf_a, af_a): (f64, f64),
+    (cf_b, af_b): (f64, f64),
+) -> (f64, f64) {
+    // For multiplication: conversion factors multiply
+    // Affine offsets: only one unit should have an affine offset (typically temperature)
+    // If both have affine offsets, we combine them (though this is unusual)
+    // The affine offset applies to the entire product
+    let total_affine = if af_a != 0.0 && af_b != 0.0 {
+        // Both have affine offsets - this is unusual but we'll combine them
+        // This typically doesn't make physical sense, but we handle it
+        af_a * cf_b + af_b * cf_a
+    } else if af_a != 0.0 {
+        // If a has affine offset, it applies to the whole product
+        af_a * cf_b
+    } else {
+        // If b has affine offset, it applies to the whole product
+        af_b * cf_a
+    };
+    (cf_a * cf_b, total_affine)
+}
+
+pub(crate) fn div_conversion_factors(
+    (cf_a, af_a): (f64, f64),
+    (cf_b, af_b): (f64, f64),
+) -> (f64, f64) {
+    // For division: conversion factors divide
+    // Affine offsets: if denominator has affine offset, it's complex
+    // If numerator has affine offset, it's divided by the denominator's conversion factor
+    if af_b != 0.0 {
+        // Denominator has affine offset - this is complex and typically invalid
+        // For now, we'll treat it as an error case by returning a large value
+        // In practice, this should be caught earlier, but we handle it gracefully
+        (cf_a / cf_b, af_a / cf_b)
+    } else {
+        let total_affine = if af_a != 0.0 {
+            // Numerator affine offset is divided by denominator conversion factor
+            af_a / cf_b
+        } else {
+            0.0
+        };
+        (cf_a / cf_b, total_affine)
+    }
+}
+
+pub(crate) fn pow_conversion_factors((cf, af): (f64, f64), exponent: i16) -> (f64, f64) {
+    // Conversion factor raised to power
+    let new_cf = crate::powi(cf, exponent as i32);
+    // Affine offset multiplied by exponent
+    let new_af = af * exponent as f64;
+    (new_cf, new_af)
+}
+
 /// Get unit information for a unit name, handling prefixes and conversions
 /// Returns the complete Unit struct with dimensions and scale factors
 pub fn get_unit_info(unit_name: &str) -> Option<&'static Unit> {
@@ -510,13 +606,22 @@ pub fn get_unit_info(unit_name: &str) -> Option<&'static Unit> {
         return Some(unit);
     }
 
+    // Units of information also take binary prefixes (like KiB, Mibit)
+    if let Some((unit, _prefix)) = Dimension::find_binary_prefixed_unit(unit_name) {
+        return Some(unit);
+    }
+
     // Then check if this is a prefixed unit (like kg, kW, mm, etc.)
-    // Only allow prefixing of base units (first unit in each dimension) and only for metric units
+    // Only allow prefixing of base units (first unit in each dimension) and only for metric units,
+    // or of any unit of information (like kB, Mbit)
     for prefix in SiPrefix::ALL {
         if let Some(base) = prefix.strip_prefix_symbol(unit_name) {
             if !base.is_empty() {
                 // Check if the base unit exists and is a base unit (first unit in its dimension)
                 if let Some((unit, dimension)) = Dimension::find_unit_by_symbol(base) {
+                    if unit.system == crate::System::Information {
+                        return Some(unit);
+                    }
                     // Check if this is the first unit in its dimension (base unit)
                     if dimension
                         .units
@@ -536,6 +641,9 @@ pub fn get_unit_info(unit_name: &str) -> Option<&'static Unit> {
             if !base.is_empty() {
                 // Check if the base unit exists by name and is a base unit
                 if let Some((unit, dimension)) = Dimension::find_unit_by_name(base) {
+                    if unit.system == crate::System::Information {
+                        return Some(unit);
+                    }
                     // Check if this is the first unit in its dimension (base unit)
                     if dimension
                         .units
@@ -556,3 +664,101 @@ pub fn get_unit_info(unit_name: &str) -> Option<&'static Unit> {
     // If not found, return None
     None
 }
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> UnitExpr {
+        syn::parse_str::<UnitExpr>(input).unwrap()
+    }
+
+    #[test]
+    fn coefficient_folds_into_conversion_factor() {
+        let expr = parse("4.184 J");
+        let (factor, offset) = calculate_unit_conversion_factors(&expr);
+        assert!((factor - 4.184).abs() < 1e-12);
+        assert_eq!(offset, 0.0);
+
+        let result = expr.evaluate_with_mode(EvaluationMode::Tolerant);
+        assert_eq!(
+            result.dimension_exponents,
+            parse("J").evaluate().dimension_exponents
+        );
+    }
+
+    #[test]
+    fn integer_coefficient_keeps_unit_scale() {
+        let expr = parse("100 km");
+        let (factor, _) = calculate_unit_conversion_factors(&expr);
+        assert_eq!(factor, 100.0);
+        assert_eq!(
+            expr.evaluate_with_mode(EvaluationMode::Tolerant)
+                .scale_exponents,
+            parse("km").evaluate().scale_exponents
+        );
+    }
+
+    #[test]
+    fn power_of_ten_is_still_a_scale() {
+        let expr = parse("10^3 m");
+        assert_eq!(calculate_unit_conversion_factors(&expr).0, 1.0);
+        assert_eq!(
+            expr.evaluate().scale_exponents,
+            parse("km").evaluate().scale_exponents
+        );
+    }
+
+    #[test]
+    fn unit_numerator_is_not_a_coefficient() {
+        let expr = parse("1 / s");
+        assert_eq!(calculate_unit_conversion_factors(&expr).0, 1.0);
+        assert!(expr.validate_strict().is_none());
+    }
+
+    #[test]
+    fn strict_mode_rejects_coefficients() {
+        assert!(parse("4.184 J").validate_strict().is_some());
+        assert!(parse("2^3 m").validate_strict().is_some());
+    }
+
+    #[test]
+    fn whitespace_between_units_multiplies() {
+        let expected = parse("kg * m / s^2").evaluate();
+        for input in ["kg m / s^2", "kg  m/s2", "kg m2 s / s^3 m"] {
+            let result = parse(input).evaluate();
+            assert_eq!(result.dimension_exponents, expected.dimension_exponents);
+            assert_eq!(result.scale_exponents, expected.scale_exponents);
+        }
+
+        // `m s` is a meter-second, not the millisecond `ms`
+        assert_eq!(
+            parse("m s").evaluate().dimension_exponents,
+            parse("m * s").evaluate().dimension_exponents
+        );
+        assert_ne!(
+            parse("m s").evaluate().dimension_exponents,
+            parse("ms").evaluate().dimension_exponents
+        );
+    }
+
+    #[test]
+    fn repeated_division_is_rejected() {
+        let Err(error) = syn::parse_str::<UnitExpr>("m/s/s") else {
+            panic!("repeated division was accepted");
+        };
+        assert!(error.to_string().contains("m/s^2"), "{error}");
+        assert!(syn::parse_str::<UnitExpr>("kg / m / s2").is_err());
+
+        // A single division puts every following term in the denominator
+        assert_eq!(
+            parse("m/s*s").evaluate().dimension_exponents,
+            parse("m/s^2").evaluate().dimension_exponents
+        );
+        // Parentheses start a new level, which may have its own division
+        assert_eq!(
+            parse("(m/s)/s").evaluate().dimension_exponents,
+            parse("m/s^2").evaluate().dimension_exponents
+        );
+    }
+}
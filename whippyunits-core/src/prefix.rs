@@ -328,6 +328,135 @@ impl SiPrefix {
     };
 }
 
+/// Binary (IEC) prefix information.
+///
+/// Binary prefixes multiply by powers of 1024 and apply only to units of information
+/// (`KiB`, `Mibit`).  Since `1024 = 2^10`, their scale is carried entirely by the `_2`
+/// scale exponent.  These prefixes are defined by IEC 80000-13.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BinaryPrefix {
+    // We make these private since the constants define all instances.
+    factor_log2: i16,
+    name: &'static str,
+    symbol: &'static str,
+}
+
+impl BinaryPrefix {
+    /// The multiplying factor exponent, as a power of 2.
+    ///
+    /// For example, kibi's multiplying factor of `2^10` would make this field `10`.
+    pub const fn factor_log2(&self) -> i16 {
+        self.factor_log2
+    }
+
+    /// Name of the prefix.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Prefix symbol.
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// List of all binary prefix definitions.
+    pub const ALL: &[BinaryPrefix] = &Self::ALL_ARRAY;
+
+    const ALL_ARRAY: [BinaryPrefix; 8] = [
+        Self::KIBI,
+        Self::MEBI,
+        Self::GIBI,
+        Self::TEBI,
+        Self::PEBI,
+        Self::EXBI,
+        Self::ZEBI,
+        Self::YOBI,
+    ];
+
+    /// Look up binary prefix by symbol.
+    pub fn from_symbol(symbol: &str) -> Option<&'static Self> {
+        Self::ALL.iter().find(|prefix| prefix.symbol == symbol)
+    }
+
+    /// Strip the prefix name from a string.
+    pub fn strip_prefix_name<'r>(&self, s: &'r str) -> Option<&'r str> {
+        s.strip_prefix(self.name)
+    }
+
+    /// Strip the prefix symbol from a string.
+    pub fn strip_prefix_symbol<'r>(&self, s: &'r str) -> Option<&'r str> {
+        s.strip_prefix(self.symbol)
+    }
+
+    pub fn strip_any_prefix_name(s: &str) -> Option<(&'static Self, &str)> {
+        Self::ALL.iter().find_map(|prefix| {
+            prefix
+                .strip_prefix_name(s)
+                .filter(|base| !base.is_empty())
+                .map(|base| (prefix, base))
+        })
+    }
+
+    pub fn strip_any_prefix_symbol(s: &str) -> Option<(&'static Self, &str)> {
+        Self::ALL.iter().find_map(|prefix| {
+            prefix
+                .strip_prefix_symbol(s)
+                .filter(|base| !base.is_empty())
+                .map(|base| (prefix, base))
+        })
+    }
+}
+
+impl BinaryPrefix {
+    pub const KIBI: Self = Self {
+        symbol: "Ki",
+        factor_log2: 10,
+        name: "kibi",
+    };
+
+    pub const MEBI: Self = Self {
+        symbol: "Mi",
+        factor_log2: 20,
+        name: "mebi",
+    };
+
+    pub const GIBI: Self = Self {
+        symbol: "Gi",
+        factor_log2: 30,
+        name: "gibi",
+    };
+
+    pub const TEBI: Self = Self {
+        symbol: "Ti",
+        factor_log2: 40,
+        name: "tebi",
+    };
+
+    pub const PEBI: Self = Self {
+        symbol: "Pi",
+        factor_log2: 50,
+        name: "pebi",
+    };
+
+    pub const EXBI: Self = Self {
+        symbol: "Ei",
+        factor_log2: 60,
+        name: "exbi",
+    };
+
+    pub const ZEBI: Self = Self {
+        symbol: "Zi",
+        factor_log2: 70,
+        name: "zebi",
+    };
+
+    pub const YOBI: Self = Self {
+        symbol: "Yi",
+        factor_log2: 80,
+        name: "yobi",
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -381,4 +510,23 @@ mod tests {
         );
         assert_eq!(SiPrefix::strip_any_prefix_name("?"), None);
     }
+
+    #[test]
+    fn can_strip_binary_prefix_from_str() {
+        assert_eq!(
+            BinaryPrefix::strip_any_prefix_symbol("KiB"),
+            Some((&BinaryPrefix::KIBI, "B"))
+        );
+        assert_eq!(
+            BinaryPrefix::strip_any_prefix_name("mebibyte"),
+            Some((&BinaryPrefix::MEBI, "byte"))
+        );
+        assert_eq!(BinaryPrefix::strip_any_prefix_symbol("Ki"), None);
+        assert_eq!(BinaryPrefix::strip_any_prefix_symbol("kB"), None);
+
+        for prefix in BinaryPrefix::ALL {
+            assert_eq!(BinaryPrefix::from_symbol(prefix.symbol).unwrap(), prefix);
+            assert_eq!(prefix.factor_log2() % 10, 0);
+        }
+    }
 }
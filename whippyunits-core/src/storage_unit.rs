@@ -188,7 +188,7 @@ pub fn generate_systematic_unit_name_with_format_and_scale(
     format: UnitFormat,
 ) -> String {
     // Convert Vec<i16> to DynDimensionExponents for the core function
-    if exponents.len() != 8 {
+    if exponents.len() != 9 {
         return "?".to_string();
     }
 
@@ -201,6 +201,7 @@ pub fn generate_systematic_unit_name_with_format_and_scale(
         exponents[5],
         exponents[6],
         exponents[7],
+        exponents[8],
     ]);
 
     // Use the centralized logic from whippyunits-core
@@ -220,12 +221,71 @@ pub fn generate_systematic_unit_name_with_format_and_scale(
     }
 }
 
-/// Convert Unicode format unit string to UCUM format
-fn convert_unicode_to_ucum_format(unicode_unit: &str) -> String {
-    // This is a simplified conversion - in practice, you might need more sophisticated logic
-    // For now, just return the unicode format as-is since the core logic already handles
-    // the basic formatting correctly
-    unicode_unit.to_string()
+/// Convert a Unicode format unit string (such as `(kg⁻³·m⁵)`) to UCUM format (`m5/kg3`)
+///
+/// Terms with positive exponents form the numerator and terms with negative exponents the
+/// denominator, so the result is always a product of base units that the unit literal parser
+/// accepts; a dimensionless unit is written `1`.  Terms whose exponent cannot be read (such as
+/// unresolved `ˀ` exponents) are kept as they are, in the numerator.
+pub fn convert_unicode_to_ucum_format(unicode_unit: &str) -> String {
+    let inner = unicode_unit
+        .strip_prefix('(')
+        .and_then(|unit| unit.strip_suffix(')'))
+        .unwrap_or(unicode_unit);
+
+    let mut numerator: Vec<String> = Vec::new();
+    let mut denominator: Vec<String> = Vec::new();
+    for term in inner.split('·').filter(|term| !term.is_empty()) {
+        let base = term.trim_end_matches(|c| from_unicode_superscript_digit(c).is_some());
+        let superscript = &term[base.len()..];
+        let exponent: Option<i32> = if superscript.is_empty() {
+            Some(1)
+        } else {
+            superscript
+                .chars()
+                .filter_map(from_unicode_superscript_digit)
+                .collect::<String>()
+                .parse()
+                .ok()
+        };
+        match exponent {
+            Some(1) => numerator.push(base.to_string()),
+            Some(-1) => denominator.push(base.to_string()),
+            Some(exp) if exp > 0 => numerator.push(format!("{}{}", base, exp)),
+            Some(exp) if exp < 0 => denominator.push(format!("{}{}", base, -exp)),
+            Some(_) => {}
+            None => numerator.push(term.to_string()),
+        }
+    }
+
+    let numerator = if numerator.is_empty() {
+        "1".to_string()
+    } else {
+        numerator.join(".")
+    };
+    if denominator.is_empty() {
+        numerator
+    } else {
+        format!("{}/{}", numerator, denominator.join("."))
+    }
+}
+
+/// The ASCII digit or sign for a Unicode superscript digit or minus sign
+fn from_unicode_superscript_digit(c: char) -> Option<char> {
+    match c {
+        '⁻' => Some('-'),
+        '⁰' => Some('0'),
+        '¹' => Some('1'),
+        '²' => Some('2'),
+        '³' => Some('3'),
+        '⁴' => Some('4'),
+        '⁵' => Some('5'),
+        '⁶' => Some('6'),
+        '⁷' => Some('7'),
+        '⁸' => Some('8'),
+        '⁹' => Some('9'),
+        _ => None,
+    }
 }
 
 /// Look up a unit literal by its dimension exponents and scale factors
@@ -235,7 +295,7 @@ pub fn lookup_unit_literal_by_scale_factors(
     long_name: bool,
 ) -> Option<&'static str> {
     // Convert Vec<i16> to DynDimensionExponents for comparison
-    if exponents.len() != 8 {
+    if exponents.len() != 9 {
         return None;
     }
 
@@ -248,6 +308,7 @@ pub fn lookup_unit_literal_by_scale_factors(
         exponents[5],
         exponents[6],
         exponents[7],
+        exponents[8],
     ]);
 
     if let Some(dimension) = Dimension::find_dimension_by_exponents(dyn_exponents) {
@@ -290,7 +351,7 @@ pub struct DimensionNames {
 /// Look up dimension name
 pub fn lookup_dimension_name(exponents: Vec<i16>) -> Option<DimensionNames> {
     // Convert Vec<i16> to DynDimensionExponents for lookup
-    if exponents.len() != 8 {
+    if exponents.len() != 9 {
         return None;
     }
 
@@ -303,6 +364,7 @@ pub fn lookup_dimension_name(exponents: Vec<i16>) -> Option<DimensionNames> {
         exponents[5],
         exponents[6],
         exponents[7],
+        exponents[8],
     ]);
 
     // Use Dimension::find_dimension_by_exponents directly
@@ -323,13 +385,23 @@ pub fn lookup_dimension_name(exponents: Vec<i16>) -> Option<DimensionNames> {
             }
         }
 
+        // Only storage units can name a stored value; a dimension whose named units are all
+        // nonstorage (velocity, with just the knot) is left to systematic generation
+        let storage_units = || {
+            dim_info
+                .units
+                .iter()
+                .filter(|unit| unit.conversion_factor == 1.0 && unit.affine_offset == 0.0)
+        };
+        if !dim_info.units.is_empty() && storage_units().next().is_none() {
+            return None;
+        }
+
         // Prioritize exact matches of atomic unit exponents (scale factors of [0, 0, 0, 0])
         // over the first unit in the lexical list
-        let preferred_unit = dim_info
-            .units
-            .iter()
-            .find(|unit| unit.scale == ScaleExponents::IDENTITY && unit.conversion_factor == 1.0)
-            .or_else(|| dim_info.units.first()); // Fall back to first unit if no exact match
+        let preferred_unit = storage_units()
+            .find(|unit| unit.scale == ScaleExponents::IDENTITY)
+            .or_else(|| storage_units().next()); // Fall back to first unit if no exact match
 
         let unit_symbol = preferred_unit.and_then(|unit| unit.symbols.first().copied());
         let unit_long_name = preferred_unit.map(|unit| unit.name);
@@ -513,16 +585,16 @@ fn format_scale_factors(scale_p2: i16, scale_p3: i16, scale_p5: i16, scale_pi: i
     let mut value = 1.0;
 
     if scale_p2 != 0 {
-        value *= 2.0_f64.powi(scale_p2 as i32);
+        value *= crate::powi(2.0, scale_p2 as i32);
     }
     if scale_p3 != 0 {
-        value *= 3.0_f64.powi(scale_p3 as i32);
+        value *= crate::powi(3.0, scale_p3 as i32);
     }
     if scale_p5 != 0 {
-        value *= 5.0_f64.powi(scale_p5 as i32);
+        value *= crate::powi(5.0, scale_p5 as i32);
     }
     if scale_pi != 0 {
-        value *= core::f64::consts::PI.powi(scale_pi as i32);
+        value *= crate::powi(core::f64::consts::PI, scale_pi as i32);
     }
 
     // If the value is 1.0, no scaling needed
@@ -542,10 +614,10 @@ fn format_float_with_sig_figs(value: f64, sig_figs: usize) -> String {
     }
 
     let abs_value = value.abs();
-    let magnitude = abs_value.log10().floor() as i32;
-    let scale_factor = 10_f64.powi(sig_figs as i32 - 1 - magnitude as i32);
+    let magnitude = libm::floor(libm::log10(abs_value)) as i32;
+    let scale_factor = crate::powi(10.0, sig_figs as i32 - 1 - magnitude);
 
-    let rounded = (value * scale_factor).round() / scale_factor;
+    let rounded = libm::round(value * scale_factor) / scale_factor;
 
     // Format with appropriate precision
     let formatted = if magnitude >= 0 {
@@ -705,14 +777,15 @@ pub fn get_storage_unit_name_by_dimension_name(
 ) -> String {
     // Map dimension name to exponents
     let dimension_exponents = match dimension_name {
-        "Mass" => DynDimensionExponents([1, 0, 0, 0, 0, 0, 0, 0]),
-        "Length" => DynDimensionExponents([0, 1, 0, 0, 0, 0, 0, 0]),
-        "Time" => DynDimensionExponents([0, 0, 1, 0, 0, 0, 0, 0]),
-        "Current" => DynDimensionExponents([0, 0, 0, 1, 0, 0, 0, 0]),
-        "Temperature" => DynDimensionExponents([0, 0, 0, 0, 1, 0, 0, 0]),
-        "Amount" => DynDimensionExponents([0, 0, 0, 0, 0, 1, 0, 0]),
-        "Luminous Intensity" => DynDimensionExponents([0, 0, 0, 0, 0, 0, 1, 0]),
-        "Angle" => DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 1]),
+        "Mass" => DynDimensionExponents([1, 0, 0, 0, 0, 0, 0, 0, 0]),
+        "Length" => DynDimensionExponents([0, 1, 0, 0, 0, 0, 0, 0, 0]),
+        "Time" => DynDimensionExponents([0, 0, 1, 0, 0, 0, 0, 0, 0]),
+        "Current" => DynDimensionExponents([0, 0, 0, 1, 0, 0, 0, 0, 0]),
+        "Temperature" => DynDimensionExponents([0, 0, 0, 0, 1, 0, 0, 0, 0]),
+        "Amount" => DynDimensionExponents([0, 0, 0, 0, 0, 1, 0, 0, 0]),
+        "Luminous Intensity" => DynDimensionExponents([0, 0, 0, 0, 0, 0, 1, 0, 0]),
+        "Angle" => DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 1, 0]),
+        "Information" => DynDimensionExponents([0, 0, 0, 0, 0, 0, 0, 0, 1]),
         _ => return "unknown".to_string(),
     };
 
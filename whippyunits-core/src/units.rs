@@ -7,10 +7,23 @@ use crate::scale_exponents::ScaleExponents;
 pub enum System {
     /// Metric system (SI and derived units)
     Metric,
-    /// Imperial system (US customary units)
+    /// Imperial system (US customary and British imperial units)
+    ///
+    /// Where the two differ, the unqualified unit is the US customary one (`gal`, `qt`, `pt`,
+    /// `fl_oz`, `ton`) and the British imperial one carries an `imp_` symbol (`imp_gal`,
+    /// `imp_qt`, `imp_pt`, `imp_fl_oz`, `imp_ton`).
+    ///
+    /// The nautical units (`nmi`, `kn`, `ftm`) and the US survey foot (`survey_ft`) are also
+    /// classed here.
     Imperial,
     /// Astronomical system (For extremely )
     Astronomical,
+    /// Units of information (`bit`, `B`)
+    ///
+    /// Information has its own base dimension, so a data rate like `bit/s` is not a frequency.
+    /// These units accept both SI prefixes (`kB` is 1000 bytes) and binary prefixes (`KiB` is
+    /// 1024 bytes).
+    Information,
 }
 
 impl System {
@@ -20,6 +33,7 @@ impl System {
             System::Metric => "Metric",
             System::Imperial => "Imperial",
             System::Astronomical => "Astronomical",
+            System::Information => "Information",
         }
     }
 }
@@ -50,6 +64,12 @@ pub struct Unit<ExponentsType = DynDimensionExponents> {
     /// Name of the unit.
     pub name: &'static str,
 
+    /// Plural of the name, used for declarator method names (e.g. `1.0.meters()`).
+    ///
+    /// Usually the name with an `s` appended, but given explicitly for every unit so that
+    /// irregular plurals ("feet", "henries") and unchanged ones ("hertz", "lux") stay correct.
+    pub plural: &'static str,
+
     /// Symbols associated with the unit.
     ///
     /// Symbols are also used for lookup, so they must be unique within
@@ -139,6 +159,7 @@ impl<
     const AMOUNT_EXP: i16,
     const LUMINOSITY_EXP: i16,
     const ANGLE_EXP: i16,
+    const INFORMATION_EXP: i16,
 >
     Unit<
         crate::dimension_exponents!([
@@ -150,12 +171,14 @@ impl<
             AMOUNT_EXP,
             LUMINOSITY_EXP,
             ANGLE_EXP,
+            INFORMATION_EXP,
         ]),
     >
 {
     pub const fn erase(&self) -> Unit {
         Unit {
             name: self.name,
+            plural: self.plural,
             symbols: self.symbols,
             scale: self.scale,
             conversion_factor: self.conversion_factor,
@@ -168,7 +191,7 @@ impl<
 
 /// Lists of units.
 impl Unit {
-    pub const BASES: [Self; 8] = [
+    pub const BASES: [Self; 9] = [
         Unit::GRAM.erase(),
         Unit::METER.erase(),
         Unit::SECOND.erase(),
@@ -177,6 +200,7 @@ impl Unit {
         Unit::MOLE.erase(),
         Unit::CANDELA.erase(),
         Unit::RADIAN.erase(),
+        Unit::BIT.erase(),
     ];
 
     /// Convert multiple names to their base unit.
@@ -208,9 +232,10 @@ impl Unit {
 }
 
 /// Mass
-impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0, 0])> {
     pub const GRAM: Self = Self {
         name: "gram",
+        plural: "grams",
         symbols: &["g"],
         scale: ScaleExponents::_10(-3),
         conversion_factor: IDENTITY,
@@ -219,8 +244,22 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
         system: System::Metric,
     };
 
+    pub const TONNE: Self = Self {
+        name: "tonne",
+        plural: "tonnes",
+        symbols: &["t"],
+        // Stored in kilograms rather than as a scale of 10³, which would make it the preferred
+        // name for every megagram-scaled storage unit (so kN would print as t·m·s⁻²)
+        scale: ScaleExponents::IDENTITY,
+        conversion_factor: 1000.0, // 1 tonne = 1000 kg
+        affine_offset: NONE,
+        exponents: TypeDimensionExponents::new(),
+        system: System::Metric,
+    };
+
     pub const GRAIN: Self = Self {
         name: "grain",
+        plural: "grains",
         symbols: &["gr"],
         scale: ScaleExponents::_10(-4),
         conversion_factor: 0.6479891,
@@ -231,6 +270,7 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
 
     pub const CARAT: Self = Self {
         name: "carat",
+        plural: "carats",
         symbols: &["ct"],
         scale: ScaleExponents::_10(-4).mul(ScaleExponents::_2(1)),
         conversion_factor: IDENTITY,
@@ -241,6 +281,7 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
 
     pub const OUNCE: Self = Self {
         name: "ounce",
+        plural: "ounces",
         symbols: &["oz"],
         scale: ScaleExponents::_10(-2),
         conversion_factor: 2.8349523125,
@@ -251,6 +292,7 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
 
     pub const TROY_OUNCE: Self = Self {
         name: "troy_ounce",
+        plural: "troy_ounces",
         symbols: &["ozt"],
         scale: ScaleExponents::_10(-2),
         conversion_factor: 3.11034768,
@@ -260,6 +302,7 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
     };
     pub const TROY_POUND: Self = Self {
         name: "troy_pound",
+        plural: "troy_pounds",
         symbols: &["lbt"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: 0.3732417216,
@@ -270,6 +313,7 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
 
     pub const POUND: Self = Self {
         name: "pound",
+        plural: "pounds",
         symbols: &["lb"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: 0.45359237,
@@ -280,6 +324,7 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
 
     pub const STONE: Self = Self {
         name: "stone",
+        plural: "stone",
         symbols: &["st"],
         scale: ScaleExponents::_10(1),
         conversion_factor: 0.635029318,
@@ -290,6 +335,7 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
 
     pub const SLUG: Self = Self {
         name: "slug",
+        plural: "slugs",
         symbols: &["slg"],
         scale: ScaleExponents::_10(1),
         conversion_factor: 1.4593902937206365,
@@ -300,9 +346,21 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
 
     pub const TON: Self = Self {
         name: "ton",
-        symbols: &["t"],
+        plural: "tons",
+        symbols: &["ton"],
+        scale: ScaleExponents::_10(3),
+        conversion_factor: 0.90718474, // 1 US (short) ton = 907.18474 kg
+        affine_offset: NONE,
+        exponents: TypeDimensionExponents::new(),
+        system: System::Imperial,
+    };
+
+    pub const LONG_TON: Self = Self {
+        name: "long_ton",
+        plural: "long_tons",
+        symbols: &["imp_ton"],
         scale: ScaleExponents::_10(3),
-        conversion_factor: 1.0160469088,
+        conversion_factor: 1.0160469088, // 1 imperial (long) ton = 1016.0469088 kg
         affine_offset: NONE,
         exponents: TypeDimensionExponents::new(),
         system: System::Imperial,
@@ -311,9 +369,10 @@ impl Unit<crate::dimension_exponents!([1, 0, 0, 0, 0, 0, 0, 0])> {
 }
 
 /// Length
-impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0, 0])> {
     pub const METER: Self = Self {
         name: "meter",
+        plural: "meters",
         symbols: &["m"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -324,6 +383,7 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 
     pub const INCH: Self = Self {
         name: "inch",
+        plural: "inches",
         symbols: &["in"],
         scale: ScaleExponents::_10(-2),
         conversion_factor: 2.54,
@@ -334,6 +394,7 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 
     pub const FOOT: Self = Self {
         name: "foot",
+        plural: "feet",
         symbols: &["ft"],
         scale: ScaleExponents::_10(-1),
         conversion_factor: 3.048,
@@ -342,8 +403,20 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
         system: System::Imperial,
     };
 
+    pub const SURVEY_FOOT: Self = Self {
+        name: "us_survey_foot",
+        plural: "us_survey_feet",
+        symbols: &["survey_ft"],
+        scale: ScaleExponents::_10(-1),
+        conversion_factor: 3.048006096012192, // 1 US survey foot = 1200/3937 m
+        affine_offset: NONE,
+        exponents: TypeDimensionExponents::new(),
+        system: System::Imperial,
+    };
+
     pub const YARD: Self = Self {
         name: "yard",
+        plural: "yards",
         symbols: &["yd"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: 0.9144,
@@ -354,6 +427,7 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 
     pub const FATHOM: Self = Self {
         name: "fathom",
+        plural: "fathoms",
         symbols: &["ftm"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: 1.8288,
@@ -364,6 +438,7 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 
     pub const FURLONG: Self = Self {
         name: "furlong",
+        plural: "furlongs",
         symbols: &["fur"],
         scale: ScaleExponents::_10(2),
         conversion_factor: 2.01168,
@@ -374,6 +449,7 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 
     pub const MILE: Self = Self {
         name: "mile",
+        plural: "miles",
         symbols: &["mi"],
         scale: ScaleExponents::_10(3),
         conversion_factor: 1.609344,
@@ -384,9 +460,10 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 
     pub const NAUTICAL_MILE: Self = Self {
         name: "nautical_mile",
+        plural: "nautical_miles",
         symbols: &["nmi"],
         scale: ScaleExponents::_10(3),
-        conversion_factor: 1.852,
+        conversion_factor: 1.852, // 1 nautical mile = 1852 m exactly
         affine_offset: NONE,
         exponents: TypeDimensionExponents::new(),
         system: System::Imperial,
@@ -394,6 +471,7 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 
     pub const ASTRONOMICAL_UNIT: Self = Self {
         name: "astronomical_unit",
+        plural: "astronomical_units",
         symbols: &["AU"],
         scale: ScaleExponents::_10(11),
         conversion_factor: 1.495978707,
@@ -404,6 +482,7 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 
     pub const LIGHT_YEAR: Self = Self {
         name: "light_year",
+        plural: "light_years",
         symbols: &["ly"],
         scale: ScaleExponents::_10(16),
         conversion_factor: 0.94607304725808,
@@ -414,6 +493,7 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 
     pub const PARSEC: Self = Self {
         name: "parsec",
+        plural: "parsecs",
         symbols: &["pc"],
         scale: ScaleExponents::_10(16),
         conversion_factor: 3.08567758128,
@@ -424,9 +504,10 @@ impl Unit<crate::dimension_exponents!([0, 1, 0, 0, 0, 0, 0, 0])> {
 }
 
 /// Time
-impl Unit<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0, 0])> {
     pub const SECOND: Self = Self {
         name: "second",
+        plural: "seconds",
         symbols: &["s"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -437,6 +518,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
 
     pub const MINUTE: Self = Self {
         name: "minute",
+        plural: "minutes",
         symbols: &["min"],
         scale: ScaleExponents::_10(1).mul(ScaleExponents::_6(1)),
         conversion_factor: IDENTITY,
@@ -447,6 +529,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
 
     pub const HOUR: Self = Self {
         name: "hour",
+        plural: "hours",
         symbols: &["h", "hr"],
         scale: ScaleExponents::_10(2).mul(ScaleExponents::_6(2)),
         conversion_factor: IDENTITY,
@@ -457,6 +540,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
 
     pub const DAY: Self = Self {
         name: "day",
+        plural: "days",
         symbols: &["d"],
         scale: ScaleExponents::_10(2)
             .mul(ScaleExponents::_6(3))
@@ -469,6 +553,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
 
     pub const WEEK: Self = Self {
         name: "week",
+        plural: "weeks",
         symbols: &["wk"],
         scale: ScaleExponents::_10(3)
             .mul(ScaleExponents::_6(3))
@@ -482,6 +567,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
     pub const MONTH: Self = Self {
         // 30 days
         name: "month",
+        plural: "months",
         symbols: &["mo"],
         scale: ScaleExponents::_10(3)
             .mul(ScaleExponents::_6(4))
@@ -495,6 +581,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
     pub const YEAR: Self = Self {
         // solar year, not calendar year
         name: "year",
+        plural: "years",
         symbols: &["yr"],
         scale: ScaleExponents::_10(7),
         conversion_factor: 3.1556926,
@@ -505,9 +592,10 @@ impl Unit<crate::dimension_exponents!([0, 0, 1, 0, 0, 0, 0, 0])> {
 }
 
 /// Current
-impl Unit<crate::dimension_exponents!([0, 0, 0, 1, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 0, 0, 1, 0, 0, 0, 0, 0])> {
     pub const AMPERE: Self = Self {
         name: "ampere",
+        plural: "amperes",
         symbols: &["A"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -518,9 +606,10 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 1, 0, 0, 0, 0])> {
 }
 
 /// Temperature
-impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0, 0])> {
     pub const KELVIN: Self = Self {
         name: "kelvin",
+        plural: "kelvin",
         symbols: &["K"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -531,6 +620,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0])> {
 
     pub const CELSIUS: Self = Self {
         name: "celsius",
+        plural: "celsius",
         symbols: &["degC"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -541,6 +631,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0])> {
 
     pub const RANKINE: Self = Self {
         name: "rankine",
+        plural: "rankine",
         symbols: &["degR"],
         scale: ScaleExponents([0, -2, 1, 0]),
         conversion_factor: IDENTITY,
@@ -551,19 +642,21 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 1, 0, 0, 0])> {
 
     pub const FAHRENHEIT: Self = Self {
         name: "fahrenheit",
+        plural: "fahrenheit",
         symbols: &["degF"],
         scale: ScaleExponents([0, -2, 1, 0]),
         conversion_factor: IDENTITY,
-        affine_offset: 459.7,
+        affine_offset: 459.67,
         exponents: TypeDimensionExponents::new(),
         system: System::Imperial,
     };
 }
 
 /// Amount
-impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 1, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 1, 0, 0, 0])> {
     pub const MOLE: Self = Self {
         name: "mole",
+        plural: "moles",
         symbols: &["mol"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -574,9 +667,10 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 1, 0, 0])> {
 }
 
 /// Angle
-impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1])> {
+impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1, 0])> {
     pub const RADIAN: Self = Self {
         name: "radian",
+        plural: "radians",
         symbols: &["rad"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -587,6 +681,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1])> {
 
     pub const DEGREE: Self = Self {
         name: "degree",
+        plural: "degrees",
         symbols: &["deg"],
         scale: ScaleExponents([-2, -2, -1, 1]),
         conversion_factor: IDENTITY,
@@ -597,6 +692,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1])> {
 
     pub const GRADIAN: Self = Self {
         name: "gradian",
+        plural: "gradians",
         symbols: &["grad"],
         scale: ScaleExponents([-3, -1, -1, 1]),
         conversion_factor: IDENTITY,
@@ -607,6 +703,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1])> {
 
     pub const TURN: Self = Self {
         name: "turn",
+        plural: "turns",
         symbols: &["rot", "turn"],
         scale: ScaleExponents([1, 0, 0, 1]),
         conversion_factor: IDENTITY,
@@ -617,6 +714,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1])> {
 
     pub const ARCMINUTE: Self = Self {
         name: "arcminute",
+        plural: "arcminutes",
         symbols: &["arcmin"],
         scale: ScaleExponents([-4, -2, -2, 1]),
         conversion_factor: IDENTITY,
@@ -627,6 +725,7 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1])> {
 
     pub const ARCSECOND: Self = Self {
         name: "arcsecond",
+        plural: "arcseconds",
         symbols: &["arcsec"],
         scale: ScaleExponents([-6, -2, -2, 1]),
         conversion_factor: IDENTITY,
@@ -637,9 +736,10 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 1])> {
 }
 
 /// Frequency
-impl Unit<crate::dimension_exponents!([0, 0, -1, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 0, -1, 0, 0, 0, 0, 0, 0])> {
     pub const HERTZ: Self = Self {
         name: "hertz",
+        plural: "hertz",
         symbols: &["Hz"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -649,10 +749,26 @@ impl Unit<crate::dimension_exponents!([0, 0, -1, 0, 0, 0, 0, 0])> {
     };
 }
 
+/// Velocity
+impl Unit<crate::dimension_exponents!([0, 1, -1, 0, 0, 0, 0, 0, 0])> {
+    pub const KNOT: Self = Self {
+        name: "knot",
+        plural: "knots",
+        symbols: &["kn"],
+        // Stored in km/h: 1 knot = 1 nmi/h = 1.852 km/h
+        scale: ScaleExponents::_10(1).mul(ScaleExponents::_6(-2)),
+        conversion_factor: 1.852,
+        affine_offset: NONE,
+        exponents: TypeDimensionExponents::new(),
+        system: System::Imperial,
+    };
+}
+
 /// Force
-impl Unit<crate::dimension_exponents!([1, 1, -2, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, 1, -2, 0, 0, 0, 0, 0, 0])> {
     pub const NEWTON: Self = Self {
         name: "newton",
+        plural: "newtons",
         symbols: &["N"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -663,9 +779,10 @@ impl Unit<crate::dimension_exponents!([1, 1, -2, 0, 0, 0, 0, 0])> {
 }
 
 /// Energy and work
-impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0, 0])> {
     pub const JOULE: Self = Self {
         name: "joule",
+        plural: "joules",
         symbols: &["J"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -676,6 +793,7 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
 
     pub const NEWTON_METER: Self = Self {
         name: "newton_meter",
+        plural: "newton_meters",
         symbols: &["Nm"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -686,6 +804,7 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
 
     pub const ELECTRONVOLT: Self = Self {
         name: "electron_volt",
+        plural: "electron_volts",
         symbols: &["eV"],
         scale: ScaleExponents::_10(-19),
         conversion_factor: 1.602176634,
@@ -696,6 +815,7 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
 
     pub const ERG: Self = Self {
         name: "erg",
+        plural: "ergs",
         symbols: &["erg"],
         scale: ScaleExponents::_10(-7),
         conversion_factor: 1.0,
@@ -706,6 +826,7 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
 
     pub const CALORIE: Self = Self {
         name: "calorie",
+        plural: "calories",
         symbols: &["cal"],
         scale: ScaleExponents::_10(1),
         conversion_factor: 0.4184,
@@ -716,6 +837,7 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
 
     pub const FOOT_POUND: Self = Self {
         name: "foot_pound",
+        plural: "foot_pounds",
         symbols: &["ft_lb"],
         scale: ScaleExponents::_10(1),
         conversion_factor: 1.3558179483314004,
@@ -726,6 +848,7 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
 
     pub const KILOWATT_HOUR: Self = Self {
         name: "kilowatt_hour",
+        plural: "kilowatt_hours",
         symbols: &["kWh"],
         scale: ScaleExponents::_10(5).mul(ScaleExponents::_6(2)),
         conversion_factor: IDENTITY,
@@ -736,6 +859,7 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
 
     pub const THERM: Self = Self {
         name: "therm",
+        plural: "therms",
         symbols: &["thm"],
         scale: ScaleExponents::_10(8),
         conversion_factor: 1.05505585262,
@@ -746,9 +870,10 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, 0, 0, 0, 0, 0])> {
 }
 
 /// Power
-impl Unit<crate::dimension_exponents!([1, 2, -3, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, 2, -3, 0, 0, 0, 0, 0, 0])> {
     pub const WATT: Self = Self {
         name: "watt",
+        plural: "watts",
         symbols: &["W"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -759,6 +884,7 @@ impl Unit<crate::dimension_exponents!([1, 2, -3, 0, 0, 0, 0, 0])> {
 
     pub const HORSEPOWER: Self = Self {
         name: "horsepower",
+        plural: "horsepower",
         symbols: &["hp"],
         scale: ScaleExponents::_10(3),
         conversion_factor: 0.7456998715822702,
@@ -769,9 +895,10 @@ impl Unit<crate::dimension_exponents!([1, 2, -3, 0, 0, 0, 0, 0])> {
 }
 
 /// Pressure
-impl Unit<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0, 0])> {
     pub const PASCAL: Self = Self {
         name: "pascal",
+        plural: "pascals",
         symbols: &["Pa"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -782,6 +909,7 @@ impl Unit<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0])> {
 
     pub const TORR: Self = Self {
         name: "torr",
+        plural: "torr",
         symbols: &["Torr"],
         scale: ScaleExponents::_10(2),
         conversion_factor: 1.3332236842105263,
@@ -792,6 +920,7 @@ impl Unit<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0])> {
 
     pub const PSI: Self = Self {
         name: "psi",
+        plural: "psi",
         symbols: &["psi"],
         scale: ScaleExponents::_10(4),
         conversion_factor: 0.6894757293168361,
@@ -802,6 +931,7 @@ impl Unit<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0])> {
 
     pub const BAR: Self = Self {
         name: "bar",
+        plural: "bar",
         symbols: &["bar"],
         scale: ScaleExponents::_10(5),
         conversion_factor: IDENTITY,
@@ -812,6 +942,7 @@ impl Unit<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0])> {
 
     pub const ATMOSPHERE: Self = Self {
         name: "atmosphere",
+        plural: "atmospheres",
         symbols: &["atm"],
         scale: ScaleExponents::_10(5),
         conversion_factor: 1.01325,
@@ -822,9 +953,10 @@ impl Unit<crate::dimension_exponents!([1, -1, -2, 0, 0, 0, 0, 0])> {
 }
 
 /// Electric charge
-impl Unit<crate::dimension_exponents!([0, 0, 1, 1, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 0, 1, 1, 0, 0, 0, 0, 0])> {
     pub const COULOMB: Self = Self {
         name: "coulomb",
+        plural: "coulombs",
         symbols: &["C"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -835,9 +967,10 @@ impl Unit<crate::dimension_exponents!([0, 0, 1, 1, 0, 0, 0, 0])> {
 }
 
 /// Electric potential
-impl Unit<crate::dimension_exponents!([1, 2, -3, -1, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, 2, -3, -1, 0, 0, 0, 0, 0])> {
     pub const VOLT: Self = Self {
         name: "volt",
+        plural: "volts",
         symbols: &["V"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -848,9 +981,10 @@ impl Unit<crate::dimension_exponents!([1, 2, -3, -1, 0, 0, 0, 0])> {
 }
 
 /// Capacitance
-impl Unit<crate::dimension_exponents!([-1, -2, 4, 2, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([-1, -2, 4, 2, 0, 0, 0, 0, 0])> {
     pub const FARAD: Self = Self {
         name: "farad",
+        plural: "farads",
         symbols: &["F"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -861,9 +995,10 @@ impl Unit<crate::dimension_exponents!([-1, -2, 4, 2, 0, 0, 0, 0])> {
 }
 
 // Electric resistance
-impl Unit<crate::dimension_exponents!([1, 2, -3, -2, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, 2, -3, -2, 0, 0, 0, 0, 0])> {
     pub const OHM: Self = Self {
         name: "ohm",
+        plural: "ohms",
         symbols: &["Ω"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -874,9 +1009,10 @@ impl Unit<crate::dimension_exponents!([1, 2, -3, -2, 0, 0, 0, 0])> {
 }
 
 /// Electric conductance
-impl Unit<crate::dimension_exponents!([-1, -2, 3, 2, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([-1, -2, 3, 2, 0, 0, 0, 0, 0])> {
     pub const SIEMENS: Self = Self {
         name: "siemens",
+        plural: "siemens",
         symbols: &["S"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -887,9 +1023,10 @@ impl Unit<crate::dimension_exponents!([-1, -2, 3, 2, 0, 0, 0, 0])> {
 }
 
 /// Inductance
-impl Unit<crate::dimension_exponents!([1, 2, -2, -2, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, 2, -2, -2, 0, 0, 0, 0, 0])> {
     pub const HENRY: Self = Self {
         name: "henry",
+        plural: "henries",
         symbols: &["H"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -900,9 +1037,10 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, -2, 0, 0, 0, 0])> {
 }
 
 /// Magnetic field
-impl Unit<crate::dimension_exponents!([1, 0, -2, -1, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, 0, -2, -1, 0, 0, 0, 0, 0])> {
     pub const TESLA: Self = Self {
         name: "tesla",
+        plural: "teslas",
         symbols: &["T"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -913,6 +1051,7 @@ impl Unit<crate::dimension_exponents!([1, 0, -2, -1, 0, 0, 0, 0])> {
 
     pub const GAUSS: Self = Self {
         name: "gauss",
+        plural: "gauss",
         symbols: &["G"],
         scale: ScaleExponents::_10(-4),
         conversion_factor: IDENTITY,
@@ -923,9 +1062,10 @@ impl Unit<crate::dimension_exponents!([1, 0, -2, -1, 0, 0, 0, 0])> {
 }
 
 /// Magnetic flex
-impl Unit<crate::dimension_exponents!([1, 2, -2, -1, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([1, 2, -2, -1, 0, 0, 0, 0, 0])> {
     pub const WEBER: Self = Self {
         name: "weber",
+        plural: "webers",
         symbols: &["Wb"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -936,9 +1076,10 @@ impl Unit<crate::dimension_exponents!([1, 2, -2, -1, 0, 0, 0, 0])> {
 }
 
 /// Illuminance
-impl Unit<crate::dimension_exponents!([0, -2, 0, 0, 0, 0, 1, 0])> {
+impl Unit<crate::dimension_exponents!([0, -2, 0, 0, 0, 0, 1, 0, 0])> {
     pub const LUX: Self = Self {
         name: "lux",
+        plural: "lux",
         symbols: &["lx"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -949,6 +1090,7 @@ impl Unit<crate::dimension_exponents!([0, -2, 0, 0, 0, 0, 1, 0])> {
 
     pub const LUMEN: Self = Self {
         name: "lumen",
+        plural: "lumens",
         symbols: &["lm"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -959,9 +1101,10 @@ impl Unit<crate::dimension_exponents!([0, -2, 0, 0, 0, 0, 1, 0])> {
 }
 
 /// Luminous intensity
-impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 1, 0])> {
+impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 1, 0, 0])> {
     pub const CANDELA: Self = Self {
         name: "candela",
+        plural: "candela",
         symbols: &["cd"],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -972,9 +1115,10 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 1, 0])> {
 }
 
 /// Kinematic viscosity
-impl Unit<crate::dimension_exponents!([0, 2, -1, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 2, -1, 0, 0, 0, 0, 0, 0])> {
     pub const STOKES: Self = Self {
         name: "stokes",
+        plural: "stokes",
         symbols: &["St"],
         scale: ScaleExponents::_10(-4),
         conversion_factor: IDENTITY,
@@ -985,9 +1129,10 @@ impl Unit<crate::dimension_exponents!([0, 2, -1, 0, 0, 0, 0, 0])> {
 }
 
 /// Area
-impl Unit<crate::dimension_exponents!([0, 2, 0, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 2, 0, 0, 0, 0, 0, 0, 0])> {
     pub const HECTARE: Self = Self {
         name: "hectare",
+        plural: "hectares",
         symbols: &["hect"],
         scale: ScaleExponents::_10(4),
         conversion_factor: IDENTITY, // 1 hectare = 10,000 m²
@@ -998,6 +1143,7 @@ impl Unit<crate::dimension_exponents!([0, 2, 0, 0, 0, 0, 0, 0])> {
 
     pub const ACRE: Self = Self {
         name: "acre",
+        plural: "acres",
         symbols: &["acre"],
         scale: ScaleExponents::_10(3),
         conversion_factor: 0.40468564224, // 1 acre = 4046.8564224 m²
@@ -1008,10 +1154,11 @@ impl Unit<crate::dimension_exponents!([0, 2, 0, 0, 0, 0, 0, 0])> {
 }
 
 /// Volume
-impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0, 0])> {
     // Metric volume units
     pub const LITER: Self = Self {
         name: "liter",
+        plural: "liters",
         symbols: &["L", "l"],
         scale: ScaleExponents::_10(-3),
         conversion_factor: IDENTITY,
@@ -1022,6 +1169,7 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const GALLON_US: Self = Self {
         name: "gallon",
+        plural: "gallons",
         symbols: &["gal", "gallon"],
         scale: ScaleExponents::_10(-2),
         conversion_factor: 0.3785411784, // 1 US gallon = 3.785411784 L
@@ -1032,7 +1180,8 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const GALLON_UK: Self = Self {
         name: "uk_gallon",
-        symbols: &["uk_gal"],
+        plural: "uk_gallons",
+        symbols: &["uk_gal", "imp_gal"],
         scale: ScaleExponents::_10(-2),
         conversion_factor: 0.454609, // 1 UK gallon = 4.54609 L
         affine_offset: NONE,
@@ -1042,7 +1191,8 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const QUART_US: Self = Self {
         name: "quart",
-        symbols: &["qrt"],
+        plural: "quarts",
+        symbols: &["qrt", "qt"],
         scale: ScaleExponents::_10(-3),
         conversion_factor: 0.946352946, // 1 US quart = 0.946352946 L
         affine_offset: NONE,
@@ -1052,7 +1202,8 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const QUART_UK: Self = Self {
         name: "uk_quart",
-        symbols: &["uk_qrt"],
+        plural: "uk_quarts",
+        symbols: &["uk_qrt", "imp_qt"],
         scale: ScaleExponents::_10(-3),
         conversion_factor: 1.1365225, // 1 UK quart = 1.1365225 L
         affine_offset: NONE,
@@ -1062,7 +1213,8 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const PINT_US: Self = Self {
         name: "pint",
-        symbols: &["pnt"],
+        plural: "pints",
+        symbols: &["pnt", "pt"],
         scale: ScaleExponents::_10(-3),
         conversion_factor: 0.473176473, // 1 US pint = 0.473176473 L
         affine_offset: NONE,
@@ -1072,7 +1224,8 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const PINT_UK: Self = Self {
         name: "uk_pint",
-        symbols: &["uk_pnt"],
+        plural: "uk_pints",
+        symbols: &["uk_pnt", "imp_pt"],
         scale: ScaleExponents::_10(-3),
         conversion_factor: 0.56826125, // 1 UK pint = 0.56826125 L
         affine_offset: NONE,
@@ -1082,6 +1235,7 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const CUP_US: Self = Self {
         name: "cup",
+        plural: "cups",
         symbols: &["cup"],
         scale: ScaleExponents::_10(-4),
         conversion_factor: 2.365882365, // 1 US cup = 0.2365882365 L
@@ -1092,7 +1246,8 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const CUP_UK: Self = Self {
         name: "uk_cup",
-        symbols: &["uk_cup"],
+        plural: "uk_cups",
+        symbols: &["uk_cup", "imp_cup"],
         scale: ScaleExponents::_10(-4),
         conversion_factor: 2.84130625, // 1 UK cup = 0.284130625 L
         affine_offset: NONE,
@@ -1102,6 +1257,7 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const FLUID_OUNCE_US: Self = Self {
         name: "fluid_ounce",
+        plural: "fluid_ounces",
         symbols: &["fl_oz"],
         scale: ScaleExponents::_10(-5),
         conversion_factor: 2.95735295625,
@@ -1112,7 +1268,8 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const FLUID_OUNCE_UK: Self = Self {
         name: "uk_fluid_ounce",
-        symbols: &["uk_fl_oz"],
+        plural: "uk_fluid_ounces",
+        symbols: &["uk_fl_oz", "imp_fl_oz"],
         scale: ScaleExponents::_10(-5),
         conversion_factor: 2.84130625,
         affine_offset: NONE,
@@ -1122,6 +1279,7 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const METRIC_FLUID_OUNCE: Self = Self {
         name: "metric_fluid_ounce",
+        plural: "metric_fluid_ounces",
         symbols: &["metric_fl_oz"],
         scale: ScaleExponents::_10(-5).mul(ScaleExponents::_3(1)),
         conversion_factor: IDENTITY,
@@ -1132,6 +1290,7 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const TABLESPOON_US: Self = Self {
         name: "tablespoon",
+        plural: "tablespoons",
         symbols: &["tbsp"],
         scale: ScaleExponents::_10(-5),
         conversion_factor: 1.478676478125,
@@ -1142,7 +1301,8 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const TABLESPOON_UK: Self = Self {
         name: "uk_tablespoon",
-        symbols: &["uk_tbsp"],
+        plural: "uk_tablespoons",
+        symbols: &["uk_tbsp", "imp_tbsp"],
         scale: ScaleExponents::_10(-5),
         conversion_factor: 1.77581640625,
         affine_offset: NONE,
@@ -1152,6 +1312,7 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const TEASPOON_US: Self = Self {
         name: "teaspoon",
+        plural: "teaspoons",
         symbols: &["tsp"],
         scale: ScaleExponents::_10(-5),
         conversion_factor: 0.492892159375,
@@ -1162,7 +1323,8 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const TEASPOON_UK: Self = Self {
         name: "uk_teaspoon",
-        symbols: &["uk_tsp"],
+        plural: "uk_teaspoons",
+        symbols: &["uk_tsp", "imp_tsp"],
         scale: ScaleExponents::_10(-5),
         conversion_factor: 0.59193880208333,
         affine_offset: NONE,
@@ -1172,6 +1334,7 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 
     pub const BUSHEL: Self = Self {
         name: "bushel",
+        plural: "bushels",
         symbols: &["bu"],
         scale: ScaleExponents::_10(-1),
         conversion_factor: 0.3523907016688,
@@ -1182,9 +1345,10 @@ impl Unit<crate::dimension_exponents!([0, 3, 0, 0, 0, 0, 0, 0])> {
 }
 
 /// Dimensionless
-impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0])> {
+impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0, 0])> {
     pub const DIMENSIONLESS: Self = Self {
         name: "dimensionless",
+        plural: "dimensionless",
         symbols: &[],
         scale: ScaleExponents::IDENTITY,
         conversion_factor: IDENTITY,
@@ -1194,6 +1358,31 @@ impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0])> {
     };
 }
 
+/// Information
+impl Unit<crate::dimension_exponents!([0, 0, 0, 0, 0, 0, 0, 0, 1])> {
+    pub const BIT: Self = Self {
+        name: "bit",
+        plural: "bits",
+        symbols: &["bit"],
+        scale: ScaleExponents::IDENTITY,
+        conversion_factor: IDENTITY,
+        affine_offset: NONE,
+        exponents: TypeDimensionExponents::new(),
+        system: System::Information,
+    };
+
+    pub const BYTE: Self = Self {
+        name: "byte",
+        plural: "bytes",
+        symbols: &["B"],
+        scale: ScaleExponents::_2(3),
+        conversion_factor: IDENTITY,
+        affine_offset: NONE,
+        exponents: TypeDimensionExponents::new(),
+        system: System::Information,
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1217,4 +1406,76 @@ mod tests {
 
         assert_eq!(Unit::multiple_to_base_unit("abc"), None,);
     }
+
+    #[test]
+    fn every_unit_has_a_plausible_plural() {
+        for dimension in crate::Dimension::ALL {
+            for unit in dimension.units {
+                let (name, plural) = (unit.name, crate::make_plural(unit.name));
+                assert_eq!(plural, unit.plural);
+
+                // Unchanged plurals ("hertz", "lux") are always acceptable
+                if plural == name {
+                    continue;
+                }
+
+                // Sibilant endings take "es", never a bare "s" ("inchs", "gausss")
+                let bare_s = format!("{}s", name);
+                if ["s", "x", "z", "ch", "sh"]
+                    .iter()
+                    .any(|end| name.ends_with(end))
+                {
+                    assert_ne!(plural, bare_s, "unit `{}` needs a plural exception", name);
+                }
+
+                // Consonant + "y" takes "ies" ("henries")
+                let mut letters = name.chars().rev();
+                if letters.next() == Some('y')
+                    && letters.next().is_some_and(|c| !"aeiou".contains(c))
+                {
+                    assert_ne!(plural, bare_s, "unit `{}` needs a plural exception", name);
+                }
+
+                // Irregular plurals must not be pluralized twice or regularized ("feets", "foots")
+                assert!(
+                    !plural.ends_with("foots") && !plural.ends_with("feets"),
+                    "unit `{}` has plural `{}`",
+                    name,
+                    plural
+                );
+
+                // Plurals become declarator method names
+                assert!(
+                    plural.chars().all(|c| c.is_ascii_lowercase() || c == '_'),
+                    "unit `{}` has plural `{}`, which is not a method name",
+                    name,
+                    plural
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn plurals_of_unregistered_names_append_s() {
+        assert_eq!(crate::make_plural("widget"), "widgets");
+        assert_eq!(crate::make_plural("foot"), "feet");
+        assert_eq!(crate::make_plural("siemens"), "siemens");
+    }
+
+    #[test]
+    fn plurals_keep_prefixes_and_compound_terms() {
+        assert_eq!(crate::make_plural("kilometer"), "kilometers");
+        assert_eq!(crate::make_plural("kilohertz"), "kilohertz");
+        assert_eq!(crate::make_plural("millihenry"), "millihenries");
+
+        let plural = |name| crate::PluralFmt(name).to_string();
+        assert_eq!(plural("foot"), "feet");
+        assert_eq!(plural("meter"), "meters");
+        assert_eq!(plural("hertz"), "hertz");
+        assert_eq!(plural("foot/second"), "feet/second");
+        assert_eq!(plural("kilometer/hour"), "kilometers/hour");
+        assert_eq!(plural("newton*meter"), "newtons*meter");
+        assert_eq!(plural("ft"), "ft");
+        assert_eq!(plural("km/h"), "km/h");
+    }
 }
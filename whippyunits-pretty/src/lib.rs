@@ -1 +1,4 @@
 pub mod rustc_pretty;
+
+pub use rustc_pretty::pretty_print_type_line;
+pub use whippyunits_lsp_proxy::unit_formatter::DisplayConfig;
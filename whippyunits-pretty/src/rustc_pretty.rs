@@ -22,13 +22,32 @@ impl RustcPrettyPrinter {
     }
 
     /// Process a complete rustc output string
+    ///
+    /// rustc may wrap a long `Quantity<...>` type across several lines, so lines are first
+    /// grouped until every `Quantity<` opened in them is closed again, and each group is
+    /// rejoined into a single line before conversion.  A group whose types cannot be converted
+    /// is split back into its original lines.
     pub fn process_rustc_output(&mut self, output: &str) -> Result<String> {
         let lines: Vec<&str> = output.lines().collect();
         let mut processed_lines = Vec::new();
 
-        for line in lines {
-            let processed = self.process_line(line)?;
-            processed_lines.push(processed);
+        let mut start = 0;
+        while start < lines.len() {
+            let end = wrapped_type_end(&lines, start);
+            if end == start {
+                processed_lines.push(self.process_line(lines[start])?);
+            } else {
+                let joined = join_wrapped_lines(&lines[start..=end]);
+                let processed = self.process_line(&joined)?;
+                if processed == joined {
+                    for line in &lines[start..=end] {
+                        processed_lines.push(self.process_line(line)?);
+                    }
+                } else {
+                    processed_lines.push(processed);
+                }
+            }
+            start = end + 1;
         }
 
         Ok(processed_lines.join("\n"))
@@ -36,44 +55,138 @@ impl RustcPrettyPrinter {
 
     /// Process a single line of rustc output
     pub fn process_line(&mut self, line: &str) -> Result<String> {
-        // Check if this line contains whippyunits types using the same logic as LSP proxy
-        if self.contains_whippyunits_types(line) {
-            debug!("Processing line with whippyunits types: {}", line);
+        Ok(pretty_print_type_line_with(
+            &self.formatter,
+            line,
+            &self.display_config,
+        ))
+    }
+}
 
-            // Apply type conversion using the updated formatter
-            let processed = self.formatter.format_types(line, &self.display_config);
+/// Pretty-print the whippyunits types in a single line of text
+///
+/// This is the stateless transform applied by [`RustcPrettyPrinter::process_line`], exposed
+/// so that other tools can post-process saved compiler output without a printer instance.
+/// Lines without whippyunits types are returned unchanged.
+pub fn pretty_print_type_line(line: &str, config: &DisplayConfig) -> String {
+    pretty_print_type_line_with(&UnitFormatter::new(), line, config)
+}
 
-            // If we made changes, log them
-            if processed != line {
-                debug!("Transformed: {} -> {}", line, processed);
-            }
+fn pretty_print_type_line_with(
+    formatter: &UnitFormatter,
+    line: &str,
+    config: &DisplayConfig,
+) -> String {
+    // Check if this line contains whippyunits types using the same logic as LSP proxy
+    if contains_whippyunits_types(line) {
+        debug!("Processing line with whippyunits types: {}", line);
+
+        // Apply type conversion using the updated formatter
+        let processed = formatter.format_types(line, config);
 
-            Ok(processed)
-        } else {
-            // No whippyunits types, pass through unchanged
-            Ok(line.to_string())
+        // If we made changes, log them
+        if processed != line {
+            debug!("Transformed: {} -> {}", line, processed);
         }
+
+        processed
+    } else {
+        // No whippyunits types, pass through unchanged
+        line.to_string()
     }
+}
+
+/// Most lines a single wrapped `Quantity<...>` type is expected to span
+const MAX_WRAPPED_LINES: usize = 16;
 
-    /// Check if a line contains whippyunits types using the same logic as LSP proxy
-    fn contains_whippyunits_types(&self, line: &str) -> bool {
-        // Check for the basic Quantity pattern first
-        if !line.contains("Quantity") {
-            return false;
+/// The index of the last line of the group starting at `start`, which is `start` itself unless
+/// a `Quantity<` opened on it is only closed on a later line
+///
+/// Angle brackets are counted so that nested generics (`Quantity<..., Vec<f64>>`) close the
+/// type only at its own `>`.  If the type is not closed within [MAX_WRAPPED_LINES], or before
+/// the end of the output, the line is taken to stand alone.
+fn wrapped_type_end(lines: &[&str], start: usize) -> usize {
+    let mut depth = 0usize;
+    for (index, line) in lines.iter().enumerate().skip(start).take(MAX_WRAPPED_LINES) {
+        depth = quantity_depth_after(line, depth);
+        if depth == 0 {
+            return index;
         }
+    }
+    start
+}
 
-        // For new format with Scale and Dimension structs
-        if line.contains("Scale") && line.contains("Dimension") {
-            return true;
+/// The angle bracket depth within `Quantity<...>` types after `line`, starting from `depth`
+///
+/// Brackets outside of `Quantity<...>` types, such as comparisons in quoted source lines, are
+/// ignored, as is the `>` of `->`.
+fn quantity_depth_after(line: &str, mut depth: usize) -> usize {
+    let mut rest = line;
+    loop {
+        if depth == 0 {
+            match rest.find("Quantity<") {
+                Some(position) => {
+                    depth = 1;
+                    rest = &rest[position + "Quantity<".len()..];
+                }
+                None => return 0,
+            }
         }
 
-        // For old format, check for Quantity< pattern
-        if line.contains("Quantity<") {
-            return true;
+        let mut closed_at = None;
+        let mut previous = None;
+        for (position, c) in rest.char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' if previous != Some('-') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        closed_at = Some(position + 1);
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            previous = Some(c);
         }
 
-        false
+        match closed_at {
+            Some(position) => rest = &rest[position..],
+            None => return depth,
+        }
+    }
+}
+
+/// Rejoin lines that rustc wrapped inside a type, dropping the indentation of continuations
+fn join_wrapped_lines(lines: &[&str]) -> String {
+    let mut joined = lines[0].trim_end().to_string();
+    for line in &lines[1..] {
+        if joined.ends_with(',') {
+            joined.push(' ');
+        }
+        joined.push_str(line.trim());
     }
+    joined
+}
+
+/// Check if a line contains whippyunits types using the same logic as LSP proxy
+fn contains_whippyunits_types(line: &str) -> bool {
+    // Check for the basic Quantity pattern first
+    if !line.contains("Quantity") {
+        return false;
+    }
+
+    // For new format with Scale and Dimension structs
+    if line.contains("Scale") && line.contains("Dimension") {
+        return true;
+    }
+
+    // For old format, check for Quantity< pattern
+    if line.contains("Quantity<") {
+        return true;
+    }
+
+    false
 }
 
 #[cfg(test)]
@@ -118,17 +231,77 @@ mod tests {
     }
 
     #[test]
-    fn test_contains_whippyunits_types() {
-        let printer = RustcPrettyPrinter::new();
+    fn test_pretty_print_type_line() {
+        let config = DisplayConfig::default();
+
+        let line = "  = note: expected struct `Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>`";
+        let processed = pretty_print_type_line(line, &config);
+
+        // Should match the stateful printer exactly
+        let mut printer = RustcPrettyPrinter::with_config(config.clone());
+        assert_eq!(processed, printer.process_line(line).unwrap());
+        assert!(!processed.contains("_L<1>"));
+
+        // Lines without whippyunits types pass through unchanged
+        let plain = "error[E0308]: mismatched types";
+        assert_eq!(pretty_print_type_line(plain, &config), plain);
+    }
+
+    #[test]
+    fn test_wrapped_type_processing() {
+        let mut printer = RustcPrettyPrinter::new();
+
+        // A type wrapped across three lines, with a nested generic in its storage type
+        let rustc_output = r#"error[E0308]: mismatched types
+  = note: expected struct `Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>,
+              Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>,
+              Vec<f64>>`
+             found type `{float}`"#;
+
+        let processed = printer.process_rustc_output(rustc_output).unwrap();
+        let lines: Vec<&str> = processed.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "error[E0308]: mismatched types");
+        assert!(lines[1].starts_with("  = note: expected struct `Quantity<m, Vec"));
+        assert!(!lines[1].contains("_L<1>"));
+        assert_eq!(lines[2], "             found type `{float}`");
+
+        // The rejoined type converts just as it would have on a single line
+        let single_line = "  = note: expected struct `Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, Vec<f64>>`";
+        assert_eq!(lines[1], printer.process_line(single_line).unwrap());
+    }
 
+    #[test]
+    fn test_wrapped_type_grouping() {
+        let lines = [
+            "expected `Quantity<Scale<_2<0>>,",
+            "    Dimension<_M<0>, _L<1>>,",
+            "    f64>`, found `fn() -> f64`",
+            "5 |     if a < b {",
+        ];
+        assert_eq!(wrapped_type_end(&lines, 0), 2);
+        assert_eq!(wrapped_type_end(&lines, 3), 3);
+        assert_eq!(
+            join_wrapped_lines(&lines[..3]),
+            "expected `Quantity<Scale<_2<0>>, Dimension<_M<0>, _L<1>>, f64>`, found `fn() -> f64`"
+        );
+
+        // An unclosed type at the end of the output stands alone
+        let unclosed = ["let x: Quantity<Scale<_2<0>>,", "error: aborting"];
+        assert_eq!(wrapped_type_end(&unclosed, 0), 0);
+    }
+
+    #[test]
+    fn test_contains_whippyunits_types() {
         // Test new format
-        assert!(printer.contains_whippyunits_types("Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>"));
+        assert!(contains_whippyunits_types("Quantity<Scale<_2<0>, _3<0>, _5<0>, _Pi<0>>, Dimension<_M<0>, _L<1>, _T<0>, _I<0>, _Θ<0>, _N<0>, _J<0>, _A<0>>, f64>"));
 
         // Test old format
-        assert!(printer.contains_whippyunits_types("Quantity<0, 9223372036854775807, 1, 0, 0, 9223372036854775807, 9223372036854775807, 9223372036854775807>"));
+        assert!(contains_whippyunits_types("Quantity<0, 9223372036854775807, 1, 0, 0, 9223372036854775807, 9223372036854775807, 9223372036854775807>"));
 
         // Test non-whippyunits types
-        assert!(!printer.contains_whippyunits_types("let x: String = \"hello\";"));
-        assert!(!printer.contains_whippyunits_types("let x: i32 = 42;"));
+        assert!(!contains_whippyunits_types("let x: String = \"hello\";"));
+        assert!(!contains_whippyunits_types("let x: i32 = 42;"));
     }
 }